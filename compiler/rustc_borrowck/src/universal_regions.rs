@@ -378,6 +378,7 @@ pub(crate) fn annotate(&self, tcx: TyCtxt<'tcx>, err: &mut Diag<'_, ()>) {
                 });
             }
             DefiningTy::CoroutineClosure(..) => {
+                // tidy-allow: debug
                 todo!()
             }
             DefiningTy::Coroutine(def_id, args) => {
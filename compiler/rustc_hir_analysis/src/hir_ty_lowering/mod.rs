@@ -2146,6 +2146,7 @@ pub fn lower_ty(&self, hir_ty: &hir::Ty<'tcx>) -> Ty<'tcx> {
                                         Err(LitToConstError::Reported(err)) => {
                                             ty::Const::new_error(tcx, err)
                                         }
+                                        // tidy-allow: debug
                                         Err(LitToConstError::TypeError) => todo!(),
                                     };
                                     (ct, ty)
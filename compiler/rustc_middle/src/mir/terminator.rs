@@ -310,6 +310,7 @@ pub fn diagnostic_message(&self) -> DiagMessage {
                 middle_assert_async_resume_after_return
             }
             ResumedAfterReturn(CoroutineKind::Desugared(CoroutineDesugaring::AsyncGen, _)) => {
+                // tidy-allow: debug
                 todo!()
             }
             ResumedAfterReturn(CoroutineKind::Desugared(CoroutineDesugaring::Gen, _)) => {
@@ -322,6 +323,7 @@ pub fn diagnostic_message(&self) -> DiagMessage {
                 middle_assert_async_resume_after_panic
             }
             ResumedAfterPanic(CoroutineKind::Desugared(CoroutineDesugaring::AsyncGen, _)) => {
+                // tidy-allow: debug
                 todo!()
             }
             ResumedAfterPanic(CoroutineKind::Desugared(CoroutineDesugaring::Gen, _)) => {
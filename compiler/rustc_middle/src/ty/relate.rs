@@ -67,6 +67,7 @@ fn relate<R: TypeRelation<TyCtxt<'tcx>>>(
                 let start = relate_opt_const(start_a, start_b)?;
                 let end = relate_opt_const(end_a, end_b)?;
                 if inc_a != inc_b {
+                    // tidy-allow: debug
                     todo!()
                 }
                 Ok(relation.cx().mk_pat(ty::PatternKind::Range { start, end, include_end: inc_a }))
@@ -1361,6 +1361,7 @@ pub(crate) fn print_markframe_trace<D: Deps>(graph: &DepGraph<D>, frame: Option<
     let mut current = frame;
     while let Some(frame) = current {
         let node = data.previous.index_to_node(frame.index);
+        // tidy-allow: debug, the stack is printed when forcing a dep node panics
         eprintln!("#{i} {node:?}");
         current = frame.parent;
         i += 1;
@@ -1,5 +1,7 @@
 use crate::spec::{base, Target};
 
 pub(crate) fn target() -> Target {
-    base::avr_gnu::target("atmega328", "-mmcu=atmega328")
+    let mut base = base::avr_gnu::target("atmega328", "-mmcu=atmega328");
+    base.metadata.tier = Some(3);
+    base
 }
@@ -4,5 +4,6 @@ pub(crate) fn target() -> Target {
     let mut base = super::i686_pc_windows_msvc::target();
     base.cpu = "pentium".into();
     base.llvm_target = "i586-pc-windows-msvc".into();
+    base.metadata.tier = Some(2);
     base
 }
@@ -10,7 +10,7 @@ pub(crate) fn target() -> Target {
         arch: "xtensa".into(),
         metadata: crate::spec::TargetMetadata {
             description: None,
-            tier: None,
+            tier: Some(3),
             host_tools: None,
             std: None,
         },
@@ -81,7 +81,7 @@ trait_selection_await_note = calling an async function returns a future
 trait_selection_but_calling_introduces = {$has_param_name ->
     [true] `{$param_name}`
     *[false] `fn` parameter
-} has {$lifetime_kind ->
+} has {$has_lifetime ->
     [true] lifetime `{$lifetime}`
     *[false] an anonymous lifetime `'_`
 } but calling `{$assoc_item}` introduces an implicit `'static` lifetime requirement
@@ -362,6 +362,7 @@ macro_rules! dbg {
         // of temporaries - https://stackoverflow.com/a/48732525/1063961
         match $val {
             tmp => {
+                // tidy-allow: debug, this is the implementation of `dbg!`
                 $crate::eprintln!("[{}:{}:{}] {} = {:#?}",
                     $crate::file!(), $crate::line!(), $crate::column!(), $crate::stringify!($val), &tmp);
                 tmp
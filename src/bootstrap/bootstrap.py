@@ -45,7 +45,22 @@ def eprint(*args, **kwargs):
     print(*args, **kwargs)
 
 
-def get(base, url, path, checksums, verbose=False):
+class NetConfig:
+    """The `[build.net]` settings of config.toml, which can be pickled into a parallel subprocess"""
+
+    def __init__(self, proxy=None, ca_bundle=None, timeout=30, retries=3):
+        self.proxy = proxy
+        self.ca_bundle = ca_bundle
+        self.timeout = timeout
+        self.retries = retries
+
+    def __str__(self):
+        return "proxy = {}, ca-bundle = {}, timeout = {}, retries = {}".format(
+            self.proxy or "<from the environment>", self.ca_bundle or "<system>",
+            self.timeout, self.retries)
+
+
+def get(base, url, path, checksums, verbose=False, net=None, mirrors=()):
     with tempfile.NamedTemporaryFile(delete=False) as temp_file:
         temp_path = temp_file.name
 
@@ -67,9 +82,23 @@ def get(base, url, path, checksums, verbose=False):
                     eprint("ignoring already-download file",
                         path, "due to failed verification")
                 os.unlink(path)
-        download(temp_path, "{}/{}".format(base, url), True, verbose)
-        if not verify(temp_path, sha256, verbose):
-            raise RuntimeError("failed verification")
+        net = net or NetConfig()
+        # Mirrors are tried in order, a download from them is only used if it's valid.
+        for mirror in mirrors:
+            mirror_url = "{}/{}".format(mirror, url)
+            try:
+                _download(temp_path, mirror_url, True, verbose, net, True)
+                if verify(temp_path, sha256, verbose):
+                    break
+            except (RuntimeError, subprocess.CalledProcessError, OSError):
+                pass
+            eprint("failed to download {}, trying the next server".format(mirror_url))
+            # Don't resume a corrupt or partial download from another server.
+            open(temp_path, "wb").close()
+        else:
+            download(temp_path, "{}/{}".format(base, url), True, verbose, net)
+            if not verify(temp_path, sha256, verbose):
+                raise RuntimeError("failed verification")
         if verbose:
             eprint("moving {} to {}".format(temp_path, path))
         shutil.move(temp_path, path)
@@ -85,17 +114,22 @@ def curl_version():
         return (0, 0)
     return (int(m[1]), int(m[2]))
 
-def download(path, url, probably_big, verbose):
+def download(path, url, probably_big, verbose, net):
     for _ in range(4):
         try:
-            _download(path, url, probably_big, verbose, True)
+            _download(path, url, probably_big, verbose, net, True)
             return
         except RuntimeError:
             eprint("\nspurious failure, trying again")
-    _download(path, url, probably_big, verbose, False)
+    try:
+        _download(path, url, probably_big, verbose, net, False)
+    except (RuntimeError, SystemExit):
+        eprint("ERROR: failed to download {}".format(url))
+        eprint("network settings ([build.net] in config.toml): {}".format(net))
+        raise
 
 
-def _download(path, url, probably_big, verbose, exception):
+def _download(path, url, probably_big, verbose, net, exception):
     # Try to use curl (potentially available on win32
     #    https://devblogs.microsoft.com/commandline/tar-and-curl-come-to-windows/)
     # If an error occurs:
@@ -115,6 +149,10 @@ def _download(path, url, probably_big, verbose, exception):
         extra_flags = []
         if curl_version() > (7, 70):
             extra_flags = [ "--retry-all-errors" ]
+        if net.proxy:
+            extra_flags += ["--proxy", net.proxy]
+        if net.ca_bundle:
+            extra_flags += ["--cacert", net.ca_bundle]
         # options should be kept in sync with
         # src/bootstrap/src/core/download.rs
         # for consistency.
@@ -122,22 +160,28 @@ def _download(path, url, probably_big, verbose, exception):
         run(["curl", option] + extra_flags + [
             # Follow redirect.
             "--location",
-            # timeout if speed is < 10 bytes/sec for > 30 seconds
-            "--speed-time", "30", "--speed-limit", "10",
-            # timeout if cannot connect within 30 seconds
-            "--connect-timeout", "30",
+            # timeout if speed is < 10 bytes/sec for > `build.net.timeout` seconds
+            "--speed-time", str(net.timeout), "--speed-limit", "10",
+            # timeout if cannot connect within `build.net.timeout` seconds
+            "--connect-timeout", str(net.timeout),
             "--output", path,
             "--continue-at", "-",
-            "--retry", "3", "--show-error", "--remote-time", "--fail", url],
+            "--retry", str(net.retries), "--show-error", "--remote-time", "--fail", url],
             verbose=verbose,
             exception=True, # Will raise RuntimeError on failure
         )
     except (subprocess.CalledProcessError, OSError, RuntimeError):
         # see http://serverfault.com/questions/301128/how-to-download
-        if platform_is_win32():
+        # PowerShell can't be told to use a different CA bundle.
+        if platform_is_win32() and not net.ca_bundle:
+            proxy = ""
+            if net.proxy:
+                proxy = "$client.Proxy = New-Object System.Net.WebProxy('{}');".format(net.proxy)
             run_powershell([
                  "[Net.ServicePointManager]::SecurityProtocol = [Net.SecurityProtocolType]::Tls12;",
-                 "(New-Object System.Net.WebClient).DownloadFile('{}', '{}')".format(url, path)],
+                 "$client = New-Object System.Net.WebClient;",
+                 proxy,
+                 "$client.DownloadFile('{}', '{}')".format(url, path)],
                 verbose=verbose,
                 exception=exception)
         # Check if the RuntimeError raised by run(curl) should be silenced
@@ -457,6 +501,8 @@ class DownloadInfo:
         stage0_data,
         pattern,
         verbose,
+        net,
+        mirrors,
     ):
         self.base_download_url = base_download_url
         self.download_path = download_path
@@ -466,6 +512,8 @@ class DownloadInfo:
         self.stage0_data = stage0_data
         self.pattern = pattern
         self.verbose = verbose
+        self.net = net
+        self.mirrors = mirrors
 
 def download_component(download_info):
     if not os.path.exists(download_info.tarball_path):
@@ -475,6 +523,8 @@ def download_component(download_info):
             download_info.tarball_path,
             download_info.stage0_data,
             verbose=download_info.verbose,
+            net=download_info.net,
+            mirrors=download_info.mirrors,
         )
 
 def unpack_component(download_info):
@@ -486,6 +536,36 @@ def unpack_component(download_info):
         verbose=download_info.verbose,
     )
 
+def detect_binary_patching_host(build):
+    """Detects hosts which need patched binaries, which are built for `build`"""
+    # Use `/etc/os-release` instead of `/etc/NIXOS`.
+    # The latter one does not exist on NixOS when using tmpfs as root.
+    try:
+        with open("/etc/os-release", "r") as f:
+            ids = [ln.strip()[len("ID="):].strip("\"'")
+                   for ln in f if ln.strip().startswith("ID=")]
+    except FileNotFoundError:
+        ids = []
+    if "nixos" in ids:
+        return "nix"
+    if "guix" in ids:
+        return "guix"
+
+    # glibc binaries on a host without glibc, e.g. Alpine. `gcompat` installs a
+    # dynamic linker with the name of the one of glibc.
+    def has_loader(prefix):
+        return any(name.startswith(prefix)
+                   for lib in ("/lib", "/lib64") if os.path.isdir(lib)
+                   for name in os.listdir(lib))
+    if "-linux-gnu" in build and has_loader("ld-musl-") and not has_loader("ld-linux"):
+        return "musl"
+    return None
+
+
+def describe_binary_patching_host(host):
+    return {"nix": "Nix", "guix": "Guix", "musl": "a musl host"}.get(host, "this host")
+
+
 class FakeArgs:
     """Used for unit tests to avoid updating all call sites"""
     def __init__(self):
@@ -503,8 +583,8 @@ class RustBuild(object):
         if args is None:
             args = FakeArgs()
         self.git_version = None
-        self.nix_deps_dir = None
-        self._should_fix_bins_and_dylibs = None
+        self._binary_patching_host = False
+        self._binary_patcher = False
         self.rust_root = os.path.abspath(os.path.join(__file__, '../../..'))
 
         self.config_toml = config_toml
@@ -531,6 +611,16 @@ class RustBuild(object):
             self.stage0_data["compiler_version"]
         )
         self.download_url = os.getenv("RUSTUP_DIST_SERVER") or self.stage0_data["dist_server"]
+        self.dist_mirrors = [
+            mirror.rstrip("/")
+            for mirror in self.get_toml_list('dist-mirrors', 'build')
+        ]
+        self.net = NetConfig(
+            proxy=self.get_toml('proxy', 'build.net'),
+            ca_bundle=self.get_toml('ca-bundle', 'build.net'),
+            timeout=int(self.get_toml('timeout', 'build.net') or 30),
+            retries=int(self.get_toml('retries', 'build.net') or 3),
+        )
 
         self.build = args.build or self.build_triple()
 
@@ -608,6 +698,8 @@ class RustBuild(object):
                     stage0_data=self.stage0_data,
                     pattern=pattern,
                     verbose=self.verbose,
+                    net=self.net,
+                    mirrors=self.dist_mirrors,
                 )
                 for filename, pattern in tarballs_to_download
             ]
@@ -653,16 +745,27 @@ class RustBuild(object):
                             # Patchelf will skip non-ELF files, but issue a warning.
                             if magic == b"\x7fELF":
                                 self.fix_bin_or_dylib(elf_path)
+                self.validate_patched_binaries(
+                    ["{}/bin/cargo".format(bin_root), "{}/bin/rustc".format(bin_root)])
 
             with output(self.rustc_stamp()) as rust_stamp:
                 rust_stamp.write(key)
 
     def should_fix_bins_and_dylibs(self):
         """Whether or not `fix_bin_or_dylib` needs to be run; can only be True
-        on NixOS or if config.toml has `build.patch-binaries-for-nix` set.
+        on Linux hosts without glibc at the usual paths, like NixOS, or if
+        config.toml enables `build.binary-patching` or `build.patch-binaries-for-nix`.
+        """
+        return self.binary_patching_host() is not None
+
+    def binary_patching_host(self):
+        """Returns the kind of host the downloaded binaries are patched for:
+        "nix", "guix", "musl" or "other", or None if they run as they are.
+
+        Keep in sync with `src/bootstrap/src/core/download/patch_binaries.rs`.
         """
-        if self._should_fix_bins_and_dylibs is not None:
-            return self._should_fix_bins_and_dylibs
+        if self._binary_patching_host is not False:
+            return self._binary_patching_host
 
         def get_answer():
             default_encoding = sys.getdefaultencoding()
@@ -670,60 +773,55 @@ class RustBuild(object):
                 ostype = subprocess.check_output(
                     ['uname', '-s']).strip().decode(default_encoding)
             except subprocess.CalledProcessError:
-                return False
+                return None
             except OSError as reason:
                 if getattr(reason, 'winerror', None) is not None:
-                    return False
+                    return None
                 raise reason
 
             if ostype != "Linux":
-                return False
+                return None
 
             # If the user has explicitly indicated whether binaries should be
-            # patched for Nix, then don't check for NixOS.
-            if self.get_toml("patch-binaries-for-nix", "build") == "true":
-                return True
-            if self.get_toml("patch-binaries-for-nix", "build") == "false":
-                return False
-
-            # Use `/etc/os-release` instead of `/etc/NIXOS`.
-            # The latter one does not exist on NixOS when using tmpfs as root.
-            try:
-                with open("/etc/os-release", "r") as f:
-                    is_nixos = any(ln.strip() in ("ID=nixos", "ID='nixos'", 'ID="nixos"')
-                                   for ln in f)
-            except FileNotFoundError:
-                is_nixos = False
-
-            # If not on NixOS, then warn if user seems to be atop Nix shell
-            if not is_nixos:
+            # patched, then don't check the host.
+            enable = self.get_toml("enable", "build.binary-patching")
+            for_nix = self.get_toml("patch-binaries-for-nix", "build")
+            if enable == "false" or (enable is None and for_nix == "false"):
+                return None
+            if enable is None and for_nix == "true":
+                return "nix"
+
+            host = detect_binary_patching_host(self.build)
+            if host is None and enable == "true":
+                return "other"
+            if host is None:
+                # If not on NixOS, then warn if user seems to be atop Nix shell
                 in_nix_shell = os.getenv('IN_NIX_SHELL')
                 if in_nix_shell:
                     eprint("The IN_NIX_SHELL environment variable is `{}`;".format(in_nix_shell),
                           "you may need to set `patch-binaries-for-nix=true` in config.toml")
+            return host
 
-            return is_nixos
-
-        answer = self._should_fix_bins_and_dylibs = get_answer()
-        if answer:
-            eprint("INFO: You seem to be using Nix.")
+        answer = self._binary_patching_host = get_answer()
+        if answer is not None:
+            eprint("INFO: Patching the downloaded binaries for {}.".format(
+                describe_binary_patching_host(answer)))
         return answer
 
-    def fix_bin_or_dylib(self, fname):
-        """Modifies the interpreter section of 'fname' to fix the dynamic linker,
-        or the RPATH section, to fix the dynamic library search path
-
-        This method is only required on NixOS and uses the PatchELF utility to
-        change the interpreter/RPATH of ELF executables.
-
-        Please see https://nixos.org/patchelf.html for more information
+    def binary_patcher(self):
+        """Returns the `patchelf` executable, the interpreter set for executables
+        (or None) and the RPATH entries, or None if the binaries can't be patched.
         """
-        assert self._should_fix_bins_and_dylibs is True
-        eprint("attempting to patch", fname)
+        if self._binary_patcher is not False:
+            return self._binary_patcher
+
+        host = self.binary_patching_host()
+        section = "build.binary-patching"
+        patchelf = self.get_toml("patchelf", section) or "patchelf"
+        interpreter = self.get_toml("dynamic-linker", section)
+        rpath = self.get_toml_list("rpath", section)
 
-        # Only build `.nix-deps` once.
-        nix_deps_dir = self.nix_deps_dir
-        if not nix_deps_dir:
+        if host == "nix":
             # Run `nix-build` to "build" each dependency (which will likely reuse
             # the existing `/nix/store` copy, or at most download a pre-built copy).
             #
@@ -753,25 +851,95 @@ class RustBuild(object):
                 ])
             except subprocess.CalledProcessError as reason:
                 eprint("WARNING: failed to call nix-build:", reason)
-                return
-            self.nix_deps_dir = nix_deps_dir
+                self._binary_patcher = None
+                return None
+            if self.get_toml("patchelf", section) is None:
+                patchelf = "{}/bin/patchelf".format(nix_deps_dir)
+            if interpreter is None:
+                with open("{}/nix-support/dynamic-linker".format(nix_deps_dir)) as dynamic_linker:
+                    interpreter = dynamic_linker.read().rstrip()
+            rpath.append(os.path.join(os.path.realpath(nix_deps_dir), "lib"))
+        elif host == "guix":
+            # The binaries of the host use the dynamic linker of its glibc.
+            if interpreter is None:
+                try:
+                    interpreter = subprocess.check_output(
+                        [patchelf, "--print-interpreter", os.path.realpath("/bin/sh")]
+                    ).decode(sys.getdefaultencoding()).strip()
+                except (subprocess.CalledProcessError, OSError) as reason:
+                    eprint("WARNING: failed to find the dynamic linker of Guix,",
+                           "`guix shell patchelf` provides patchelf:", reason)
+                    self._binary_patcher = None
+                    return None
+            library_path = os.getenv("LIBRARY_PATH")
+            if library_path:
+                rpath.extend(library_path.split(os.pathsep))
+        elif host == "musl" and interpreter is None:
+            eprint("WARNING: the downloaded binaries need glibc, which isn't installed on this",
+                   "musl host. Install `gcompat`, or set `build.binary-patching.dynamic-linker`",
+                   "in config.toml to a glibc dynamic linker.")
+            self._binary_patcher = None
+            return None
+
+        # The libraries of glibc are next to its dynamic linker.
+        if host != "nix" and interpreter is not None:
+            lib_dir = os.path.dirname(interpreter)
+            if lib_dir not in rpath:
+                rpath.insert(0, lib_dir)
+
+        self._binary_patcher = (patchelf, interpreter, rpath)
+        return self._binary_patcher
 
-        patchelf = "{}/bin/patchelf".format(nix_deps_dir)
-        rpath_entries = [
-            os.path.join(os.path.realpath(nix_deps_dir), "lib")
-        ]
-        patchelf_args = ["--add-rpath", ":".join(rpath_entries)]
-        if ".so" not in fname:
+    def fix_bin_or_dylib(self, fname):
+        """Modifies the interpreter section of 'fname' to fix the dynamic linker,
+        or the RPATH section, to fix the dynamic library search path
+
+        This method is only required on hosts without glibc at the usual paths,
+        like NixOS, and uses the PatchELF utility to change the interpreter/RPATH
+        of ELF executables.
+
+        Please see https://nixos.org/patchelf.html for more information
+        """
+        assert self._binary_patching_host is not None
+        patcher = self.binary_patcher()
+        if patcher is None:
+            return
+        patchelf, interpreter, rpath = patcher
+
+        patchelf_args = []
+        if rpath:
+            patchelf_args += ["--add-rpath", ":".join(rpath)]
+        if interpreter is not None and ".so" not in fname:
             # Finally, set the correct .interp for binaries
-            with open("{}/nix-support/dynamic-linker".format(nix_deps_dir)) as dynamic_linker:
-                patchelf_args += ["--set-interpreter", dynamic_linker.read().rstrip()]
+            patchelf_args += ["--set-interpreter", interpreter]
+        if not patchelf_args:
+            return
 
+        eprint("attempting to patch", fname)
         try:
             subprocess.check_output([patchelf] + patchelf_args + [fname])
         except subprocess.CalledProcessError as reason:
             eprint("WARNING: failed to call patchelf:", reason)
             return
 
+    def validate_patched_binaries(self, executables):
+        """Checks that the patched executables run, and exits otherwise"""
+        for executable in executables:
+            try:
+                subprocess.check_output([executable, "--version"], stderr=subprocess.STDOUT)
+            except (subprocess.CalledProcessError, OSError) as reason:
+                output = getattr(reason, "output", None)
+                if output:
+                    reason = output.decode(sys.getdefaultencoding(), "replace").strip()
+                eprint("ERROR: {} doesn't run after patching it for {}: {}".format(
+                    executable, describe_binary_patching_host(self.binary_patching_host()),
+                    reason))
+                eprint("HELP: set `dynamic-linker` and `rpath` in the `[build.binary-patching]`",
+                       "section of config.toml to the dynamic linker and the library directories",
+                       "of glibc on this host, or `enable = false` if the binaries run without",
+                       "patching")
+                sys.exit(1)
+
     def rustc_stamp(self):
         """Return the path for .rustc-stamp at the given stage
 
@@ -845,6 +1013,30 @@ class RustBuild(object):
                     return RustBuild.get_string(value) or value.strip()
         return None
 
+    def get_toml_list(self, key, section=None):
+        """Returns the strings of an array of strings in config.toml, which has to be on a single
+        line. If the key does not exist, the result is empty.
+
+        >>> rb = RustBuild()
+        >>> rb.config_toml = '[a]\\nkey = ["value1", "value2"]\\n[b]\\nkey = []'
+        >>> rb.get_toml_list('key', 'a')
+        ['value1', 'value2']
+        >>> rb.get_toml_list('key', 'b')
+        []
+        >>> rb.get_toml_list('key', 'c')
+        []
+        """
+        cur_section = None
+        for line in self.config_toml.splitlines():
+            section_match = re.match(r'^\s*\[(.*)\]\s*$', line)
+            if section_match is not None:
+                cur_section = section_match.group(1)
+
+            match = re.match(r'^{}\s*=(.*)$'.format(key), line)
+            if match is not None and (section is None or section == cur_section):
+                return re.findall(r'"([^"]*)"', match.group(1))
+        return []
+
     def cargo(self):
         """Return config path for cargo"""
         return self.program_config('cargo')
@@ -1121,6 +1313,24 @@ def parse_stage0_file(path):
                 result[key.strip()] = value.strip()
     return result
 
+def with_includes(path, config_toml, including=()):
+    """Appends the files of the `include` array of the config.toml at `path`, which has to be on
+    a single line, with their own includes, to its contents `config_toml`. The last file comes
+    first, so that the first match of a key is the one which takes precedence, like in bootstrap,
+    which reports the missing files and the cycles."""
+    match = re.search(r'^include\s*=(.*)$', config_toml, re.MULTILINE)
+    if match is None:
+        return config_toml
+    including = including + (os.path.realpath(path),)
+    for include in reversed(re.findall(r'"([^"]*)"', match.group(1))):
+        include = os.path.realpath(os.path.join(os.path.dirname(path), include))
+        if include in including or not os.path.exists(include):
+            continue
+        with open(include) as included:
+            config_toml += os.linesep + with_includes(include, included.read(), including)
+    return config_toml
+
+
 def bootstrap(args):
     """Configure, fetch, build and run the initial bootstrap"""
     rust_root = os.path.abspath(os.path.join(__file__, '../../..'))
@@ -1144,7 +1354,7 @@ def bootstrap(args):
     # but not if `config.toml` hasn't been created.
     if not using_default_path or os.path.exists(toml_path):
         with open(toml_path) as config:
-            config_toml = config.read()
+            config_toml = with_includes(toml_path, config.read())
     else:
         config_toml = ''
 
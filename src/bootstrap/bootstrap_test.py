@@ -8,6 +8,7 @@ import unittest
 import tempfile
 import hashlib
 import sys
+from unittest import mock
 
 from shutil import rmtree
 
@@ -114,6 +115,10 @@ class GenerateAndParseConfig(unittest.TestCase):
         build = serialize_and_parse(["--set", "target.x86_64-unknown-linux-gnu.cc=gcc"])
         self.assertEqual(build.get_toml("cc", section="target.x86_64-unknown-linux-gnu"), 'gcc')
 
+    def test_set_subsection(self):
+        build = serialize_and_parse(["--set", "build.net.retries=5"])
+        self.assertEqual(build.get_toml("retries", section="build.net"), '5')
+
     def test_set_top_level(self):
         build = serialize_and_parse(["--set", "profile=compiler"])
         self.assertEqual(build.get_toml("profile"), 'compiler')
@@ -127,6 +132,40 @@ class GenerateAndParseConfig(unittest.TestCase):
         self.assertNotEqual(build.config_toml.find("codegen-backends = ['llvm']"), -1)
 
 
+class NetSettings(unittest.TestCase):
+    """Test that the `[build.net]` settings reach the command line of curl"""
+
+    def curl_args(self, config_toml):
+        build = bootstrap.RustBuild(config_toml=config_toml, args=bootstrap.FakeArgs())
+        commands = []
+        with mock.patch.object(bootstrap, "run", lambda args, **kwargs: commands.append(args)), \
+                mock.patch.object(bootstrap, "require", lambda *args, **kwargs: None), \
+                mock.patch.object(bootstrap, "curl_version", lambda: (8, 0)):
+            bootstrap._download("rustc.tar.xz", "https://static.rust-lang.org/rustc.tar.xz",
+                                False, False, build.net, True)
+        self.assertEqual(len(commands), 1)
+        return commands[0]
+
+    def value_of(self, args, flag):
+        return args[args.index(flag) + 1] if flag in args else None
+
+    def test_default(self):
+        args = self.curl_args("")
+        self.assertEqual(self.value_of(args, "--connect-timeout"), "30")
+        self.assertEqual(self.value_of(args, "--retry"), "3")
+        self.assertIsNone(self.value_of(args, "--proxy"))
+        self.assertIsNone(self.value_of(args, "--cacert"))
+
+    def test_net_settings(self):
+        args = self.curl_args(
+            '[build.net]\nproxy = "http://proxy.example.com:3128"\nca-bundle = "/etc/ca.pem"\n'
+            'timeout = 120\nretries = 0\n')
+        self.assertEqual(self.value_of(args, "--proxy"), "http://proxy.example.com:3128")
+        self.assertEqual(self.value_of(args, "--cacert"), "/etc/ca.pem")
+        self.assertEqual(self.value_of(args, "--speed-time"), "120")
+        self.assertEqual(self.value_of(args, "--retry"), "0")
+
+
 class BuildBootstrap(unittest.TestCase):
     """Test that we generate the appropriate arguments when building bootstrap"""
 
@@ -388,6 +388,8 @@ def apply_args(known_args, option_checking, config):
 #
 # Note that the `target` section is handled separately as we'll duplicate it
 # per configured target, so there's a bit of special handling for that here.
+# Subtables of other sections, like `[build.net]`, are kept as sections of their
+# own, named after the full path of the table.
 def parse_example_config(known_args, config):
     sections = {}
     cur_section = None
@@ -408,7 +410,7 @@ def parse_example_config(known_args, config):
             cur_section = line[1:-1]
             if cur_section.startswith('target'):
                 cur_section = 'target'
-            elif '.' in cur_section:
+            elif '.' in cur_section and cur_section.split('.')[0] not in sections:
                 raise RuntimeError("don't know how to deal with section: {}".format(cur_section))
             sections[cur_section] = [line]
             section_order.append(cur_section)
@@ -510,6 +512,11 @@ def configure_file(sections, top_level_keys, targets, config):
             for target in section_config:
                 configure_section(targets[target], section_config[target])
         else:
+            section_config = dict(section_config)
+            for key, value in list(section_config.items()):
+                subsection = "{}.{}".format(section_key, key)
+                if isinstance(value, dict) and subsection in sections:
+                    configure_section(sections[subsection], section_config.pop(key))
             configure_section(sections[section_key], section_config)
 
 
@@ -15,10 +15,11 @@
 //! switching compilers for the bootstrap and for build scripts will probably
 //! never get replaced.
 
-use std::env;
+use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use std::{env, mem, thread};
 
 use shared_helpers::{
     dylib_path, dylib_path_var, exe, maybe_dump, parse_rustc_stage, parse_rustc_verbose,
@@ -45,7 +46,8 @@ fn main() {
     // determine the version of the compiler, the real compiler needs to be
     // used. Currently, these two states are differentiated based on whether
     // --target and -vV is/isn't passed.
-    let is_build_script = target.is_none() && version.is_none();
+    let is_version = version.is_some();
+    let is_build_script = target.is_none() && !is_version;
     let (rustc, libdir) = if is_build_script {
         ("RUSTC_SNAPSHOT", "RUSTC_SNAPSHOT_LIBDIR")
     } else {
@@ -268,6 +270,12 @@ fn main() {
 
     maybe_dump(format!("stage{stage}-rustc"), &cmd);
 
+    // Linking the crates, and their LTO, use the most memory, so only `RUSTC_MAX_CONCURRENT_LINKS`
+    // of them are built at once with `build.max-concurrent-links`.
+    let crate_type = parse_value_from_args(&orig_args, "--crate-type");
+    let links = is_test || matches!(crate_type, Some("bin" | "dylib" | "cdylib" | "staticlib"));
+    let _link_slot = if links && !is_version { wait_for_link_slot() } else { None };
+
     let start = Instant::now();
     let (child, status) = {
         let errmsg = format!("\nFailed to run:\n{cmd:?}\n-------------");
@@ -321,6 +329,31 @@ fn main() {
     }
 }
 
+/// Waits until one of the `RUSTC_MAX_CONCURRENT_LINKS` slots in the `RUSTC_LINK_SLOTS` directory
+/// is free, and returns its lock, which is held until it's dropped.
+fn wait_for_link_slot() -> Option<fd_lock::RwLock<File>> {
+    let slots = env::var("RUSTC_MAX_CONCURRENT_LINKS").ok()?;
+    let slots: u32 = slots.parse().expect("RUSTC_MAX_CONCURRENT_LINKS is not a number");
+    let dir = PathBuf::from(env::var_os("RUSTC_LINK_SLOTS").expect("RUSTC_LINK_SLOTS was not set"));
+    fs::create_dir_all(&dir).expect("couldn't create the directory of the link slots");
+    loop {
+        for slot in 0..slots {
+            let file = fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(dir.join(format!("slot-{slot}")))
+                .expect("couldn't open a link slot");
+            let mut lock = fd_lock::RwLock::new(file);
+            // The lock is released when its file is closed, so the guard isn't needed.
+            if lock.try_write().map(mem::forget).is_ok() {
+                return Some(lock);
+            }
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
 #[cfg(all(not(unix), not(windows)))]
 // In the future we can add this for more platforms
 fn format_rusage_data(_child: Child) -> Option<String> {
@@ -12,6 +12,8 @@
 use crate::core::config::TargetSelection;
 use crate::{Compiler, Mode, Subcommand};
 
+mod graft;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Std {
     pub target: TargetSelection,
@@ -207,6 +209,12 @@ fn run(self, builder: &Builder<'_>) {
         let compiler = builder.compiler(builder.top_stage, builder.config.build);
         let target = self.target;
 
+        let kind = self.override_build_kind.unwrap_or(builder.kind);
+        if graft::is_enabled(builder, kind, target, &self.crates) {
+            graft::check(builder, &self.crates);
+            return;
+        }
+
         if compiler.stage != 0 {
             // If we're not in stage 0, then we won't have a std from the beta
             // compiler around. That means we need to make sure there's one in
@@ -0,0 +1,136 @@
+//! `build.graft-ci-rustc`, which checks the compiler crates given to `x check`, like
+//! `x check compiler/rustc_borrowck`, against the libraries of the other compiler crates in the
+//! `rustc-dev` component downloaded by `download-rustc`, instead of checking all the crates they
+//! depend on first.
+//!
+//! Each crate is checked outside of cargo by the downloaded compiler, which built the libraries,
+//! with the features enabled by default, and with its dependencies found in the libraries. The
+//! changes to the other crates since the commit the compiler was downloaded for aren't seen, so
+//! this is for the crates whose changes don't need ones in their dependencies, and the check
+//! without the option still checks everything. The crates with a build script, like `rustc_llvm`,
+//! aren't supported.
+//!
+//! Only `x check` is supported, not `x build`, which would need the libraries grafted into the
+//! sysroot of the stage in `compile.rs`. As cargo doesn't run, the features bootstrap enables, its
+//! `RUSTFLAGS`, lints and cfgs aren't used, and a dependency with several versions among the
+//! libraries is left to rustc to find, so the check can differ from the one without the option,
+//! see `build.graft-ci-rustc` in `config.example.toml`.
+
+use std::fs;
+
+use crate::core::builder::{Builder, Kind};
+use crate::core::config::TargetSelection;
+use crate::utils::exec::command;
+use crate::utils::helpers::{exe, t};
+
+#[cfg(test)]
+mod tests;
+
+/// The extensions of the libraries the dependencies are found in.
+const LIBRARY_EXTENSIONS: &[&str] = &["rlib", "rmeta", "so", "dylib", "dll"];
+
+/// Whether the check of the compiler crates `crates` of `kind` uses the downloaded ones instead.
+pub(super) fn is_enabled(
+    builder: &Builder<'_>,
+    kind: Kind,
+    target: TargetSelection,
+    crates: &[String],
+) -> bool {
+    builder.config.graft_ci_rustc
+        && kind == Kind::Check
+        && target == builder.config.build
+        && !crates.is_empty()
+        && crates.len() < builder.in_tree_crates("rustc-main", Some(target)).len()
+        && builder.download_rustc()
+}
+
+/// Checks the compiler crates `crates` against the downloaded libraries of the others.
+pub(super) fn check(builder: &Builder<'_>, crates: &[String]) {
+    let host = builder.config.build;
+    let sysroot = builder.config.ci_rustc_dir();
+    let libdir = sysroot.join("lib").join("rustlib").join(host).join("lib");
+    let libraries: Vec<String> = if builder.config.dry_run() {
+        vec![]
+    } else {
+        t!(fs::read_dir(&libdir))
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect()
+    };
+
+    for name in crates {
+        let krate = &builder.crates[name];
+        let Some(lib) = &krate.lib else {
+            println!("graft-ci-rustc: skipping {name}, which has no library");
+            continue;
+        };
+        if lib.build_script {
+            eprintln!("ERROR: {name} has a build script, which `build.graft-ci-rustc` can't run");
+            eprintln!("HELP: check it with `build.graft-ci-rustc = false`");
+            crate::exit!(1);
+        }
+
+        let mut cmd = command(sysroot.join("bin").join(exe("rustc", host)));
+        cmd.arg("--crate-name")
+            .arg(&lib.name)
+            .arg("--crate-type")
+            .arg(if lib.proc_macro { "proc-macro" } else { "lib" })
+            .arg("--edition")
+            .arg(&lib.edition)
+            .arg("--emit=metadata")
+            .arg("--out-dir")
+            .arg(builder.out.join(host).join("graft-ci-rustc").join(name))
+            .arg("--sysroot")
+            .arg(&sysroot)
+            // Uses the unstable compiler crates like cargo builds them.
+            .arg("-Zforce-unstable-if-unmarked");
+        for feature in &lib.default_features {
+            cmd.arg("--cfg").arg(format!("feature=\"{feature}\""));
+        }
+        for (extern_name, crate_name) in &lib.deps {
+            // Without a single library, rustc finds the crate in the sysroot if it's used, or
+            // explains why it can't.
+            let arg = match find_library(&libraries, crate_name) {
+                Some(file) => format!("{extern_name}={}", libdir.join(file).display()),
+                None => extern_name.clone(),
+            };
+            cmd.arg("--extern").arg(arg);
+        }
+        if lib.proc_macro {
+            cmd.arg("--extern").arg("proc_macro");
+        }
+        cmd.arg(&lib.src_path)
+            .env("RUSTC_BOOTSTRAP", "1")
+            .env("CARGO_MANIFEST_DIR", &krate.path)
+            .env("CARGO_PKG_NAME", name)
+            .env("CARGO_CRATE_NAME", &lib.name)
+            .env("CARGO_PKG_VERSION", "0.0.0")
+            .env("CFG_RELEASE", builder.rust_release())
+            .env("CFG_RELEASE_CHANNEL", &builder.config.channel)
+            .env("CFG_VERSION", builder.rust_version())
+            .env("CFG_COMPILER_HOST_TRIPLE", host.triple);
+
+        let _guard = builder.msg_check(format_args!("{name} against the CI compiler crates"), host);
+        cmd.run(builder);
+    }
+}
+
+/// Returns the library of the crate `name` among `libraries`, the files of a library directory,
+/// unless there are several versions of it.
+fn find_library<'a>(libraries: &'a [String], name: &str) -> Option<&'a str> {
+    // The libraries are `lib<name>-<hash>.<extension>`, or `<name>-<hash>.dll`.
+    let mut found: Vec<(&str, &str)> = libraries
+        .iter()
+        .filter_map(|file| {
+            let (stem, extension) = file.rsplit_once('.')?;
+            let stem = stem.strip_prefix("lib").unwrap_or(stem);
+            let hash = stem.strip_prefix(name)?.strip_prefix('-')?;
+            LIBRARY_EXTENSIONS.contains(&extension).then_some((hash, file.as_str()))
+        })
+        .collect();
+    found.sort_unstable();
+    found.dedup_by_key(|(hash, _)| *hash);
+    match found[..] {
+        [(_, file)] => Some(file),
+        _ => None,
+    }
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn find_libraries() {
+    let libraries: Vec<String> = [
+        "librustc_middle-0123.rlib",
+        "librustc_middle-0123.rmeta",
+        "librustc_middle_macros-4567.so",
+        "libitertools-89ab.rlib",
+        "libitertools-cdef.rlib",
+        "rustc_macros-0123.dll",
+        "librustc_span-0123.d",
+    ]
+    .into_iter()
+    .map(Into::into)
+    .collect();
+
+    assert_eq!(find_library(&libraries, "rustc_middle"), Some("librustc_middle-0123.rlib"));
+    assert_eq!(find_library(&libraries, "rustc_macros"), Some("rustc_macros-0123.dll"));
+    // Two versions of a crate are left to rustc, and so are the missing crates.
+    assert_eq!(find_library(&libraries, "itertools"), None);
+    assert_eq!(find_library(&libraries, "rustc_span"), None);
+}
@@ -3,7 +3,8 @@
 //! Responsible for cleaning out a build directory of all old and stale
 //! artifacts to prepare for a fresh build. Currently doesn't remove the
 //! `build/cache` directory (download cache) or the `build/$target/llvm`
-//! directory unless the `--all` flag is present.
+//! directory unless the `--all` flag is present. `--gc` removes only the
+//! stale artifacts, see the `gc` module.
 
 use std::fs;
 use std::io::{self, ErrorKind};
@@ -13,6 +14,8 @@
 use crate::utils::helpers::t;
 use crate::{Build, Compiler, Kind, Mode, Subcommand};
 
+mod gc;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CleanAll {}
 
@@ -25,13 +28,19 @@ fn make_run(run: RunConfig<'_>) {
     }
 
     fn run(self, builder: &Builder<'_>) -> Self::Output {
-        let Subcommand::Clean { all, stage } = builder.config.cmd else {
+        let Subcommand::Clean { all, stage, gc } = builder.config.cmd else {
             unreachable!("wrong subcommand?")
         };
 
         if all && stage.is_some() {
             panic!("--all and --stage can't be used at the same time for `x clean`");
         }
+        if gc && (all || stage.is_some()) {
+            panic!("--gc can't be used with --all or --stage for `x clean`");
+        }
+        if gc {
+            return gc::collect_garbage(builder.build);
+        }
 
         clean(builder.build, all, stage)
     }
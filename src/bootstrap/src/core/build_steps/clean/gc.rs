@@ -0,0 +1,311 @@
+//! `x clean --gc`, which removes the artifacts of the build directory which are unlikely to be
+//! used again, according to the policies of the `[build.gc]` section of `config.toml`:
+//!
+//! - the toolchains of the stages and the downloads of each kind beyond `keep-toolchains`, and the
+//!   documentation builds beyond `keep-docs`, the least recently used ones first
+//! - toolchains, documentation builds and downloads which weren't used for `max-age` days
+//! - LLVM builds which aren't used by the configuration, e.g. an in-tree build of LLVM with
+//!   `download-ci-llvm = true`
+//! - the least recently used artifacts, until the build directory is smaller than `max-size`
+//! - stamps listing files which don't exist anymore
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use walkdir::WalkDir;
+
+use super::rm_rf;
+use crate::core::config::GcConfig;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+const GIB: u64 = 1 << 30;
+
+/// Artifacts which are removed together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Artifact {
+    /// The directories of the artifact, e.g. `stage1` and `stage1-std` for a toolchain.
+    paths: Vec<PathBuf>,
+    kind: ArtifactKind,
+    /// The newest modification time of the files at the top of the directories.
+    last_used: SystemTime,
+    size: u64,
+    /// Whether the artifact is used by the current configuration, which protects it from all
+    /// policies but `max-size`.
+    in_use: bool,
+}
+
+/// The kinds of artifacts, of which the policies keep the `keep-*` most recently used ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ArtifactKind {
+    /// The directories of a stage, i.e. its sysroot and the crates its compiler built, for a
+    /// target.
+    Toolchain(String),
+    /// A documentation directory of a target.
+    Docs(String),
+    /// A download of the cache, with the kind of the download.
+    Download(&'static str),
+    /// A build of LLVM which isn't used by the configuration.
+    UnusedLlvm,
+}
+
+pub(super) fn collect_garbage(build: &Build) {
+    if build.config.dry_run() {
+        return;
+    }
+
+    let cache = cache_dir(build);
+    let mut total_size = dir_size(&build.out);
+    if !cache.starts_with(&build.out) {
+        total_size += dir_size(&cache);
+    }
+    let artifacts = find_artifacts(build);
+    let removed = select(artifacts, &build.config.gc, SystemTime::now(), total_size);
+
+    let mut reclaimed = 0;
+    for (artifact, reason) in &removed {
+        let paths: Vec<_> = artifact
+            .paths
+            .iter()
+            .map(|path| path.strip_prefix(&build.out).unwrap_or(path).display().to_string())
+            .collect();
+        println!("Removing {} ({}): {reason}", paths.join(", "), format_size(artifact.size));
+        for path in &artifact.paths {
+            rm_rf(path);
+        }
+        reclaimed += artifact.size;
+    }
+    for stamp in orphaned_stamps(build) {
+        println!(
+            "Removing {}: the listed files don't exist anymore",
+            stamp.strip_prefix(&build.out).unwrap_or(&stamp).display()
+        );
+        rm_rf(&stamp);
+    }
+
+    if removed.is_empty() {
+        println!("Nothing to remove, the build directory uses {}.", format_size(total_size));
+    } else {
+        println!(
+            "Reclaimed {}, the build directory uses {} now.",
+            format_size(reclaimed),
+            format_size(total_size.saturating_sub(reclaimed))
+        );
+    }
+}
+
+/// Returns the artifacts to remove according to `policy`, with the reason.
+fn select(
+    mut artifacts: Vec<Artifact>,
+    policy: &GcConfig,
+    now: SystemTime,
+    total_size: u64,
+) -> Vec<(Artifact, String)> {
+    // The most recently used ones first, which are the ones kept by `keep-*`.
+    artifacts.sort_by_key(|a| std::cmp::Reverse(a.last_used));
+
+    let mut removed = Vec::new();
+    let mut kept = Vec::new();
+    let mut kept_of_kind: HashMap<ArtifactKind, usize> = HashMap::new();
+    for artifact in artifacts {
+        let keep = match artifact.kind {
+            ArtifactKind::Toolchain(_) | ArtifactKind::Download(_) => policy.keep_toolchains,
+            ArtifactKind::Docs(_) => policy.keep_docs,
+            ArtifactKind::UnusedLlvm => 0,
+        };
+        let newer = kept_of_kind.entry(artifact.kind.clone()).or_default();
+        let days =
+            now.duration_since(artifact.last_used).unwrap_or_default().as_secs() / DAY.as_secs();
+        let reason = if artifact.kind == ArtifactKind::UnusedLlvm {
+            Some("not used by the configuration".to_owned())
+        } else if artifact.in_use {
+            None
+        } else if *newer >= keep {
+            Some(format!("{newer} more recently used ones are kept"))
+        } else if policy.max_age.is_some_and(|max_age| days >= max_age) {
+            Some(format!("not used for {days} days"))
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => removed.push((artifact, reason)),
+            None => {
+                *newer += 1;
+                kept.push(artifact);
+            }
+        }
+    }
+
+    if let Some(max_size) = policy.max_size {
+        let mut size =
+            total_size.saturating_sub(removed.iter().map(|(artifact, _)| artifact.size).sum());
+        while size > max_size * GIB {
+            let Some(artifact) = kept.pop() else {
+                break;
+            };
+            size = size.saturating_sub(artifact.size);
+            removed.push((artifact, format!("the build directory is larger than {max_size} GiB")));
+        }
+    }
+    removed
+}
+
+/// Returns the cache of the downloads, see `Config::download_component`.
+fn cache_dir(build: &Build) -> PathBuf {
+    build.config.bootstrap_cache_path.clone().unwrap_or_else(|| build.out.join("cache"))
+}
+
+fn find_artifacts(build: &Build) -> Vec<Artifact> {
+    let mut artifacts = Vec::new();
+    let triples: BTreeSet<_> = build
+        .hosts
+        .iter()
+        .chain(&build.targets)
+        .chain([&build.build])
+        .map(|target| target.triple.to_string())
+        .collect();
+    for triple in triples {
+        let target_dir = build.out.join(&triple);
+        let Ok(entries) = fs::read_dir(&target_dir) else {
+            continue;
+        };
+        let mut stages: HashMap<u32, Vec<PathBuf>> = HashMap::new();
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                continue;
+            }
+            if let Some(stage) = stage_of(&name) {
+                // The stage0 toolchain of the build triple is downloaded.
+                if name != "stage0" && name != "stage0-sysroot" {
+                    stages.entry(stage).or_default().push(entry.path());
+                }
+            } else if name == "doc" || name.ends_with("-doc") {
+                artifacts.push(artifact(vec![entry.path()], ArtifactKind::Docs(triple.clone())));
+            }
+        }
+        for paths in stages.into_values() {
+            artifacts.push(artifact(paths, ArtifactKind::Toolchain(triple.clone())));
+        }
+
+        let target = build.config.target_config.iter().find(|(t, _)| t.triple == triple.as_str());
+        let external_llvm = target.is_some_and(|(_, config)| config.llvm_config.is_some());
+        let is_build = *build.build.triple == triple;
+        let unused_llvm = if external_llvm || (is_build && build.config.llvm_from_ci) {
+            "llvm"
+        } else if is_build {
+            "ci-llvm"
+        } else {
+            continue;
+        };
+        let path = target_dir.join(unused_llvm);
+        if path.exists() {
+            artifacts.push(artifact(vec![path], ArtifactKind::UnusedLlvm));
+        }
+    }
+
+    let stage0 = &build.config.stage0_metadata;
+    let mut used_downloads = vec![stage0.compiler.date.as_str()];
+    used_downloads.extend(stage0.rustfmt.as_ref().map(|rustfmt| rustfmt.date.as_str()));
+    for entry in fs::read_dir(cache_dir(build)).into_iter().flatten().flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let kind = ArtifactKind::Download(download_kind(&name));
+        let mut download = artifact(vec![entry.path()], kind);
+        download.in_use = used_downloads.contains(&name.as_str());
+        artifacts.push(download);
+    }
+    artifacts
+}
+
+fn artifact(paths: Vec<PathBuf>, kind: ArtifactKind) -> Artifact {
+    let last_used = paths.iter().map(|path| last_used(path)).max().unwrap_or(UNIX_EPOCH);
+    let size = paths.iter().map(|path| dir_size(path)).sum();
+    Artifact { paths, kind, last_used, size, in_use: false }
+}
+
+/// Returns the stage of a directory like `stage1` or `stage1-std`.
+fn stage_of(name: &str) -> Option<u32> {
+    let stage = name.strip_prefix("stage")?;
+    let end = stage.find(|c: char| !c.is_ascii_digit()).unwrap_or(stage.len());
+    if end < stage.len() && !stage[end..].starts_with('-') {
+        return None;
+    }
+    stage[..end].parse().ok()
+}
+
+/// Returns the kind of a download from the name of its directory in the cache.
+fn download_kind(name: &str) -> &'static str {
+    // Keep in sync with the keys of `Config::download_component` and `Config::download_ci_llvm`.
+    let is_date = name.len() == "2024-01-01".len()
+        && name.char_indices().all(|(i, c)| match i {
+            4 | 7 => c == '-',
+            _ => c.is_ascii_digit(),
+        });
+    if name.starts_with("llvm-") {
+        "CI LLVM"
+    } else if is_date {
+        "stage0 toolchain"
+    } else {
+        "CI rustc"
+    }
+}
+
+/// The newest modification time of a directory and of the files in its first levels, which are
+/// updated by builds without walking the whole tree.
+fn last_used(path: &Path) -> SystemTime {
+    WalkDir::new(path)
+        .max_depth(3)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+        .unwrap_or(UNIX_EPOCH)
+}
+
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .flatten()
+        .filter(|entry| !entry.file_type().is_dir())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Returns the stamps of the crates built by cargo, like `.libstd.stamp`, which list files that
+/// don't exist anymore.
+fn orphaned_stamps(build: &Build) -> Vec<PathBuf> {
+    WalkDir::new(&build.out)
+        .max_depth(6)
+        .into_iter()
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.file_type().is_file() && name.starts_with(".lib") && name.ends_with(".stamp")
+        })
+        .filter(|entry| fs::read(entry.path()).is_ok_and(|contents| is_orphaned(&contents)))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// Checks whether a stamp lists files which don't exist. See `run_cargo` for the format.
+fn is_orphaned(stamp: &[u8]) -> bool {
+    stamp
+        .split(|b| *b == 0)
+        .filter(|part| !part.is_empty())
+        .any(|part| std::str::from_utf8(&part[1..]).is_ok_and(|path| !Path::new(path).exists()))
+}
+
+fn format_size(bytes: u64) -> String {
+    const MIB: u64 = 1 << 20;
+    if bytes >= GIB {
+        format!("{:.1} GiB", bytes as f64 / GIB as f64)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    }
+}
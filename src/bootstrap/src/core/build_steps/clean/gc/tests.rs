@@ -0,0 +1,81 @@
+use super::*;
+
+fn days(n: u64) -> Duration {
+    Duration::from_secs(n * DAY.as_secs())
+}
+
+fn artifact(name: &str, kind: ArtifactKind, days_ago: u64, gib: u64) -> Artifact {
+    Artifact {
+        paths: vec![PathBuf::from(name)],
+        kind,
+        last_used: UNIX_EPOCH + days(1000) - days(days_ago),
+        size: gib * GIB,
+        in_use: false,
+    }
+}
+
+fn removed(artifacts: Vec<Artifact>, policy: &GcConfig, total_gib: u64) -> Vec<String> {
+    let now = UNIX_EPOCH + days(1000);
+    select(artifacts, policy, now, total_gib * GIB)
+        .into_iter()
+        .map(|(artifact, reason)| format!("{}: {reason}", artifact.paths[0].display()))
+        .collect()
+}
+
+#[test]
+fn keep_most_recently_used() {
+    let toolchain = || ArtifactKind::Toolchain("A-A".to_owned());
+    let artifacts = vec![
+        artifact("stage0", toolchain(), 3, 1),
+        artifact("stage1", toolchain(), 1, 1),
+        artifact("stage2", toolchain(), 2, 1),
+        artifact("doc", ArtifactKind::Docs("A-A".to_owned()), 10, 1),
+        artifact("llvm", ArtifactKind::UnusedLlvm, 0, 1),
+    ];
+    let policy = GcConfig { keep_docs: 1, ..GcConfig::default() };
+    assert_eq!(
+        removed(artifacts, &policy, 5),
+        ["llvm: not used by the configuration", "stage0: 2 more recently used ones are kept"]
+    );
+}
+
+#[test]
+fn max_age() {
+    let download = |name, days_ago| artifact(name, ArtifactKind::Download("CI rustc"), days_ago, 1);
+    let mut stage0 = download("2024-01-01", 60);
+    stage0.in_use = true;
+    let artifacts = vec![download("a", 5), download("b", 40), stage0];
+    let policy = GcConfig { max_age: Some(30), keep_toolchains: 3, ..GcConfig::default() };
+    assert_eq!(removed(artifacts, &policy, 3), ["b: not used for 40 days"]);
+}
+
+#[test]
+fn max_size() {
+    let toolchain = |target: &str| ArtifactKind::Toolchain(target.to_owned());
+    let artifacts = vec![
+        artifact("A-A/stage1", toolchain("A-A"), 1, 10),
+        artifact("B-B/stage1", toolchain("B-B"), 2, 10),
+        artifact("C-C/stage1", toolchain("C-C"), 3, 10),
+    ];
+    let policy = GcConfig { max_size: Some(15), max_age: None, ..GcConfig::default() };
+    assert_eq!(
+        removed(artifacts, &policy, 35),
+        [
+            "C-C/stage1: the build directory is larger than 15 GiB",
+            "B-B/stage1: the build directory is larger than 15 GiB",
+        ]
+    );
+}
+
+#[test]
+fn names() {
+    assert_eq!(stage_of("stage0"), Some(0));
+    assert_eq!(stage_of("stage1-std"), Some(1));
+    assert_eq!(stage_of("stage12-tools-bin"), Some(12));
+    assert_eq!(stage_of("stages"), None);
+    assert_eq!(stage_of("stage"), None);
+
+    assert_eq!(download_kind("2024-07-26"), "stage0 toolchain");
+    assert_eq!(download_kind("llvm-0123abcd-false"), "CI LLVM");
+    assert_eq!(download_kind("0123456789abcdef0123456789abcdef01234567"), "CI rustc");
+}
@@ -10,9 +10,10 @@
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::io::prelude::*;
-use std::io::BufReader;
+use std::io::{self, BufReader, IsTerminal};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::time::Instant;
 use std::{env, fs, str};
 
 use build_helper::git::get_closest_merge_commit;
@@ -24,6 +25,7 @@
 use crate::core::builder::{
     crate_description, Builder, Cargo, Kind, PathSet, RunConfig, ShouldRun, Step, TaskPath,
 };
+use crate::core::config::flags::Color;
 use crate::core::config::{DebuginfoLevel, LlvmLibunwind, RustcLto, TargetSelection};
 use crate::utils::exec::command;
 use crate::utils::helpers::{
@@ -31,6 +33,10 @@
 };
 use crate::{CLang, Compiler, DependencyType, GitRepo, Mode, LLVM_TOOLS};
 
+mod diagnostics;
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Std {
     pub target: TargetSelection,
@@ -242,6 +248,19 @@ fn run(self, builder: &Builder<'_>) {
             }
         }
 
+        // The stage 1 standard library can be downloaded from the remote cache of `build.cache`.
+        let stamp = libstd_stamp(builder, compiler, target);
+        let cache_key = std_cache_key(builder, &self);
+        if let Some(key) = &cache_key {
+            if fetch_cached_stamp(builder, "std", target, key, &stamp) {
+                builder.ensure(StdLink::from_std(
+                    self,
+                    builder.compiler(compiler.stage, builder.config.build),
+                ));
+                return;
+            }
+        }
+
         // We build a sysroot for mir-opt tests using the same trick that Miri does: A check build
         // with -Zalways-encode-mir. This frees us from the need to have a target linker, and the
         // fact that this is a check build integrates nicely with run_cargo.
@@ -292,11 +311,14 @@ fn run(self, builder: &Builder<'_>) {
             builder,
             cargo,
             vec![],
-            &libstd_stamp(builder, compiler, target),
+            &stamp,
             target_deps,
             self.is_for_mir_opt_tests, // is_check
             false,
         );
+        if let Some(key) = &cache_key {
+            store_cached_stamp(builder, "std", target, key, &stamp);
+        }
 
         builder.ensure(StdLink::from_std(
             self,
@@ -975,6 +997,19 @@ fn run(self, builder: &Builder<'_>) -> u32 {
             builder.config.build,
         ));
 
+        // The stage 1 compiler can be downloaded from the remote cache of `build.cache`.
+        let stamp = librustc_stamp(builder, compiler, target);
+        let cache_key = rustc_cache_key(builder, &self);
+        if let Some(key) = &cache_key {
+            if fetch_cached_stamp(builder, "rustc", target, key, &stamp) {
+                builder.ensure(RustcLink::from_rustc(
+                    self,
+                    builder.compiler(compiler.stage, builder.config.build),
+                ));
+                return compiler.stage;
+            }
+        }
+
         let mut cargo = builder::Cargo::new(
             builder,
             compiler,
@@ -1005,7 +1040,7 @@ fn run(self, builder: &Builder<'_>) -> u32 {
             compiler.host,
             target,
         );
-        let stamp = librustc_stamp(builder, compiler, target);
+        crate::utils::keep_stage::record_build_start(builder, &stamp);
         run_cargo(
             builder,
             cargo,
@@ -1029,6 +1064,10 @@ fn run(self, builder: &Builder<'_>) -> u32 {
             strip_debug(builder, target, &rustc_driver);
         }
 
+        if let Some(key) = &cache_key {
+            store_cached_stamp(builder, "rustc", target, key, &stamp);
+        }
+
         builder.ensure(RustcLink::from_rustc(
             self,
             builder.compiler(compiler.stage, builder.config.build),
@@ -1038,6 +1077,145 @@ fn run(self, builder: &Builder<'_>) -> u32 {
     }
 }
 
+/// The key of the standard library in the remote cache of `build.cache`, for the stage 1 builds.
+/// It's built by the stage 1 compiler, so it depends on the sources of both.
+fn std_cache_key(builder: &Builder<'_>, std: &Std) -> Option<String> {
+    if std.compiler.stage != 1 || std.is_for_mir_opt_tests || std.target.is_synthetic() {
+        return None;
+    }
+    let config = &builder.config;
+    let options = (
+        config.rust_debug_assertions_std,
+        config.rust_overflow_checks_std,
+        config.rust_debuginfo_level_std,
+        config.rust_codegen_units_std,
+        builder.std_features(std.target),
+    );
+    stage1_cache_key(
+        builder,
+        std.compiler.host,
+        &["library", "compiler", "src/bootstrap", "src/llvm-project", "src/stage0", "Cargo.lock"],
+        &format!("{std:?} {options:?}"),
+    )
+}
+
+/// The key of the stage 1 compiler in the remote cache of `build.cache`.
+fn rustc_cache_key(builder: &Builder<'_>, rustc: &Rustc) -> Option<String> {
+    if rustc.compiler.stage != 0 {
+        return None;
+    }
+    stage1_cache_key(
+        builder,
+        rustc.target,
+        &["compiler", "src/bootstrap", "src/llvm-project", "src/stage0", "Cargo.lock"],
+        &format!("{rustc:?}"),
+    )
+}
+
+/// The key of artifacts of the stage 1 compiler of `host` in the remote cache, the hash of the
+/// committed `sources`, of `options` and of the options changing the compiler. `None` if the cache
+/// is disabled, if the sources have uncommitted changes, or with an external LLVM.
+fn stage1_cache_key(
+    builder: &Builder<'_>,
+    host: TargetSelection,
+    sources: &[&str],
+    options: &str,
+) -> Option<String> {
+    let config = &builder.config;
+    if config.cache.url.is_none()
+        || config.target_config.get(&host).is_some_and(|target| target.llvm_config.is_some())
+    {
+        return None;
+    }
+    let flags = (
+        config.rust_debug_assertions,
+        config.rust_overflow_checks,
+        config.rust_rpath,
+        config.rustc_parallel,
+        config.jemalloc,
+        config.rust_randomize_layout,
+        config.llvm_from_ci,
+        config.omit_git_hash,
+    );
+    let values = (
+        &config.rust_optimize,
+        config.rust_debuginfo_level_rustc,
+        config.rust_codegen_units,
+        &config.rust_lto,
+        &config.rust_codegen_backends,
+        config.rust_new_symbol_mangling,
+        &config.channel,
+    );
+    let options = format!(
+        "{options} {} {} {flags:?} {values:?}",
+        builder.rust_version(),
+        llvm::llvm_cache_options(builder, host)
+    );
+    crate::generate_committed_sources_hash(builder, sources, &options)
+}
+
+/// Restores the artifacts `name` of `target` listed by `stamp` from the remote cache of
+/// `build.cache`, if it has the ones for `key`, in which case cargo doesn't need to run.
+fn fetch_cached_stamp(
+    builder: &Builder<'_>,
+    name: &str,
+    target: TargetSelection,
+    key: &str,
+    stamp: &Path,
+) -> bool {
+    if !builder.config.fetch_cached_artifacts(name, target, key, &builder.out) {
+        return false;
+    }
+    // The paths of the stamp in the cache are relative to the build directory.
+    let Some(contents) = relocate_stamp(&t!(fs::read(stamp)), Path::new(""), &builder.out) else {
+        return false;
+    };
+    t!(fs::write(stamp, contents));
+    true
+}
+
+/// Uploads the artifacts `name` of `target` listed by `stamp` to the remote cache of `build.cache`
+/// for `key`, if `build.cache.upload` is enabled.
+fn store_cached_stamp(
+    builder: &Builder<'_>,
+    name: &str,
+    target: TargetSelection,
+    key: &str,
+    stamp: &Path,
+) {
+    if !builder.config.cache.upload || builder.config.dry_run() {
+        return;
+    }
+    let Some(contents) = relocate_stamp(&t!(fs::read(stamp)), &builder.out, Path::new("")) else {
+        println!("not uploading {name} to the cache, its files aren't all in the build directory");
+        return;
+    };
+    let relative_stamp = builder.config.tempdir().join(format!("{name}-{target}.stamp"));
+    t!(fs::write(&relative_stamp, contents));
+    let mut files: Vec<_> = builder
+        .read_stamp_file(stamp)
+        .into_iter()
+        .map(|(file, _)| (t!(file.strip_prefix(&builder.out)).to_owned(), file))
+        .collect();
+    files.push((t!(stamp.strip_prefix(&builder.out)).to_owned(), relative_stamp.clone()));
+    builder.config.store_cached_artifacts(name, target, key, &files);
+    t!(fs::remove_file(&relative_stamp));
+}
+
+/// Rewrites the paths of a stamp of `run_cargo` in `from` to be in `to`, like the paths relative
+/// to the build directory of the stamps in the remote cache. `None` if a path isn't in `from`.
+fn relocate_stamp(contents: &[u8], from: &Path, to: &Path) -> Option<Vec<u8>> {
+    let mut relocated = Vec::new();
+    for part in contents.split(|b| *b == 0).filter(|part| !part.is_empty()) {
+        let (dependency_type, path) = part.split_at(1);
+        let path = Path::new(str::from_utf8(path).ok()?).strip_prefix(from).ok()?;
+        relocated.extend(dependency_type);
+        relocated.extend(to.join(path).to_str()?.as_bytes());
+        relocated.push(0);
+    }
+    Some(relocated)
+}
+
 pub fn rustc_cargo(
     builder: &Builder<'_>,
     cargo: &mut Cargo,
@@ -2034,7 +2212,7 @@ pub fn run_cargo(
         let (filenames, crate_types) = match msg {
             CargoMessage::CompilerArtifact {
                 filenames,
-                target: CargoTarget { crate_types },
+                target: CargoTarget { crate_types, .. },
                 ..
             } => (filenames, crate_types),
             _ => return,
@@ -2181,8 +2359,13 @@ pub fn stream_cargo(
     let cargo = cmd.as_command_mut();
     // Instruct Cargo to give us json messages on stdout, critically leaving
     // stderr as piped so we can get those pretty colors.
+    // The diagnostics are rendered by `Diagnostics`, which hides the duplicates, unless the
+    // output is verbose.
+    let render_diagnostics = !builder.config.json_output && !builder.config.is_verbose();
     let mut message_format = if builder.config.json_output {
         String::from("json")
+    } else if render_diagnostics {
+        String::from("json-diagnostic-rendered-ansi")
     } else {
         String::from("json-render-diagnostics")
     };
@@ -2192,21 +2375,47 @@ pub fn stream_cargo(
     }
     cargo.arg("--message-format").arg(message_format).stdout(Stdio::piped());
 
+    // Cargo reports how long each unit took in `timing-info` messages, which are exported with the
+    // build metrics, and reported by `--timings`.
+    if builder.config.timings
+        || cfg!(feature = "build-metrics") && !builder.config.metrics_exports.is_empty()
+    {
+        cargo.arg("-Zunstable-options").arg("--timings=json");
+    }
+
     for arg in tail_args {
         cargo.arg(arg);
     }
 
     builder.verbose(|| println!("running: {cargo:?}"));
 
+    #[cfg(test)]
+    builder.record_command(cargo);
+    if builder.config.plan {
+        crate::utils::plan::print(cargo);
+    }
+    if builder.config.why {
+        crate::utils::why::explain(builder, cargo);
+    }
     if builder.config.dry_run() {
         return true;
     }
+    crate::utils::why::record(builder, cargo);
 
+    let start = Instant::now();
+    builder.tui.cargo_start();
     let mut child = match cargo.spawn() {
         Ok(child) => child,
         Err(e) => panic!("failed to execute command: {cargo:?}\nERROR: {e}"),
     };
 
+    let color = match builder.config.color {
+        Color::Always => true,
+        Color::Never => false,
+        Color::Auto => io::stderr().is_terminal(),
+    };
+    let mut diagnostics = diagnostics::Diagnostics::new(color);
+
     // Spawn Cargo slurping up its JSON output. We'll start building up the
     // `deps` array of all files it generated along with a `toplevel` array of
     // files we need to probe for later.
@@ -2219,6 +2428,43 @@ pub fn stream_cargo(
                     // Forward JSON to stdout.
                     println!("{line}");
                 }
+                #[cfg(feature = "build-metrics")]
+                if let CargoMessage::TimingInfo { package_id, target, mode, duration, rmeta_time } =
+                    &msg
+                {
+                    builder.metrics.record_cargo_unit(
+                        package_id,
+                        &target.name,
+                        mode,
+                        *duration,
+                        *rmeta_time,
+                        builder,
+                    );
+                }
+                if let CargoMessage::TimingInfo { package_id, target, mode, duration, rmeta_time } =
+                    &msg
+                {
+                    builder.timings.record_cargo_unit(
+                        package_id,
+                        &target.name,
+                        mode,
+                        *duration,
+                        *rmeta_time,
+                        builder,
+                    );
+                }
+                if let CargoMessage::CompilerArtifact { target, .. } = &msg {
+                    builder.json_trace.cargo_artifact(&target.name, &target.crate_types);
+                    builder.tui.cargo_artifact(&target.name);
+                }
+                if let CargoMessage::CompilerMessage { target, message } = &msg {
+                    let rendered = message.rendered.as_deref().filter(|_| render_diagnostics);
+                    if let Some(rendered) =
+                        rendered.and_then(|rendered| diagnostics.record(&target.name, rendered))
+                    {
+                        eprint!("{rendered}");
+                    }
+                }
                 cb(msg)
             }
             // If this was informational, just print it out and continue
@@ -2226,8 +2472,14 @@ pub fn stream_cargo(
         }
     }
 
+    if let Some(summary) = diagnostics.summary() {
+        eprint!("{summary}");
+    }
+
     // Make sure Cargo actually succeeded after we read all of its stdout.
     let status = t!(child.wait());
+    builder.tui.cargo_finish();
+    builder.json_trace.command(cargo, status.code(), start.elapsed());
     if builder.is_verbose() && !status.success() {
         eprintln!(
             "command did not execute successfully: {cargo:?}\n\
@@ -2239,15 +2491,38 @@ pub fn stream_cargo(
 
 #[derive(Deserialize)]
 pub struct CargoTarget<'a> {
+    name: Cow<'a, str>,
     crate_types: Vec<Cow<'a, str>>,
 }
 
+/// The part of a diagnostic of rustc forwarded by cargo which bootstrap prints.
+#[derive(Deserialize)]
+pub struct CargoDiagnostic<'a> {
+    rendered: Option<Cow<'a, str>>,
+}
+
 #[derive(Deserialize)]
 #[serde(tag = "reason", rename_all = "kebab-case")]
 pub enum CargoMessage<'a> {
-    CompilerArtifact { filenames: Vec<Cow<'a, str>>, target: CargoTarget<'a> },
+    CompilerArtifact {
+        filenames: Vec<Cow<'a, str>>,
+        target: CargoTarget<'a>,
+    },
+    /// Only emitted when cargo doesn't render the diagnostics itself.
+    CompilerMessage {
+        target: CargoTarget<'a>,
+        message: CargoDiagnostic<'a>,
+    },
     BuildScriptExecuted,
     BuildFinished,
+    /// Only emitted with `--timings=json`.
+    TimingInfo {
+        package_id: Cow<'a, str>,
+        target: CargoTarget<'a>,
+        mode: Cow<'a, str>,
+        duration: f64,
+        rmeta_time: Option<f64>,
+    },
 }
 
 pub fn strip_debug(builder: &Builder<'_>, target: TargetSelection, path: &Path) {
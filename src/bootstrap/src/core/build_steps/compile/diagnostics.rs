@@ -0,0 +1,82 @@
+//! The rendering of the diagnostics of the crates built by cargo, which hides the duplicates, so
+//! that a warning of a macro expanded in many crates is printed once instead of once per crate.
+//! Once cargo finishes, the duplicates are counted with the crates which emitted them.
+//!
+//! Cargo renders the diagnostics itself with `--verbose`, and `--json-output` forwards them.
+
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default)]
+pub(super) struct Diagnostics {
+    /// Whether the diagnostics are printed with their colors.
+    color: bool,
+    /// The index of each diagnostic in `emitted`, by its rendering.
+    indices: HashMap<String, usize>,
+    /// The diagnostics, in the order they were first emitted, with the crates which emitted them.
+    emitted: Vec<(String, Vec<String>)>,
+}
+
+impl Diagnostics {
+    pub(super) fn new(color: bool) -> Self {
+        Diagnostics { color, ..Default::default() }
+    }
+
+    /// Records the diagnostic `rendered`, with colors, of the crate `krate`, and returns what to
+    /// print for it, which is nothing if it was already emitted.
+    pub(super) fn record(&mut self, krate: &str, rendered: &str) -> Option<String> {
+        if let Some(&index) = self.indices.get(rendered) {
+            self.emitted[index].1.push(krate.to_owned());
+            return None;
+        }
+        self.indices.insert(rendered.to_owned(), self.emitted.len());
+        self.emitted.push((rendered.to_owned(), vec![krate.to_owned()]));
+        Some(if self.color { rendered.to_owned() } else { strip_colors(rendered) })
+    }
+
+    /// Returns the count of the duplicates which were hidden, with the first line of each
+    /// diagnostic and the crates which emitted it, if any were.
+    pub(super) fn summary(&self) -> Option<String> {
+        let mut hidden = 0;
+        let mut summary = String::new();
+        for (rendered, crates) in &self.emitted {
+            if crates.len() < 2 {
+                continue;
+            }
+            hidden += crates.len() - 1;
+            let mut names: Vec<&str> = crates.iter().map(String::as_str).collect();
+            names.sort_unstable();
+            names.dedup();
+            let rendered = strip_colors(rendered);
+            summary.push_str(&format!(
+                "    {}\n        emitted {} times, by {}\n",
+                rendered.lines().next().unwrap_or_default(),
+                crates.len(),
+                names.join(", ")
+            ));
+        }
+        if hidden == 0 {
+            return None;
+        }
+        Some(format!(
+            "note: {hidden} duplicate diagnostics were hidden, `--verbose` shows them:\n{summary}"
+        ))
+    }
+}
+
+/// Removes the escape sequences of the colors from `text`, like `\x1b[1m`.
+fn strip_colors(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // The sequences end with a letter, like `m`.
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn hide_duplicate_diagnostics() {
+    let warning = "\x1b[1m\x1b[33mwarning\x1b[0m: unused import: `Foo`\n --> src/lib.rs:1:5\n";
+    let mut diagnostics = Diagnostics::new(false);
+    assert_eq!(
+        diagnostics.record("core", warning).as_deref(),
+        Some("warning: unused import: `Foo`\n --> src/lib.rs:1:5\n")
+    );
+    assert_eq!(diagnostics.record("alloc", warning), None);
+    assert_eq!(diagnostics.record("core", warning), None);
+    assert!(diagnostics.record("std", "error: mismatched types\n").is_some());
+
+    assert_eq!(
+        diagnostics.summary().as_deref(),
+        Some(
+            "note: 2 duplicate diagnostics were hidden, `--verbose` shows them:\n    \
+             warning: unused import: `Foo`\n        emitted 3 times, by alloc, core\n"
+        )
+    );
+    assert_eq!(Diagnostics::new(true).summary(), None);
+
+    // The colors are kept when they're enabled.
+    assert_eq!(Diagnostics::new(true).record("core", warning).as_deref(), Some(warning));
+}
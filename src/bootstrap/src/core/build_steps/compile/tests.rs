@@ -0,0 +1,13 @@
+use super::*;
+
+#[test]
+fn relocated_stamps() {
+    let stamp = b"t/build/host/stage1-std/deps/libstd.rlib\0s/build/host/stage1/crt1.o\0";
+    let relative = relocate_stamp(stamp, Path::new("/build"), Path::new("")).unwrap();
+    assert_eq!(relative, b"thost/stage1-std/deps/libstd.rlib\0shost/stage1/crt1.o\0");
+    let absolute = relocate_stamp(&relative, Path::new(""), Path::new("/build")).unwrap();
+    assert_eq!(relocate_stamp(&absolute, Path::new("/build"), Path::new("")).unwrap(), relative);
+
+    // The artifacts outside of the build directory can't be relocated.
+    assert_eq!(relocate_stamp(b"h/usr/lib/libLLVM.so\0", Path::new("/build"), Path::new("")), None);
+}
@@ -0,0 +1,136 @@
+//! Implementation of `x completions`, which prints the completion script of a shell for `x.py`,
+//! `x` and their `./` forms.
+//!
+//! The script is the one `x run generate-completions` writes to `src/etc/completions`, from the
+//! options of `flags.rs`, followed, for bash, zsh and fish, by the completion of the paths of the
+//! steps of each subcommand in this checkout, from their `should_run`, like `tests/ui` or the
+//! profiles of `x setup`. The paths are completed in addition to the files, so that the paths of
+//! single tests still complete.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::{self, Write as _};
+use std::iter;
+
+use clap::{CommandFactory, ValueEnum};
+use clap_complete::Shell;
+
+use crate::core::builder::{Builder, Kind};
+use crate::core::config::flags::Flags;
+use crate::utils::helpers::t;
+
+#[cfg(test)]
+mod tests;
+
+/// The names of the commands completed.
+const COMMANDS: &[&str] = &["x.py", "x", "./x.py", "./x"];
+
+/// Prints the completion script of `shell`, by default the one of `$SHELL`.
+pub fn completions(builder: &Builder<'_>, shell: Option<Shell>) {
+    let Some(shell) = shell.or_else(Shell::from_env) else {
+        eprintln!("ERROR: can't find the current shell in $SHELL");
+        eprintln!("HELP: pass it, like `x completions bash`");
+        crate::exit!(1);
+    };
+
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut Flags::command(), "x.py", &mut script);
+    let mut script = String::from_utf8(script).expect("completion script should be UTF-8");
+
+    let mut paths = BTreeMap::new();
+    for &kind in Kind::value_variants() {
+        if matches!(kind, Kind::MiriSetup | Kind::MiriTest) {
+            continue;
+        }
+        let kind_paths: Vec<String> = Builder::completion_paths(builder, kind)
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        if !kind_paths.is_empty() {
+            paths.insert(subcommand_pattern(kind), kind_paths);
+        }
+    }
+    match shell {
+        Shell::Bash => script.push_str(&bash(&paths)),
+        Shell::Zsh => script.push_str(&zsh(&paths)),
+        Shell::Fish => script.push_str(&fish(&paths)),
+        _ => {}
+    }
+    t!(io::stdout().write_all(script.as_bytes()));
+}
+
+/// The names of the subcommand of `kind` and of its aliases, like `build|b`.
+fn subcommand_pattern(kind: Kind) -> String {
+    let value = kind.to_possible_value().expect("the kinds aren't skipped");
+    let aliases = value.get_name_and_aliases().skip(1);
+    iter::once(kind.as_str()).chain(aliases).collect::<Vec<_>>().join("|")
+}
+
+/// Adds the paths to the completions of `_x.py`, by the first subcommand in the words.
+fn bash(paths: &BTreeMap<String, Vec<String>>) -> String {
+    let mut script = String::from(
+        "
+_x.py_steps() {
+    _x.py \"$@\"
+    local cur=\"${COMP_WORDS[COMP_CWORD]}\" word
+    if [[ ${cur} == -* ]]; then
+        return 0
+    fi
+    for word in \"${COMP_WORDS[@]:1:COMP_CWORD-1}\"; do
+        case \"${word}\" in
+",
+    );
+    for (subcommand, paths) in paths {
+        writeln!(script, "            {subcommand})").unwrap();
+        writeln!(
+            script,
+            "                COMPREPLY+=($(compgen -W \"{}\" -- \"${{cur}}\"))",
+            paths.join(" ")
+        )
+        .unwrap();
+        writeln!(script, "                return 0\n                ;;").unwrap();
+    }
+    script.push_str("        esac\n    done\n}\n\n");
+    writeln!(script, "complete -F _x.py_steps -o bashdefault -o default {}", COMMANDS.join(" "))
+        .unwrap();
+    script
+}
+
+/// Adds the paths to the completions of `_x.py`, for the scripts which are sourced.
+fn zsh(paths: &BTreeMap<String, Vec<String>>) -> String {
+    let mut script = String::from(
+        "
+_x.py_steps() {
+    _x.py \"$@\"
+    local word
+    for word in ${words[2,CURRENT-1]}; do
+        case $word in
+",
+    );
+    for (subcommand, paths) in paths {
+        writeln!(script, "            ({subcommand}) compadd -- {}; return ;;", paths.join(" "))
+            .unwrap();
+    }
+    script.push_str("        esac\n    done\n}\n\n");
+    writeln!(script, "compdef _x.py_steps {}", COMMANDS.join(" ")).unwrap();
+    script
+}
+
+/// Adds the paths as arguments of the subcommands, and completes `x` like `x.py`.
+fn fish(paths: &BTreeMap<String, Vec<String>>) -> String {
+    let mut script = String::from("\n");
+    for (subcommand, paths) in paths {
+        for subcommand in subcommand.split('|') {
+            writeln!(
+                script,
+                "complete -c x.py -n \"__fish_x.py_using_subcommand {subcommand}\" -a \"{}\"",
+                paths.join(" ")
+            )
+            .unwrap();
+        }
+    }
+    for command in &COMMANDS[1..] {
+        writeln!(script, "complete -c {command} -w x.py").unwrap();
+    }
+    script
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+fn paths() -> BTreeMap<String, Vec<String>> {
+    [
+        ("build|b".to_owned(), vec!["compiler".to_owned(), "library".to_owned()]),
+        ("setup".to_owned(), vec!["compiler".to_owned(), "hook".to_owned()]),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn bash_paths() {
+    let script = bash(&paths());
+    assert!(script.contains(
+        "            build|b)\n                COMPREPLY+=($(compgen -W \"compiler library\" -- \
+         \"${cur}\"))\n                return 0\n                ;;\n"
+    ));
+    assert!(
+        script.ends_with("complete -F _x.py_steps -o bashdefault -o default x.py x ./x.py ./x\n")
+    );
+}
+
+#[test]
+fn zsh_paths() {
+    let script = zsh(&paths());
+    assert!(script.contains("            (setup) compadd -- compiler hook; return ;;\n"));
+    assert!(script.ends_with("compdef _x.py_steps x.py x ./x.py ./x\n"));
+}
+
+#[test]
+fn fish_paths() {
+    let script = fish(&paths());
+    // The aliases are separate subcommands for fish.
+    assert!(script.contains(
+        "complete -c x.py -n \"__fish_x.py_using_subcommand b\" -a \"compiler library\"\n"
+    ));
+    assert!(script.contains("complete -c ./x -w x.py\n"));
+}
+
+#[test]
+fn subcommand_patterns() {
+    assert_eq!(subcommand_pattern(Kind::Build), "build|b");
+    assert_eq!(subcommand_pattern(Kind::Format), "fmt");
+}
@@ -130,6 +130,7 @@ struct RustbookSrc<P: Step> {
 
 impl<P: Step> Step for RustbookSrc<P> {
     type Output = ();
+    const DETACHED: bool = true;
 
     fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
         run.never()
@@ -149,6 +150,9 @@ fn run(self, builder: &Builder<'_>) {
         let out = out.join(&name);
         let index = out.join("index.html");
         let rustbook = builder.tool_exe(Tool::Rustbook);
+        // The book containing this one, like the main book for its older editions, may still be
+        // rendered in the background, which removes this one first.
+        builder.wait_for_writes_to(&out);
 
         if !builder.config.dry_run()
             && (!up_to_date(&src, &index) || !up_to_date(&rustbook, &index))
@@ -167,7 +171,8 @@ fn run(self, builder: &Builder<'_>) {
                 rustbook_cmd.env("PATH", new_path);
             }
 
-            rustbook_cmd.arg("build").arg(&src).arg("-d").arg(&out).run(builder);
+            rustbook_cmd.arg("build").arg(&src).arg("-d").arg(&out);
+            builder.run_in_background(format!("Rustbook ({target}) - {name}"), &out, rustbook_cmd);
 
             for lang in &self.languages {
                 let out = out.join(lang);
@@ -175,15 +180,13 @@ fn run(self, builder: &Builder<'_>) {
                 builder.info(&format!("Rustbook ({target}) - {name} - {lang}"));
                 let _ = fs::remove_dir_all(&out);
 
-                builder
-                    .tool_cmd(Tool::Rustbook)
-                    .arg("build")
-                    .arg(&src)
-                    .arg("-d")
-                    .arg(&out)
-                    .arg("-l")
-                    .arg(lang)
-                    .run(builder);
+                let mut rustbook_cmd = builder.tool_cmd(Tool::Rustbook);
+                rustbook_cmd.arg("build").arg(&src).arg("-d").arg(&out).arg("-l").arg(lang);
+                builder.run_in_background(
+                    format!("Rustbook ({target}) - {name} - {lang}"),
+                    &out,
+                    rustbook_cmd,
+                );
             }
         }
 
@@ -1,46 +1,236 @@
-//! Runs rustfmt on the repository.
+//! Runs rustfmt on the repository, and the formatters of the other languages enabled in the
+//! `[build.formatters]` section of `config.toml`.
 
-use std::collections::VecDeque;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::ffi::OsString;
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::SyncSender;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 
 use build_helper::ci::CiEnv;
-use build_helper::git::get_git_modified_files;
+use build_helper::git::{get_git_modified_files, get_git_modified_files_since};
+use ignore::overrides::Override;
 use ignore::WalkBuilder;
 
-use crate::core::builder::Builder;
+use crate::core::builder::{Builder, Kind};
+use crate::core::config::flags::{FmtMessageFormat, FormatArgs};
+use crate::core::config::PythonFormatter;
 use crate::utils::exec::command;
 use crate::utils::helpers::{self, program_out_of_date, t};
+use crate::utils::jobserver;
 
-fn rustfmt(src: &Path, rustfmt: &Path, paths: &[PathBuf], check: bool) -> impl FnMut(bool) -> bool {
-    let mut cmd = Command::new(rustfmt);
+#[cfg(unix)]
+mod daemon;
+mod markdown;
+#[cfg(test)]
+mod tests;
+
+/// What the formatters do with the files.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Fix,
+    Check,
+    /// Check the files, and print a JSON record for each misformatted file.
+    CheckJson,
+    /// Format the files saved by `x fmt --watch`, reporting failures without exiting.
+    Watch,
+}
+
+/// A formatter of the files with some extensions.
+struct Formatter {
+    name: &'static str,
+    extensions: &'static [&'static str],
+    /// Restricts the formatter to the files with these names, like `Cargo.toml`.
+    file_names: Option<&'static [&'static str]>,
+    program: PathBuf,
+    args: Vec<OsString>,
+    /// The arguments for formatting the files.
+    fix_args: &'static [&'static str],
+    /// The arguments for failing if the files aren't formatted, instead of formatting them.
+    check_args: &'static [&'static str],
+    /// The arguments for printing the mismatches in the JSON format of `rustfmt --emit json`,
+    /// instead of formatting the files. Without them, `--message-format=json` only reports the
+    /// failure of the check.
+    json_args: Option<&'static [&'static str]>,
+    /// The arguments for formatting the source read from stdin as the given file, and printing it
+    /// to stdout.
+    stdin_args: fn(&Path) -> Vec<OsString>,
+}
+
+/// Returns rustfmt, followed by the formatters enabled in `config.toml`.
+fn formatters(build: &Builder<'_>, rustfmt: PathBuf) -> Vec<Formatter> {
+    let config = &build.config.formatters;
+    let tidy_config = build.src.join("src/tools/tidy/config");
     // Avoid the submodule config paths from coming into play. We only allow a single global config
     // for the workspace for now.
-    cmd.arg("--config-path").arg(src.canonicalize().unwrap());
-    cmd.arg("--edition").arg("2021");
-    cmd.arg("--unstable-features");
-    cmd.arg("--skip-children");
-    if check {
-        cmd.arg("--check");
+    let rustfmt_config = build.src.canonicalize().unwrap();
+    let mut formatters = vec![Formatter {
+        name: "rustfmt",
+        extensions: &["rs"],
+        file_names: None,
+        program: rustfmt,
+        args: vec![
+            "--config-path".into(),
+            rustfmt_config.into(),
+            "--edition".into(),
+            "2021".into(),
+            "--unstable-features".into(),
+            "--skip-children".into(),
+        ],
+        fix_args: &[],
+        check_args: &["--check"],
+        json_args: Some(&["--emit", "json"]),
+        stdin_args: |_| vec![],
+    }];
+    let program = |path: &Option<PathBuf>, name: &str| path.clone().unwrap_or_else(|| name.into());
+    match config.python {
+        Some(PythonFormatter::Ruff) => formatters.push(Formatter {
+            name: "ruff",
+            extensions: &["py"],
+            file_names: None,
+            program: program(&config.ruff, "ruff"),
+            args: vec![
+                "format".into(),
+                "--quiet".into(),
+                "--config".into(),
+                tidy_config.join("ruff.toml").into(),
+            ],
+            fix_args: &[],
+            check_args: &["--check"],
+            json_args: None,
+            stdin_args: |path| vec!["--stdin-filename".into(), path.into()],
+        }),
+        Some(PythonFormatter::Black) => formatters.push(Formatter {
+            name: "black",
+            extensions: &["py"],
+            file_names: None,
+            program: program(&config.black, "black"),
+            args: vec!["--quiet".into(), "--config".into(), tidy_config.join("black.toml").into()],
+            fix_args: &[],
+            check_args: &["--check"],
+            json_args: None,
+            stdin_args: |path| vec!["--stdin-filename".into(), path.into(), "-".into()],
+        }),
+        None => {}
+    }
+    if config.shell {
+        formatters.push(Formatter {
+            name: "shfmt",
+            extensions: &["sh"],
+            file_names: None,
+            program: program(&config.shfmt, "shfmt"),
+            args: vec![],
+            fix_args: &["--write"],
+            check_args: &["--diff"],
+            json_args: None,
+            stdin_args: |path| vec!["--filename".into(), path.into()],
+        });
+    }
+    if config.toml || config.manifests {
+        // The configuration sorts the dependency tables of the manifests.
+        formatters.push(Formatter {
+            name: "taplo",
+            extensions: &["toml"],
+            file_names: if config.toml { None } else { Some(&["Cargo.toml"]) },
+            program: program(&config.taplo, "taplo"),
+            args: vec!["fmt".into(), "--config".into(), tidy_config.join("taplo.toml").into()],
+            fix_args: &[],
+            check_args: &["--check"],
+            json_args: None,
+            stdin_args: |path| vec!["--stdin-filepath".into(), path.into(), "-".into()],
+        });
     }
+    formatters
+}
+
+impl Formatter {
+    /// Whether the formatter formats the file at `path`.
+    fn handles(&self, path: &Path) -> bool {
+        let has_extension =
+            path.extension().is_some_and(|ext| self.extensions.iter().any(|e| ext == *e));
+        let has_name = self.file_names.map_or(true, |names| {
+            path.file_name().is_some_and(|name| names.iter().any(|n| name == *n))
+        });
+        has_extension && has_name
+    }
+}
+
+/// Spawns `formatter` on `paths`. With `Mode::CheckJson`, the JSON records of the misformatted
+/// files are printed once it completes. The changes needed by the misformatted files are
+/// appended to `patch` when checking, if the formatter can print them, and the time it took for
+/// each file to `timings`, if any.
+fn run_formatter<'a>(
+    formatter: &Formatter,
+    paths: &[PathBuf],
+    mode: Mode,
+    src: &'a Path,
+    patch: &'a RefCell<String>,
+    timings: Option<&'a RefCell<Vec<FileTiming>>>,
+) -> impl FnMut(bool) -> bool + 'a {
+    let mut cmd = Command::new(&formatter.program);
+    cmd.args(&formatter.args);
+    match (mode, formatter.json_args) {
+        (Mode::Fix | Mode::Watch, _) => cmd.args(formatter.fix_args),
+        // The output is replaced by the preview of the patch, see `patch_preview`.
+        (Mode::Check, Some(_)) => cmd.args(formatter.check_args).stdout(Stdio::piped()),
+        (Mode::Check, None) => cmd.args(formatter.check_args),
+        (Mode::CheckJson, Some(json_args)) => cmd.args(json_args).stdout(Stdio::piped()),
+        // Keep stdout for the JSON records.
+        (Mode::CheckJson, None) => cmd.args(formatter.check_args).stdout(io::stderr()),
+    };
     cmd.args(paths);
     let cmd_debug = format!("{cmd:?}");
-    let mut cmd = cmd.spawn().expect("running rustfmt");
-    // Poor man's async: return a closure that might wait for rustfmt's completion (depending on
-    // the value of the `block` argument).
+    // When the human check fails, the formatter runs again to print the mismatches for the patch.
+    let mut json_cmd = formatter.json_args.filter(|_| mode == Mode::Check).map(|json_args| {
+        let mut json_cmd = Command::new(&formatter.program);
+        json_cmd.args(&formatter.args).args(json_args).args(paths);
+        json_cmd
+    });
+    let (name, paths) = (formatter.name, paths.to_vec());
+    let start = Instant::now();
+    let mut cmd = cmd.spawn().unwrap_or_else(|err| panic!("running {}: {err}", formatter.name));
+    // Read the output while the formatter runs, so that it doesn't block on a full pipe.
+    let mut stdout = cmd.stdout.take().map(|mut stdout| {
+        std::thread::spawn(move || {
+            let mut output = String::new();
+            t!(stdout.read_to_string(&mut output));
+            output
+        })
+    });
+    // Wait on another thread, so that the time of the formatter doesn't include the time until the
+    // completion is polled.
+    let mut waiter = Some(std::thread::spawn(move || (cmd.wait(), start.elapsed())));
+    // Poor man's async: return a closure that might wait for the formatter's completion (depending
+    // on the value of the `block` argument).
     move |block: bool| -> bool {
-        let status = if !block {
-            match cmd.try_wait() {
-                Ok(Some(status)) => Ok(status),
-                Ok(None) => return false,
-                Err(err) => Err(err),
-            }
-        } else {
-            cmd.wait()
-        };
+        if !block && !waiter.as_ref().unwrap().is_finished() {
+            return false;
+        }
+        let (status, elapsed) = waiter.take().unwrap().join().unwrap();
+        if let Some(timings) = timings {
+            timings.borrow_mut().extend(file_timings(name, &paths, src, elapsed));
+        }
+        let output = stdout.take().map(|stdout| stdout.join().unwrap()).unwrap_or_default();
         if !status.unwrap().success() {
+            if mode == Mode::Watch {
+                // Likely a file saved with a syntax error.
+                eprintln!("fmt error: Running `{cmd_debug}` failed.");
+                return true;
+            }
+            if let Some(json_cmd) = &mut json_cmd {
+                // Report all the misformatted files before failing. Without mismatches, the check
+                // failed for another reason, like a syntax error.
+                let json = t!(json_cmd.output());
+                if record_mismatches(&String::from_utf8_lossy(&json.stdout), mode, src, patch) {
+                    return true;
+                }
+                print!("{output}");
+            }
             eprintln!(
                 "fmt error: Running `{}` failed.\nIf you're running `tidy`, \
                 try again with `--bless`. Or, if you just want to format \
@@ -49,10 +239,265 @@ fn rustfmt(src: &Path, rustfmt: &Path, paths: &[PathBuf], check: bool) -> impl F
             );
             crate::exit!(1);
         }
+        if mode == Mode::CheckJson {
+            record_mismatches(&output, mode, src, patch);
+        }
         true
     }
 }
 
+/// Appends the changes of the mismatches printed by `rustfmt --emit json` to `patch`, and prints
+/// their JSON records with `Mode::CheckJson`. Returns whether there are any.
+fn record_mismatches(output: &str, mode: Mode, src: &Path, patch: &RefCell<String>) -> bool {
+    let mismatches: Vec<RustfmtMismatches> =
+        if output.trim().is_empty() { vec![] } else { t!(serde_json::from_str(output)) };
+    for file in &mismatches {
+        let path = file.name.strip_prefix(src).unwrap_or(&file.name).display().to_string();
+        let source = t!(std::fs::read_to_string(&file.name));
+        let record = MisformattedFile { path, edits: edits(&source, &file.mismatches) };
+        patch.borrow_mut().push_str(&unified_diff(&record.path, &source, &record.edits));
+        if mode == Mode::CheckJson {
+            println!("{}", t!(serde_json::to_string(&record)));
+        }
+    }
+    !mismatches.is_empty()
+}
+
+/// The mismatches of a file in the output of `rustfmt --emit json`.
+#[derive(serde_derive::Deserialize)]
+struct RustfmtMismatches {
+    name: PathBuf,
+    mismatches: Vec<RustfmtMismatch>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RustfmtMismatch {
+    original_begin_line: usize,
+    original_end_line: usize,
+    /// The original lines, each followed by a newline.
+    original: String,
+    /// The formatted lines, each followed by a newline.
+    expected: String,
+}
+
+/// The record of a misformatted file printed by `x fmt --check --message-format=json`.
+#[derive(serde_derive::Serialize)]
+struct MisformattedFile {
+    /// The path relative to the root of the repository.
+    path: String,
+    edits: Vec<Edit>,
+}
+
+/// The summary of `x fmt` written by `--summary-json`.
+#[derive(serde_derive::Serialize, Default)]
+struct FormatSummary {
+    /// The files rewritten by the formatters, relative to the root of the repository.
+    rewritten: Vec<String>,
+    /// The number of formatted files which were already formatted.
+    unchanged: usize,
+    /// The number of formatter processes spawned.
+    processes: usize,
+    elapsed_secs: f64,
+}
+
+/// The time a formatter took for a file with `x fmt --timings`, which is the time of its batch
+/// split by the sizes of the files.
+#[derive(serde_derive::Serialize, Debug, PartialEq)]
+struct FileTiming {
+    /// The path relative to the root of the repository.
+    path: String,
+    formatter: &'static str,
+    secs: f64,
+    /// The number of files of the batch. With 1, the time is exact.
+    batch_files: usize,
+}
+
+/// Splits the time a formatter took for a batch between its files, by their sizes.
+fn file_timings(
+    formatter: &'static str,
+    paths: &[PathBuf],
+    src: &Path,
+    elapsed: Duration,
+) -> Vec<FileTiming> {
+    let sizes: Vec<u64> =
+        paths.iter().map(|path| path.metadata().map_or(0, |metadata| metadata.len())).collect();
+    let total: u64 = sizes.iter().sum();
+    paths
+        .iter()
+        .zip(sizes)
+        .map(|(path, size)| {
+            let share =
+                if total == 0 { 1.0 / paths.len() as f64 } else { size as f64 / total as f64 };
+            FileTiming {
+                path: path.strip_prefix(src).unwrap_or(path).display().to_string(),
+                formatter,
+                secs: elapsed.as_secs_f64() * share,
+                batch_files: paths.len(),
+            }
+        })
+        .collect()
+}
+
+/// Prints the `max` slowest files of `timings`, and writes all of them to `path` as JSON, the
+/// slowest first.
+fn report_timings(mut timings: Vec<FileTiming>, max: usize, path: &Path) {
+    timings.sort_by(|a, b| b.secs.total_cmp(&a.secs));
+    let total: f64 = timings.iter().map(|timing| timing.secs).sum();
+    let shown = max.min(timings.len());
+    println!("fmt: the {shown} slowest of {} files ({total:.2}s in total):", timings.len());
+    for timing in timings.iter().take(max) {
+        let batch = match timing.batch_files {
+            1 => String::new(),
+            files => format!(", estimated from a batch of {files} files"),
+        };
+        println!("{:>8.3}s {} ({}{batch})", timing.secs, timing.path, timing.formatter);
+    }
+    t!(std::fs::write(path, t!(serde_json::to_string_pretty(&timings))));
+    println!("fmt: the times of all files are written to {}", path.display());
+}
+
+/// Writes the summary of a formatting run to `path`, if any.
+fn write_summary(mode: Mode, path: Option<&Path>, summary: &FormatSummary) {
+    if let (Mode::Fix, Some(path)) = (mode, path) {
+        t!(std::fs::write(path, t!(serde_json::to_string_pretty(summary))));
+    }
+}
+
+/// A replacement of the bytes `byte_start..byte_end` of a file, which are the lines
+/// `line_start..=line_end`, or an insertion before `line_start` if the range is empty.
+#[derive(serde_derive::Serialize, Debug, PartialEq, Eq)]
+struct Edit {
+    byte_start: usize,
+    byte_end: usize,
+    line_start: usize,
+    line_end: usize,
+    replacement: String,
+}
+
+/// Converts the line-based mismatches of rustfmt to byte ranges of `source`.
+fn edits(source: &str, mismatches: &[RustfmtMismatch]) -> Vec<Edit> {
+    // The offsets of the starts of the lines, and the end of the file.
+    let mut line_starts = vec![0];
+    line_starts.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+    if line_starts.last() != Some(&source.len()) {
+        line_starts.push(source.len());
+    }
+    let offset = |line: usize| line_starts.get(line).copied().unwrap_or(source.len());
+    mismatches
+        .iter()
+        .map(|mismatch| {
+            let first_line = mismatch.original_begin_line.saturating_sub(1);
+            let lines = mismatch.original.matches('\n').count();
+            Edit {
+                byte_start: offset(first_line),
+                byte_end: offset(first_line + lines),
+                line_start: mismatch.original_begin_line,
+                line_end: mismatch.original_end_line,
+                replacement: mismatch.expected.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Returns the first `max_hunks` hunks of `patch`, followed by the number of hunks of each file,
+/// which `x fmt --check` prints when it fails.
+fn patch_preview(patch: &str, max_hunks: usize) -> String {
+    let mut preview = String::new();
+    let mut hunks_of_files: Vec<(&str, usize)> = Vec::new();
+    let mut shown = 0;
+    let mut showing = true;
+    for line in patch.lines() {
+        if let Some(paths) = line.strip_prefix("diff --git a/") {
+            hunks_of_files.push((paths.split(" b/").next().unwrap(), 0));
+            showing = shown < max_hunks;
+        } else if line.starts_with("@@ ") {
+            hunks_of_files.last_mut().unwrap().1 += 1;
+            showing = shown < max_hunks;
+            shown += usize::from(showing);
+        }
+        if showing {
+            preview.push_str(line);
+            preview.push('\n');
+        }
+    }
+    let total: usize = hunks_of_files.iter().map(|(_, hunks)| hunks).sum();
+    if total > shown {
+        preview.push_str(&format!("... and {} more hunks\n", total - shown));
+    }
+    for (path, hunks) in hunks_of_files {
+        let plural = if hunks == 1 { "" } else { "s" };
+        preview.push_str(&format!("{path}: {hunks} change{plural} pending\n"));
+    }
+    preview
+}
+
+/// Returns the changes of `edits` to the file at `path` as a unified diff, with 3 lines of context
+/// like `git diff`, so that `git apply` accepts it.
+fn unified_diff(path: &str, source: &str, edits: &[Edit]) -> String {
+    const CONTEXT: usize = 3;
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+    // The range of original lines replaced by each edit, and the replacement lines.
+    let changes: Vec<(usize, usize, Vec<&str>)> = edits
+        .iter()
+        .map(|edit| {
+            let start = edit.line_start.saturating_sub(1).min(lines.len());
+            let len = source[edit.byte_start..edit.byte_end].split_inclusive('\n').count();
+            (start, start + len, edit.replacement.split_inclusive('\n').collect())
+        })
+        .collect();
+
+    let push_line = |diff: &mut String, prefix: char, line: &str| {
+        diff.push(prefix);
+        diff.push_str(line);
+        if !line.ends_with('\n') {
+            diff.push_str("\n\\ No newline at end of file\n");
+        }
+    };
+    let mut diff = format!("diff --git a/{path} b/{path}\n--- a/{path}\n+++ b/{path}\n");
+    // The number of lines added by the previous hunks.
+    let mut offset = 0isize;
+    let mut i = 0;
+    while i < changes.len() {
+        // Changes whose contexts touch are in the same hunk.
+        let mut j = i + 1;
+        while j < changes.len() && changes[j].0 <= changes[j - 1].1 + 2 * CONTEXT {
+            j += 1;
+        }
+        let hunk = &changes[i..j];
+        let start = hunk[0].0.saturating_sub(CONTEXT);
+        let end = (hunk[hunk.len() - 1].1 + CONTEXT).min(lines.len());
+        let removed: usize = hunk.iter().map(|(start, end, _)| end - start).sum();
+        let added: usize = hunk.iter().map(|(_, _, replacement)| replacement.len()).sum();
+        let old_len = end - start;
+        let new_len = old_len - removed + added;
+        // An empty range starts at the line before it.
+        let old_start = if old_len == 0 { start } else { start + 1 };
+        let new_start = if new_len == 0 { start } else { start + 1 };
+        let new_start = (new_start as isize + offset) as usize;
+        diff.push_str(&format!("@@ -{old_start},{old_len} +{new_start},{new_len} @@\n"));
+
+        let mut line = start;
+        for (change_start, change_end, replacement) in hunk {
+            for context in &lines[line..*change_start] {
+                push_line(&mut diff, ' ', context);
+            }
+            for original in &lines[*change_start..*change_end] {
+                push_line(&mut diff, '-', original);
+            }
+            for replacement in replacement {
+                push_line(&mut diff, '+', replacement);
+            }
+            line = *change_end;
+        }
+        for context in &lines[line..end] {
+            push_line(&mut diff, ' ', context);
+        }
+        offset += added as isize - removed as isize;
+        i = j;
+    }
+    diff
+}
+
 fn get_rustfmt_version(build: &Builder<'_>) -> Option<(String, PathBuf)> {
     let stamp_file = build.out.join("rustfmt.stamp");
 
@@ -85,15 +530,186 @@ fn update_rustfmt_version(build: &Builder<'_>) {
     t!(std::fs::write(stamp_file, version))
 }
 
-/// Returns the Rust files modified between the `merge-base` of HEAD and
-/// rust-lang/master and what is now on the disk. Does not include removed files.
+/// Returns the files with `extensions` modified between `since`, the `merge-base` of HEAD and
+/// `base`, or the `merge-base` of HEAD and rust-lang/master by default, and what is now on the
+/// disk. Does not include removed files.
 ///
 /// Returns `None` if all files should be formatted.
-fn get_modified_rs_files(build: &Builder<'_>) -> Result<Option<Vec<String>>, String> {
+fn get_modified_files(
+    build: &Builder<'_>,
+    since: Option<&str>,
+    base: Option<&str>,
+    extensions: &[&str],
+) -> Result<Option<Vec<String>>, String> {
+    // An explicit revision or branch selects the files regardless of the format cache.
+    if let Some(since) = since {
+        return get_git_modified_files_since(Some(&build.config.src), since, extensions).map(Some);
+    }
+    if let Some(base) = base {
+        let merge_base = helpers::git(Some(&build.src))
+            .allow_failure()
+            .args(["merge-base", base, "HEAD"])
+            .run_capture_stdout(build)
+            .stdout_if_ok()
+            .ok_or_else(|| format!("`{base}` has no merge-base with HEAD"))?;
+        let merge_base = merge_base.trim();
+        return get_git_modified_files_since(Some(&build.config.src), merge_base, extensions)
+            .map(Some);
+    }
     if !verify_rustfmt_version(build) {
         return Ok(None);
     }
-    get_git_modified_files(&build.config.git_config(), Some(&build.config.src), &["rs"])
+    get_git_modified_files(&build.config.git_config(), Some(&build.config.src), extensions)
+}
+
+/// Returns the path of `build.src` relative to the root of its git working tree, like `rust/`, or
+/// an empty string if it's the root.
+fn git_prefix(build: &Builder<'_>) -> String {
+    let output = helpers::git(Some(&build.src))
+        .args(["rev-parse", "--show-prefix"])
+        .run_capture_stdout(build)
+        .stdout();
+    output.trim_end_matches('\n').to_owned()
+}
+
+/// Converts the paths printed by git to paths relative to `build.src`, skipping the ones outside
+/// of it.
+fn strip_git_prefix(prefix: &str, paths: Vec<String>) -> Vec<String> {
+    if prefix.is_empty() {
+        return paths;
+    }
+    paths.iter().filter_map(|path| path.strip_prefix(prefix)).map(|path| path.to_owned()).collect()
+}
+
+fn is_shallow_clone(build: &Builder<'_>) -> bool {
+    helpers::git(Some(&build.src))
+        .allow_failure()
+        .args(["rev-parse", "--is-shallow-repository"])
+        .run_capture_stdout(build)
+        .stdout_if_ok()
+        .is_some_and(|output| output.trim() == "true")
+}
+
+/// Returns the files with `extensions` staged in the index of git. Does not include removed
+/// files.
+fn get_staged_files(build: &Builder<'_>, extensions: &[&str]) -> Vec<String> {
+    let output = helpers::git(Some(&build.src))
+        .args(["diff", "--cached", "--name-only", "--diff-filter=d", "-z"])
+        .run_capture_stdout(build)
+        .stdout();
+    output
+        .split_terminator('\0')
+        .filter(|path| {
+            Path::new(path).extension().is_some_and(|ext| extensions.iter().any(|e| ext == *e))
+        })
+        .map(|path| path.to_owned())
+        .collect()
+}
+
+/// Formats the source read from stdin as the file `path`, and prints it to stdout. Sources of the
+/// files which aren't formatted by `x fmt`, like the ones ignored by `rustfmt.toml`, are printed
+/// unchanged.
+fn format_stdin(formatters: &[Formatter], overrides: &Override, path: &Path) {
+    let mut source = Vec::new();
+    t!(io::stdin().read_to_end(&mut source));
+    let path = t!(std::env::current_dir()).join(path);
+    match format_source(formatters, overrides, &path, source) {
+        Ok(output) => t!(io::stdout().write_all(&output)),
+        Err(err) => {
+            eprintln!("fmt error: {err}");
+            crate::exit!(1);
+        }
+    }
+}
+
+/// Formats `source` as the file at the absolute `path`, returning it unchanged if `x fmt` doesn't
+/// format the file.
+fn format_source(
+    formatters: &[Formatter],
+    overrides: &Override,
+    path: &Path,
+    source: Vec<u8>,
+) -> Result<Vec<u8>, String> {
+    let formatter = match formatters.iter().find(|formatter| formatter.handles(path)) {
+        Some(formatter) if !overrides.matched(path, false).is_ignore() => formatter,
+        _ => return Ok(source),
+    };
+    run_with_stdin(formatter, path, source, Stdio::inherit())
+}
+
+/// Pipes `source` through `formatter` as the file `path`, returning the formatted source.
+fn run_with_stdin(
+    formatter: &Formatter,
+    path: &Path,
+    source: Vec<u8>,
+    stderr: Stdio,
+) -> Result<Vec<u8>, String> {
+    let mut cmd = Command::new(&formatter.program);
+    cmd.args(&formatter.args).args((formatter.stdin_args)(path));
+    cmd.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(stderr);
+    let cmd_debug = format!("{cmd:?}");
+    let mut child = cmd.spawn().map_err(|err| format!("running {}: {err}", formatter.name))?;
+    let mut stdin = child.stdin.take().unwrap();
+    // Write while the formatter runs, so that neither of them blocks on a full pipe.
+    let writer = std::thread::spawn(move || stdin.write_all(&source));
+    let output = child.wait_with_output().map_err(|err| format!("running {cmd_debug}: {err}"))?;
+    let written = writer.join().unwrap();
+    if !output.status.success() {
+        return Err(format!("Running `{cmd_debug}` failed."));
+    }
+    written.map_err(|err| format!("writing to {cmd_debug}: {err}"))?;
+    Ok(output.stdout)
+}
+
+/// Formats the files selected by `walker` whenever they're saved, until `x fmt --watch` is
+/// interrupted. Bootstrap has no file system notifier among its dependencies, so this polls the
+/// modification times of the files.
+fn watch_files(formatters: &[Formatter], walker: &WalkBuilder, src: &Path) -> ! {
+    // Nothing is checked, so this stays empty.
+    let patch = RefCell::new(String::new());
+    let mut mtimes = modification_times(walker);
+    println!("fmt: watching {} files for changes, press Ctrl-C to stop", mtimes.len());
+    loop {
+        std::thread::sleep(Duration::from_millis(500));
+        let current = modification_times(walker);
+        let saved: Vec<PathBuf> = current
+            .iter()
+            .filter(|(path, mtime)| mtimes.get(*path) != Some(mtime))
+            .map(|(path, _)| path.clone())
+            .collect();
+        mtimes = current;
+        for formatter in formatters {
+            let paths: Vec<PathBuf> =
+                saved.iter().filter(|path| formatter.handles(path)).cloned().collect();
+            if paths.is_empty() {
+                continue;
+            }
+            run_formatter(formatter, &paths, Mode::Watch, src, &patch, None)(true);
+            let names: Vec<String> = paths
+                .iter()
+                .map(|path| path.strip_prefix(src).unwrap_or(path).display().to_string())
+                .collect();
+            print_paths(Mode::Watch, "formatted", Some("saved"), &names);
+        }
+        // The formatters saved the files again.
+        for path in saved {
+            if let Ok(mtime) = path.metadata().and_then(|metadata| metadata.modified()) {
+                mtimes.insert(path, mtime);
+            }
+        }
+    }
+}
+
+fn modification_times(walker: &WalkBuilder) -> HashMap<PathBuf, SystemTime> {
+    walker
+        .build()
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|file_type| file_type.is_file()))
+        .filter_map(|entry| {
+            let mtime = entry.metadata().ok()?.modified().ok()?;
+            Some((entry.into_path(), mtime))
+        })
+        .collect()
 }
 
 #[derive(serde_derive::Deserialize)]
@@ -103,40 +719,148 @@ struct RustfmtConfig {
 
 // Prints output describing a collection of paths, with lines such as "formatted modified file
 // foo/bar/baz" or "skipped 20 untracked files".
-fn print_paths(verb: &str, adjective: Option<&str>, paths: &[String]) {
+fn print_paths(mode: Mode, verb: &str, adjective: Option<&str>, paths: &[String]) {
     let len = paths.len();
     let adjective =
         if let Some(adjective) = adjective { format!("{adjective} ") } else { String::new() };
     if len <= 10 {
         for path in paths {
-            println!("fmt: {verb} {adjective}file {path}");
+            print_status(mode, &format!("fmt: {verb} {adjective}file {path}"));
         }
     } else {
-        println!("fmt: {verb} {len} {adjective}files");
+        print_status(mode, &format!("fmt: {verb} {len} {adjective}files"));
+    }
+}
+
+/// Prints a line of the human output, which goes to stderr with `--message-format=json` to keep
+/// stdout for the JSON records.
+fn print_status(mode: Mode, line: &str) {
+    if mode == Mode::CheckJson {
+        eprintln!("{line}");
+    } else {
+        println!("{line}");
+    }
+}
+
+/// The progress of a format, as the files found by the walker and the ones whose formatter
+/// completed.
+#[derive(Default)]
+struct Progress {
+    discovered: AtomicUsize,
+    formatted: AtomicUsize,
+}
+
+impl Progress {
+    /// Prints the progress line to stderr, rewriting it in place on a terminal.
+    fn print(&self, is_tty: bool, batch: &str) {
+        let formatted = self.formatted.load(Ordering::Relaxed);
+        let discovered = self.discovered.load(Ordering::Relaxed);
+        let line = format!("fmt: {formatted}/{discovered} files, {batch}");
+        if is_tty {
+            eprint!("\r{line}\x1b[K");
+            let _ = io::stderr().flush();
+        } else {
+            eprintln!("{line}");
+        }
+    }
+}
+
+/// A running formatter, with the token of the jobserver it uses and the number of its files.
+type Child<F> = (F, Option<jobserver::Token>, usize);
+
+/// Waits for a token of the jobserver, reaping the children which complete meanwhile. Returns
+/// `None` once all of them completed, as the next process can use the implicit token then.
+fn acquire_token<F: FnMut(bool) -> bool>(
+    jobserver: &mut jobserver::Client,
+    children: &mut VecDeque<Child<F>>,
+    progress: &Progress,
+) -> Option<jobserver::Token> {
+    loop {
+        if let Some(token) = t!(jobserver.acquire_timeout(Duration::from_millis(10))) {
+            return Some(token);
+        }
+        children.retain_mut(|(child, _token, len)| {
+            let done = child(false);
+            if done {
+                progress.formatted.fetch_add(*len, Ordering::Relaxed);
+            }
+            !done
+        });
+        if children.is_empty() {
+            return None;
+        }
     }
 }
 
-pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
+pub fn format(build: &Builder<'_>, args: &FormatArgs, paths: &[PathBuf]) {
+    let &FormatArgs {
+        check,
+        all,
+        staged,
+        ref since,
+        ref base,
+        message_format,
+        ref stdin,
+        ref summary_json,
+        include_untracked,
+        watch,
+        quiet,
+        processes: max_processes,
+        batch_size,
+        daemon,
+        stop,
+    } = args;
+    let timings = build.config.timings;
     if !paths.is_empty() {
         eprintln!(
             "fmt error: path arguments are no longer accepted; use `--all` to format everything"
         );
         crate::exit!(1);
     };
+    let mode = match (check, message_format) {
+        (false, FmtMessageFormat::Human) if watch => Mode::Watch,
+        (false, FmtMessageFormat::Human) => Mode::Fix,
+        (true, FmtMessageFormat::Human) => Mode::Check,
+        (true, FmtMessageFormat::Json) => Mode::CheckJson,
+        (false, FmtMessageFormat::Json) => {
+            eprintln!("fmt error: `--message-format=json` requires `--check`");
+            crate::exit!(1);
+        }
+    };
+    if timings && (stdin.is_some() || watch || daemon || stop) {
+        eprintln!("fmt error: `--timings` can't be used with `--stdin`, `--watch` or the daemon");
+        crate::exit!(1);
+    }
     if build.config.dry_run() {
         return;
     }
+    if (daemon || stop) && cfg!(not(unix)) {
+        eprintln!("fmt error: The daemon of `x fmt` uses Unix sockets, which this platform lacks");
+        crate::exit!(1);
+    }
+    #[cfg(unix)]
+    if stop {
+        daemon::stop(&daemon::socket_path(build));
+        return;
+    }
 
     // By default, we only check modified files locally to speed up runtime. Exceptions are if
     // `--all` is specified or we are in CI. We check all files in CI to avoid bugs in
-    // `get_modified_rs_files` letting regressions slip through; we also care about CI time less
-    // since this is still very fast compared to building the compiler.
-    let all = all || CiEnv::is_ci();
+    // `get_modified_files` letting regressions slip through; we also care about CI time less
+    // since this is still very fast compared to building the compiler. `--staged`, `--since`
+    // and `--base` are explicit requests for a subset of the files, which are respected in CI too.
+    let subset_flag = if staged {
+        Some("--staged")
+    } else if since.is_some() {
+        Some("--since")
+    } else if base.is_some() {
+        Some("--base")
+    } else {
+        None
+    };
+    // `--watch` formats the saved files among all of them.
+    let all = all || watch || (subset_flag.is_none() && CiEnv::is_ci());
 
-    let mut builder = ignore::types::TypesBuilder::new();
-    builder.add_defaults();
-    builder.select("rust");
-    let matcher = builder.build().unwrap();
     let rustfmt_config = build.src.join("rustfmt.toml");
     if !rustfmt_config.exists() {
         eprintln!("fmt error: Not running formatting checks; rustfmt.toml does not exist.");
@@ -145,6 +869,57 @@ pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
     }
     let rustfmt_config = t!(std::fs::read_to_string(&rustfmt_config));
     let rustfmt_config: RustfmtConfig = t!(toml::from_str(&rustfmt_config));
+
+    let rustfmt_path = build.initial_rustfmt().unwrap_or_else(|| {
+        eprintln!("fmt error: `x fmt` is not supported on this channel");
+        crate::exit!(1);
+    });
+    assert!(rustfmt_path.exists(), "{}", rustfmt_path.display());
+    // Shared by the walker and the thread spawning the formatters.
+    let formatters = Arc::new(formatters(build, rustfmt_path));
+    for formatter in &formatters[1..] {
+        let available =
+            command(&formatter.program).allow_failure().arg("--version").run_capture(build);
+        if available.is_failure() {
+            eprintln!(
+                "fmt error: `{}` is enabled in the `[build.formatters]` section of config.toml, \
+                 but `{}` could not be run",
+                formatter.name,
+                formatter.program.display()
+            );
+            crate::exit!(1);
+        }
+    }
+    let mut all_extensions: Vec<&str> =
+        formatters.iter().flat_map(|formatter| formatter.extensions).copied().collect();
+    // The markdown files are formatted after the others, see `markdown::format_files`.
+    let markdown = build.config.formatters.markdown;
+    if markdown {
+        all_extensions.push("md");
+    }
+
+    let mut builder = ignore::types::TypesBuilder::new();
+    for (i, formatter) in formatters.iter().enumerate() {
+        // The names of the types are alphanumeric.
+        let name = format!("formatter{i}");
+        match formatter.file_names {
+            Some(file_names) => {
+                for file_name in file_names {
+                    builder.add(&name, file_name).unwrap();
+                }
+            }
+            None => {
+                for extension in formatter.extensions {
+                    builder.add(&name, &format!("*.{extension}")).unwrap();
+                }
+            }
+        }
+        builder.select(&name);
+    }
+    let matcher = builder.build().unwrap();
+
+    // The files ignored by `rustfmt.toml`, like the tests and the submodules, are skipped by all
+    // formatters.
     let mut override_builder = ignore::overrides::OverrideBuilder::new(&build.src);
     for ignore in rustfmt_config.ignore {
         if ignore.starts_with('!') {
@@ -160,6 +935,26 @@ pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
             override_builder.add(&format!("!{ignore}")).expect(&ignore);
         }
     }
+    // `x fmt --exclude <glob>` adds to the ignore list for one run. The paths skipped by `x test
+    // tidy` are steps, not files.
+    if build.kind == Kind::Format {
+        for exclude in &build.config.skip {
+            let exclude = exclude.to_string_lossy();
+            override_builder.add(&format!("!{exclude}")).expect(&exclude);
+        }
+    }
+    if let Some(path) = stdin {
+        format_stdin(&formatters, &override_builder.build().unwrap(), path);
+        return;
+    }
+    #[cfg(unix)]
+    if daemon {
+        t!(std::fs::create_dir_all(&build.out));
+        let socket = daemon::socket_path(build);
+        daemon::run(&formatters, &override_builder.build().unwrap(), &build.src, &socket);
+        return;
+    }
+
     let git_available =
         helpers::git(None).allow_failure().arg("--version").run_capture(build).is_success();
 
@@ -172,6 +967,9 @@ pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
             .run_capture(build)
             .is_success();
         if in_working_tree {
+            // git prints the paths relative to the root of the working tree, which contains
+            // `build.src` in a subdirectory when the checkout is nested in another repository.
+            let prefix = git_prefix(build);
             let untracked_paths_output = helpers::git(Some(&build.src))
                 .arg("status")
                 .arg("--porcelain")
@@ -184,76 +982,208 @@ pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
                 .filter_map(
                     |entry| entry.strip_prefix("?? "), // returns None if the prefix doesn't match
                 )
+                .filter_map(|path| path.strip_prefix(prefix.as_str()))
                 .map(|x| x.to_string())
                 .collect();
-            print_paths("skipped", Some("untracked"), &untracked_paths);
-
-            for untracked_path in untracked_paths {
-                // The leading `/` makes it an exact match against the
-                // repository root, rather than a glob. Without that, if you
-                // have `foo.rs` in the repository root it will also match
-                // against anything like `compiler/rustc_foo/src/foo.rs`,
-                // preventing the latter from being formatted.
-                override_builder.add(&format!("!/{untracked_path}")).expect(&untracked_path);
+            // The files ignored by git, like build artifacts, aren't untracked but ignored, and
+            // the walker skips them regardless.
+            let include_untracked = include_untracked || build.config.fmt_untracked;
+            if !include_untracked {
+                print_paths(mode, "skipped", Some("untracked"), &untracked_paths);
+                for untracked_path in &untracked_paths {
+                    // The leading `/` makes it an exact match against the
+                    // repository root, rather than a glob. Without that, if you
+                    // have `foo.rs` in the repository root it will also match
+                    // against anything like `compiler/rustc_foo/src/foo.rs`,
+                    // preventing the latter from being formatted.
+                    override_builder.add(&format!("!/{untracked_path}")).expect(untracked_path);
+                }
             }
-            if !all {
+            if staged {
+                adjective = Some("staged");
+                let files = get_staged_files(build, &all_extensions);
+                let files = strip_git_prefix(&prefix, files);
+                if files.is_empty() {
+                    print_status(mode, "fmt info: No staged files detected for formatting.");
+                    write_summary(mode, summary_json.as_deref(), &FormatSummary::default());
+                    return;
+                }
+                for file in files {
+                    override_builder.add(&format!("/{file}")).expect(&file);
+                }
+            } else if !all {
                 adjective = Some("modified");
-                match get_modified_rs_files(build) {
+                let modified_files = || {
+                    get_modified_files(build, since.as_deref(), base.as_deref(), &all_extensions)
+                };
+                let mut modified = modified_files();
+                let shallow = modified.is_err() && is_shallow_clone(build);
+                if shallow {
+                    // The merge-base is likely older than the fetched history.
+                    eprintln!("fmt info: Fetching more history of the shallow clone.");
+                    let deepened = helpers::git(Some(&build.src))
+                        .allow_failure()
+                        .args(["fetch", "--deepen=1000"])
+                        .run(build);
+                    if deepened {
+                        modified = modified_files();
+                    }
+                }
+                match modified {
                     Ok(Some(files)) => {
+                        let mut files = strip_git_prefix(&prefix, files);
+                        if include_untracked {
+                            // New files are modified too. git lists the untracked directories
+                            // instead of their files.
+                            files.extend(
+                                untracked_paths
+                                    .iter()
+                                    .filter(|path| {
+                                        path.ends_with('/')
+                                            || Path::new(path).extension().is_some_and(|ext| {
+                                                all_extensions.iter().any(|e| ext == *e)
+                                            })
+                                    })
+                                    .cloned(),
+                            );
+                        }
                         if files.is_empty() {
-                            println!("fmt info: No modified files detected for formatting.");
+                            print_status(
+                                mode,
+                                "fmt info: No modified files detected for formatting.",
+                            );
+                            let summary = FormatSummary::default();
+                            write_summary(mode, summary_json.as_deref(), &summary);
                             return;
                         }
 
                         for file in files {
-                            override_builder.add(&format!("/{file}")).expect(&file);
+                            let glob = if file.ends_with('/') {
+                                format!("/{file}**")
+                            } else {
+                                format!("/{file}")
+                            };
+                            override_builder.add(&glob).expect(&file);
                         }
                     }
                     Ok(None) => {}
+                    Err(err) if since.is_some() || base.is_some() => {
+                        eprintln!("fmt error: Could not diff against the revision:");
+                        eprintln!("fmt error: {err}");
+                        crate::exit!(1);
+                    }
                     Err(err) => {
                         eprintln!("fmt warning: Something went wrong running git commands:");
                         eprintln!("fmt warning: {err}");
+                        if shallow {
+                            eprintln!(
+                                "fmt warning: The clone is shallow, `git fetch --unshallow` \
+                                 fetches the history needed to find the modified files."
+                            );
+                        }
                         eprintln!("fmt warning: Falling back to formatting all files.");
                     }
                 }
             }
+        } else if let Some(flag) = subset_flag {
+            eprintln!("fmt error: `{flag}` requires a git working tree");
+            crate::exit!(1);
         } else {
             eprintln!("fmt: warning: Not in git tree. Skipping git-aware format checks");
         }
+    } else if let Some(flag) = subset_flag {
+        eprintln!("fmt error: `{flag}` requires git, which could not be found");
+        crate::exit!(1);
     } else {
         eprintln!("fmt: warning: Could not find usable git. Skipping git-aware format checks");
     }
 
     let override_ = override_builder.build().unwrap(); // `override` is a reserved keyword
 
-    let rustfmt_path = build.initial_rustfmt().unwrap_or_else(|| {
-        eprintln!("fmt error: `x fmt` is not supported on this channel");
-        crate::exit!(1);
-    });
-    assert!(rustfmt_path.exists(), "{}", rustfmt_path.display());
-    let src = build.src.clone();
-    let (tx, rx): (SyncSender<PathBuf>, _) = std::sync::mpsc::sync_channel(128);
-    let walker = WalkBuilder::new(src.clone()).types(matcher).overrides(override_).build_parallel();
+    let mut walker = WalkBuilder::new(&build.src);
+    walker.types(matcher).overrides(override_);
+    if watch {
+        watch_files(&formatters, &walker, &build.src);
+    }
+    let walker = walker.build_parallel();
 
+    // rustfmt can't run in-process: `rustfmt-nightly` links to the `rustc_private` crates of the
+    // toolchain it's built with, while bootstrap is built by the stage0 cargo as a regular crate,
+    // and the stage0 rustfmt is only a binary. Instead, each process formats a batch of files,
+    // see `build.fmt-batch-size`, to amortize the cost of spawning it.
+    //
     // There is a lot of blocking involved in spawning a child process and reading files to format.
-    // Spawn more processes than available concurrency to keep the CPU busy.
-    let max_processes = build.jobs() as usize * 2;
+    // Spawn more processes than available concurrency to keep the CPU busy, unless `fmt-jobs` is
+    // set. When run by make or cargo, the processes beyond the first one also need a token of their
+    // jobserver, so that nested builds don't oversubscribe the machine.
+    let max_processes = max_processes.or(build.config.fmt_jobs).unwrap_or(build.jobs() * 2);
+    let batch_size = batch_size.or(build.config.fmt_batch_size).unwrap_or(64);
+    if max_processes == 0 || batch_size == 0 {
+        eprintln!("fmt error: `build.fmt-jobs` and `build.fmt-batch-size` must be at least 1");
+        crate::exit!(1);
+    }
+    let (max_processes, batch_size) = (max_processes as usize, batch_size as usize);
+    let mut jobserver = jobserver::Client::from_env();
+
+    // The paths to format, with the index of their formatter. The walker fills up to two batches
+    // ahead.
+    let (tx, rx): (SyncSender<(usize, PathBuf)>, _) = std::sync::mpsc::sync_channel(2 * batch_size);
+
+    // The progress line is printed by the thread spawning the formatters, after each batch. It's
+    // throttled when stderr isn't a terminal, to keep the logs of CI short.
+    let progress = Arc::new(Progress::default());
+    let is_tty = build.config.stderr_is_tty;
+    let verb = if check { "checking" } else { "formatting" };
 
     // Spawn child processes on a separate thread so we can batch entries we have received from
-    // ignore.
+    // ignore. It returns the patch of the misformatted files found by the check, the number of
+    // processes it spawned, and the times of the files with `--timings`.
+    let src = build.src.clone();
+    let walker_formatters = Arc::clone(&formatters);
+    let thread_progress = Arc::clone(&progress);
     let thread = std::thread::spawn(move || {
+        let progress = &*thread_progress;
+        let patch = RefCell::new(String::new());
+        let file_times = RefCell::new(Vec::new());
         let mut children = VecDeque::new();
+        let mut processes = 0;
+        let mut last_print: Option<Instant> = None;
         while let Ok(path) = rx.recv() {
             // Try getting more paths from the channel to amortize the overhead of spawning
             // processes.
-            let paths: Vec<_> = rx.try_iter().take(63).chain(std::iter::once(path)).collect();
+            let mut batches = vec![Vec::new(); formatters.len()];
+            for (formatter, path) in rx.try_iter().take(batch_size - 1).chain(std::iter::once(path))
+            {
+                batches[formatter].push(path);
+            }
 
-            let child = rustfmt(&src, &rustfmt_path, paths.as_slice(), check);
-            children.push_back(child);
+            for (formatter, paths) in formatters.iter().zip(batches) {
+                if !paths.is_empty() {
+                    // The first process uses the implicit token of bootstrap.
+                    let token = match &mut jobserver {
+                        Some(jobserver) if !children.is_empty() => {
+                            acquire_token(jobserver, &mut children, progress)
+                        }
+                        _ => None,
+                    };
+                    let timings = timings.then_some(&file_times);
+                    let child = run_formatter(formatter, &paths, mode, &src, &patch, timings);
+                    children.push_back((child, token, paths.len()));
+                    processes += 1;
+
+                    let throttle = if is_tty { Duration::ZERO } else { Duration::from_secs(5) };
+                    if !quiet && last_print.map_or(true, |last| last.elapsed() >= throttle) {
+                        let batch = format!("{verb} {} files with {}", paths.len(), formatter.name);
+                        progress.print(is_tty, &batch);
+                        last_print = Some(Instant::now());
+                    }
+                }
+            }
 
             // Poll completion before waiting.
             for i in (0..children.len()).rev() {
-                if children[i](false) {
+                if children[i].0(false) {
+                    progress.formatted.fetch_add(children[i].2, Ordering::Relaxed);
                     children.swap_remove_back(i);
                     break;
                 }
@@ -261,24 +1191,40 @@ pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
 
             if children.len() >= max_processes {
                 // Await oldest child.
-                children.pop_front().unwrap()(true);
+                let (mut child, _token, len) = children.pop_front().unwrap();
+                child(true);
+                progress.formatted.fetch_add(len, Ordering::Relaxed);
             }
         }
 
         // Await remaining children.
-        for mut child in children {
+        for (mut child, _token, _len) in children {
             child(true);
         }
+        if is_tty && last_print.is_some() {
+            // Clear the progress line.
+            eprint!("\r\x1b[K");
+        }
+        (patch.into_inner(), processes, file_times.into_inner())
     });
 
+    let start = Instant::now();
     let formatted_paths = Mutex::new(Vec::new());
+    // The modification times of the files before formatting, to find the rewritten ones.
+    let mtimes = Mutex::new(Vec::new());
+    let mtimes_ref = &mtimes;
     let formatted_paths_ref = &formatted_paths;
+    let formatters = &*walker_formatters;
+    let progress = &*progress;
     walker.run(|| {
         let tx = tx.clone();
         Box::new(move |entry| {
             let cwd = std::env::current_dir();
             let entry = t!(entry);
             if entry.file_type().map_or(false, |t| t.is_file()) {
+                progress.discovered.fetch_add(1, Ordering::Relaxed);
+                let mtime = entry.metadata().ok().and_then(|metadata| metadata.modified().ok());
+                mtimes_ref.lock().unwrap().push((entry.path().to_path_buf(), mtime));
                 formatted_paths_ref.lock().unwrap().push({
                     // `into_path` produces an absolute path. Try to strip `cwd` to get a shorter
                     // relative path.
@@ -290,19 +1236,97 @@ pub fn format(build: &Builder<'_>, check: bool, all: bool, paths: &[PathBuf]) {
                     }
                     path.display().to_string()
                 });
-                t!(tx.send(entry.into_path()));
+                let formatter =
+                    formatters.iter().position(|formatter| formatter.handles(entry.path()));
+                t!(tx.send((formatter.unwrap(), entry.into_path())));
             }
             ignore::WalkState::Continue
         })
     });
-    let mut paths = formatted_paths.into_inner().unwrap();
-    paths.sort();
-    print_paths(if check { "checked" } else { "formatted" }, adjective, &paths);
-
     drop(tx);
 
-    thread.join().unwrap();
-    if !check {
+    let (mut patch, processes, file_times) = thread.join().unwrap();
+    let mut paths = formatted_paths.into_inner().unwrap();
+    let mut markdown_rewritten = Vec::new();
+    if markdown {
+        let mut builder = ignore::types::TypesBuilder::new();
+        builder.add("markdown", "*.md").unwrap();
+        builder.select("markdown");
+        let mut walker = WalkBuilder::new(build.src.join("src/doc"));
+        walker.types(builder.build().unwrap()).overrides(override_builder.build().unwrap());
+        let formatted = markdown::format_files(&formatters[0], &walker, mode, &build.src);
+        // Relative to the current directory, like the paths of the walker.
+        let cwd = std::env::current_dir().unwrap_or_default();
+        paths.extend(
+            formatted
+                .files
+                .iter()
+                .map(|path| path.strip_prefix(&cwd).unwrap_or(path).display().to_string()),
+        );
+        patch.push_str(&formatted.patch);
+        markdown_rewritten = formatted.rewritten;
+    }
+    // Printed once the progress line is cleared.
+    paths.sort();
+    print_paths(mode, if check { "checked" } else { "formatted" }, adjective, &paths);
+    if timings {
+        t!(std::fs::create_dir_all(&build.out));
+        report_timings(file_times, 10, &build.out.join("fmt-timings.json"));
+    }
+    // CI can upload the patch, so that the changes can be applied without the same rustfmt.
+    let patch_path = build.out.join("fmt.patch");
+    if !patch.is_empty() {
+        t!(std::fs::create_dir_all(&build.out));
+        t!(std::fs::write(&patch_path, &patch));
+        if mode == Mode::CheckJson {
+            eprintln!("fmt error: Some files aren't formatted, see the JSON records on stdout.");
+        } else {
+            print!("{}", patch_preview(&patch, 10));
+            eprintln!("fmt error: Some files aren't formatted.");
+        }
+        eprintln!(
+            "fmt info: `git apply {}` formats them, or run `./x.py fmt`.",
+            patch_path.display()
+        );
+        crate::exit!(1);
+    } else if check && patch_path.exists() {
+        // Don't leave the patch of a previous check around.
+        t!(std::fs::remove_file(&patch_path));
+    }
+    if mode == Mode::Fix {
+        let mtimes = mtimes.into_inner().unwrap();
+        let mut rewritten: Vec<String> = mtimes
+            .iter()
+            .filter(|(path, mtime)| {
+                path.metadata().and_then(|metadata| metadata.modified()).ok() != *mtime
+            })
+            .map(|(path, _)| path)
+            .chain(&markdown_rewritten)
+            .map(|path| path.strip_prefix(&build.src).unwrap_or(path).display().to_string())
+            .collect();
+        rewritten.sort();
+        let summary = FormatSummary {
+            unchanged: paths.len() - rewritten.len(),
+            rewritten,
+            processes,
+            elapsed_secs: start.elapsed().as_secs_f64(),
+        };
+        println!(
+            "fmt: rewrote {} files, {} were already formatted ({} processes, {:.2}s)",
+            summary.rewritten.len(),
+            summary.unchanged,
+            summary.processes,
+            summary.elapsed_secs
+        );
+        write_summary(mode, summary_json.as_deref(), &summary);
+    }
+    if staged && !check {
+        // rustfmt only changes the files in the working tree.
+        println!("fmt info: Run `git add` again to stage the formatting changes.");
+    }
+    // Formatting only the staged files or the ones modified since a revision doesn't make the
+    // format cache valid for the others.
+    if !check && subset_flag.is_none() {
         update_rustfmt_version(build);
     }
 }
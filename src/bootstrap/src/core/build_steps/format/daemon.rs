@@ -0,0 +1,130 @@
+//! `x fmt --daemon`, which formats the sources sent by editors over a Unix socket, so that a
+//! format-on-save doesn't pay for starting bootstrap, parsing its configuration and finding the
+//! formatters each time. The formatters themselves still run once per request, see the comment on
+//! `max_processes` in `format`.
+//!
+//! A client connects to `build/fmt.sock`, writes `format <path>` and a newline followed by the
+//! source, and shuts down its writing half. The daemon answers `ok` and a newline followed by the
+//! formatted source, or `error <message>`. For example:
+//!
+//! ```text
+//! { echo "format compiler/rustc/src/main.rs"; cat main.rs; } | nc -NU build/fmt.sock
+//! ```
+//!
+//! Relative paths are relative to the root of the repository. `stop` stops the daemon, which
+//! `x fmt --stop` sends, and it also stops after `IDLE_TIMEOUT` without requests.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use ignore::overrides::Override;
+
+use super::{format_source, Formatter};
+use crate::core::builder::Builder;
+use crate::utils::helpers::t;
+
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+pub(super) fn socket_path(build: &Builder<'_>) -> PathBuf {
+    build.out.join("fmt.sock")
+}
+
+/// Serves the requests of the clients until `stop` or `IDLE_TIMEOUT`.
+pub(super) fn run(formatters: &[Formatter], overrides: &Override, src: &Path, socket: &Path) {
+    let listener = match UnixListener::bind(socket) {
+        Ok(listener) => listener,
+        Err(err) if err.kind() == io::ErrorKind::AddrInUse => {
+            if UnixStream::connect(socket).is_ok() {
+                eprintln!("fmt error: A daemon is already listening on {}.", socket.display());
+                crate::exit!(1);
+            }
+            // The socket of a daemon which didn't exit cleanly.
+            t!(std::fs::remove_file(socket));
+            t!(UnixListener::bind(socket))
+        }
+        Err(err) => panic!("binding {}: {err}", socket.display()),
+    };
+    // Accepting doesn't block, to notice `stop` and the idle timeout.
+    t!(listener.set_nonblocking(true));
+    println!("fmt: listening on {}, `./x.py fmt --stop` stops the daemon", socket.display());
+
+    let stop = AtomicBool::new(false);
+    let last_request = Mutex::new(Instant::now());
+    std::thread::scope(|scope| {
+        while !stop.load(Ordering::Relaxed) {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    *last_request.lock().unwrap() = Instant::now();
+                    let stop = &stop;
+                    scope.spawn(move || {
+                        if let Err(err) = serve(stream, formatters, overrides, src, stop) {
+                            eprintln!("fmt warning: Failed to answer a request: {err}");
+                        }
+                    });
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    if last_request.lock().unwrap().elapsed() >= IDLE_TIMEOUT {
+                        println!("fmt: no requests for {} minutes", IDLE_TIMEOUT.as_secs() / 60);
+                        break;
+                    }
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+                Err(err) => panic!("accepting on {}: {err}", socket.display()),
+            }
+        }
+    });
+    let _ = std::fs::remove_file(socket);
+    println!("fmt: daemon stopped");
+}
+
+fn serve(
+    stream: UnixStream,
+    formatters: &[Formatter],
+    overrides: &Override,
+    src: &Path,
+    stop: &AtomicBool,
+) -> io::Result<()> {
+    // Accepted sockets inherit the non-blocking mode of the listener on some platforms.
+    stream.set_nonblocking(false)?;
+    let mut reader = BufReader::new(&stream);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    let request = request.trim_end_matches('\n');
+    if request == "stop" {
+        stop.store(true, Ordering::Relaxed);
+        return (&stream).write_all(b"ok\n");
+    }
+    let Some(path) = request.strip_prefix("format ") else {
+        return writeln!(&stream, "error unknown request `{request}`");
+    };
+    let mut source = Vec::new();
+    reader.read_to_end(&mut source)?;
+    match format_source(formatters, overrides, &src.join(path), source) {
+        Ok(output) => {
+            (&stream).write_all(b"ok\n")?;
+            (&stream).write_all(&output)
+        }
+        Err(err) => writeln!(&stream, "error {err}"),
+    }
+}
+
+/// Stops the daemon listening on `socket`, for `x fmt --stop`.
+pub(super) fn stop(socket: &Path) {
+    let Ok(mut stream) = UnixStream::connect(socket) else {
+        println!("fmt info: No daemon is listening on {}.", socket.display());
+        return;
+    };
+    t!(stream.write_all(b"stop\n"));
+    t!(stream.shutdown(Shutdown::Write));
+    let mut response = String::new();
+    t!(stream.read_to_string(&mut response));
+    if response != "ok\n" {
+        eprintln!("fmt error: The daemon answered `{}`.", response.trim_end());
+        crate::exit!(1);
+    }
+}
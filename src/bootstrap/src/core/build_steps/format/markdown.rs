@@ -0,0 +1,210 @@
+//! The formatting of the ```rust code blocks of the markdown documentation in `src/doc`, enabled
+//! by `build.formatters.markdown`. The submodules of the books are ignored by `rustfmt.toml`, so
+//! this covers the books of this repository and the pages pointing to the others.
+//!
+//! Each block is piped through rustfmt, as is or in a `main` function like rustdoc does, since most
+//! examples are statements. The blocks rustfmt can't parse either way, like sketches of an API, are
+//! left as they are, and so are the ones marked `ignore` or `compile_fail`, and the ones with lines
+//! hidden by rustdoc and mdbook, which aren't Rust.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use ignore::WalkBuilder;
+
+use super::{run_with_stdin, unified_diff, Edit, Formatter, MisformattedFile, Mode};
+use crate::utils::helpers::t;
+
+#[cfg(test)]
+mod tests;
+
+/// The code of a fenced code block, between the lines of its fences.
+#[derive(Debug, PartialEq, Eq)]
+struct CodeBlock {
+    byte_start: usize,
+    byte_end: usize,
+    /// The line of the first line of code, starting at 1.
+    line_start: usize,
+    /// The indentation of the fences, like in a list item, which the lines of code share.
+    indent: usize,
+}
+
+/// The files formatted by `format_files`.
+#[derive(Default)]
+pub(super) struct Formatted {
+    pub(super) files: Vec<PathBuf>,
+    /// The files rewritten with `Mode::Fix`.
+    pub(super) rewritten: Vec<PathBuf>,
+    /// The changes needed by the misformatted files when checking.
+    pub(super) patch: String,
+}
+
+/// Formats the code blocks of the markdown files selected by `walker` with `rustfmt`.
+pub(super) fn format_files(
+    rustfmt: &Formatter,
+    walker: &WalkBuilder,
+    mode: Mode,
+    src: &Path,
+) -> Formatted {
+    let mut formatted = Formatted::default();
+    for entry in walker.build().flatten() {
+        if !entry.file_type().is_some_and(|file_type| file_type.is_file()) {
+            continue;
+        }
+        let path = entry.into_path();
+        let source = t!(std::fs::read_to_string(&path));
+        let edits = edits(rustfmt, &source);
+        formatted.files.push(path.clone());
+        if edits.is_empty() {
+            continue;
+        }
+        let relative = path.strip_prefix(src).unwrap_or(&path).display().to_string();
+        match mode {
+            Mode::Fix | Mode::Watch => {
+                t!(std::fs::write(&path, apply(&source, &edits)));
+                formatted.rewritten.push(path);
+            }
+            Mode::Check | Mode::CheckJson => {
+                formatted.patch.push_str(&unified_diff(&relative, &source, &edits));
+                if mode == Mode::CheckJson {
+                    let record = MisformattedFile { path: relative, edits };
+                    println!("{}", t!(serde_json::to_string(&record)));
+                }
+            }
+        }
+    }
+    formatted
+}
+
+/// Returns the replacements of the code blocks of `source` which aren't formatted.
+fn edits(rustfmt: &Formatter, source: &str) -> Vec<Edit> {
+    rust_code_blocks(source)
+        .into_iter()
+        .filter_map(|block| {
+            let code = &source[block.byte_start..block.byte_end];
+            let formatted = format_code(rustfmt, &dedent(code, block.indent))?;
+            let formatted = indent(&formatted, block.indent);
+            if formatted == code {
+                return None;
+            }
+            Some(Edit {
+                byte_start: block.byte_start,
+                byte_end: block.byte_end,
+                line_start: block.line_start,
+                line_end: block.line_start + code.lines().count() - 1,
+                replacement: formatted,
+            })
+        })
+        .collect()
+}
+
+fn apply(source: &str, edits: &[Edit]) -> String {
+    let mut formatted = String::new();
+    let mut end = 0;
+    for edit in edits {
+        formatted.push_str(&source[end..edit.byte_start]);
+        formatted.push_str(&edit.replacement);
+        end = edit.byte_end;
+    }
+    formatted.push_str(&source[end..]);
+    formatted
+}
+
+/// Returns the ```rust code blocks of `source` which can be formatted.
+fn rust_code_blocks(source: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    // The opening fence of the current block, and whether the block can be formatted.
+    let mut open: Option<(&str, CodeBlock, bool)> = None;
+    let mut offset = 0;
+    for (i, line) in source.split_inclusive('\n').enumerate() {
+        let line_offset = offset;
+        offset += line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+        let indent = content.len() - content.trim_start_matches(' ').len();
+        let content = &content[indent..];
+        match &mut open {
+            None => {
+                if let Some((fence, info)) = fence(content) {
+                    // The code starts on the next line.
+                    let (byte_start, line_start) = (offset, i + 2);
+                    let block = CodeBlock { byte_start, byte_end: byte_start, line_start, indent };
+                    open = Some((fence, block, is_rust(info)));
+                }
+            }
+            Some((opening, block, formattable)) => {
+                let closes = fence(content).is_some_and(|(fence, rest)| {
+                    fence.starts_with(*opening) && rest.trim().is_empty()
+                });
+                if closes {
+                    block.byte_end = line_offset;
+                    let (_, block, formattable) = open.take().unwrap();
+                    if formattable && block.byte_start < block.byte_end {
+                        blocks.push(block);
+                    }
+                } else if !content.is_empty() && (indent < block.indent || is_hidden(content)) {
+                    *formattable = false;
+                }
+            }
+        }
+    }
+    // An unclosed block is skipped, it's likely a mistake.
+    blocks
+}
+
+/// Splits a line starting with a code fence into the fence and the info string.
+fn fence(line: &str) -> Option<(&str, &str)> {
+    let c = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = line.len() - line.trim_start_matches(c).len();
+    (len >= 3).then(|| line.split_at(len))
+}
+
+/// Whether the info string of a code block, like `rust,no_run`, marks Rust code rustfmt can
+/// likely format.
+fn is_rust(info: &str) -> bool {
+    let mut attributes =
+        info.split(|c: char| c == ',' || c.is_whitespace()).filter(|attr| !attr.is_empty());
+    attributes.next() == Some("rust")
+        && !attributes.any(|attr| attr == "ignore" || attr == "compile_fail")
+}
+
+/// Whether a line of code is hidden in the rendered documentation, like `# fn main() {`, or
+/// escaped, like `##[derive(Debug)]`.
+fn is_hidden(line: &str) -> bool {
+    line == "#" || line.starts_with("# ") || line.starts_with("##")
+}
+
+/// Formats `code` as a file, or as the body of a `main` function. The lines of the body are as
+/// long as the ones of a file, minus the indentation of the function.
+fn format_code(rustfmt: &Formatter, code: &str) -> Option<String> {
+    let run = |source: String| {
+        let output = run_with_stdin(rustfmt, Path::new("example.rs"), source.into(), Stdio::null());
+        String::from_utf8(output.ok()?).ok()
+    };
+    if let Some(formatted) = run(code.to_owned()) {
+        return Some(formatted);
+    }
+    let formatted = run(format!("fn main() {{\n{}}}\n", indent(code, 4)))?;
+    let body = formatted.strip_prefix("fn main() {\n")?.strip_suffix("}\n")?;
+    Some(dedent(body, 4))
+}
+
+/// Indents the lines of `code` which aren't empty by `indent` spaces.
+fn indent(code: &str, indent: usize) -> String {
+    code.split_inclusive('\n')
+        .map(
+            |line| {
+                if line.trim().is_empty() { line.to_owned() } else { " ".repeat(indent) + line }
+            },
+        )
+        .collect()
+}
+
+/// Removes the indentation of the lines of `code`, up to `indent` spaces.
+fn dedent(code: &str, indent: usize) -> String {
+    code.split_inclusive('\n')
+        .map(|line| {
+            let spaces = line.len() - line.trim_start_matches(' ').len();
+            &line[spaces.min(indent)..]
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+fn code_of_blocks(source: &str) -> Vec<&str> {
+    rust_code_blocks(source).iter().map(|block| &source[block.byte_start..block.byte_end]).collect()
+}
+
+#[test]
+fn code_blocks() {
+    let source = "# Title\n\n```rust\nfn main() {}\n```\n\n```\nnot rust\n```\n";
+    assert_eq!(
+        rust_code_blocks(source),
+        [CodeBlock { byte_start: 17, byte_end: 30, line_start: 4, indent: 0 }]
+    );
+    assert_eq!(code_of_blocks(source), ["fn main() {}\n"]);
+
+    // A longer fence isn't closed by a shorter one, and fences can be indented in lists.
+    let source =
+        "- item\n\n  ````rust,no_run\n  let x = 1;\n  ```\n  ````\n~~~rust\nlet y=2;\n~~~\n";
+    assert_eq!(code_of_blocks(source), ["  let x = 1;\n  ```\n", "let y=2;\n"]);
+}
+
+#[test]
+fn skipped_code_blocks() {
+    let source = "```rust,ignore\nfoo!\n```\n\
+                  ```rust compile_fail\nlet x: u8 = 256;\n```\n\
+                  ```rust\n# fn main() {\nlet x = 1;\n# }\n```\n\
+                  ```rust\n```\n\
+                  ```text\nfn main() {}\n```\n\
+                  ```rust\nunclosed\n";
+    assert_eq!(code_of_blocks(source), Vec::<&str>::new());
+    assert!(is_rust("rust,edition2021"));
+    assert!(!is_rust("rusty"));
+}
+
+#[test]
+fn indentation() {
+    assert_eq!(dedent("  let x = 1;\n\n    foo();\n", 2), "let x = 1;\n\n  foo();\n");
+    assert_eq!(indent("let x = 1;\n\nfoo();\n", 4), "    let x = 1;\n\n    foo();\n");
+}
+
+#[test]
+fn apply_edits() {
+    let source = "text\n```rust\nlet x=1;\n```\n";
+    let edit = Edit {
+        byte_start: 13,
+        byte_end: 22,
+        line_start: 3,
+        line_end: 3,
+        replacement: "let x = 1;\n".to_owned(),
+    };
+    assert_eq!(apply(source, &[edit]), "text\n```rust\nlet x = 1;\n```\n");
+}
@@ -0,0 +1,113 @@
+use super::*;
+
+fn mismatch(begin: usize, end: usize, original: &str, expected: &str) -> RustfmtMismatch {
+    RustfmtMismatch {
+        original_begin_line: begin,
+        original_end_line: end,
+        original: original.to_owned(),
+        expected: expected.to_owned(),
+    }
+}
+
+#[test]
+fn byte_ranges_of_mismatches() {
+    let source = "fn main() {\nlet x=1;\n}\nfn f() {}";
+    let mismatches = [
+        mismatch(2, 2, "let x=1;\n", "    let x = 1;\n"),
+        mismatch(4, 4, "fn f() {}\n", "\nfn f() {}\n"),
+    ];
+    let edits = edits(source, &mismatches);
+    assert_eq!(
+        edits,
+        [
+            Edit {
+                byte_start: 12,
+                byte_end: 21,
+                line_start: 2,
+                line_end: 2,
+                replacement: "    let x = 1;\n".to_owned(),
+            },
+            Edit {
+                byte_start: 23,
+                byte_end: source.len(),
+                line_start: 4,
+                line_end: 4,
+                replacement: "\nfn f() {}\n".to_owned(),
+            },
+        ]
+    );
+    assert_eq!(&source[edits[0].byte_start..edits[0].byte_end], "let x=1;\n");
+}
+
+#[test]
+fn insertions_and_deletions() {
+    let source = "a\n\n\nb\n";
+    let edits = edits(source, &[mismatch(2, 3, "\n\n", "\n"), mismatch(5, 5, "", "c\n")]);
+    assert_eq!((edits[0].byte_start, edits[0].byte_end), (2, 4));
+    assert_eq!((edits[1].byte_start, edits[1].byte_end), (source.len(), source.len()));
+}
+
+#[test]
+fn unified_diff_of_edits() {
+    let source = "fn main() {\nlet x=1;\n}\n\n\n\n\n\n\nfn f() {}";
+    let mismatches = [
+        mismatch(2, 2, "let x=1;\n", "    let x = 1;\n"),
+        mismatch(10, 10, "fn f() {}\n", "fn f() {}\n"),
+    ];
+    let diff = unified_diff("src/main.rs", source, &edits(source, &mismatches));
+    assert_eq!(
+        diff,
+        "diff --git a/src/main.rs b/src/main.rs
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,5 +1,5 @@
+ fn main() {
+-let x=1;
++    let x = 1;
+ }
+ \n \n@@ -7,4 +7,4 @@
+ \n \n \n-fn f() {}
+\\ No newline at end of file
++fn f() {}
+"
+    );
+}
+
+#[test]
+fn unified_diff_of_insertion() {
+    let source = "a\nb\n";
+    let diff = unified_diff("a.txt", source, &edits(source, &[mismatch(3, 3, "", "c\n")]));
+    assert_eq!(diff.lines().nth(3), Some("@@ -1,2 +1,3 @@"));
+    assert!(diff.ends_with(" b\n+c\n"));
+}
+
+#[test]
+fn preview_of_patch() {
+    let source = "a\nb\n";
+    let mut patch = unified_diff("a.rs", source, &edits(source, &[mismatch(1, 1, "a\n", "A\n")]));
+    let source = "c\n\n\n\n\n\n\n\nd\n";
+    let mismatches = [mismatch(1, 1, "c\n", "C\n"), mismatch(9, 9, "d\n", "D\n")];
+    patch.push_str(&unified_diff("b.rs", source, &edits(source, &mismatches)));
+
+    let preview = patch_preview(&patch, 2);
+    assert!(preview.contains("+A\n") && preview.contains("+C\n"), "{preview}");
+    assert!(!preview.contains("+D\n"), "{preview}");
+    assert!(
+        preview
+            .ends_with("... and 1 more hunks\na.rs: 1 change pending\nb.rs: 2 changes pending\n")
+    );
+}
+
+#[test]
+fn timings_of_files() {
+    // Without sizes, the time of the batch is split evenly.
+    let paths = [PathBuf::from("/src/missing/a.rs"), PathBuf::from("/src/missing/b.rs")];
+    let timings = file_timings("rustfmt", &paths, Path::new("/src"), Duration::from_secs(3));
+    let timing = |path: &str| FileTiming {
+        path: path.to_owned(),
+        formatter: "rustfmt",
+        secs: 1.5,
+        batch_files: 2,
+    };
+    assert_eq!(timings, [timing("missing/a.rs"), timing("missing/b.rs")]);
+}
@@ -14,9 +14,11 @@
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+use std::time::Instant;
 
 use build_helper::ci::CiEnv;
 use build_helper::git::get_closest_merge_commit;
+use walkdir::WalkDir;
 
 use crate::core::builder::{Builder, RunConfig, ShouldRun, Step};
 use crate::core::config::{Config, TargetSelection};
@@ -25,7 +27,7 @@
 use crate::utils::helpers::{
     self, exe, get_clang_cl_resource_dir, output, t, unhashed_basename, up_to_date, HashStamp,
 };
-use crate::{generate_smart_stamp_hash, CLang, GitRepo, Kind};
+use crate::{generate_committed_sources_hash, generate_smart_stamp_hash, CLang, GitRepo, Kind};
 
 #[derive(Clone)]
 pub struct LlvmResult {
@@ -133,6 +135,9 @@ pub fn prebuilt_llvm_config(builder: &Builder<'_>, target: TargetSelection) -> L
 
     let stamp = out_dir.join("llvm-finished-building");
     let stamp = HashStamp::new(stamp, Some(smart_stamp_hash));
+    if builder.config.why {
+        crate::utils::why::explain_stamp("LLVM", "src/llvm-project", &stamp);
+    }
 
     if stamp.is_done() {
         if stamp.hash.is_none() {
@@ -297,6 +302,15 @@ fn run(self, builder: &Builder<'_>) -> LlvmResult {
             panic!("shared linking to LLVM is not currently supported on {}", target.triple);
         }
 
+        // Download the artifacts built by CI from the same sources with the same options, if any.
+        let cache_key = llvm_cache_key(builder, target);
+        if let Some(key) = &cache_key {
+            if builder.config.fetch_cached_artifacts("llvm", target, key, &out_dir) {
+                t!(stamp.write());
+                return res;
+            }
+        }
+
         let _guard = builder.msg_unstaged(Kind::Build, "LLVM", target);
         t!(stamp.remove());
         let _time = helpers::timeit(builder);
@@ -457,7 +471,8 @@ fn run(self, builder: &Builder<'_>) -> LlvmResult {
             cfg.define("LLVM_ENABLE_PROJECTS", enabled_llvm_projects.join(";"));
         }
 
-        if let Some(num_linkers) = builder.config.llvm_link_jobs {
+        let link_jobs = builder.config.llvm_link_jobs.or(builder.config.max_concurrent_links);
+        if let Some(num_linkers) = link_jobs {
             if num_linkers > 0 {
                 cfg.define("LLVM_PARALLEL_LINK_JOBS", num_linkers.to_string());
             }
@@ -515,7 +530,7 @@ fn run(self, builder: &Builder<'_>) -> LlvmResult {
             return res;
         }
 
-        cfg.build();
+        build_cmake(builder, "llvm", target, &mut cfg);
 
         // Helper to find the name of LLVM's shared library on darwin and linux.
         let find_llvm_lib_name = |extension| {
@@ -566,12 +581,67 @@ fn run(self, builder: &Builder<'_>) -> LlvmResult {
             );
         }
 
+        if let Some(key) = &cache_key {
+            // The build directory isn't needed once LLVM is installed in `out_dir`.
+            let files: Vec<_> = WalkDir::new(&out_dir)
+                .into_iter()
+                .filter_entry(|entry| entry.path() != out_dir.join("build"))
+                .map(|entry| t!(entry))
+                .filter(|entry| !entry.file_type().is_dir())
+                .map(|entry| {
+                    (t!(entry.path().strip_prefix(&out_dir)).to_owned(), entry.into_path())
+                })
+                .collect();
+            builder.config.store_cached_artifacts("llvm", target, key, &files);
+        }
+
         t!(stamp.write());
 
         res
     }
 }
 
+/// The options of LLVM changing its artifacts, for the keys of the remote cache of `build.cache`.
+pub(crate) fn llvm_cache_options(builder: &Builder<'_>, target: TargetSelection) -> String {
+    let config = &builder.config;
+    let mut build_config: Vec<_> = config.llvm_build_config.iter().collect();
+    build_config.sort();
+    let flags = (
+        config.llvm_assertions,
+        config.llvm_optimize,
+        config.llvm_thin_lto,
+        config.llvm_release_debuginfo,
+        config.llvm_static_stdcpp,
+        config.llvm_libzstd,
+        builder.llvm_link_shared(),
+        config.llvm_use_libcxx,
+        config.llvm_plugins,
+        config.llvm_enzyme,
+        config.llvm_polly,
+    );
+    let values = (
+        &config.llvm_targets,
+        &config.llvm_experimental_targets,
+        &config.llvm_version_suffix,
+        &config.llvm_cflags,
+        &config.llvm_cxxflags,
+        &config.llvm_ldflags,
+        &config.llvm_use_linker,
+    );
+    format!("{target} {flags:?} {values:?} {build_config:?}")
+}
+
+/// The key of LLVM in the remote cache of `build.cache`. `None` if the cache is disabled, if the
+/// submodule has uncommitted changes, or with clang, whose build directory is needed to build it
+/// for other targets.
+fn llvm_cache_key(builder: &Builder<'_>, target: TargetSelection) -> Option<String> {
+    if builder.config.cache.url.is_none() || builder.config.llvm_clang {
+        return None;
+    }
+    let options = format!("{} {}", llvm_cache_options(builder, target), builder.config.channel);
+    generate_committed_sources_hash(builder, &["src/llvm-project"], &options)
+}
+
 fn check_llvm_version(builder: &Builder<'_>, llvm_config: &Path) {
     if builder.config.dry_run() {
         return;
@@ -587,6 +657,18 @@ fn check_llvm_version(builder: &Builder<'_>, llvm_config: &Path) {
     panic!("\n\nbad LLVM version: {version}, need >=17.0\n\n")
 }
 
+/// Builds the CMake project configured by `cfg`, recording it in the `--json-trace`.
+fn build_cmake(
+    builder: &Builder<'_>,
+    project: &str,
+    target: TargetSelection,
+    cfg: &mut cmake::Config,
+) {
+    let start = Instant::now();
+    cfg.build();
+    builder.json_trace.cmake(project, &target.triple, start.elapsed());
+}
+
 fn configure_cmake(
     builder: &Builder<'_>,
     target: TargetSelection,
@@ -937,7 +1019,7 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
             .define("ENZYME_EXTERNAL_SHARED_LIB", "ON")
             .define("LLVM_DIR", builder.llvm_out(target));
 
-        cfg.build();
+        build_cmake(builder, "enzyme", target, &mut cfg);
 
         t!(stamp.write());
         out_dir
@@ -1059,7 +1141,7 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
             );
         }
 
-        cfg.build();
+        build_cmake(builder, "lld", target, &mut cfg);
 
         t!(File::create(&done_stamp));
         out_dir
@@ -1154,7 +1236,7 @@ fn run(self, builder: &Builder<'_>) -> Self::Output {
 
         for runtime in &runtimes {
             cfg.build_target(&runtime.cmake_target);
-            cfg.build();
+            build_cmake(builder, "sanitizers", self.target, &mut cfg);
         }
         t!(stamp.write());
 
@@ -1,7 +1,9 @@
+pub(crate) mod bug_report;
 pub(crate) mod check;
 pub(crate) mod clean;
 pub(crate) mod clippy;
 pub(crate) mod compile;
+pub(crate) mod completions;
 pub(crate) mod dist;
 pub(crate) mod doc;
 pub(crate) mod format;
@@ -9,6 +11,7 @@
 pub(crate) mod install;
 pub(crate) mod llvm;
 pub(crate) mod perf;
+pub(crate) mod repro;
 pub(crate) mod run;
 pub(crate) mod setup;
 pub(crate) mod suggest;
@@ -0,0 +1,236 @@
+//! Implementation of `x repro`, which records an invocation into `build/repro.json`, and replays a
+//! recorded one, e.g. on another machine, to reproduce a failure which depends on the
+//! configuration or on the environment.
+//!
+//! The recording holds the configuration of `x bug-report`, merged with the defaults of its
+//! profile and without secrets, the environment variables which influence the build, the commit,
+//! the versions of the stage0 toolchain and the steps of a dry run of the invocation with
+//! `--dump-graph`. The replay prints how the checkout, the toolchain and the steps differ from the
+//! recorded ones, and runs the invocation with the recorded configuration and environment.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::core::build_steps::bug_report::{
+    capture, effective_config, is_secret, ENV_PREFIXES, REDACTED,
+};
+use crate::core::builder::Builder;
+use crate::utils::exec::command;
+use crate::utils::helpers::t;
+
+#[cfg(test)]
+mod tests;
+
+/// The variables set by `x.py` for the machine it runs on, which are kept when replaying.
+const MACHINE_VARS: &[&str] = &["BOOTSTRAP_PARENT_ID", "BOOTSTRAP_PYTHON", "RUST_BOOTSTRAP_CONFIG"];
+
+#[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+struct Repro {
+    /// The arguments of the invocation, after `x`.
+    args: Vec<String>,
+    commit: Option<String>,
+    rust_version: String,
+    build: String,
+    /// The output of `rustc -vV` of the stage0 compiler, with its commit hash.
+    rustc: String,
+    /// The output of `cargo -V` of the stage0 cargo, with its commit hash.
+    cargo: String,
+    /// The effective `config.toml`.
+    config: String,
+    env: BTreeMap<String, String>,
+    /// The `Debug` representations of the steps ensured by the invocation, in order.
+    steps: Vec<String>,
+}
+
+/// The part of `build/bootstrap-graph.json` with the steps.
+#[derive(serde_derive::Deserialize)]
+struct Graph {
+    nodes: Vec<GraphNode>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct GraphNode {
+    debug: String,
+}
+
+/// Records the invocation passed after `--` into `build/repro.json`, or replays the one recorded
+/// in `replay`.
+pub fn repro(builder: &Builder<'_>, replay: Option<&Path>) {
+    if builder.config.dry_run() {
+        return;
+    }
+    match replay {
+        Some(path) => replay_invocation(builder, path),
+        None => record(builder),
+    }
+}
+
+fn record(builder: &Builder<'_>) {
+    let args = &builder.config.free_args;
+    if args.is_empty() {
+        eprintln!("ERROR: pass the invocation to record after `--`, like `x repro -- build`");
+        crate::exit!(1);
+    }
+    let env = std::env::vars()
+        .filter(|(name, _)| ENV_PREFIXES.iter().any(|prefix| name.starts_with(prefix)))
+        .filter(|(name, _)| !MACHINE_VARS.contains(&name.as_str()))
+        .map(|(name, value)| {
+            let value = if is_secret(&name) { REDACTED.to_owned() } else { value };
+            (name, value)
+        })
+        .collect();
+    let repro = Repro {
+        args: args.clone(),
+        commit: builder.rust_sha().map(str::to_owned),
+        rust_version: builder.rust_version(),
+        build: builder.config.build.to_string(),
+        rustc: toolchain_version(builder, &builder.initial_rustc, "-vV"),
+        cargo: toolchain_version(builder, &builder.initial_cargo, "-V"),
+        config: effective_config(builder),
+        env,
+        steps: steps(builder, args, builder.config.config.as_deref(), &[], &[]),
+    };
+
+    let path = builder.out.join("repro.json");
+    t!(fs::write(&path, t!(serde_json::to_string_pretty(&repro))));
+    println!(
+        "Wrote `x {}`, with {} steps, to {}.\n\
+         Secrets were removed from the configuration and the environment, please review the file \
+         before sharing it.",
+        args.join(" "),
+        repro.steps.len(),
+        path.display()
+    );
+}
+
+fn replay_invocation(builder: &Builder<'_>, path: &Path) {
+    let repro: Repro = t!(serde_json::from_str(&t!(fs::read_to_string(path))));
+    let differs = |what: &str, recorded: &str, here: &str| {
+        if recorded != here {
+            println!("repro: {what} differs, recorded:\n{recorded}\nhere:\n{here}\n");
+        }
+    };
+    let commit = builder.rust_sha().unwrap_or("unknown");
+    differs("the commit", repro.commit.as_deref().unwrap_or("unknown"), commit);
+    differs("the version", &repro.rust_version, &builder.rust_version());
+    differs("the build triple", &repro.build, &builder.config.build.to_string());
+    differs(
+        "the stage0 rustc",
+        &repro.rustc,
+        &toolchain_version(builder, &builder.initial_rustc, "-vV"),
+    );
+    differs(
+        "the stage0 cargo",
+        &repro.cargo,
+        &toolchain_version(builder, &builder.initial_cargo, "-V"),
+    );
+
+    let config = builder.out.join("repro").join("config.toml");
+    t!(fs::create_dir_all(config.parent().unwrap()));
+    t!(fs::write(&config, &repro.config));
+    let local: Vec<(String, String)> = std::env::vars().collect();
+    let (set, removed) = replay_env(&repro.env, &local);
+    let redacted: Vec<&str> = repro
+        .env
+        .iter()
+        .filter(|(_, value)| *value == REDACTED)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !redacted.is_empty() {
+        println!("repro: keeping the local values of the secrets {}", redacted.join(", "));
+    }
+
+    let current = steps(builder, &repro.args, Some(&config), &set, &removed);
+    let (missing, extra) = diff_steps(&repro.steps, &current);
+    for step in &missing {
+        println!("repro: the recorded step {step} doesn't run here");
+    }
+    for step in &extra {
+        println!("repro: the step {step} runs here, but wasn't recorded");
+    }
+
+    println!("Replaying `x {}`", repro.args.join(" "));
+    // This invocation holds the lock of the build directory.
+    let mut cmd = command(t!(std::env::current_exe()));
+    cmd.arg("--bypass-bootstrap-lock").args(&repro.args).env("RUST_BOOTSTRAP_CONFIG", &config);
+    for (name, value) in &set {
+        cmd.env(name, value);
+    }
+    for name in &removed {
+        cmd.env_remove(name);
+    }
+    cmd.run(builder);
+}
+
+/// Returns the version of the stage0 `tool`, printed with `flag`.
+fn toolchain_version(builder: &Builder<'_>, tool: &Path, flag: &str) -> String {
+    let mut cmd = command(tool);
+    cmd.arg(flag);
+    capture(builder, cmd)
+}
+
+/// Returns the steps of a dry run of `x <args>`, from the graph written by `--dump-graph`.
+fn steps(
+    builder: &Builder<'_>,
+    args: &[String],
+    config: Option<&Path>,
+    set: &[(String, String)],
+    removed: &[String],
+) -> Vec<String> {
+    // This invocation holds the lock of the build directory.
+    let mut cmd = command(t!(std::env::current_exe()));
+    cmd.args(["--bypass-bootstrap-lock", "--dry-run", "--dump-graph"]).args(args);
+    if let Some(config) = config {
+        cmd.env("RUST_BOOTSTRAP_CONFIG", config);
+    }
+    for (name, value) in set {
+        cmd.env(name, value);
+    }
+    for name in removed {
+        cmd.env_remove(name);
+    }
+    let stdout = cmd.run_capture_stdout(builder).stdout();
+    // The build directory of the invocation may not be this one.
+    let Some(json) = graph_path(&stdout) else {
+        eprintln!("ERROR: `x {} --dump-graph` didn't write the graph of its steps", args.join(" "));
+        crate::exit!(1);
+    };
+    let graph: Graph = t!(serde_json::from_str(&t!(fs::read_to_string(json))));
+    graph.nodes.into_iter().map(|node| node.debug).collect()
+}
+
+/// Returns the JSON file of the graph from the output of `--dump-graph`.
+fn graph_path(stdout: &str) -> Option<&str> {
+    let line = stdout.lines().rev().find(|line| line.starts_with("Wrote the graph of "))?;
+    line.rsplit_once(" and ").map(|(_, json)| json)
+}
+
+/// Returns the variables to set and to remove to replay with the recorded environment `recorded`,
+/// from the `local` one. The secrets and the variables of the machine keep their local values.
+fn replay_env(
+    recorded: &BTreeMap<String, String>,
+    local: &[(String, String)],
+) -> (Vec<(String, String)>, Vec<String>) {
+    let set = recorded
+        .iter()
+        .filter(|(name, value)| *value != REDACTED && !MACHINE_VARS.contains(&name.as_str()))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    let removed = local
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| ENV_PREFIXES.iter().any(|prefix| name.starts_with(prefix)))
+        .filter(|name| !MACHINE_VARS.contains(&name.as_str()) && !recorded.contains_key(*name))
+        .cloned()
+        .collect();
+    (set, removed)
+}
+
+/// Returns the `recorded` steps which aren't in `current`, and the `current` ones which weren't
+/// recorded.
+fn diff_steps<'a>(recorded: &'a [String], current: &'a [String]) -> (Vec<&'a str>, Vec<&'a str>) {
+    let missing = recorded.iter().filter(|step| !current.contains(step)).map(String::as_str);
+    let extra = current.iter().filter(|step| !recorded.contains(step)).map(String::as_str);
+    (missing.collect(), extra.collect())
+}
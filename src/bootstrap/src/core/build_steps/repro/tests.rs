@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn find_graph_path() {
+    let stdout = "Building bootstrap\n\
+                  Wrote the graph of 2 steps to b/bootstrap-graph.dot and b/bootstrap-graph.json\n";
+    assert_eq!(graph_path(stdout), Some("b/bootstrap-graph.json"));
+    assert_eq!(graph_path("Building bootstrap\n"), None);
+}
+
+#[test]
+fn replay_recorded_env() {
+    let recorded: BTreeMap<String, String> = [
+        ("RUSTFLAGS", "-Ctarget-cpu=native"),
+        ("CARGO_REGISTRY_TOKEN", REDACTED),
+        ("BOOTSTRAP_PYTHON", "/usr/bin/python3"),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_owned(), value.to_owned()))
+    .collect();
+    let local: Vec<(String, String)> = [
+        ("CC", "clang"),
+        ("CARGO_REGISTRY_TOKEN", "local"),
+        ("BOOTSTRAP_PYTHON", "/opt/python3"),
+        ("HOME", "/home/user"),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_owned(), value.to_owned()))
+    .collect();
+
+    // The secrets and the variables of the machine keep their local values, and the variables
+    // influencing the build which weren't recorded are removed.
+    let (set, removed) = replay_env(&recorded, &local);
+    assert_eq!(set, [("RUSTFLAGS".to_owned(), "-Ctarget-cpu=native".to_owned())]);
+    assert_eq!(removed, ["CC"]);
+}
+
+#[test]
+fn diff_recorded_steps() {
+    let recorded = ["Std { stage: 1 }".to_owned(), "Rustc { stage: 1 }".to_owned()];
+    let current = ["Std { stage: 1 }".to_owned(), "Assemble { stage: 1 }".to_owned()];
+    let (missing, extra) = diff_steps(&recorded, &current);
+    assert_eq!(missing, ["Rustc { stage: 1 }"]);
+    assert_eq!(extra, ["Assemble { stage: 1 }"]);
+}
@@ -2,8 +2,11 @@
 //!
 //! A bit of a hodge-podge as e.g. if a tool's a test fixture it should be in `build_steps::test`.
 //! If it can be reached from `./x.py run` it can go here.
+//!
+//! Tools which only need the arguments passed after `--` don't need a step of their own, they are
+//! run by [`RunTool`].
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::core::build_steps::dist::distdir;
 use crate::core::build_steps::test;
@@ -12,6 +15,7 @@
 use crate::core::config::flags::get_completion;
 use crate::core::config::TargetSelection;
 use crate::utils::exec::command;
+use crate::utils::helpers::add_dylib_path;
 use crate::Mode;
 
 #[derive(Debug, PartialOrd, Ord, Clone, Hash, PartialEq, Eq)]
@@ -283,3 +287,135 @@ fn make_run(run: RunConfig<'_>) {
         run.builder.ensure(GenerateCompletions);
     }
 }
+
+/// Tools which link to the compiler and are built at the configured stage, with their paths and the
+/// names of their binaries. If several binaries are built from the same path, the first of them is
+/// run when the path is passed, the others have to be selected by their name.
+const RUSTC_TOOLS: &[(&str, &str)] = &[
+    ("src/tools/cargo", "cargo"),
+    ("src/tools/clippy", "clippy-driver"),
+    ("src/tools/clippy", "cargo-clippy"),
+    ("src/tools/error_index_generator", "error_index_generator"),
+    ("src/tools/llvm-bitcode-linker", "llvm-bitcode-linker"),
+    ("src/tools/rust-analyzer", "rust-analyzer"),
+    ("src/tools/rustdoc", "rustdoc"),
+    ("src/tools/rustfmt", "rustfmt"),
+    ("src/tools/rustfmt", "cargo-fmt"),
+];
+
+/// Tools which have their own step in this module, as they need more than the arguments from the
+/// command line.
+const TOOLS_WITH_STEPS: &[&str] = &[
+    // tidy-alphabetical-start
+    "src/tools/build-manifest",
+    "src/tools/bump-stage0",
+    "src/tools/collect-license-metadata",
+    "src/tools/generate-copyright",
+    "src/tools/generate-windows-sys",
+    "src/tools/miri",
+    "src/tools/replace-version-placeholder",
+    // tidy-alphabetical-end
+];
+
+/// Returns the paths and the names of the binaries of all tools which are run by [`RunTool`].
+fn runnable_tools() -> impl Iterator<Item = (&'static str, &'static str)> {
+    Tool::ALL
+        .iter()
+        .map(|&(_, path, name)| (path, name))
+        .chain(RUSTC_TOOLS.iter().copied())
+        .filter(|(path, _)| !TOOLS_WITH_STEPS.contains(path))
+}
+
+/// Builds and runs any other tool in `src/tools`, selected by its path or the name of its binary,
+/// with the arguments after `--`.
+///
+/// Bootstrap tools are built by the stage 0 compiler. Tools linking to the compiler are built like
+/// the tools of a sysroot of the configured stage, so they need at least stage 1.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RunTool {
+    name: &'static str,
+}
+
+impl Step for RunTool {
+    type Output = ();
+    const ONLY_HOSTS: bool = true;
+
+    fn should_run(mut run: ShouldRun<'_>) -> ShouldRun<'_> {
+        for (path, name) in runnable_tools() {
+            run = run.path(path);
+            if !Path::new(path).ends_with(name) {
+                run = run.alias(name);
+            }
+        }
+        run
+    }
+
+    fn make_run(run: RunConfig<'_>) {
+        for pathset in &run.paths {
+            let selected = &pathset.assert_single_path().path;
+            let is_selected = |path: &str| Path::new(path) == selected.as_path();
+            let (_, name) = runnable_tools()
+                .find(|&(path, name)| is_selected(path) || is_selected(name))
+                .unwrap();
+            run.builder.ensure(RunTool { name });
+        }
+    }
+
+    fn run(self, builder: &Builder<'_>) {
+        if let Some((tool, ..)) = Tool::ALL.iter().find(|&&(_, _, name)| name == self.name) {
+            let mut cmd = builder.tool_cmd(tool.clone());
+            cmd.args(builder.config.args());
+            cmd.run(builder);
+            return;
+        }
+
+        let host = builder.build.build;
+        let stage = builder.top_stage;
+        if stage == 0 {
+            eprintln!("{} cannot be run at stage 0, use `--stage 1` or higher", self.name);
+            crate::exit!(1);
+        }
+
+        let mut cmd = if self.name == "error_index_generator" {
+            // Built in the same stage as rustdoc, like for the error index in the documentation.
+            tool::ErrorIndex::command(builder)
+        } else {
+            // Like for `Miri`, the tools of the stage N sysroot are built by the stage N-1
+            // compiler and link to the compiler libraries of stage N.
+            let target_compiler = builder.compiler(stage, host);
+            let compiler = builder.compiler(stage - 1, host);
+            let extra_features = Vec::new();
+            let exe = match self.name {
+                "cargo" => builder.ensure(tool::Cargo { compiler, target: host }),
+                "cargo-clippy" => {
+                    builder.ensure(tool::CargoClippy { compiler, target: host, extra_features })
+                }
+                "cargo-fmt" => {
+                    builder.ensure(tool::Cargofmt { compiler, target: host, extra_features })
+                }
+                "clippy-driver" => {
+                    builder.ensure(tool::Clippy { compiler, target: host, extra_features })
+                }
+                "llvm-bitcode-linker" => builder.ensure(tool::LlvmBitcodeLinker {
+                    compiler,
+                    target: host,
+                    extra_features,
+                }),
+                "rust-analyzer" => builder.ensure(tool::RustAnalyzer { compiler, target: host }),
+                "rustdoc" => builder.rustdoc(target_compiler),
+                "rustfmt" => {
+                    builder.ensure(tool::Rustfmt { compiler, target: host, extra_features })
+                }
+                name => unreachable!("unknown tool {name}"),
+            };
+
+            let mut cmd = command(exe);
+            let mut dylib_paths = builder.rustc_lib_paths(target_compiler);
+            dylib_paths.push(builder.sysroot_libdir(target_compiler, host));
+            add_dylib_path(dylib_paths, &mut cmd);
+            cmd
+        };
+        cmd.args(builder.config.args());
+        cmd.run(builder);
+    }
+}
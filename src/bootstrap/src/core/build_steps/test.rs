@@ -5,9 +5,11 @@
 
 use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 use std::{env, fs, iter};
 
 use clap_complete::shells;
+use serde_json::json;
 
 use crate::core::build_steps::doc::DocumentationFormat;
 use crate::core::build_steps::synthetic_targets::MirOptPanicAbortSyntheticTarget;
@@ -17,7 +19,7 @@
 use crate::core::builder::{
     self, crate_description, Alias, Builder, Compiler, Kind, RunConfig, ShouldRun, Step,
 };
-use crate::core::config::flags::{get_completion, Subcommand};
+use crate::core::config::flags::{get_completion, FormatArgs, Subcommand};
 use crate::core::config::TargetSelection;
 use crate::utils::exec::{command, BootstrapCommand};
 use crate::utils::helpers::{
@@ -1053,6 +1055,14 @@ fn run(self, builder: &Builder<'_>) {
         if let Some(s) = builder.config.cmd.extra_checks() {
             cmd.arg(format!("--extra-checks={s}"));
         }
+        if builder.config.cmd.only_modified() {
+            // Tidy uses the same git machinery as `x fmt` to find the modified files.
+            let git_config = builder.config.git_config();
+            cmd.arg("--only-modified");
+            cmd.arg(format!("--git-repository={}", git_config.git_repository));
+            cmd.arg(format!("--nightly-branch={}", git_config.nightly_branch));
+            cmd.arg(format!("--git-merge-commit-email={}", git_config.git_merge_commit_email));
+        }
         let mut args = std::env::args_os();
         if args.any(|arg| arg == OsStr::new("--")) {
             cmd.arg("--");
@@ -1074,13 +1084,8 @@ fn run(self, builder: &Builder<'_>) {
                 );
                 crate::exit!(1);
             }
-            let all = false;
-            crate::core::build_steps::format::format(
-                builder,
-                !builder.config.cmd.bless(),
-                all,
-                &[],
-            );
+            let args = FormatArgs { check: !builder.config.cmd.bless(), ..Default::default() };
+            crate::core::build_steps::format::format(builder, &args, &[]);
         }
 
         builder.info("tidy check");
@@ -2121,7 +2126,22 @@ fn run(self, builder: &Builder<'_>) {
             compiler.host,
             target,
         );
-        try_run_tests(builder, &mut cmd, false);
+        let run_suite = |cmd: &mut BootstrapCommand, compare_mode: Option<&str>| {
+            let start = Instant::now();
+            let passed = try_run_tests(builder, cmd, false);
+            if !builder.config.dry_run() {
+                let suite = json!({
+                    "suite": suite,
+                    "mode": mode,
+                    "compare_mode": compare_mode,
+                    "target": self.target.triple.to_string(),
+                    "host": self.compiler.host.triple.to_string(),
+                    "stage": self.compiler.stage,
+                });
+                builder.json_trace.test_suite(suite, passed, start.elapsed());
+            }
+        };
+        run_suite(&mut cmd, None);
 
         if let Some(compare_mode) = compare_mode {
             cmd.arg("--compare-mode").arg(compare_mode);
@@ -2144,7 +2164,7 @@ fn run(self, builder: &Builder<'_>) {
                 suite, mode, compare_mode, &compiler.host, target
             ));
             let _time = helpers::timeit(builder);
-            try_run_tests(builder, &mut cmd, false);
+            run_suite(&mut cmd, Some(compare_mode));
         }
     }
 }
@@ -11,8 +11,12 @@
 use crate::utils::channel::GitInfo;
 use crate::utils::exec::{command, BootstrapCommand};
 use crate::utils::helpers::{add_dylib_path, exe, git, t};
+use crate::utils::step_cache::{dep_info_files, StepCache};
 use crate::{gha, Compiler, Kind, Mode};
 
+mod prune;
+mod reuse;
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum SourceType {
     InTree,
@@ -74,8 +78,9 @@ fn should_run(run: ShouldRun<'_>) -> ShouldRun<'_> {
     fn run(self, builder: &Builder<'_>) -> PathBuf {
         let compiler = self.compiler;
         let target = self.target;
-        let mut tool = self.tool;
+        let tool = self.tool;
         let path = self.path;
+        let step = format!("{self:?}");
 
         match self.mode {
             Mode::ToolRustc => {
@@ -101,6 +106,29 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
             cargo.allow_features(self.allow_features);
         }
         cargo.args(self.cargo_args);
+
+        // HACK(#82501): on Windows, the tools directory gets added to PATH when running tests, and
+        // compiletest confuses HTML tidy with the in-tree tidy. Name the in-tree tidy something
+        // different so the problem doesn't come up.
+        let bin_name = if tool == "tidy" { "rust-tidy" } else { tool };
+
+        // Skip cargo if neither the tool, its sources nor the compiler changed since it was built.
+        let cargo_out = builder.cargo_out(compiler, self.mode, target);
+        let cache = StepCache::new(
+            builder,
+            &format!("tool-{bin_name}-stage{}-{}", compiler.stage, compiler.host),
+            target,
+            &format!("{step} {cargo:?}"),
+        );
+        if cache.is_fresh(&[&cargo_out.join(exe(bin_name, target))]) {
+            builder.verbose(|| println!("{tool} is up to date, not running cargo"));
+            cargo.into_cmd().mark_as_executed();
+            return copy_link_tool_bin(builder, self.compiler, self.target, self.mode, bin_name);
+        }
+        if self.mode != Mode::ToolBootstrap {
+            reuse::seed(builder, compiler, self.mode, target);
+        }
+
         let _guard = builder.msg_tool(
             Kind::Build,
             self.mode,
@@ -111,7 +139,12 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
         );
 
         // we check this below
-        let build_success = compile::stream_cargo(builder, cargo, vec![], &mut |_| {});
+        let mut filenames = vec![];
+        let build_success = compile::stream_cargo(builder, cargo, vec![], &mut |msg| {
+            if let compile::CargoMessage::CompilerArtifact { filenames: files, .. } = msg {
+                filenames.extend(files.iter().map(|file| PathBuf::from(&**file)));
+            }
+        });
 
         builder.save_toolstate(
             tool,
@@ -121,13 +154,21 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
         if !build_success {
             crate::exit!(1);
         } else {
-            // HACK(#82501): on Windows, the tools directory gets added to PATH when running tests, and
-            // compiletest confuses HTML tidy with the in-tree tidy. Name the in-tree tidy something
-            // different so the problem doesn't come up.
-            if tool == "tidy" {
-                tool = "rust-tidy";
+            if let Ok(dep_info) = fs::read_to_string(cargo_out.join(format!("{bin_name}.d"))) {
+                let rustc = match self.mode {
+                    Mode::ToolBootstrap => builder.initial_rustc.clone(),
+                    _ => builder.rustc(compiler),
+                };
+                cache.store(dep_info_files(&dep_info).into_iter().chain([rustc]));
+            }
+            if self.mode != Mode::ToolBootstrap {
+                reuse::record(builder, compiler, self.mode, target);
             }
-            copy_link_tool_bin(builder, self.compiler, self.target, self.mode, tool)
+            if self.mode == Mode::ToolRustc {
+                let compiler_stamp = compile::librustc_stamp(builder, compiler, target);
+                prune::record_and_prune(builder, &cargo_out, bin_name, &filenames, &compiler_stamp);
+            }
+            copy_link_tool_bin(builder, self.compiler, self.target, self.mode, bin_name)
         }
     }
 }
@@ -251,6 +292,13 @@ pub enum Tool {
             )+
         }
 
+        impl Tool {
+            /// All tools, with their paths and the names of their binaries.
+            pub const ALL: &'static [(Tool, &'static str, &'static str)] = &[
+                $((Tool::$name, $path, $tool_name),)+
+            ];
+        }
+
         impl<'a> Builder<'a> {
             pub fn tool_exe(&self, tool: Tool) -> PathBuf {
                 match tool {
@@ -653,6 +701,7 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
             features.as_slice(),
         );
 
+        reuse::seed(builder, build_compiler, Mode::ToolRustc, target);
         let _guard = builder.msg_tool(
             Kind::Build,
             Mode::ToolRustc,
@@ -661,7 +710,24 @@ fn run(self, builder: &Builder<'_>) -> PathBuf {
             &self.compiler.host,
             &target,
         );
-        cargo.into_cmd().run(builder);
+        let mut filenames = vec![];
+        let build_success = compile::stream_cargo(builder, cargo, vec![], &mut |msg| {
+            if let compile::CargoMessage::CompilerArtifact { filenames: files, .. } = msg {
+                filenames.extend(files.iter().map(|file| PathBuf::from(&**file)));
+            }
+        });
+        if !build_success {
+            crate::exit!(1);
+        }
+        reuse::record(builder, build_compiler, Mode::ToolRustc, target);
+        let compiler_stamp = compile::librustc_stamp(builder, build_compiler, target_compiler.host);
+        prune::record_and_prune(
+            builder,
+            &builder.cargo_out(build_compiler, Mode::ToolRustc, target),
+            "rustdoc",
+            &filenames,
+            &compiler_stamp,
+        );
 
         // Cargo adds a number of paths to the dylib search path on windows, which results in
         // the wrong rustdoc being executed. To avoid the conflicting rustdocs, we name the "tool"
@@ -0,0 +1,105 @@
+//! The pruning of the stale libraries of the tools using the compiler crates, like clippy and
+//! rustdoc, which share the `stageN-tools` directory of the compiler building them.
+//!
+//! The version of the compiler, which includes its commit, is part of the hashes of the libraries
+//! built by cargo, so each rebuild of the compiler leaves a copy of all the libraries of these
+//! tools in `deps`, which cargo never removes. Each tool build records the libraries it uses in
+//! `.tool-artifacts/<tool>`, next to `deps`, and then removes the libraries which no tool uses and
+//! which are older than the compiler, as they were built by a previous one. The libraries of the
+//! tests of the tools are newer than the compiler, so they are kept until it changes again.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::core::builder::Builder;
+use crate::utils::helpers::{mtime, t};
+
+#[cfg(test)]
+mod tests;
+
+/// The extensions of the libraries in `deps`, the other files being small or executables.
+const LIBRARY_EXTENSIONS: &[&str] = &["rlib", "rmeta", "so", "dylib", "dll"];
+
+/// Records the libraries of `filenames`, from the artifact messages of cargo, as the ones used by
+/// `tool` in the cargo output directory `cargo_out`, and removes the libraries of `deps` which no
+/// tool uses and which are older than `compiler_stamp`.
+pub(super) fn record_and_prune(
+    builder: &Builder<'_>,
+    cargo_out: &Path,
+    tool: &str,
+    filenames: &[PathBuf],
+    compiler_stamp: &Path,
+) {
+    if builder.config.dry_run() {
+        return;
+    }
+    let deps = cargo_out.join("deps");
+    let records = cargo_out.join(".tool-artifacts");
+    t!(fs::create_dir_all(&records));
+    let used: Vec<&str> = filenames
+        .iter()
+        .filter(|filename| filename.parent() == Some(deps.as_path()))
+        .filter_map(|filename| filename.file_name()?.to_str())
+        .collect();
+    t!(fs::write(records.join(tool), used.join("\n")));
+
+    let mut live = BTreeSet::new();
+    for record in t!(fs::read_dir(&records)) {
+        let record = t!(fs::read_to_string(t!(record).path()));
+        live.extend(record.lines().map(str::to_owned));
+    }
+    let Ok(entries) = fs::read_dir(&deps) else {
+        return;
+    };
+    let files: Vec<(String, SystemTime)> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((entry.file_name().into_string().ok()?, modified))
+        })
+        .collect();
+
+    let stale = stale_libraries(&files, &live, mtime(compiler_stamp));
+    if stale.is_empty() {
+        return;
+    }
+    let mut size = 0;
+    for name in &stale {
+        let path = deps.join(name);
+        let len = fs::metadata(&path).map_or(0, |metadata| metadata.len());
+        builder.verbose_than(1, || println!("removing the stale library {}", path.display()));
+        // A library loaded by a running tool can't be removed on Windows.
+        if fs::remove_file(&path).is_ok() {
+            size += len;
+        }
+    }
+    builder.verbose(|| {
+        println!(
+            "removed {} stale libraries of the tools ({} MiB) from {}",
+            stale.len(),
+            size >> 20,
+            cargo_out.display()
+        )
+    });
+}
+
+/// Returns the libraries of `files`, with their modification times, which aren't `live` and were
+/// built before the compiler, at `compiler_built`.
+fn stale_libraries<'a>(
+    files: &'a [(String, SystemTime)],
+    live: &BTreeSet<String>,
+    compiler_built: SystemTime,
+) -> Vec<&'a str> {
+    files
+        .iter()
+        .filter(|(name, modified)| {
+            let extension = Path::new(name).extension().and_then(|extension| extension.to_str());
+            extension.is_some_and(|extension| LIBRARY_EXTENSIONS.contains(&extension))
+                && !live.contains(name)
+                && *modified < compiler_built
+        })
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
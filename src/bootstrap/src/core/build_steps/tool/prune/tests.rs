@@ -0,0 +1,32 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use super::*;
+
+#[test]
+fn prune_stale_libraries() {
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    let files: Vec<(String, SystemTime)> = [
+        ("libclippy_lints-0123.rlib", 10),
+        ("libclippy_lints-0123.rmeta", 10),
+        ("libclippy_lints-4567.rlib", 30),
+        ("libserde-89ab.rlib", 10),
+        ("libserde_derive-cdef.so", 10),
+        ("libtest_helper-0123.rlib", 30),
+        ("clippy_lints-0123.d", 10),
+        ("clippy_driver-0123", 10),
+    ]
+    .into_iter()
+    .map(|(name, secs)| (name.to_owned(), at(secs)))
+    .collect();
+    let live: BTreeSet<String> =
+        ["libclippy_lints-4567.rlib", "libserde-89ab.rlib"].into_iter().map(Into::into).collect();
+
+    // The libraries built since the compiler, like the ones of the tests, are kept, and so are
+    // the dep-info files and the executables.
+    assert_eq!(
+        stale_libraries(&files, &live, at(20)),
+        ["libclippy_lints-0123.rlib", "libclippy_lints-0123.rmeta", "libserde_derive-cdef.so"]
+    );
+    // Nothing is pruned without a compiler.
+    assert!(stale_libraries(&files, &live, UNIX_EPOCH).is_empty());
+}
@@ -0,0 +1,109 @@
+//! The reuse of the artifacts of the tools of one stage by the next one, like the libraries of
+//! crates.io which clippy, miri and rustdoc depend on.
+//!
+//! The compilers of consecutive stages are often equivalent: they're built from the same sources,
+//! report the same version, and link to the same standard library and compiler crates, which are
+//! uplifted from stage 1 unless `build.full-bootstrap` is set. The artifacts they build are then
+//! byte-identical, but cargo can't know it, as the `stageN-tools` directories are separate. Each
+//! tool build records the key of its compiler in `.compiler-key`, next to `deps`, which is its
+//! version and the libraries of its sysroot, and the first build of a stage starts from a copy of
+//! the directory of the previous stage when their keys match, so that cargo only rebuilds what's
+//! actually different.
+
+use std::fs;
+use std::path::Path;
+
+use crate::core::builder::Builder;
+use crate::core::config::TargetSelection;
+use crate::utils::exec::command;
+use crate::utils::helpers::{set_file_times, t};
+use crate::{Compiler, Mode};
+
+#[cfg(test)]
+mod tests;
+
+/// Seeds the output directory of the tools built by `compiler` for `target` with the one of the
+/// previous stage, if it doesn't exist yet and was built by an equivalent compiler.
+pub(super) fn seed(builder: &Builder<'_>, compiler: Compiler, mode: Mode, target: TargetSelection) {
+    let out = builder.stage_out(compiler, mode);
+    if builder.config.dry_run() || compiler.stage == 0 || out.exists() {
+        return;
+    }
+    let previous = Compiler { stage: compiler.stage - 1, host: compiler.host };
+    let previous_out = builder.stage_out(previous, mode);
+    let previous_key = builder.cargo_out(previous, mode, target).join(".compiler-key");
+    let Ok(previous_key) = fs::read_to_string(previous_key) else {
+        return;
+    };
+    if previous_key != compiler_key(builder, compiler, target) {
+        builder.verbose(|| {
+            println!("not reusing {}, built by another compiler", previous_out.display())
+        });
+        return;
+    }
+    builder.verbose(|| {
+        println!("reusing the artifacts of {} for {}", previous_out.display(), out.display())
+    });
+    copy_tools_dir(&previous_out, &out);
+}
+
+/// Copies the output directory of the tools `src` to `dst`. Only the libraries of `deps` are hard
+/// links, as the compiler replaces them instead of writing into them. The other files, like the
+/// fingerprints of cargo and `.compiler-key`, are written in place, so they're copies, which keep
+/// the builds of `dst` from changing the state of `src`.
+fn copy_tools_dir(src: &Path, dst: &Path) {
+    t!(fs::create_dir_all(dst));
+    for entry in t!(fs::read_dir(src)) {
+        let entry = t!(entry);
+        let (src, dst) = (entry.path(), dst.join(entry.file_name()));
+        if t!(entry.file_type()).is_dir() {
+            copy_tools_dir(&src, &dst);
+            continue;
+        }
+        let in_deps = src.parent().and_then(Path::file_name).is_some_and(|dir| dir == "deps");
+        let library = src.extension().is_some_and(|ext| ext == "rlib" || ext == "rmeta");
+        if in_deps && library && fs::hard_link(&src, &dst).is_ok() {
+            continue;
+        }
+        t!(fs::copy(&src, &dst));
+        // Cargo compares the modification times of the fingerprints with the ones of the sources.
+        let modified = t!(t!(src.metadata()).modified());
+        t!(set_file_times(&dst, fs::FileTimes::new().set_modified(modified)));
+    }
+}
+
+/// Records the key of `compiler` in the output directory of the tools it built for `target`.
+pub(super) fn record(
+    builder: &Builder<'_>,
+    compiler: Compiler,
+    mode: Mode,
+    target: TargetSelection,
+) {
+    if builder.config.dry_run() {
+        return;
+    }
+    let key = compiler_key(builder, compiler, target);
+    t!(fs::write(builder.cargo_out(compiler, mode, target).join(".compiler-key"), key));
+}
+
+/// Returns what the artifacts built by `compiler` for `target` depend on, besides their sources.
+fn compiler_key(builder: &Builder<'_>, compiler: Compiler, target: TargetSelection) -> String {
+    let version = command(builder.rustc(compiler)).arg("-vV").run_capture_stdout(builder).stdout();
+    let mut libraries = Vec::new();
+    let sysroots = [compiler.host, target].map(|target| builder.sysroot_libdir(compiler, target));
+    for dir in sysroots {
+        libraries.extend(
+            builder.read_dir(&dir).filter_map(|entry| entry.file_name().into_string().ok()),
+        );
+    }
+    key(&version, libraries)
+}
+
+/// Returns the key of a compiler with the version `version`, whose sysroot has `libraries`. The
+/// names of the libraries have the hashes of their metadata, which change with their compiler,
+/// their sources and their flags.
+fn key(version: &str, mut libraries: Vec<String>) -> String {
+    libraries.sort_unstable();
+    libraries.dedup();
+    format!("{}\n{}\n", version.trim_end(), libraries.join("\n"))
+}
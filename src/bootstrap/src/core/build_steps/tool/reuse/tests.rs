@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn compiler_keys() {
+    let libraries = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+    let version = "rustc 1.84.0-dev\nbinary: rustc\nhost: x86_64-unknown-linux-gnu\n";
+
+    // The order of the directory entries doesn't matter, and neither do the libraries of both
+    // sysroots of a host.
+    assert_eq!(
+        key(version, libraries(&["libstd-0123.rlib", "libcore-4567.rlib", "libstd-0123.rlib"])),
+        key(version, libraries(&["libcore-4567.rlib", "libstd-0123.rlib"]))
+    );
+    assert_eq!(
+        key(version, libraries(&["libcore-4567.rlib"])),
+        "rustc 1.84.0-dev\nbinary: rustc\nhost: x86_64-unknown-linux-gnu\nlibcore-4567.rlib\n"
+    );
+    // A standard library built by another compiler has other hashes.
+    assert_ne!(
+        key(version, libraries(&["libstd-0123.rlib"])),
+        key(version, libraries(&["libstd-89ab.rlib"]))
+    );
+    assert_ne!(
+        key(version, libraries(&["libstd-0123.rlib"])),
+        key("rustc 1.83.0-beta.1\n", libraries(&["libstd-0123.rlib"]))
+    );
+}
+
+#[test]
+fn copied_tools_dir() {
+    let dir = std::env::temp_dir().join(format!("bootstrap-reuse-{}", std::process::id()));
+    let previous = dir.join("stage1-tools/x86_64-unknown-linux-gnu/release");
+    t!(fs::create_dir_all(previous.join("deps")));
+    t!(fs::create_dir_all(previous.join(".fingerprint/clippy-0123")));
+    t!(fs::write(previous.join("deps/libserde-4567.rlib"), "serde"));
+    t!(fs::write(previous.join(".fingerprint/clippy-0123/invoked.timestamp"), ""));
+    t!(fs::write(previous.join(".compiler-key"), "stage 1"));
+
+    let next = dir.join("stage2-tools/x86_64-unknown-linux-gnu/release");
+    copy_tools_dir(&previous, &next);
+    assert_eq!(t!(fs::read_to_string(next.join("deps/libserde-4567.rlib"))), "serde");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let inode = |path: &Path| t!(path.metadata()).ino();
+        let library = "deps/libserde-4567.rlib";
+        assert_eq!(inode(&previous.join(library)), inode(&next.join(library)));
+    }
+
+    // The files written in place by the builds of the next stage don't change the previous one.
+    t!(fs::write(next.join(".fingerprint/clippy-0123/invoked.timestamp"), "stage 2"));
+    t!(fs::write(next.join(".compiler-key"), "stage 2"));
+    let read = |path: &str| t!(fs::read_to_string(previous.join(path)));
+    assert_eq!(read(".fingerprint/clippy-0123/invoked.timestamp"), "");
+    assert_eq!(read(".compiler-key"), "stage 1");
+    t!(fs::remove_dir_all(&dir));
+}
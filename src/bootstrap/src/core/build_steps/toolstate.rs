@@ -1,10 +1,11 @@
 //! [Toolstate] checks to keep tools building
 //!
 //! Reachable via `./x.py test` but mostly relevant for CI, since it isn't run locally by default.
+//! `./x.py toolstate` checks the tools and the submodules locally.
 //!
 //! [Toolstate]: https://forge.rust-lang.org/infra/toolstate.html
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::{Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::{env, fmt, fs, time};
@@ -12,6 +13,7 @@
 use serde_derive::{Deserialize, Serialize};
 
 use crate::core::builder::{Builder, RunConfig, ShouldRun, Step};
+use crate::utils::exec::command;
 use crate::utils::helpers::{self, t};
 
 // Each cycle is 42 days long (6 weeks); the last week is 35..=42 then.
@@ -85,6 +87,15 @@ fn days_since_beta_promotion() -> u64 {
     // ("rustc-dev-guide", "src/doc/rustc-dev-guide"),
 ];
 
+// These tools are only checked by `x toolstate`, they are tested on CI like the compiler.
+static LOCAL_TOOLS: &[(&str, &str)] = &[
+    ("cargo", "src/tools/cargo"),
+    ("clippy", "src/tools/clippy"),
+    ("miri", "src/tools/miri"),
+    ("rust-analyzer", "src/tools/rust-analyzer"),
+    ("rustfmt", "src/tools/rustfmt"),
+];
+
 fn print_error(tool: &str, submodule: &str) {
     eprintln!();
     eprintln!("We detected that this PR updated '{tool}', but its tests failed.");
@@ -461,3 +472,149 @@ fn state(&self) -> ToolState {
         }
     }
 }
+
+/// Checks whether the tools build and pass their tests with the current compiler, and compares
+/// the results with the states recorded by `x toolstate --record`. Also reports the submodules
+/// which are behind their upstream branch.
+///
+/// Each tool is built and tested by a separate invocation of bootstrap, so that a failure doesn't
+/// stop the others from being checked. The output of each tool is written to
+/// `build/toolstate/<tool>.log`.
+pub fn toolstate(builder: &Builder<'_>, record: bool) {
+    if builder.config.dry_run() {
+        return;
+    }
+
+    let dir = builder.out.join("toolstate");
+    t!(fs::create_dir_all(&dir));
+    let expected_path = dir.join("expected.json");
+    let expected: ToolstateData = match fs::read(&expected_path) {
+        Ok(expected) => t!(serde_json::from_slice(&expected)),
+        Err(_) => Default::default(),
+    };
+
+    let selected = |path: &str| {
+        builder.paths.is_empty() || builder.paths.iter().any(|p| Path::new(path).ends_with(p))
+    };
+    let mut toolstates = BTreeMap::new();
+    for &(tool, path) in LOCAL_TOOLS.iter().chain(STABLE_TOOLS).chain(NIGHTLY_TOOLS) {
+        if !selected(path) {
+            continue;
+        }
+        eprintln!("Checking {tool}...");
+        let mut log = String::new();
+        let build = if path.starts_with("src/doc") { "doc" } else { "build" };
+        let state = if !run_bootstrap(builder, &[build, path], &mut log) {
+            ToolState::BuildFail
+        } else if !run_bootstrap(builder, &["test", path, "--no-fail-fast"], &mut log) {
+            ToolState::TestFail
+        } else {
+            ToolState::TestPass
+        };
+        t!(fs::write(dir.join(format!("{tool}.log")), log));
+        toolstates.insert(Box::<str>::from(tool), state);
+    }
+
+    let mut regressed = false;
+    println!("\nTools (stage {}):", builder.top_stage);
+    for (tool, &state) in &toolstates {
+        let comparison = match expected.get(tool) {
+            Some(&old) if state < old => {
+                regressed = true;
+                let log = dir.join(format!("{tool}.log"));
+                format!(", regressed from {old}, see {}", log.display())
+            }
+            Some(&old) if state > old => format!(", improved from {old}"),
+            _ => String::new(),
+        };
+        println!("    {tool:<16} {state}{comparison}");
+    }
+
+    println!("\nSubmodules:");
+    for submodule in build_helper::util::parse_gitmodules(&builder.src) {
+        println!("    {submodule:<28} {}", submodule_health(builder, submodule));
+    }
+
+    if record {
+        let mut recorded = expected;
+        recorded.extend(toolstates);
+        t!(fs::write(&expected_path, t!(serde_json::to_string_pretty(&recorded))));
+        println!("\nRecorded the expected states in {}", expected_path.display());
+    } else if expected.is_empty() {
+        println!(
+            "\nNo expected states were recorded yet, run `x toolstate --record` on the commit to \
+             compare with first."
+        );
+    }
+
+    if regressed {
+        crate::exit!(1);
+    }
+}
+
+/// Runs bootstrap with `args`, appending its output to `log`. Returns whether it succeeded.
+fn run_bootstrap(builder: &Builder<'_>, args: &[&str], log: &mut String) -> bool {
+    // This invocation holds the lock of the build directory.
+    let mut cmd = command(t!(env::current_exe())).allow_failure();
+    cmd.arg("--bypass-bootstrap-lock").args(args).arg("--stage").arg(builder.top_stage.to_string());
+    if let Some(config) = &builder.config.config {
+        cmd.env("RUST_BOOTSTRAP_CONFIG", config);
+    }
+    let output = cmd.run_capture(builder);
+
+    log.push_str(&format!("$ x {}\n", args.join(" ")));
+    log.push_str(&output.stdout_if_present().unwrap_or_default());
+    log.push_str(&output.stderr_if_present().unwrap_or_default());
+    output.is_success()
+}
+
+/// Describes whether the submodule at `path` is checked out at the commit recorded in the
+/// repository, and whether that commit is behind the upstream branch.
+fn submodule_health(builder: &Builder<'_>, path: &str) -> String {
+    let capture = |dir: Option<&Path>, args: &[&str]| {
+        let mut git = helpers::git(dir).allow_failure();
+        git.args(args);
+        git.run_capture_stdout(builder).stdout_if_ok().map(|stdout| stdout.trim().to_owned())
+    };
+
+    let gitlink = format!("HEAD:{path}");
+    let recorded = capture(Some(builder.src.as_path()), &["rev-parse", gitlink.as_str()]);
+    let Some(recorded) = recorded else {
+        return "not recorded at HEAD".to_owned();
+    };
+    let submodule = builder.src.join(path);
+    if !submodule.join(".git").exists() {
+        return "not checked out".to_owned();
+    }
+    let mut health = match capture(Some(submodule.as_path()), &["rev-parse", "HEAD"]) {
+        Some(commit) if commit == recorded => String::new(),
+        _ => "checked out at another commit (run `git submodule update`), ".to_owned(),
+    };
+
+    let gitmodules = builder.src.join(".gitmodules");
+    let config_key = |key: &str| {
+        let key = format!("submodule.{path}.{key}");
+        capture(None, &["config", "--file", gitmodules.to_str().unwrap(), key.as_str()])
+    };
+    let (Some(url), branch) = (config_key("url"), config_key("branch")) else {
+        health.push_str("no upstream");
+        return health;
+    };
+    let upstream_ref = branch.as_ref().map_or("HEAD".to_owned(), |b| format!("refs/heads/{b}"));
+    let upstream = capture(None, &["ls-remote", url.as_str(), upstream_ref.as_str()])
+        .and_then(|refs| Some(refs.split_whitespace().next()?.to_owned()));
+    let branch = branch.as_deref().unwrap_or("the default branch");
+    match upstream {
+        None => health.push_str(&format!("could not reach {url}")),
+        Some(upstream) if upstream == recorded => health.push_str("up to date"),
+        Some(upstream) => {
+            // Only known if the upstream commit was fetched into the submodule.
+            let range = format!("{recorded}..{upstream}");
+            match capture(Some(submodule.as_path()), &["rev-list", "--count", range.as_str()]) {
+                Some(count) => health.push_str(&format!("{count} commits behind {branch}")),
+                None => health.push_str(&format!("behind {branch} (at {})", &upstream[..10])),
+            }
+        }
+    }
+    health
+}
@@ -1,27 +1,32 @@
-use std::path::PathBuf;
+//! Implementation of `x vendor`, which vendors the dependencies of all workspaces of the
+//! repository into `vendor`, so that the compiler and the tools can be built offline.
+
+use std::path::{Path, PathBuf};
 
 use crate::core::build_steps::tool::SUBMODULES_FOR_RUSTBOOK;
 use crate::core::builder::{Builder, RunConfig, ShouldRun, Step};
 use crate::utils::exec::command;
+use crate::utils::helpers::t;
 
-/// List of default paths used for vendoring for `x vendor` and dist tarballs.
-pub fn default_paths_to_vendor(builder: &Builder<'_>) -> Vec<PathBuf> {
-    let mut paths = vec![];
-    for p in [
-        "src/tools/cargo/Cargo.toml",
-        "src/tools/rust-analyzer/Cargo.toml",
-        "compiler/rustc_codegen_cranelift/Cargo.toml",
-        "compiler/rustc_codegen_gcc/Cargo.toml",
-        "library/Cargo.toml",
-        "src/bootstrap/Cargo.toml",
-        "src/tools/rustbook/Cargo.toml",
-        "src/tools/rustc-perf/Cargo.toml",
-        "src/tools/opt-dist/Cargo.toml",
-    ] {
-        paths.push(builder.src.join(p));
-    }
-
-    paths
+/// List of default paths used for vendoring for `x vendor` and dist tarballs, with the submodules
+/// they require.
+///
+/// The workspace in the root of the repository is always vendored, as `cargo vendor` is run there.
+pub fn default_paths_to_vendor(builder: &Builder<'_>) -> Vec<(PathBuf, Vec<&'static str>)> {
+    [
+        ("src/tools/cargo/Cargo.toml", vec!["src/tools/cargo"]),
+        ("src/tools/rust-analyzer/Cargo.toml", vec![]),
+        ("compiler/rustc_codegen_cranelift/Cargo.toml", vec![]),
+        ("compiler/rustc_codegen_gcc/Cargo.toml", vec![]),
+        ("library/Cargo.toml", vec![]),
+        ("src/bootstrap/Cargo.toml", vec![]),
+        ("src/tools/rustbook/Cargo.toml", SUBMODULES_FOR_RUSTBOOK.into()),
+        ("src/tools/rustc-perf/Cargo.toml", vec!["src/tools/rustc-perf"]),
+        ("src/tools/opt-dist/Cargo.toml", vec![]),
+    ]
+    .into_iter()
+    .map(|(path, submodules)| (builder.src.join(path), submodules))
+    .collect()
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -48,6 +53,8 @@ fn make_run(run: RunConfig<'_>) {
         });
     }
 
+    /// Vendors the dependencies of all workspaces, writes the Cargo configuration which uses them
+    /// to `.cargo/config.toml` and checks that nothing has to be downloaded anymore.
     fn run(self, builder: &Builder<'_>) -> Self::Output {
         let mut cmd = command(&builder.initial_cargo);
         cmd.arg("vendor");
@@ -56,27 +63,85 @@ fn run(self, builder: &Builder<'_>) -> Self::Output {
             cmd.arg("--versioned-dirs");
         }
 
-        // These submodules must be present for `x vendor` to work.
-        for submodule in SUBMODULES_FOR_RUSTBOOK.iter().chain(["src/tools/cargo"].iter()) {
-            builder.build.require_submodule(submodule, None);
-        }
+        let mut manifests = vec![self.root_dir.join("Cargo.toml")];
 
-        // Sync these paths by default.
-        for p in default_paths_to_vendor(builder) {
-            cmd.arg("--sync").arg(p);
+        // Sync these paths by default, the submodules must be present for `x vendor` to work.
+        for (p, submodules) in default_paths_to_vendor(builder) {
+            for submodule in submodules {
+                builder.build.require_submodule(submodule, None);
+            }
+            manifests.push(p);
         }
 
         // Also sync explicitly requested paths.
-        for sync_arg in self.sync_args {
-            cmd.arg("--sync").arg(sync_arg);
+        manifests.extend(self.sync_args);
+
+        for manifest in &manifests[1..] {
+            cmd.arg("--sync").arg(manifest);
         }
 
         // Will read the libstd Cargo.toml
         // which uses the unstable `public-dependency` feature.
         cmd.env("RUSTC_BOOTSTRAP", "1");
 
-        cmd.current_dir(self.root_dir);
+        cmd.current_dir(&self.root_dir);
+
+        // `cargo vendor` prints the configuration which replaces the sources with the vendored
+        // ones. Bootstrap removes `.cargo` unless `build.vendor` is set, so it can't contain
+        // anything else.
+        let cargo_config = cmd.run_capture_stdout(builder).stdout();
+        let cargo_config_dir = self.root_dir.join(".cargo");
+        builder.create_dir(&cargo_config_dir);
+        builder.create(
+            &cargo_config_dir.join("config.toml"),
+            &format!("# Generated by `x vendor`\n{cargo_config}"),
+        );
+
+        verify_offline(builder, &self.root_dir, &manifests);
+
+        if !builder.config.vendor {
+            println!(
+                "Vendored the dependencies into {}.\n\
+                 Set `build.vendor = true` in config.toml to use them.",
+                self.root_dir.join("vendor").display()
+            );
+        }
+    }
+}
+
+/// Checks that all dependencies of the workspaces can be resolved from the vendored sources
+/// alone. `cargo fetch` runs with an empty `$CARGO_HOME`, so that packages cached from
+/// earlier downloads are not used.
+fn verify_offline(builder: &Builder<'_>, root_dir: &Path, manifests: &[PathBuf]) {
+    if builder.config.dry_run() {
+        return;
+    }
+
+    let cargo_home = builder.tempdir().join("vendor-cargo-home");
+    if cargo_home.exists() {
+        t!(std::fs::remove_dir_all(&cargo_home));
+    }
+    t!(std::fs::create_dir_all(&cargo_home));
 
-        cmd.run(builder);
+    for manifest in manifests {
+        let mut cmd = command(&builder.initial_cargo).allow_failure();
+        cmd.arg("fetch")
+            .arg("--frozen")
+            .arg("--manifest-path")
+            .arg(manifest)
+            .env("CARGO_HOME", &cargo_home)
+            .env("RUSTC", &builder.initial_rustc)
+            .env("RUSTC_BOOTSTRAP", "1")
+            // The configuration is only found in the parent directories of the working directory.
+            .current_dir(root_dir);
+        let output = cmd.run_capture(builder);
+        if output.is_failure() {
+            eprintln!(
+                "ERROR: the vendored sources are incomplete for {}:\n{}",
+                manifest.display(),
+                output.stderr()
+            );
+            crate::exit!(1);
+        }
     }
 }
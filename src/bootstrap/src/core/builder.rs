@@ -21,9 +21,10 @@
 use crate::utils::cache::Cache;
 use crate::utils::exec::{command, BootstrapCommand};
 use crate::utils::helpers::{
-    self, add_dylib_path, add_link_lib_path, check_cfg_arg, exe, libdir, linker_args, linker_flags,
-    t, LldThreads,
+    self, add_dylib_path, add_link_lib_path, check_cfg_arg, edit_distance, exe, libdir,
+    linker_args, linker_flags, t, LldThreads,
 };
+use crate::utils::step_graph::field;
 pub use crate::Compiler;
 use crate::{
     prepare_behaviour_dump_dir, Build, CLang, Crate, DocTests, GitRepo, Mode, EXTRA_CHECK_CFGS,
@@ -52,7 +53,7 @@ pub struct Builder<'a> {
 
     /// A stack of [`Step`]s to run before we can run this builder. The output
     /// of steps is cached in [`Self::cache`].
-    stack: RefCell<Vec<Box<dyn Any>>>,
+    stack: RefCell<Vec<StackEntry>>,
 
     /// The total amount of time we spent running [`Step`]s in [`Self::stack`].
     time_spent_on_dependencies: Cell<Duration>,
@@ -63,6 +64,13 @@ pub struct Builder<'a> {
     pub paths: Vec<PathBuf>,
 }
 
+/// A [`Step`] being run, on [`Builder::stack`].
+struct StackEntry {
+    step: Box<dyn Any>,
+    /// Describes the step in the chain of a cycle, so that steps are only formatted then.
+    describe: fn(&dyn Any) -> String,
+}
+
 impl<'a> Deref for Builder<'a> {
     type Target = Build;
 
@@ -83,6 +91,10 @@ pub trait Step: 'static + Clone + Debug + PartialEq + Eq + Hash {
     /// If true, then this rule should be skipped if --target was specified, but --host was not
     const ONLY_HOSTS: bool = false;
 
+    /// Whether the steps ensuring this step don't read what it writes, so that they don't wait
+    /// for the commands it runs in the background, like the rendering of a book.
+    const DETACHED: bool = false;
+
     /// Primary function to execute this rule. Can call `builder.ensure()`
     /// with other steps to run those.
     ///
@@ -427,13 +439,8 @@ fn run(v: &[StepDescription], builder: &Builder<'_>, paths: &[PathBuf]) {
             );
         }
 
-        if paths.is_empty() || builder.config.include_default_paths {
-            for (desc, should_run) in v.iter().zip(&should_runs) {
-                if desc.default && should_run.is_really_default() {
-                    desc.maybe_run(builder, should_run.paths.iter().cloned().collect());
-                }
-            }
-        }
+        // The default steps run once the paths are known to match steps.
+        let run_defaults = paths.is_empty() || builder.config.include_default_paths;
 
         // Attempt to resolve paths to be relative to the builder source directory.
         let mut paths: Vec<PathBuf> = paths
@@ -459,20 +466,17 @@ fn run(v: &[StepDescription], builder: &Builder<'_>, paths: &[PathBuf]) {
 
         // Handle all test suite paths.
         // (This is separate from the loop below to avoid having to handle multiple paths in `is_suite_path` somehow.)
+        let mut suites_to_run = vec![];
         paths.retain(|path| {
             for (desc, should_run) in v.iter().zip(&should_runs) {
                 if let Some(suite) = should_run.is_suite_path(path) {
-                    desc.maybe_run(builder, vec![suite.clone()]);
+                    suites_to_run.push((desc, suite.clone()));
                     return false;
                 }
             }
             true
         });
 
-        if paths.is_empty() {
-            return;
-        }
-
         let mut path_lookup: Vec<(PathBuf, bool)> =
             paths.clone().into_iter().map(|p| (p, false)).collect();
 
@@ -506,15 +510,22 @@ fn run(v: &[StepDescription], builder: &Builder<'_>, paths: &[PathBuf]) {
         // Sort the steps before running them to respect the CLI order.
         steps_to_run.sort_by_key(|(index, _, _)| *index);
 
-        // Handle all PathSets.
-        for (_index, desc, pathsets) in steps_to_run {
-            if !pathsets.is_empty() {
-                desc.maybe_run(builder, pathsets);
-            }
-        }
-
+        // Paths which match no step are an error, reported before running any step.
         if !paths.is_empty() {
             eprintln!("ERROR: no `{}` rules matched {:?}", builder.kind.as_str(), paths,);
+            let candidates = step_paths(&should_runs, builder.kind);
+            for path in &paths {
+                let suggestions = suggest_paths(path, &candidates);
+                if !suggestions.is_empty() {
+                    let suggestions: Vec<_> =
+                        suggestions.iter().map(|path| format!("`{}`", path.display())).collect();
+                    eprintln!(
+                        "HELP: did you mean {} instead of `{}`?",
+                        suggestions.join(" or "),
+                        path.display()
+                    );
+                }
+            }
             eprintln!(
                 "HELP: run `x.py {} --help --verbose` to show a list of available paths",
                 builder.kind.as_str()
@@ -524,9 +535,64 @@ fn run(v: &[StepDescription], builder: &Builder<'_>, paths: &[PathBuf]) {
             );
             crate::exit!(1);
         }
+
+        if run_defaults {
+            for (desc, should_run) in v.iter().zip(&should_runs) {
+                if desc.default && should_run.is_really_default() {
+                    desc.maybe_run(builder, should_run.paths.iter().cloned().collect());
+                }
+            }
+        }
+
+        for (desc, suite) in suites_to_run {
+            desc.maybe_run(builder, vec![suite]);
+        }
+
+        // Handle all PathSets.
+        for (_index, desc, pathsets) in steps_to_run {
+            if !pathsets.is_empty() {
+                desc.maybe_run(builder, pathsets);
+            }
+        }
     }
 }
 
+/// Returns the paths and aliases of the steps of `kind`, which the paths on the command line can
+/// match.
+fn step_paths<'a>(should_runs: &'a [ShouldRun<'_>], kind: Kind) -> Vec<&'a Path> {
+    let mut paths: Vec<&Path> = should_runs
+        .iter()
+        .flat_map(|should_run| &should_run.paths)
+        .flat_map(|pathset| match pathset {
+            PathSet::Set(set) => set.iter().collect(),
+            PathSet::Suite(suite) => vec![suite],
+        })
+        .filter(|task_path| task_path.kind.map_or(true, |path_kind| path_kind == kind))
+        .map(|task_path| task_path.path.as_path())
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Returns the paths of `candidates` close to `path`, which matched no step, the closest first:
+/// the ones with a small edit distance, like `compiler/rustc_middle` for `compilerr/rustc_middle`,
+/// and the ones with the same file name, like `tests/ui` for `src/test/ui` after the tests moved.
+fn suggest_paths<'a>(path: &Path, candidates: &[&'a Path]) -> Vec<&'a Path> {
+    let requested = path.to_string_lossy();
+    let max_distance = (requested.chars().count() / 4).max(1);
+    let mut suggestions: Vec<(usize, &Path)> = candidates
+        .iter()
+        .filter_map(|&candidate| {
+            let distance = edit_distance(&requested, &candidate.to_string_lossy());
+            let same_name = path.file_name().is_some() && candidate.file_name() == path.file_name();
+            (distance <= max_distance || same_name).then_some((distance, candidate))
+        })
+        .collect();
+    suggestions.sort();
+    suggestions.into_iter().take(3).map(|(_, candidate)| candidate).collect()
+}
+
 enum ReallyDefault<'a> {
     Bool(bool),
     Lazy(LazyLock<bool, Box<dyn Fn() -> bool + 'a>>),
@@ -715,6 +781,10 @@ pub enum Kind {
     Suggest,
     Vendor,
     Perf,
+    BugReport,
+    Toolstate,
+    Completions,
+    Repro,
 }
 
 impl Kind {
@@ -739,6 +809,10 @@ pub fn as_str(&self) -> &'static str {
             Kind::Suggest => "suggest",
             Kind::Vendor => "vendor",
             Kind::Perf => "perf",
+            Kind::BugReport => "bug-report",
+            Kind::Toolstate => "toolstate",
+            Kind::Completions => "completions",
+            Kind::Repro => "repro",
         }
     }
 
@@ -1000,12 +1074,19 @@ macro_rules! describe {
                 run::GenerateCopyright,
                 run::GenerateWindowsSys,
                 run::GenerateCompletions,
+                run::RunTool,
             ),
             Kind::Setup => describe!(setup::Profile, setup::Hook, setup::Link, setup::Vscode),
             Kind::Clean => describe!(clean::CleanAll, clean::Rustc, clean::Std),
             Kind::Vendor => describe!(vendor::Vendor),
             // special-cased in Build::build()
-            Kind::Format | Kind::Suggest | Kind::Perf => vec![],
+            Kind::Format
+            | Kind::Suggest
+            | Kind::Perf
+            | Kind::BugReport
+            | Kind::Toolstate
+            | Kind::Completions
+            | Kind::Repro => vec![],
             Kind::MiriTest | Kind::MiriSetup => unreachable!(),
         }
     }
@@ -1044,6 +1125,17 @@ pub fn get_help(build: &Build, kind: Kind) -> Option<String> {
         Some(help)
     }
 
+    /// Returns the paths of the steps of `kind`, like `--help -v` lists them, for the scripts of
+    /// `x completions`.
+    pub fn completion_paths(build: &Build, kind: Kind) -> Vec<PathBuf> {
+        let builder = Self::new_internal(build, kind, vec![]);
+        let should_runs: Vec<ShouldRun<'_>> = Builder::get_step_descriptions(kind)
+            .iter()
+            .map(|desc| (desc.should_run)(ShouldRun::new(&builder, desc.kind)))
+            .collect();
+        step_paths(&should_runs, kind).into_iter().map(Path::to_owned).collect()
+    }
+
     fn new_internal(build: &Build, kind: Kind, paths: Vec<PathBuf>) -> Builder<'_> {
         Builder {
             build,
@@ -1059,7 +1151,7 @@ fn new_internal(build: &Build, kind: Kind, paths: Vec<PathBuf>) -> Builder<'_> {
     pub fn new(build: &Build) -> Builder<'_> {
         let paths = &build.config.paths;
         let (kind, paths) = match build.config.cmd {
-            Subcommand::Build => (Kind::Build, &paths[..]),
+            Subcommand::Build | Subcommand::Why => (Kind::Build, &paths[..]),
             Subcommand::Check { .. } => (Kind::Check, &paths[..]),
             Subcommand::Clippy { .. } => (Kind::Clippy, &paths[..]),
             Subcommand::Fix => (Kind::Fix, &paths[..]),
@@ -1079,6 +1171,10 @@ pub fn new(build: &Build) -> Builder<'_> {
             ),
             Subcommand::Vendor { .. } => (Kind::Vendor, &paths[..]),
             Subcommand::Perf { .. } => (Kind::Perf, &paths[..]),
+            Subcommand::BugReport { .. } => (Kind::BugReport, &[][..]),
+            Subcommand::Toolstate { .. } => (Kind::Toolstate, &paths[..]),
+            Subcommand::Completions { .. } => (Kind::Completions, &[][..]),
+            Subcommand::Repro { .. } => (Kind::Repro, &[][..]),
         };
 
         Self::new_internal(build, kind, paths.to_owned())
@@ -1734,7 +1830,10 @@ fn cargo(
             Mode::Std | Mode::Rustc | Mode::Codegen | Mode::ToolRustc => String::new(),
         };
 
-        cargo.arg("-j").arg(self.jobs().to_string());
+        // The crates compiled on the workers of the distributed compilation don't use the cores
+        // of this machine, so more of them can be compiled at once.
+        let jobs = self.config.distributed.as_ref().and_then(|distributed| distributed.jobs);
+        cargo.arg("-j").arg(jobs.unwrap_or_else(|| self.jobs()).to_string());
 
         // FIXME: Temporary fix for https://github.com/rust-lang/cargo/issues/3005
         // Force cargo to output binaries with disambiguating hashes in the name
@@ -1832,6 +1931,13 @@ fn cargo(
             cargo.env("RUSTC_WRAPPER_REAL", existing_wrapper);
         }
 
+        // The distributed compilation runs the compiler through sccache, which sends the crates
+        // to the scheduler configured by `utils::distributed`.
+        if let Some(distributed) = &self.config.distributed {
+            cargo.env("RUSTC_WRAPPER_REAL", &distributed.sccache);
+            cargo.env("SCCACHE_CONF", crate::utils::distributed::sccache_config_path(&self.out));
+        }
+
         // If this is for `miri-test`, prepare the sysroots.
         if cmd_kind == Kind::MiriTest {
             self.ensure(compile::Std::new(compiler, compiler.host));
@@ -2019,6 +2125,11 @@ fn cargo(
             cargo.env("RUSTC_BACKTRACE_ON_ICE", "1");
         }
 
+        if let Some(links) = self.config.max_concurrent_links {
+            cargo.env("RUSTC_MAX_CONCURRENT_LINKS", links.to_string());
+            cargo.env("RUSTC_LINK_SLOTS", self.out.join("tmp").join("link-slots"));
+        }
+
         cargo.env("RUSTC_VERBOSE", self.verbosity.to_string());
 
         // Downstream forks of the Rust compiler might want to use a custom libc to add support for
@@ -2252,30 +2363,58 @@ fn cargo(
     pub fn ensure<S: Step>(&'a self, step: S) -> S::Output {
         {
             let mut stack = self.stack.borrow_mut();
-            for stack_step in stack.iter() {
-                // should skip
-                if stack_step.downcast_ref::<S>().map_or(true, |stack_step| *stack_step != step) {
-                    continue;
-                }
-                let mut out = String::new();
-                out += &format!("\n\nCycle in build detected when adding {step:?}\n");
-                for el in stack.iter().rev() {
-                    out += &format!("\t{el:?}\n");
-                }
-                panic!("{}", out);
+            let ensured = |entry: &StackEntry| {
+                entry.step.downcast_ref::<S>().is_some_and(|stack_step| *stack_step == step)
+            };
+            if let Some(start) = stack.iter().position(ensured) {
+                let mut chain: Vec<String> =
+                    stack[start..].iter().map(|entry| (entry.describe)(&*entry.step)).collect();
+                chain.push(describe_step::<S>(&step));
+                panic!("{}", cycle_message(&chain));
             }
+            self.step_graph.enter_step(&step, self);
             if let Some(out) = self.cache.get(&step) {
                 self.verbose_than(1, || println!("{}c {:?}", "  ".repeat(stack.len()), step));
+                self.step_graph.exit_step(self);
+                if !S::DETACHED && !stack.is_empty() && self.scheduler.is_enabled(self) {
+                    let key = format!("{} {step:?}", type_name::<S>());
+                    self.scheduler.wait_for_step(&key, self);
+                }
 
                 return out;
             }
+            if let Some(out) = self.step_journal.completed(&step, self) {
+                self.verbose(|| println!("{}r {:?}", "  ".repeat(stack.len()), step));
+                self.step_graph.exit_step(self);
+                self.cache.put(step, out.clone());
+                return out;
+            }
             self.verbose_than(1, || println!("{}> {:?}", "  ".repeat(stack.len()), step));
-            stack.push(Box::new(step.clone()));
+            stack.push(StackEntry { step: Box::new(step.clone()), describe: describe_step::<S> });
         }
 
         #[cfg(feature = "build-metrics")]
         self.metrics.enter_step(&step, self);
 
+        // The depth of the step, which is on the stack.
+        let depth = self.stack.borrow().len() - 1;
+        let trace_step = (self.json_trace.is_enabled() && !self.config.dry_run()).then(|| {
+            let step_type = type_name::<S>();
+            let step_type = step_type.strip_prefix("bootstrap::").unwrap_or(step_type);
+            (step_type, format!("{step:?}"))
+        });
+        if let Some((step_type, debug)) = &trace_step {
+            self.json_trace.step_start(step_type, debug, depth);
+        }
+        self.timings.enter_step(&step, self);
+        let show_step = self.tui.is_enabled() && !self.config.dry_run();
+        if show_step {
+            self.tui.step_start(format!("{step:?}"));
+        }
+
+        crate::utils::step_hooks::run(self, &step, true);
+        let first_job = self.scheduler.next_job();
+        let failures = self.delayed_failures.borrow().len();
         let (out, dur) = {
             let start = Instant::now();
             let zero = Duration::new(0, 0);
@@ -2286,6 +2425,15 @@ pub fn ensure<S: Step>(&'a self, step: S) -> S::Output {
             (out, dur - deps)
         };
 
+        if let Some((step_type, debug)) = &trace_step {
+            self.json_trace.step_finish(step_type, debug, depth, dur);
+        }
+        self.timings.exit_step(dur.as_secs_f64(), self);
+        crate::utils::step_hooks::run(self, &step, false);
+        if show_step {
+            self.tui.step_finish();
+        }
+
         if self.config.print_step_timings && !self.config.dry_run() {
             let step_string = format!("{step:?}");
             let brace_index = step_string.find('{').unwrap_or(0);
@@ -2305,9 +2453,22 @@ pub fn ensure<S: Step>(&'a self, step: S) -> S::Output {
         {
             let mut stack = self.stack.borrow_mut();
             let cur_step = stack.pop().expect("step stack empty");
-            assert_eq!(cur_step.downcast_ref(), Some(&step));
+            assert_eq!(cur_step.step.downcast_ref(), Some(&step));
+        }
+        if self.scheduler.is_enabled(self) {
+            // The step ensuring this one may read what its commands write.
+            let wait = !S::DETACHED && !self.stack.borrow().is_empty();
+            let key = format!("{} {step:?}", type_name::<S>());
+            self.scheduler.step_finished(key, first_job..self.scheduler.next_job(), wait, self);
         }
+        self.step_graph.exit_step(self);
         self.verbose_than(1, || println!("{}< {:?}", "  ".repeat(self.stack.borrow().len()), step));
+        // The step didn't complete if one of its commands failed, or still runs in the background.
+        if self.delayed_failures.borrow().len() == failures
+            && first_job == self.scheduler.next_job()
+        {
+            self.step_journal.record(&step, &out, self);
+        }
         self.cache.put(step, out.clone());
         out
     }
@@ -2359,10 +2520,26 @@ pub(crate) fn maybe_open_in_browser<S: Step>(&self, path: impl AsRef<Path>) {
         }
     }
 
+    /// Runs `command`, which writes into `out`, in the background with `build.rustbook-jobs`.
+    /// This is for the last commands of a step, whose outputs only the steps depending on it read.
+    pub(crate) fn run_in_background(&self, label: String, out: &Path, command: BootstrapCommand) {
+        self.scheduler.run(self, label, out, command);
+    }
+
+    /// Waits for the commands running in the background which write into `out`, before reading
+    /// or removing it.
+    pub(crate) fn wait_for_writes_to(&self, out: &Path) {
+        if self.scheduler.is_enabled(self) {
+            self.scheduler.wait_for_conflicting(out, self);
+        }
+    }
+
     pub(crate) fn open_in_browser(&self, path: impl AsRef<Path>) {
         if self.config.dry_run() || !self.config.cmd.open() {
             return;
         }
+        // The documentation may still be rendered in the background.
+        self.scheduler.wait_all(self);
 
         let path = path.as_ref();
         self.info(&format!("Opening doc {}", path.display()));
@@ -2372,6 +2549,32 @@ pub(crate) fn open_in_browser(&self, path: impl AsRef<Path>) {
     }
 }
 
+/// Describes the step `step` of type `S` by its type, stage and target, like
+/// `compile::Std (stage 1, x86_64-unknown-linux-gnu)`, or by its `Debug` representation.
+fn describe_step<S: Step>(step: &dyn Any) -> String {
+    let step_type = type_name::<S>();
+    let step_type = step_type.strip_prefix("bootstrap::core::build_steps::").unwrap_or(step_type);
+    let debug = format!("{:?}", step.downcast_ref::<S>().expect("step of another type"));
+    let target = field(&debug, "target").or_else(|| field(&debug, "host"));
+    match (field(&debug, "stage"), target) {
+        (Some(stage), Some(target)) => format!("{step_type} (stage {stage}, {target})"),
+        (None, Some(target)) => format!("{step_type} ({target})"),
+        _ => format!("{step_type} {debug}"),
+    }
+}
+
+/// Returns the message of a cycle of steps, where the last step of `chain` is the first one.
+fn cycle_message(chain: &[String]) -> String {
+    let mut message = format!(
+        "\n\nCycle in build detected: `{}` ensures itself through these steps:\n",
+        chain[0]
+    );
+    for (i, step) in chain.iter().enumerate() {
+        message += &format!("{} {step}\n", if i == 0 { "   " } else { "->" });
+    }
+    message
+}
+
 /// Represents flag values in `String` form with whitespace delimiter to pass it to the compiler later.
 ///
 /// `-Z crate-attr` flags will be applied recursively on the target code using the `rustc_parse::parser::Parser`.
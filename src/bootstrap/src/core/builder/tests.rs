@@ -1,46 +1,15 @@
-use std::thread;
-
 use super::*;
 use crate::core::build_steps::doc::DocumentationFormat;
 use crate::core::config::Config;
-use crate::Flags;
+use crate::utils::tests::{ConfigBuilder, Recording};
 
 fn configure(cmd: &str, host: &[&str], target: &[&str]) -> Config {
     configure_with_args(&[cmd.to_owned()], host, target)
 }
 
 fn configure_with_args(cmd: &[String], host: &[&str], target: &[&str]) -> Config {
-    let mut config = Config::parse(Flags::parse(cmd));
-    // don't save toolstates
-    config.save_toolstates = None;
-    config.dry_run = DryRun::SelfCheck;
-
-    // Ignore most submodules, since we don't need them for a dry run, and the
-    // tests run much faster without them.
-    //
-    // The src/doc/book submodule is needed because TheBook step tries to
-    // access files even during a dry-run (may want to consider just skipping
-    // that in a dry run).
-    let submodule_build = Build::new(Config {
-        // don't include LLVM, so CI doesn't require ninja/cmake to be installed
-        rust_codegen_backends: vec![],
-        ..Config::parse(Flags::parse(&["check".to_owned()]))
-    });
-    submodule_build.require_submodule("src/doc/book", None);
-    config.submodules = Some(false);
-
-    config.ninja_in_file = false;
-    // try to avoid spurious failures in dist where we create/delete each others file
-    // HACK: rather than pull in `tempdir`, use the one that cargo has conveniently created for us
-    let dir = Path::new(env!("OUT_DIR"))
-        .join("tmp-rustbuild-tests")
-        .join(&thread::current().name().unwrap_or("unknown").replace(":", "-"));
-    t!(fs::create_dir_all(&dir));
-    config.out = dir;
-    config.build = TargetSelection::from_user("A-A");
-    config.hosts = host.iter().map(|s| TargetSelection::from_user(s)).collect();
-    config.targets = target.iter().map(|s| TargetSelection::from_user(s)).collect();
-    config
+    let args: Vec<&str> = cmd.iter().map(String::as_str).collect();
+    ConfigBuilder::new(&args).hosts(host).targets(target).create_config()
 }
 
 fn first<A, B>(v: Vec<(A, B)>) -> Vec<A> {
@@ -133,6 +102,21 @@ fn test_intersection() {
     assert_eq!(command_paths, vec![PathBuf::from("library/stdarch")]);
 }
 
+#[test]
+fn test_suggest_paths() {
+    let candidates: Vec<&Path> =
+        ["compiler/rustc_middle", "compiler/rustc_metadata", "library/std", "tests/ui"]
+            .iter()
+            .map(Path::new)
+            .collect();
+    let suggest = |path: &str| suggest_paths(Path::new(path), &candidates);
+    assert_eq!(suggest("compilerr/rustc_midle"), [Path::new("compiler/rustc_middle")]);
+    assert_eq!(suggest("library/st"), [Path::new("library/std")]);
+    // The paths which moved keep their file name.
+    assert_eq!(suggest("src/test/ui"), [Path::new("tests/ui")]);
+    assert!(suggest("x").is_empty());
+}
+
 #[test]
 fn validate_path_remap() {
     let build = Build::new(configure("test", &["A-A"], &["A-A"]));
@@ -212,6 +196,23 @@ fn alias_and_path_for_library() {
     assert_eq!(first(cache.all::<doc::Std>()), &[doc_std!(A => A, stage = 0)]);
 }
 
+#[test]
+fn describe_cycle_of_steps() {
+    let std = std!(A => B, stage = 1);
+    let chain = [
+        describe_step::<compile::Std>(&std),
+        "compile::Rustc (stage 1, A-A)".to_owned(),
+        describe_step::<compile::Std>(&std),
+    ];
+    assert_eq!(chain[0], "compile::Std (stage 1, B-B)");
+    assert_eq!(
+        cycle_message(&chain),
+        "\n\nCycle in build detected: `compile::Std (stage 1, B-B)` ensures itself through these \
+         steps:\n    compile::Std (stage 1, B-B)\n-> compile::Rustc (stage 1, A-A)\n\
+         -> compile::Std (stage 1, B-B)\n"
+    );
+}
+
 mod defaults {
     use pretty_assertions::assert_eq;
 
@@ -756,3 +757,59 @@ fn test_docs() {
         );
     }
 }
+
+mod commands {
+    use pretty_assertions::assert_eq;
+
+    use super::{ConfigBuilder, Recording};
+
+    #[test]
+    fn run_bump_stage0() {
+        let config = ConfigBuilder::new(&["run", "src/tools/bump-stage0", "--", "--help"]);
+        let recording = Recording::run(config.create_config());
+        recording.assert_snapshot("run-bump-stage0", &recording.find("bump-stage0"), &[]);
+    }
+
+    fn build_std(config: ConfigBuilder) -> Recording {
+        let recording = Recording::run(config.create_config());
+        assert!(!recording.cargo("library/sysroot/Cargo.toml").is_empty());
+        recording
+    }
+
+    #[test]
+    fn std_debug_assertions() {
+        let recording = build_std(ConfigBuilder::new(&["build", "library", "--stage", "1"]));
+        for cargo in recording.cargo("library/sysroot/Cargo.toml") {
+            assert_eq!(cargo.env("CARGO_PROFILE_RELEASE_DEBUG_ASSERTIONS"), Some("false"));
+        }
+
+        let recording = build_std(
+            ConfigBuilder::new(&["build", "library", "--stage", "1"])
+                .set("rust.debug-assertions-std=true"),
+        );
+        for cargo in recording.cargo("library/sysroot/Cargo.toml") {
+            assert_eq!(cargo.env("CARGO_PROFILE_RELEASE_DEBUG_ASSERTIONS"), Some("true"));
+        }
+    }
+
+    #[test]
+    fn json_output() {
+        let recording = build_std(ConfigBuilder::new(&["build", "library", "--stage", "1"]));
+        for cargo in recording.cargo("library/sysroot/Cargo.toml") {
+            assert_eq!(cargo.arg_after("--message-format"), Some("json-diagnostic-rendered-ansi"));
+        }
+
+        // Cargo renders the diagnostics itself, with the duplicates, with `--verbose`.
+        let recording =
+            build_std(ConfigBuilder::new(&["build", "library", "--stage", "1", "--verbose"]));
+        for cargo in recording.cargo("library/sysroot/Cargo.toml") {
+            assert_eq!(cargo.arg_after("--message-format"), Some("json-render-diagnostics"));
+        }
+
+        let recording =
+            build_std(ConfigBuilder::new(&["build", "library", "--stage", "1", "--json-output"]));
+        for cargo in recording.cargo("library/sysroot/Cargo.toml") {
+            assert_eq!(cargo.arg_after("--message-format"), Some("json"));
+        }
+    }
+}
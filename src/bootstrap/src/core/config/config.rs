@@ -5,6 +5,7 @@
 
 use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
 use std::fmt::{self, Display};
 use std::io::IsTerminal;
 use std::path::{absolute, Path, PathBuf};
@@ -15,16 +16,17 @@
 
 use build_helper::exit;
 use build_helper::git::{get_closest_merge_commit, output_result, GitConfig};
+use build_helper::stage0_parser::VersionMetadata;
 use serde::{Deserialize, Deserializer};
 use serde_derive::Deserialize;
 
 use crate::core::build_steps::compile::CODEGEN_BACKEND_PREFIX;
 use crate::core::build_steps::llvm;
 pub use crate::core::config::flags::Subcommand;
-use crate::core::config::flags::{Color, Flags, Warnings};
+use crate::core::config::flags::{Color, Flags, OnFailure, Ui, Warnings};
 use crate::utils::cache::{Interned, INTERNER};
 use crate::utils::channel::{self, GitInfo};
-use crate::utils::helpers::{self, exe, output, t};
+use crate::utils::helpers::{self, edit_distance, exe, output, t};
 
 macro_rules! check_ci_llvm {
     ($name:expr) => {
@@ -173,6 +175,12 @@ pub struct Config {
     pub target_config: HashMap<TargetSelection, Target>,
     pub full_bootstrap: bool,
     pub bootstrap_cache_path: Option<PathBuf>,
+    pub dist_mirrors: Vec<String>,
+    pub net: NetConfig,
+    pub gc: GcConfig,
+    pub cache: CacheConfig,
+    pub distributed: Option<DistributedConfig>,
+    pub step_hooks: Vec<StepHook>,
     pub extended: bool,
     pub tools: Option<HashSet<String>>,
     pub sanitizers: bool,
@@ -184,7 +192,27 @@ pub struct Config {
     pub json_output: bool,
     pub test_compare_mode: bool,
     pub color: Color,
+    /// The progress view of `--ui`, see `utils::tui`.
+    pub ui: Ui,
     pub patch_binaries_for_nix: Option<bool>,
+    pub binary_patching: BinaryPatchingConfig,
+    pub formatters: FormattersConfig,
+    /// Whether `x fmt` formats the files which aren't tracked by git, instead of skipping them.
+    pub fmt_untracked: bool,
+    /// The maximum number of formatter processes of `x fmt` running at once.
+    pub fmt_jobs: Option<u32>,
+    /// The maximum number of files passed to each formatter process.
+    pub fmt_batch_size: Option<u32>,
+    /// Whether the steps whose inputs didn't change since they last ran are skipped.
+    pub step_cache: bool,
+    /// The maximum number of books which rustbook renders at once in the background.
+    pub rustbook_jobs: u32,
+    /// The maximum number of crates which are linked at once, like `rustc_driver` and the tools.
+    pub max_concurrent_links: Option<u32>,
+    /// Whether stage 0 is kept like `--keep-stage 0` when only the standard library changed.
+    pub auto_keep_stage: bool,
+    /// Whether `x check` of some compiler crates checks them against the downloaded ones.
+    pub graft_ci_rustc: bool,
     pub stage0_metadata: build_helper::stage0_parser::Stage0,
     pub android_ndk: Option<PathBuf>,
     /// Whether to use the `c` feature of the `compiler_builtins` crate.
@@ -194,17 +222,37 @@ pub struct Config {
     pub stderr_is_tty: bool,
 
     pub on_fail: Option<String>,
+    /// What to do when a command fails, see `utils::failure_report`.
+    pub on_failure: OnFailure,
     pub stage: u32,
     pub keep_stage: Vec<u32>,
     pub keep_stage_std: Vec<u32>,
     pub src: PathBuf,
     /// defaults to `config.toml`
     pub config: Option<PathBuf>,
+    /// The files included by `config.toml`, and by the files it includes.
+    pub config_includes: Vec<PathBuf>,
+    /// The `RUST_BOOTSTRAP_*` variables of the environment which override `config.toml`, by name.
+    pub env_overrides: Vec<(String, String)>,
     pub jobs: Option<u32>,
     pub cmd: Subcommand,
     pub incremental: bool,
     pub dry_run: DryRun,
     pub dump_bootstrap_shims: bool,
+    /// Whether the graph of the steps of a dry run is written to the build directory.
+    pub dump_graph: bool,
+    /// Whether the commands of a dry run are printed, see `utils::plan`.
+    pub plan: bool,
+    /// Whether the steps completed by the interrupted invocation are skipped, see
+    /// `utils::step_journal`.
+    pub resume: bool,
+    /// Whether the dry run of `x why` explains why its cargo invocations would rebuild, see
+    /// `utils::why`.
+    pub why: bool,
+    /// Whether the report of `--timings` is written, see `utils::timings`.
+    pub timings: bool,
+    /// The file of `--json-trace`, see `utils::json_trace`.
+    pub json_trace: Option<PathBuf>,
     /// Arguments appearing after `--` to be forwarded to tools,
     /// e.g. `--fix-broken` or test arguments.
     pub free_args: Vec<String>,
@@ -320,6 +368,7 @@ pub struct Config {
     pub save_toolstates: Option<PathBuf>,
     pub print_step_timings: bool,
     pub print_step_rusage: bool,
+    pub metrics_exports: Vec<MetricsExport>,
 
     // Fallback musl-root for all targets
     pub musl_root: Option<PathBuf>,
@@ -351,15 +400,163 @@ pub struct Config {
     initial_rustfmt: RefCell<RustfmtState>,
     #[cfg(test)]
     pub initial_rustfmt: RefCell<RustfmtState>,
+    /// Whether the rustfmt pinned in `src/stage0` is downloaded when the one of `build.rustfmt` is
+    /// missing or has another version.
+    pub pin_rustfmt: bool,
 
     /// The paths to work with. For example: with `./x check foo bar` we get
     /// `paths=["foo", "bar"]`.
     pub paths: Vec<PathBuf>,
 }
 
+/// Settings for the network access of bootstrap, from the `[build.net]` section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetConfig {
+    /// Proxy for all downloads, instead of the proxy configured by the environment.
+    pub proxy: Option<String>,
+    /// Certificate authorities to verify the servers with, instead of the system ones.
+    pub ca_bundle: Option<PathBuf>,
+    /// Seconds after which a download is aborted, if it can't connect or stalls.
+    pub timeout: u32,
+    /// How often a failed download is retried.
+    pub retries: u32,
+}
+
+impl Default for NetConfig {
+    fn default() -> Self {
+        NetConfig { proxy: None, ca_bundle: None, timeout: 30, retries: 3 }
+    }
+}
+
+impl fmt::Display for NetConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let NetConfig { proxy, ca_bundle, timeout, retries } = self;
+        write!(f, "proxy = {}, ", proxy.as_deref().unwrap_or("<from the environment>"))?;
+        match ca_bundle {
+            Some(ca_bundle) => write!(f, "ca-bundle = {}, ", ca_bundle.display())?,
+            None => write!(f, "ca-bundle = <system>, ")?,
+        }
+        write!(f, "timeout = {timeout}, retries = {retries}")
+    }
+}
+
+/// Settings for patching the downloaded binaries on hosts without glibc at the usual paths, from
+/// the `[build.binary-patching]` section.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BinaryPatchingConfig {
+    /// Whether the binaries are patched, instead of detecting whether the host needs it.
+    pub enable: Option<bool>,
+    /// The dynamic linker set as the interpreter of the executables.
+    pub dynamic_linker: Option<PathBuf>,
+    /// Directories added to the RPATH of the executables and the shared libraries.
+    pub rpath: Vec<PathBuf>,
+    /// The `patchelf` executable, instead of the one in `PATH` or from `<nixpkgs>`.
+    pub patchelf: Option<PathBuf>,
+}
+
+/// The formatters `x fmt` runs in addition to rustfmt, from the `[build.formatters]` section.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormattersConfig {
+    /// The formatter of the Python files, which aren't formatted if unset.
+    pub python: Option<PythonFormatter>,
+    /// Whether the shell scripts are formatted with shfmt.
+    pub shell: bool,
+    /// Whether the TOML files are formatted with taplo.
+    pub toml: bool,
+    /// Whether the `Cargo.toml` manifests are formatted with taplo, which `toml` implies.
+    pub manifests: bool,
+    /// Whether the Rust code blocks of the markdown files of `src/doc` are formatted with rustfmt.
+    pub markdown: bool,
+    /// The executables of the formatters, instead of the ones in `PATH`.
+    pub ruff: Option<PathBuf>,
+    pub black: Option<PathBuf>,
+    pub shfmt: Option<PathBuf>,
+    pub taplo: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PythonFormatter {
+    Ruff,
+    Black,
+}
+
+impl FromStr for PythonFormatter {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ruff" => Ok(Self::Ruff),
+            "black" => Ok(Self::Black),
+            invalid => Err(format!(
+                "Invalid value '{invalid}' for build.formatters.python config, expected \"ruff\" \
+                 or \"black\"."
+            )),
+        }
+    }
+}
+
+/// The remote cache of the artifacts of LLVM and of the stage 1 standard library and compiler,
+/// from the `[build.cache]` section.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// The base URL of the cache, which is disabled if unset.
+    pub url: Option<String>,
+    /// Whether the artifacts which weren't cached are uploaded once built.
+    pub upload: bool,
+}
+
+/// The distributed compilation of the crates, from the `[build.distributed]` section, which is
+/// enabled by its `scheduler-url`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DistributedConfig {
+    /// The URL of the `sccache-dist` scheduler assigning the crates to its workers.
+    pub scheduler_url: String,
+    /// The token authenticating bootstrap to the scheduler.
+    pub auth_token: Option<String>,
+    /// The sccache executable compiling the crates.
+    pub sccache: PathBuf,
+    /// The number of crates cargo compiles at once, instead of the number of jobs.
+    pub jobs: Option<u32>,
+}
+
+/// A command of the `[[build.hooks]]` tables, run before or after the steps it matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepHook {
+    /// The type of the steps, like `compile::Std`.
+    pub step: String,
+    /// Whether the command runs before the steps, instead of after them.
+    pub before: bool,
+    pub stage: Option<u32>,
+    pub target: Option<String>,
+    /// The program and its arguments, which are templates, see `utils::step_hooks`.
+    pub command: Vec<String>,
+}
+
+/// Policies of `x clean --gc`, from the `[build.gc]` section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GcConfig {
+    /// Size in GiB the build directory is reduced to by removing the least recently used artifacts.
+    pub max_size: Option<u64>,
+    /// Days after which unused artifacts are removed.
+    pub max_age: Option<u64>,
+    /// How many toolchains (stages) and downloads of each kind are kept per target.
+    pub keep_toolchains: usize,
+    /// How many documentation builds are kept per target.
+    pub keep_docs: usize,
+}
+
+impl Default for GcConfig {
+    fn default() -> Self {
+        GcConfig { max_size: None, max_age: Some(30), keep_toolchains: 2, keep_docs: 2 }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub enum RustfmtState {
     SystemToolchain(PathBuf),
+    /// The rustfmt of `build.rustfmt` with `build.pin-rustfmt`, which is replaced by the pinned
+    /// one if their versions differ.
+    Unverified(PathBuf),
     Downloaded(PathBuf),
     Unavailable,
     #[default]
@@ -446,6 +643,27 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
     }
 }
 
+/// Additional formats the build metrics are exported in, see `build.metrics-export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsExport {
+    /// A textfile for the textfile collector of the Prometheus node exporter.
+    Prometheus,
+    /// An OTLP trace in the JSON encoding.
+    Otlp,
+}
+
+impl FromStr for MetricsExport {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "prometheus" => Ok(Self::Prometheus),
+            "otlp" => Ok(Self::Otlp),
+            invalid => Err(format!("Invalid value '{invalid}' for build.metrics-export config.")),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 // N.B.: This type is used everywhere, and the entire codebase relies on it being Copy.
 // Making !Copy is highly nontrivial!
@@ -617,8 +835,50 @@ pub(crate) struct TomlConfig {
     llvm: Option<Llvm>,
     rust: Option<Rust>,
     target: Option<HashMap<String, TomlTarget>>,
+    /// The `[target-templates.<name>]` tables, which the targets can inherit from.
+    target_templates: Option<HashMap<String, TomlTarget>>,
     dist: Option<Dist>,
     profile: Option<String>,
+    /// The `[profiles.<name>]` tables, see [`NamedProfile`].
+    profiles: Option<HashMap<String, toml::Value>>,
+    /// The edits of the lists which no layer of the configuration merged so far sets.
+    #[serde(skip)]
+    list_edits: Vec<ListEdit>,
+    /// The files included by the file of this layer, see [`resolve_includes`].
+    #[serde(skip)]
+    includes: Vec<PathBuf>,
+}
+
+/// A `[profiles.<name>]` table of `config.toml`, selected with `--profile <name>`, which bundles
+/// settings with a subcommand and paths. The settings are the tables of `config.toml`, like
+/// `[profiles.<name>.rust]`, and override the ones of `config.toml`, but not the ones of `--set`.
+/// The subcommand and the paths are used when none are passed on the command line.
+struct NamedProfile {
+    command: Option<String>,
+    paths: Vec<PathBuf>,
+    settings: TomlConfig,
+}
+
+impl NamedProfile {
+    fn parse(value: toml::Value) -> Result<Self, String> {
+        let toml::Value::Table(mut table) = value else {
+            return Err("it isn't a table".to_owned());
+        };
+        let command = match table.remove("command") {
+            Some(toml::Value::String(command)) => Some(command),
+            Some(_) => return Err("`command` isn't a string".to_owned()),
+            None => None,
+        };
+        let paths = match table.remove("paths") {
+            Some(paths) => Vec::<PathBuf>::deserialize(paths).map_err(|e| e.to_string())?,
+            None => Vec::new(),
+        };
+        let settings = toml_config(toml::Value::Table(table), false).map_err(|e| e.to_string())?;
+        if settings.profiles.is_some() {
+            return Err("profiles can't be nested".to_owned());
+        }
+        Ok(NamedProfile { command, paths, settings })
+    }
 }
 
 /// Since we use `#[serde(deny_unknown_fields)]` on `TomlConfig`, we need a wrapper type
@@ -631,6 +891,546 @@ pub(crate) struct ChangeIdWrapper {
     pub(crate) inner: Option<usize>,
 }
 
+/// The keys of the top level of `config.toml`, the ones of [`TomlConfig`].
+const TOP_LEVEL_KEYS: &[&str] = &[
+    "change-id",
+    "build",
+    "install",
+    "llvm",
+    "rust",
+    "target",
+    "target-templates",
+    "dist",
+    "profile",
+    "profiles",
+];
+
+/// The keys of a `[profiles.<name>]` table, see [`NamedProfile`].
+const PROFILE_KEYS: &[&str] = &[
+    "change-id",
+    "build",
+    "install",
+    "llvm",
+    "rust",
+    "target",
+    "target-templates",
+    "dist",
+    "profile",
+    "command",
+    "paths",
+];
+
+/// Returns the keys of the table of `config.toml` at `path`, like `["build", "net"]`, or `None`
+/// when they aren't known, like the targets of `[target]`.
+fn table_keys(path: &[&str]) -> Option<&'static [&'static str]> {
+    Some(match path {
+        [] => TOP_LEVEL_KEYS,
+        ["build"] => Build::FIELDS,
+        ["build", "net"] => Net::FIELDS,
+        ["build", "gc"] => Gc::FIELDS,
+        ["build", "cache"] => Cache::FIELDS,
+        ["build", "distributed"] => Distributed::FIELDS,
+        ["build", "hooks"] => Hook::FIELDS,
+        ["build", "binary-patching"] => BinaryPatching::FIELDS,
+        ["build", "formatters"] => Formatters::FIELDS,
+        ["install"] => Install::FIELDS,
+        ["llvm"] => Llvm::FIELDS,
+        ["rust"] => Rust::FIELDS,
+        ["dist"] => Dist::FIELDS,
+        ["target" | "target-templates", _] => TomlTarget::FIELDS,
+        ["profiles", _] => PROFILE_KEYS,
+        ["profiles", _, path @ ..] => return table_keys(path),
+        _ => return None,
+    })
+}
+
+/// Checks that the keys of the tables of `config.toml` are known, so that a misspelled key isn't
+/// mistaken for another one. The unknown keys are an error, with the known key they're the closest
+/// to, unless `allow_unknown` is set, for the keys of forks, which are then removed with a warning.
+pub(crate) fn check_keys(
+    value: &mut toml::Value,
+    allow_unknown: bool,
+) -> Result<(), toml::de::Error> {
+    let mut unknown = Vec::new();
+    unknown_keys(value, &mut Vec::new(), allow_unknown, &mut unknown);
+    if unknown.is_empty() {
+        Ok(())
+    } else if allow_unknown {
+        for message in unknown {
+            println!("WARNING: ignoring the {message}");
+        }
+        Ok(())
+    } else {
+        Err(serde::de::Error::custom(format!(
+            "{}\nHELP: pass `--allow-unknown-config` to ignore the unknown fields",
+            unknown.join("\n")
+        )))
+    }
+}
+
+/// Adds the messages of the unknown keys of `value`, at `path`, to `unknown`, and removes them
+/// when `remove` is set.
+fn unknown_keys(
+    value: &mut toml::Value,
+    path: &mut Vec<String>,
+    remove: bool,
+    unknown: &mut Vec<String>,
+) {
+    match value {
+        toml::Value::Table(table) => {
+            let keys: Vec<&str> = path.iter().map(String::as_str).collect();
+            if let Some(known) = table_keys(&keys) {
+                let unknown_in_table: Vec<String> = table
+                    .keys()
+                    .filter(|key| !known.contains(&key.as_str()) && !is_list_edit(&keys, key))
+                    .cloned()
+                    .collect();
+                for key in unknown_in_table {
+                    let table_name = if path.is_empty() {
+                        "the top level".to_owned()
+                    } else {
+                        format!("`[{}]`", path.join("."))
+                    };
+                    unknown.push(unknown_key_message(&key, &table_name, known));
+                    if remove {
+                        table.remove(&key);
+                    }
+                }
+            }
+            for (key, value) in table.iter_mut() {
+                path.push(key.clone());
+                unknown_keys(value, path, remove, unknown);
+                path.pop();
+            }
+        }
+        // The tables of an array, like the ones of `[[build.hooks]]`, have the keys of its path.
+        toml::Value::Array(values) => {
+            for value in values {
+                unknown_keys(value, path, remove, unknown);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Merges the files of the `include` array of the configuration `value` of `file` into it, in
+/// order, and then `value` itself, so that each file overrides the ones before it, see
+/// [`merge_values`]. The paths are relative to the directory of `file`, and the included files can
+/// include other files, but not the ones `including` them. All the included files are added to
+/// `included`.
+pub(crate) fn resolve_includes(
+    file: &Path,
+    value: &mut toml::Value,
+    including: &mut Vec<PathBuf>,
+    included: &mut Vec<PathBuf>,
+) -> Result<(), toml::de::Error> {
+    let error = <toml::de::Error as serde::de::Error>::custom::<String>;
+    let include = match value.as_table_mut().and_then(|table| table.remove("include")) {
+        None => return Ok(()),
+        Some(toml::Value::Array(include)) => include,
+        Some(_) => {
+            return Err(error(format!("`include` of {} isn't an array of paths", file.display())));
+        }
+    };
+    let dir = file.parent().unwrap_or(Path::new("."));
+    let mut merged = toml::Value::Table(Default::default());
+    for path in include {
+        let Some(path) = path.as_str() else {
+            return Err(error(format!("`include` of {} isn't an array of paths", file.display())));
+        };
+        let path = dir.join(path).canonicalize().map_err(|e| {
+            error(format!("failed to read `{}` included by {}: {e}", path, file.display()))
+        })?;
+        if including.contains(&path) {
+            return Err(error(format!("{} includes itself", path.display())));
+        }
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| error(format!("failed to read {}: {e}", path.display())))?;
+        let mut included_value: toml::Value = toml::from_str(&contents)
+            .map_err(|e| error(format!("failed to parse {}: {e}", path.display())))?;
+        including.push(path.clone());
+        resolve_includes(&path, &mut included_value, including, included)?;
+        including.pop();
+        included.push(path);
+        merge_values(&mut merged, included_value);
+    }
+    merge_values(&mut merged, std::mem::replace(value, toml::Value::Boolean(false)));
+    *value = merged;
+    Ok(())
+}
+
+/// Merges `other` into `value`: the tables are merged key by key, and the other values, like the
+/// arrays, are replaced. The edits of the lists of `other` edit the lists of `value`, see
+/// [`ListEdit`].
+fn merge_values(value: &mut toml::Value, other: toml::Value) {
+    match (value, other) {
+        (toml::Value::Table(table), toml::Value::Table(mut other)) => {
+            let lists: Vec<String> = other
+                .keys()
+                .filter_map(|key| key.strip_suffix(['+', '-']))
+                .map(str::to_owned)
+                .collect();
+            for list in lists {
+                let Some(edit) = remove_list_edit(&mut other, &list) else {
+                    continue;
+                };
+                if other.contains_key(&list) {
+                    // `other` sets the list too, which replaces the one of `value`.
+                    apply_list_edit(&mut other, &list, edit);
+                } else if !apply_list_edit(table, &list, edit.clone()) {
+                    let mut pending = remove_list_edit(table, &list).unwrap_or_default();
+                    pending.then(edit);
+                    insert_list_edit(table, &list, pending);
+                }
+            }
+            for key in other.keys() {
+                // The list of `other` replaces the edits of `value`.
+                remove_list_edit(table, key);
+            }
+            for (key, other) in other {
+                match table.get_mut(&key) {
+                    Some(value) => merge_values(value, other),
+                    None => {
+                        table.insert(key, other);
+                    }
+                }
+            }
+        }
+        (value, other) => *value = other,
+    }
+}
+
+/// The edit of a list of `config.toml`, like `build.tools`, by a layer of the configuration, like
+/// an included file or `--set`. The `"<key>-"` and `"<key>+"` keys of a table, like `"tools-"` and
+/// `"tools+"` in `[build]`, remove values from the list of the layers below it, and then append the
+/// values which aren't in it. The edits apply to the list of the same table when it sets one, and
+/// the ones of the lists which no layer sets apply to the empty list.
+#[derive(Clone, Debug, Default)]
+struct ListEdit {
+    /// The path of the list, like `["build", "tools"]`.
+    path: Vec<String>,
+    remove: Vec<String>,
+    append: Vec<String>,
+}
+
+impl ListEdit {
+    /// Adds the edit `later`, of a layer above the one of this edit, to this edit.
+    fn then(&mut self, later: ListEdit) {
+        for value in later.remove {
+            self.append.retain(|appended| *appended != value);
+            if !self.remove.contains(&value) {
+                self.remove.push(value);
+            }
+        }
+        for value in later.append {
+            self.remove.retain(|removed| *removed != value);
+            if !self.append.contains(&value) {
+                self.append.push(value);
+            }
+        }
+    }
+
+    fn apply(&self, list: &mut Vec<String>) {
+        list.retain(|value| !self.remove.contains(value));
+        for value in &self.append {
+            if !list.contains(value) {
+                list.push(value.clone());
+            }
+        }
+    }
+}
+
+/// A list of [`TomlConfig`] which can be edited, see [`ListEdit`].
+trait ListValue {
+    fn is_set(&self) -> bool;
+    fn edit(&mut self, edit: &ListEdit);
+}
+
+impl ListValue for Option<Vec<String>> {
+    fn is_set(&self) -> bool {
+        self.is_some()
+    }
+
+    fn edit(&mut self, edit: &ListEdit) {
+        edit.apply(self.get_or_insert_with(Vec::new));
+    }
+}
+
+impl ListValue for Option<HashSet<String>> {
+    fn is_set(&self) -> bool {
+        self.is_some()
+    }
+
+    fn edit(&mut self, edit: &ListEdit) {
+        let set = self.get_or_insert_with(HashSet::new);
+        set.retain(|value| !edit.remove.contains(value));
+        set.extend(edit.append.iter().cloned());
+    }
+}
+
+/// Whether the key of `config.toml` at `path`, like `["build", "tools"]`, is a list which the
+/// layers of the configuration can edit, see [`ListEdit`].
+fn is_list_key(path: &[&str]) -> bool {
+    match path {
+        ["build", "host" | "target" | "tools"]
+        | ["rust", "codegen-backends"]
+        | ["dist", "compression-formats"]
+        | ["target" | "target-templates", _, "codegen-backends"] => true,
+        ["profiles", _, path @ ..] => is_list_key(path),
+        _ => false,
+    }
+}
+
+/// Whether `key` of the table at `path` edits one of its lists, like `"tools+"` in `[build]`.
+fn is_list_edit(path: &[&str], key: &str) -> bool {
+    key.strip_suffix(['+', '-']).is_some_and(|list| is_list_key(&[path, &[list]].concat()))
+}
+
+/// Returns the strings of the array `value`, if it's an array of strings.
+fn strings(value: &toml::Value) -> Option<Vec<String>> {
+    let values = value.as_array()?.iter().map(|value| value.as_str().map(str::to_owned));
+    values.collect()
+}
+
+fn strings_value(values: Vec<String>) -> toml::Value {
+    toml::Value::Array(values.into_iter().map(toml::Value::String).collect())
+}
+
+/// Removes the edit of the list `list` from `table`, if it has one, see [`ListEdit`].
+fn remove_list_edit(table: &mut toml::value::Table, list: &str) -> Option<ListEdit> {
+    let mut take = |suffix: char| {
+        let key = format!("{list}{suffix}");
+        let values = strings(table.get(&key)?)?;
+        table.remove(&key);
+        Some(values)
+    };
+    let (remove, append) = (take('-'), take('+'));
+    if remove.is_none() && append.is_none() {
+        return None;
+    }
+    let (remove, append) = (remove.unwrap_or_default(), append.unwrap_or_default());
+    Some(ListEdit { path: Vec::new(), remove, append })
+}
+
+fn insert_list_edit(table: &mut toml::value::Table, list: &str, edit: ListEdit) {
+    if !edit.remove.is_empty() {
+        table.insert(format!("{list}-"), strings_value(edit.remove));
+    }
+    if !edit.append.is_empty() {
+        table.insert(format!("{list}+"), strings_value(edit.append));
+    }
+}
+
+/// Applies `edit` to the list `list` of `table`, and returns whether `table` has this list.
+fn apply_list_edit(table: &mut toml::value::Table, list: &str, edit: ListEdit) -> bool {
+    let Some(mut values) = table.get(list).and_then(strings) else {
+        return false;
+    };
+    edit.apply(&mut values);
+    table.insert(list.to_owned(), strings_value(values));
+    true
+}
+
+/// Removes the edits of the lists of the configuration `value`, see [`ListEdit`], and returns the
+/// ones of the lists which the tables of `value` don't set. The edits of the `[profiles]` are the
+/// ones of their settings, when they're selected.
+fn take_list_edits(
+    value: &mut toml::Value,
+    path: &mut Vec<String>,
+    edits: &mut Vec<ListEdit>,
+) -> Result<(), toml::de::Error> {
+    let error = <toml::de::Error as serde::de::Error>::custom::<String>;
+    let toml::Value::Table(table) = value else {
+        return Ok(());
+    };
+    if path.first().is_some_and(|table| table == "profiles") {
+        return Ok(());
+    }
+    let keys: Vec<&str> = path.iter().map(String::as_str).collect();
+    let edit_keys = table.keys().filter(|key| key.ends_with(['+', '-']));
+    for key in edit_keys.cloned().collect::<Vec<_>>() {
+        let list = &key[..key.len() - 1];
+        let list_path = [&keys[..], &[list]].concat();
+        if !is_list_key(&list_path) {
+            if table_keys(&keys).is_some_and(|known| known.contains(&list)) {
+                return Err(error(format!(
+                    "`{}` isn't a list, so `{key}` can't edit it",
+                    list_path.join(".")
+                )));
+            }
+            // The unknown keys are reported by `check_keys`.
+            continue;
+        }
+        let Some(values) = table.get(&key) else {
+            // It was taken with the other edit of its list.
+            continue;
+        };
+        if strings(values).is_none() {
+            return Err(error(format!(
+                "`{key}` of `{}` isn't an array of strings",
+                keys.join(".")
+            )));
+        }
+        let Some(mut edit) = remove_list_edit(table, list) else {
+            continue;
+        };
+        if !apply_list_edit(table, list, edit.clone()) {
+            edit.path = list_path.iter().map(|&key| key.to_owned()).collect();
+            edits.push(edit);
+        }
+    }
+    for (key, value) in table.iter_mut() {
+        path.push(key.clone());
+        take_list_edits(value, path, edits)?;
+        path.pop();
+    }
+    Ok(())
+}
+
+/// Deserializes the configuration `value` of a layer, like `config.toml` or `--set`, with the
+/// edits of its lists, see [`ListEdit`], after checking its keys, see [`check_keys`].
+fn toml_config(mut value: toml::Value, allow_unknown: bool) -> Result<TomlConfig, toml::de::Error> {
+    let mut list_edits = Vec::new();
+    take_list_edits(&mut value, &mut Vec::new(), &mut list_edits)?;
+    check_keys(&mut value, allow_unknown)?;
+    let mut config = TomlConfig::deserialize(value)?;
+    config.list_edits = list_edits;
+    Ok(config)
+}
+
+/// Fills the keys of each target which `inherits` a template of `[target-templates]`, or another
+/// target, with the ones of that table which the target doesn't set. The table can inherit another
+/// one too, and the templates are used before the targets of the same name.
+fn inherit_targets(
+    targets: &mut HashMap<String, TomlTarget>,
+    templates: &HashMap<String, TomlTarget>,
+) -> Result<(), String> {
+    let mut inherited = HashMap::new();
+    for (triple, target) in targets.iter() {
+        let mut target = target.clone();
+        let mut chain = vec![triple.as_str()];
+        while let Some(parent) = target.inherits.take() {
+            let base = templates.get_key_value(&parent).or_else(|| targets.get_key_value(&parent));
+            let Some((name, base)) = base else {
+                return Err(format!(
+                    "`target.{triple}` inherits `{parent}`, which is neither a template of \
+                     `[target-templates]` nor a target"
+                ));
+            };
+            if chain.contains(&name.as_str()) {
+                chain.push(name);
+                return Err(format!("the targets inherit each other: {}", chain.join(" -> ")));
+            }
+            chain.push(name);
+            target.merge(base.clone(), ReplaceOpt::IgnoreDuplicate);
+        }
+        inherited.insert(triple.clone(), target);
+    }
+    *targets = inherited;
+    Ok(())
+}
+
+/// The prefix of the environment variables overriding a key of `config.toml`, like
+/// `RUST_BOOTSTRAP_RUST_DEBUG_ASSERTIONS` for `rust.debug-assertions`.
+const ENV_OVERRIDE_PREFIX: &str = "RUST_BOOTSTRAP_";
+
+/// The tables of `config.toml` whose keys can be overridden by the environment. The keys of
+/// `[target.<triple>]` can't be, as the triples have underscores.
+const ENV_OVERRIDE_TABLES: &[&str] = &["build", "install", "llvm", "rust", "dist"];
+
+/// Returns the key of `config.toml` overridden by the environment variable `name`, like
+/// `build.net.proxy` for `RUST_BOOTSTRAP_BUILD_NET_PROXY`, if it's an override. It's an error
+/// when `name` starts with a table, but isn't one of its keys.
+pub(crate) fn env_override_key(name: &str) -> Result<Option<String>, String> {
+    let Some(name_in_table) = name.strip_prefix(ENV_OVERRIDE_PREFIX) else {
+        return Ok(None);
+    };
+    for &table in ENV_OVERRIDE_TABLES {
+        let Some(key) = name_in_table.strip_prefix(&env_key(table)) else {
+            continue;
+        };
+        let Some(key) = key.strip_prefix('_') else {
+            continue;
+        };
+        return match find_env_key(&[table], key) {
+            Some(path) => Ok(Some(path.join("."))),
+            None => Err(format!("`{name}` overrides no key of `[{table}]` in config.toml")),
+        };
+    }
+    Ok(None)
+}
+
+/// Returns the path of the key of the table at `path` named `name` in the environment, like
+/// `["build", "net", "proxy"]` for `NET_PROXY` in `[build]`.
+fn find_env_key(path: &[&'static str], name: &str) -> Option<Vec<&'static str>> {
+    for &key in table_keys(path)? {
+        let key_path = [path, &[key]].concat();
+        let env_name = env_key(key);
+        if name == env_name {
+            return Some(key_path);
+        }
+        let nested = name.strip_prefix(&env_name).and_then(|name| name.strip_prefix('_'));
+        if let Some(found) = nested.and_then(|nested| find_env_key(&key_path, nested)) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// Returns the name of `key` in the environment, like `DEBUG_ASSERTIONS` for
+/// `debug-assertions`.
+fn env_key(key: &str) -> String {
+    key.to_uppercase().replace('-', "_")
+}
+
+/// Parses the override `option` of `--set` or of the environment, like `rust.lto=fat`, or
+/// `build.tools+=["clippy"]` to edit a list, see [`ListEdit`].
+fn parse_override(option: &str, allow_unknown: bool) -> Result<TomlConfig, toml::de::Error> {
+    fn get_table(option: &str, allow_unknown: bool) -> Result<TomlConfig, toml::de::Error> {
+        toml::from_str(option).and_then(|table: toml::Value| toml_config(table, allow_unknown))
+    }
+
+    // The keys of TOML can't have `+` or `-` at their end without quotes.
+    let quoted;
+    let option = match option.split_once('=') {
+        Some((key, value)) if key.ends_with(['+', '-']) => {
+            quoted = match key.rsplit_once('.') {
+                Some((table, list)) => format!(r#"{table}."{list}"={value}"#),
+                None => format!(r#""{key}"={value}"#),
+            };
+            quoted.as_str()
+        }
+        _ => option,
+    };
+    let err = match get_table(option, allow_unknown) {
+        Ok(v) => return Ok(v),
+        Err(e) => e,
+    };
+    // We want to be able to set string values without quotes,
+    // like in `configure.py`. Try adding quotes around the right hand side
+    match option.split_once('=') {
+        Some((key, value)) if !value.contains('"') => {
+            get_table(&format!(r#"{key}="{value}""#), allow_unknown)
+        }
+        _ => Err(err),
+    }
+}
+
+fn unknown_key_message(key: &str, table: &str, known: &[&str]) -> String {
+    let max_distance = (key.chars().count() / 3).max(1);
+    let closest = known
+        .iter()
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min();
+    match closest {
+        Some((_, candidate)) => {
+            format!("unknown field `{key}` in {table}, did you mean `{candidate}`?")
+        }
+        None => format!("unknown field `{key}` in {table}"),
+    }
+}
+
 /// Describes how to handle conflicts in merging two [`TomlConfig`]
 #[derive(Copy, Clone, Debug)]
 enum ReplaceOpt {
@@ -646,12 +1446,98 @@ trait Merge {
     fn merge(&mut self, other: Self, replace: ReplaceOpt);
 }
 
+impl TomlConfig {
+    /// Returns the list at `path` which the layers of the configuration can edit, see
+    /// [`ListEdit`], and creates its table when `create` is set.
+    fn list_mut(&mut self, path: &[String], create: bool) -> Option<&mut dyn ListValue> {
+        fn table<T: Default>(table: &mut Option<T>, create: bool) -> Option<&mut T> {
+            if create { Some(table.get_or_insert_with(Default::default)) } else { table.as_mut() }
+        }
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        let list: &mut dyn ListValue = match path[..] {
+            ["build", "host"] => &mut table(&mut self.build, create)?.host,
+            ["build", "target"] => &mut table(&mut self.build, create)?.target,
+            ["build", "tools"] => &mut table(&mut self.build, create)?.tools,
+            ["rust", "codegen-backends"] => &mut table(&mut self.rust, create)?.codegen_backends,
+            ["dist", "compression-formats"] => {
+                &mut table(&mut self.dist, create)?.compression_formats
+            }
+            [targets @ ("target" | "target-templates"), triple, "codegen-backends"] => {
+                let targets = if targets == "target" {
+                    table(&mut self.target, create)?
+                } else {
+                    table(&mut self.target_templates, create)?
+                };
+                let target = if create {
+                    targets.entry(triple.to_owned()).or_default()
+                } else {
+                    targets.get_mut(triple)?
+                };
+                &mut target.codegen_backends
+            }
+            _ => return None,
+        };
+        Some(list)
+    }
+
+    /// Adds `edit`, of a layer above the ones merged so far, to the configuration.
+    fn add_list_edit(&mut self, edit: ListEdit) {
+        if let Some(list) = self.list_mut(&edit.path, false).filter(|list| list.is_set()) {
+            list.edit(&edit);
+            return;
+        }
+        match self.list_edits.iter_mut().find(|pending| pending.path == edit.path) {
+            Some(pending) => pending.then(edit),
+            None => self.list_edits.push(edit),
+        }
+    }
+
+    /// Applies the edits of the lists which no layer of the configuration sets to empty lists,
+    /// once all the layers are merged.
+    fn resolve_list_edits(&mut self) {
+        for edit in std::mem::take(&mut self.list_edits) {
+            if let Some(list) = self.list_mut(&edit.path, true) {
+                list.edit(&edit);
+            }
+        }
+    }
+}
+
 impl Merge for TomlConfig {
-    fn merge(
-        &mut self,
-        TomlConfig { build, install, llvm, rust, dist, target, profile: _, change_id }: Self,
-        replace: ReplaceOpt,
-    ) {
+    fn merge(&mut self, mut other: Self, replace: ReplaceOpt) {
+        // The edits of a list are dropped when the layer above them sets the list, and apply to
+        // the list of the layer below them, see `ListEdit`.
+        let is_set = |config: &mut TomlConfig, edit: &ListEdit| {
+            config.list_mut(&edit.path, false).is_some_and(|list| list.is_set())
+        };
+        let mut other_edits = std::mem::take(&mut other.list_edits);
+        match replace {
+            ReplaceOpt::IgnoreDuplicate => other_edits.retain(|edit| !is_set(self, edit)),
+            ReplaceOpt::Override | ReplaceOpt::ErrorOnDuplicate => {
+                self.list_edits.retain(|edit| !is_set(&mut other, edit))
+            }
+        }
+        let (lower_edits, higher_edits) = match replace {
+            ReplaceOpt::IgnoreDuplicate => (other_edits, std::mem::take(&mut self.list_edits)),
+            ReplaceOpt::Override | ReplaceOpt::ErrorOnDuplicate => {
+                (std::mem::take(&mut self.list_edits), other_edits)
+            }
+        };
+
+        let TomlConfig {
+            build,
+            install,
+            llvm,
+            rust,
+            dist,
+            target,
+            target_templates,
+            profile: _,
+            profiles: _,
+            change_id,
+            list_edits: _,
+            includes: _,
+        } = other;
         fn do_merge<T: Merge>(x: &mut Option<T>, y: Option<T>, replace: ReplaceOpt) {
             if let Some(new) = y {
                 if let Some(original) = x {
@@ -668,19 +1554,31 @@ fn do_merge<T: Merge>(x: &mut Option<T>, y: Option<T>, replace: ReplaceOpt) {
         do_merge(&mut self.rust, rust, replace);
         do_merge(&mut self.dist, dist, replace);
 
-        match (self.target.as_mut(), target) {
-            (_, None) => {}
-            (None, Some(target)) => self.target = Some(target),
-            (Some(original_target), Some(new_target)) => {
-                for (triple, new) in new_target {
-                    if let Some(original) = original_target.get_mut(&triple) {
-                        original.merge(new, replace);
-                    } else {
-                        original_target.insert(triple, new);
+        fn merge_targets(
+            x: &mut Option<HashMap<String, TomlTarget>>,
+            y: Option<HashMap<String, TomlTarget>>,
+            replace: ReplaceOpt,
+        ) {
+            match (x.as_mut(), y) {
+                (_, None) => {}
+                (None, Some(target)) => *x = Some(target),
+                (Some(original_target), Some(new_target)) => {
+                    for (triple, new) in new_target {
+                        if let Some(original) = original_target.get_mut(&triple) {
+                            original.merge(new, replace);
+                        } else {
+                            original_target.insert(triple, new);
+                        }
                     }
                 }
             }
         }
+        merge_targets(&mut self.target, target, replace);
+        merge_targets(&mut self.target_templates, target_templates, replace);
+
+        for edit in lower_edits.into_iter().chain(higher_edits) {
+            self.add_list_edit(edit);
+        }
     }
 }
 
@@ -694,6 +1592,11 @@ struct $name {
             $($field: Option<$field_ty>,)*
         }
 
+        impl $name {
+            /// The keys of the table.
+            const FIELDS: &'static [&'static str] = &[$($field_key,)*];
+        }
+
         impl Merge for $name {
             fn merge(&mut self, other: Self, replace: ReplaceOpt) {
                 $(
@@ -773,20 +1676,17 @@ fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
                                     };
                                 })*
                                 key => {
-                                    return Err(serde::de::Error::unknown_field(key, FIELDS));
+                                    return Err(serde::de::Error::unknown_field(key, $name::FIELDS));
                                 }
                             }
                         }
                         Ok($name { $($field),* })
                     }
                 }
-                const FIELDS: &'static [&'static str] = &[
-                    $($field_key,)*
-                ];
                 Deserializer::deserialize_struct(
                     deserializer,
                     stringify!($name),
-                    FIELDS,
+                    $name::FIELDS,
                     Field,
                 )
             }
@@ -836,6 +1736,7 @@ struct Build {
         cargo: Option<PathBuf> = "cargo",
         rustc: Option<PathBuf> = "rustc",
         rustfmt: Option<PathBuf> = "rustfmt",
+        pin_rustfmt: Option<bool> = "pin-rustfmt",
         cargo_clippy: Option<PathBuf> = "cargo-clippy",
         docs: Option<bool> = "docs",
         compiler_docs: Option<bool> = "compiler-docs",
@@ -852,6 +1753,12 @@ struct Build {
         vendor: Option<bool> = "vendor",
         full_bootstrap: Option<bool> = "full-bootstrap",
         bootstrap_cache_path: Option<PathBuf> = "bootstrap-cache-path",
+        dist_mirrors: Option<Vec<String>> = "dist-mirrors",
+        net: Option<Net> = "net",
+        gc: Option<Gc> = "gc",
+        cache: Option<Cache> = "cache",
+        distributed: Option<Distributed> = "distributed",
+        hooks: Option<Vec<Hook>> = "hooks",
         extended: Option<bool> = "extended",
         tools: Option<HashSet<String>> = "tools",
         verbose: Option<usize> = "verbose",
@@ -871,13 +1778,98 @@ struct Build {
         dist_stage: Option<u32> = "dist-stage",
         bench_stage: Option<u32> = "bench-stage",
         patch_binaries_for_nix: Option<bool> = "patch-binaries-for-nix",
+        binary_patching: Option<BinaryPatching> = "binary-patching",
+        formatters: Option<Formatters> = "formatters",
+        fmt_untracked: Option<bool> = "fmt-untracked",
+        fmt_jobs: Option<u32> = "fmt-jobs",
+        fmt_batch_size: Option<u32> = "fmt-batch-size",
+        step_cache: Option<bool> = "step-cache",
+        rustbook_jobs: Option<u32> = "rustbook-jobs",
+        max_concurrent_links: Option<u32> = "max-concurrent-links",
+        auto_keep_stage: Option<bool> = "auto-keep-stage",
+        graft_ci_rustc: Option<bool> = "graft-ci-rustc",
         // NOTE: only parsed by bootstrap.py, `--feature build-metrics` enables metrics unconditionally
         metrics: Option<bool> = "metrics",
+        metrics_export: Option<Vec<String>> = "metrics-export",
         android_ndk: Option<PathBuf> = "android-ndk",
         optimized_compiler_builtins: Option<bool> = "optimized-compiler-builtins",
     }
 }
 
+define_config! {
+    /// TOML representation of the network settings of bootstrap.
+    struct Net {
+        proxy: Option<String> = "proxy",
+        ca_bundle: Option<PathBuf> = "ca-bundle",
+        timeout: Option<u32> = "timeout",
+        retries: Option<u32> = "retries",
+    }
+}
+
+define_config! {
+    /// TOML representation of the remote cache of the artifacts.
+    struct Cache {
+        url: Option<String> = "url",
+        upload: Option<bool> = "upload",
+    }
+}
+
+define_config! {
+    /// TOML representation of the distributed compilation of the crates.
+    struct Distributed {
+        scheduler_url: Option<String> = "scheduler-url",
+        auth_token: Option<String> = "auth-token",
+        sccache: Option<PathBuf> = "sccache",
+        jobs: Option<u32> = "jobs",
+    }
+}
+
+define_config! {
+    /// TOML representation of a command run before or after some steps.
+    struct Hook {
+        step: Option<String> = "step",
+        when: Option<String> = "when",
+        stage: Option<u32> = "stage",
+        target: Option<String> = "target",
+        command: Option<Vec<String>> = "command",
+    }
+}
+
+define_config! {
+    /// TOML representation of the settings for patching the downloaded binaries.
+    struct BinaryPatching {
+        enable: Option<bool> = "enable",
+        dynamic_linker: Option<PathBuf> = "dynamic-linker",
+        rpath: Option<Vec<PathBuf>> = "rpath",
+        patchelf: Option<PathBuf> = "patchelf",
+    }
+}
+
+define_config! {
+    /// TOML representation of the formatters of `x fmt`.
+    struct Formatters {
+        python: Option<String> = "python",
+        shell: Option<bool> = "shell",
+        toml: Option<bool> = "toml",
+        manifests: Option<bool> = "manifests",
+        markdown: Option<bool> = "markdown",
+        ruff: Option<PathBuf> = "ruff",
+        black: Option<PathBuf> = "black",
+        shfmt: Option<PathBuf> = "shfmt",
+        taplo: Option<PathBuf> = "taplo",
+    }
+}
+
+define_config! {
+    /// TOML representation of the policies of `x clean --gc`.
+    struct Gc {
+        max_size: Option<u64> = "max-size",
+        max_age: Option<u64> = "max-age",
+        keep_toolchains: Option<usize> = "keep-toolchains",
+        keep_docs: Option<usize> = "keep-docs",
+    }
+}
+
 define_config! {
     /// TOML representation of various global install decisions.
     struct Install {
@@ -926,6 +1918,7 @@ struct Llvm {
 }
 
 define_config! {
+    #[derive(Default)]
     struct Dist {
         sign_folder: Option<String> = "sign-folder",
         upload_addr: Option<String> = "upload-addr",
@@ -1086,6 +2079,7 @@ fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
 
 define_config! {
     /// TOML representation of how the Rust build is configured.
+    #[derive(Default)]
     struct Rust {
         optimize: Option<RustOptimize> = "optimize",
         debug: Option<bool> = "debug",
@@ -1146,7 +2140,9 @@ struct Rust {
 
 define_config! {
     /// TOML representation of how each build target is configured.
+    #[derive(Clone, Default)]
     struct TomlTarget {
+        inherits: Option<String> = "inherits",
         cc: Option<String> = "cc",
         cxx: Option<String> = "cxx",
         ar: Option<String> = "ar",
@@ -1226,19 +2222,30 @@ pub(crate) fn get_builder_toml(&self, build_name: &str) -> Result<TomlConfig, to
         Self::get_toml(&builder_config_path)
     }
 
+    pub(crate) fn get_toml(file: &Path) -> Result<TomlConfig, toml::de::Error> {
+        Self::read_toml(file, false)
+    }
+
     #[cfg(test)]
-    pub(crate) fn get_toml(_: &Path) -> Result<TomlConfig, toml::de::Error> {
+    fn read_toml(_: &Path, _allow_unknown: bool) -> Result<TomlConfig, toml::de::Error> {
         Ok(TomlConfig::default())
     }
 
+    /// Reads the configuration of `file`, whose unknown keys are an error, unless `allow_unknown`
+    /// is set, see [`check_keys`].
     #[cfg(not(test))]
-    pub(crate) fn get_toml(file: &Path) -> Result<TomlConfig, toml::de::Error> {
+    fn read_toml(file: &Path, allow_unknown: bool) -> Result<TomlConfig, toml::de::Error> {
         let contents =
             t!(fs::read_to_string(file), format!("config file {} not found", file.display()));
         // Deserialize to Value and then TomlConfig to prevent the Deserialize impl of
         // TomlConfig and sub types to be monomorphized 5x by toml.
         toml::from_str(&contents)
-            .and_then(|table: toml::Value| TomlConfig::deserialize(table))
+            .and_then(|mut table: toml::Value| {
+                let file = file.canonicalize().unwrap_or_else(|_| file.to_owned());
+                let mut includes = Vec::new();
+                resolve_includes(&file, &mut table, &mut vec![file.clone()], &mut includes)?;
+                Ok(TomlConfig { includes, ..toml_config(table, allow_unknown)? })
+            })
             .inspect_err(|_| {
                 if let Ok(Some(changes)) = toml::from_str(&contents)
                     .and_then(|table: toml::Value| ChangeIdWrapper::deserialize(table))
@@ -1255,7 +2262,8 @@ pub(crate) fn get_toml(file: &Path) -> Result<TomlConfig, toml::de::Error> {
     }
 
     pub fn parse(flags: Flags) -> Config {
-        Self::parse_inner(flags, Self::get_toml)
+        let allow_unknown = flags.allow_unknown_config;
+        Self::parse_inner(flags, |file| Self::read_toml(file, allow_unknown))
     }
 
     pub(crate) fn parse_inner(
@@ -1271,14 +2279,27 @@ pub(crate) fn parse_inner(
         config.rustc_error_format = flags.rustc_error_format;
         config.json_output = flags.json_output;
         config.on_fail = flags.on_fail;
+        config.on_failure = flags.on_failure;
         config.jobs = Some(threads_from_config(flags.jobs as u32));
+        config.why = matches!(flags.cmd, Subcommand::Why);
         config.cmd = flags.cmd;
         config.incremental = flags.incremental;
-        config.dry_run = if flags.dry_run { DryRun::UserSelected } else { DryRun::Disabled };
+        // The graph is the one of the steps which would run, which the dry run finds.
+        config.dry_run = if flags.dry_run || flags.dump_graph || flags.plan || config.why {
+            DryRun::UserSelected
+        } else {
+            DryRun::Disabled
+        };
         config.dump_bootstrap_shims = flags.dump_bootstrap_shims;
+        config.dump_graph = flags.dump_graph;
+        config.plan = flags.plan;
+        config.resume = flags.resume;
+        config.timings = flags.timings;
+        config.json_trace = flags.json_trace;
         config.keep_stage = flags.keep_stage;
         config.keep_stage_std = flags.keep_stage_std;
         config.color = flags.color;
+        config.ui = flags.ui;
         config.free_args = std::mem::take(&mut flags.free_args);
         config.llvm_profile_use = flags.llvm_profile_use;
         config.llvm_profile_generate = flags.llvm_profile_generate;
@@ -1377,6 +2398,7 @@ pub(crate) fn parse_inner(
             config.config = None;
             TomlConfig::default()
         };
+        config.config_includes = std::mem::take(&mut toml.includes);
 
         if cfg!(test) {
             // When configuring bootstrap for tests, make sure to set the rustc and Cargo to the
@@ -1389,6 +2411,43 @@ pub(crate) fn parse_inner(
             build.cargo = build.cargo.take().or(std::env::var_os("CARGO").map(|p| p.into()));
         }
 
+        if let Some(name) = &flags.profile {
+            let profile = toml.profiles.as_mut().and_then(|profiles| profiles.remove(name));
+            let Some(profile) = profile else {
+                let mut names: Vec<_> = toml.profiles.iter().flat_map(|p| p.keys()).collect();
+                names.sort();
+                eprintln!(
+                    "ERROR: no `[profiles.{name}]` in config.toml, the profiles are: {names:?}"
+                );
+                exit!(2);
+            };
+            let profile = NamedProfile::parse(profile).unwrap_or_else(|e| {
+                eprintln!("ERROR: Failed to parse the profile `{name}` of config.toml: {e}");
+                exit!(2);
+            });
+            let command = profile.command.as_ref().map(|command| {
+                Subcommand::from_name(command).unwrap_or_else(|| {
+                    eprintln!("ERROR: `{command}` of the profile `{name}` isn't a subcommand");
+                    exit!(2);
+                })
+            });
+            if flags.command_from_profile {
+                let Some(command) = &command else {
+                    eprintln!("ERROR: the profile `{name}` doesn't have a `command`, pass one");
+                    exit!(2);
+                };
+                config.cmd = command.clone();
+            }
+            let same_command = command.is_some_and(|command| command.kind() == config.cmd.kind());
+            if config.paths.is_empty() && same_command {
+                config.paths = profile.paths;
+            }
+            if profile.settings.profile.is_some() {
+                toml.profile = profile.settings.profile.clone();
+            }
+            toml.merge(profile.settings, ReplaceOpt::Override);
+        }
+
         if let Some(include) = &toml.profile {
             // Allows creating alias for profile names, allowing
             // profiles to be renamed while maintaining back compatibility
@@ -1413,36 +2472,56 @@ pub(crate) fn parse_inner(
             toml.merge(included_toml, ReplaceOpt::IgnoreDuplicate);
         }
 
-        let mut override_toml = TomlConfig::default();
-        for option in flags.set.iter() {
-            fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
-                toml::from_str(option).and_then(|table: toml::Value| TomlConfig::deserialize(table))
-            }
-
-            let mut err = match get_table(option) {
-                Ok(v) => {
-                    override_toml.merge(v, ReplaceOpt::ErrorOnDuplicate);
+        // The environment overrides `config.toml`, and `--set` overrides the environment.
+        let mut env_toml = TomlConfig::default();
+        // Other variables aren't read, so that they don't need to be valid UTF-8.
+        let mut env_vars: Vec<(String, OsString)> = env::vars_os()
+            .filter_map(|(name, value)| Some((name.into_string().ok()?, value)))
+            .filter(|(name, _)| name.starts_with(ENV_OVERRIDE_PREFIX))
+            .collect();
+        env_vars.sort();
+        for (name, value) in env_vars {
+            let key = match env_override_key(&name) {
+                Ok(Some(key)) => key,
+                Ok(None) => continue,
+                Err(e) if flags.allow_unknown_config => {
+                    println!("WARNING: ignoring {e}");
                     continue;
                 }
-                Err(e) => e,
+                Err(e) => {
+                    eprintln!("ERROR: {e}\nHELP: pass `--allow-unknown-config` to ignore it");
+                    exit!(2);
+                }
             };
-            // We want to be able to set string values without quotes,
-            // like in `configure.py`. Try adding quotes around the right hand side
-            if let Some((key, value)) = option.split_once('=') {
-                if !value.contains('"') {
-                    match get_table(&format!(r#"{key}="{value}""#)) {
-                        Ok(v) => {
-                            override_toml.merge(v, ReplaceOpt::ErrorOnDuplicate);
-                            continue;
-                        }
-                        Err(e) => err = e,
-                    }
+            let Some(value) = value.to_str() else {
+                eprintln!("ERROR: the value of `{name}` isn't valid UTF-8");
+                exit!(2);
+            };
+            match parse_override(&format!("{key}={value}"), flags.allow_unknown_config) {
+                Ok(v) => {
+                    env_toml.merge(v, ReplaceOpt::ErrorOnDuplicate);
+                    config.env_overrides.push((name, value.to_owned()));
+                }
+                Err(err) => {
+                    eprintln!("failed to parse the override `{key}` of `{name}`: `{err}");
+                    exit!(2)
+                }
+            }
+        }
+        toml.merge(env_toml, ReplaceOpt::Override);
+
+        let mut override_toml = TomlConfig::default();
+        for option in flags.set.iter() {
+            match parse_override(option, flags.allow_unknown_config) {
+                Ok(v) => override_toml.merge(v, ReplaceOpt::ErrorOnDuplicate),
+                Err(err) => {
+                    eprintln!("failed to parse override `{option}`: `{err}");
+                    exit!(2)
                 }
             }
-            eprintln!("failed to parse override `{option}`: `{err}");
-            exit!(2)
         }
         toml.merge(override_toml, ReplaceOpt::Override);
+        toml.resolve_list_edits();
 
         config.change_id = toml.change_id.inner;
 
@@ -1454,6 +2533,7 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             cargo,
             rustc,
             rustfmt,
+            pin_rustfmt,
             cargo_clippy,
             docs,
             compiler_docs,
@@ -1470,6 +2550,12 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             vendor,
             full_bootstrap,
             bootstrap_cache_path,
+            dist_mirrors,
+            net,
+            gc,
+            cache,
+            distributed,
+            hooks,
             extended,
             tools,
             verbose,
@@ -1489,8 +2575,19 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             dist_stage,
             bench_stage,
             patch_binaries_for_nix,
+            binary_patching,
+            formatters,
+            fmt_untracked,
+            fmt_jobs,
+            fmt_batch_size,
+            step_cache,
+            rustbook_jobs,
+            max_concurrent_links,
+            auto_keep_stage,
+            graft_ci_rustc,
             // This field is only used by bootstrap.py
             metrics: _,
+            metrics_export,
             android_ndk,
             optimized_compiler_builtins,
         } = toml.build.unwrap_or_default();
@@ -1578,6 +2675,55 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
         config.submodules = submodules;
         config.android_ndk = android_ndk;
         config.bootstrap_cache_path = bootstrap_cache_path;
+        config.dist_mirrors = dist_mirrors
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mirror| mirror.trim_end_matches('/').to_owned())
+            .collect();
+        if let Some(Net { proxy, ca_bundle, timeout, retries }) = net {
+            config.net.proxy = proxy;
+            config.net.ca_bundle = ca_bundle;
+            set(&mut config.net.timeout, timeout);
+            set(&mut config.net.retries, retries);
+        }
+        if let Some(Gc { max_size, max_age, keep_toolchains, keep_docs }) = gc {
+            config.gc.max_size = max_size;
+            // `max-age = 0` disables the limit, as it would remove everything.
+            if let Some(max_age) = max_age {
+                config.gc.max_age = Some(max_age).filter(|&days| days > 0);
+            }
+            set(&mut config.gc.keep_toolchains, keep_toolchains);
+            set(&mut config.gc.keep_docs, keep_docs);
+        }
+        if let Some(Cache { url, upload }) = cache {
+            config.cache = CacheConfig {
+                url: url.map(|url| url.trim_end_matches('/').to_owned()),
+                upload: upload.unwrap_or_default(),
+            };
+        }
+        if let Some(Distributed { scheduler_url, auth_token, sccache, jobs }) = distributed {
+            config.distributed = scheduler_url.map(|scheduler_url| DistributedConfig {
+                scheduler_url,
+                auth_token,
+                sccache: sccache.unwrap_or_else(|| "sccache".into()),
+                jobs,
+            });
+        }
+        config.step_hooks = hooks
+            .unwrap_or_default()
+            .into_iter()
+            .map(|Hook { step, when, stage, target, command }| {
+                let (Some(step), Some(command)) = (step, command.filter(|c| !c.is_empty())) else {
+                    panic!("build.hooks need a `step` and a non-empty `command`");
+                };
+                let before = match when.as_deref() {
+                    Some("before") => true,
+                    Some("after") | None => false,
+                    Some(invalid) => panic!("Invalid value '{invalid}' for build.hooks.when."),
+                };
+                StepHook { step, before, stage, target, command }
+            })
+            .collect();
         set(&mut config.low_priority, low_priority);
         set(&mut config.compiler_docs, compiler_docs);
         set(&mut config.library_docs_private_items, library_docs_private_items);
@@ -1597,6 +2743,51 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
         set(&mut config.print_step_timings, print_step_timings);
         set(&mut config.print_step_rusage, print_step_rusage);
         config.patch_binaries_for_nix = patch_binaries_for_nix;
+        if let Some(BinaryPatching { enable, dynamic_linker, rpath, patchelf }) = binary_patching {
+            config.binary_patching = BinaryPatchingConfig {
+                enable,
+                dynamic_linker,
+                rpath: rpath.unwrap_or_default(),
+                patchelf,
+            };
+        }
+        if let Some(Formatters {
+            python,
+            shell,
+            toml,
+            manifests,
+            markdown,
+            ruff,
+            black,
+            shfmt,
+            taplo,
+        }) = formatters
+        {
+            config.formatters = FormattersConfig {
+                python: python.map(|v| v.parse().unwrap_or_else(|err| panic!("{err}"))),
+                shell: shell.unwrap_or_default(),
+                toml: toml.unwrap_or_default(),
+                manifests: manifests.unwrap_or_default(),
+                markdown: markdown.unwrap_or_default(),
+                ruff,
+                black,
+                shfmt,
+                taplo,
+            };
+        }
+        set(&mut config.fmt_untracked, fmt_untracked);
+        config.fmt_jobs = fmt_jobs;
+        config.fmt_batch_size = fmt_batch_size;
+        config.step_cache = step_cache.unwrap_or(true);
+        config.rustbook_jobs = rustbook_jobs.unwrap_or(1);
+        config.max_concurrent_links = max_concurrent_links.filter(|&links| links > 0);
+        set(&mut config.auto_keep_stage, auto_keep_stage);
+        set(&mut config.graft_ci_rustc, graft_ci_rustc);
+        config.metrics_exports = metrics_export
+            .unwrap_or_default()
+            .iter()
+            .map(|format| MetricsExport::from_str(format).unwrap_or_else(|err| panic!("{err}")))
+            .collect();
 
         config.verbose = cmp::max(config.verbose, flags.verbose as usize);
 
@@ -1933,6 +3124,13 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             config.llvm_from_ci = config.parse_download_ci_llvm(None, false);
         }
 
+        let templates = toml.target_templates.take().unwrap_or_default();
+        if let Some(targets) = &mut toml.target {
+            inherit_targets(targets, &templates).unwrap_or_else(|e| {
+                eprintln!("ERROR: {e}");
+                exit!(2);
+            });
+        }
         if let Some(t) = toml.target {
             for (triple, cfg) in t {
                 let mut target = Target::from_triple(&triple);
@@ -2037,11 +3235,14 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             set(&mut config.dist_include_mingw_linker, include_mingw_linker)
         }
 
+        set(&mut config.pin_rustfmt, pin_rustfmt);
         if let Some(r) = rustfmt {
-            *config.initial_rustfmt.borrow_mut() = if r.exists() {
-                RustfmtState::SystemToolchain(r)
-            } else {
-                RustfmtState::Unavailable
+            *config.initial_rustfmt.borrow_mut() = match (r.exists(), config.pin_rustfmt) {
+                (true, false) => RustfmtState::SystemToolchain(r),
+                (true, true) => RustfmtState::Unverified(r),
+                (false, false) => RustfmtState::Unavailable,
+                // Download the pinned one.
+                (false, true) => RustfmtState::LazyEvaluated,
             };
         }
 
@@ -2123,7 +3324,7 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             Subcommand::Doc { .. } => {
                 flags.stage.or(doc_stage).unwrap_or(if download_rustc { 2 } else { 0 })
             }
-            Subcommand::Build { .. } => {
+            Subcommand::Build { .. } | Subcommand::Why => {
                 flags.stage.or(build_stage).unwrap_or(if download_rustc { 2 } else { 1 })
             }
             Subcommand::Test { .. } | Subcommand::Miri { .. } => {
@@ -2132,7 +3333,7 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             Subcommand::Bench { .. } => flags.stage.or(bench_stage).unwrap_or(2),
             Subcommand::Dist { .. } => flags.stage.or(dist_stage).unwrap_or(2),
             Subcommand::Install { .. } => flags.stage.or(install_stage).unwrap_or(2),
-            Subcommand::Perf { .. } => flags.stage.unwrap_or(1),
+            Subcommand::Perf { .. } | Subcommand::Toolstate { .. } => flags.stage.unwrap_or(1),
             // These are all bootstrap tools, which don't depend on the compiler.
             // The stage we pass shouldn't matter, but use 0 just in case.
             Subcommand::Clean { .. }
@@ -2142,7 +3343,10 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             | Subcommand::Setup { .. }
             | Subcommand::Format { .. }
             | Subcommand::Suggest { .. }
-            | Subcommand::Vendor { .. } => flags.stage.unwrap_or(0),
+            | Subcommand::Vendor { .. }
+            | Subcommand::BugReport { .. }
+            | Subcommand::Completions { .. }
+            | Subcommand::Repro { .. } => flags.stage.unwrap_or(0),
         };
 
         // CI should always run stage 2 builds, unless it specifically states otherwise
@@ -2171,7 +3375,12 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
                 | Subcommand::Format { .. }
                 | Subcommand::Suggest { .. }
                 | Subcommand::Vendor { .. }
-                | Subcommand::Perf { .. } => {}
+                | Subcommand::Perf { .. }
+                | Subcommand::BugReport { .. }
+                | Subcommand::Toolstate { .. }
+                | Subcommand::Completions { .. }
+                | Subcommand::Repro { .. }
+                | Subcommand::Why => {}
             }
         }
 
@@ -2403,8 +3612,19 @@ pub(crate) fn download_rustc_commit(&self) -> Option<&str> {
     }
 
     pub(crate) fn initial_rustfmt(&self) -> Option<PathBuf> {
+        let unverified = match &*self.initial_rustfmt.borrow() {
+            RustfmtState::Unverified(p) if !self.dry_run() => Some(p.clone()),
+            _ => None,
+        };
+        if let Some(configured) = unverified {
+            let state = self.verify_rustfmt_version(configured);
+            *self.initial_rustfmt.borrow_mut() = state;
+        }
+
         match &mut *self.initial_rustfmt.borrow_mut() {
-            RustfmtState::SystemToolchain(p) | RustfmtState::Downloaded(p) => Some(p.clone()),
+            RustfmtState::SystemToolchain(p)
+            | RustfmtState::Unverified(p)
+            | RustfmtState::Downloaded(p) => Some(p.clone()),
             RustfmtState::Unavailable => None,
             r @ RustfmtState::LazyEvaluated => {
                 if self.dry_run() {
@@ -2421,6 +3641,33 @@ pub(crate) fn initial_rustfmt(&self) -> Option<PathBuf> {
         }
     }
 
+    /// Compares the version of the rustfmt of `build.rustfmt` with the one pinned in
+    /// `src/stage0`, which is downloaded and used instead if they differ so that all contributors
+    /// format the same way.
+    fn verify_rustfmt_version(&self, configured: PathBuf) -> RustfmtState {
+        let Some(pinned) = self.stage0_metadata.rustfmt.as_ref() else {
+            return RustfmtState::SystemToolchain(configured);
+        };
+        let version = Command::new(&configured)
+            .arg("--version")
+            .output()
+            .ok()
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned());
+        if version.as_deref().is_some_and(|version| is_pinned_rustfmt(version, pinned)) {
+            return RustfmtState::SystemToolchain(configured);
+        }
+        let Some(downloaded) = self.maybe_download_rustfmt() else {
+            return RustfmtState::SystemToolchain(configured);
+        };
+        println!(
+            "NOTE: {} is `{}`, which isn't the version pinned in src/stage0, using the \
+             downloaded one instead because of `build.pin-rustfmt`",
+            configured.display(),
+            version.as_deref().unwrap_or("not runnable")
+        );
+        RustfmtState::Downloaded(downloaded)
+    }
+
     /// Runs a function if verbosity is greater than 0
     pub fn verbose(&self, f: impl Fn()) {
         if self.verbose > 0 {
@@ -3071,3 +4318,39 @@ fn threads_from_config(v: u32) -> u32 {
         n => n,
     }
 }
+
+/// Whether `version`, printed by `rustfmt --version` like `rustfmt 1.7.1-nightly (a7399ba6
+/// 2024-09-03)`, is the one of the toolchain pinned as `pinned`. A toolchain is built from a commit
+/// of the day it's published, or of the day before.
+pub(crate) fn is_pinned_rustfmt(version: &str, pinned: &VersionMetadata) -> bool {
+    let Some((release, commit)) = version.strip_prefix("rustfmt ").and_then(|v| v.split_once(" ("))
+    else {
+        return false;
+    };
+    let same_channel = match pinned.version.as_str() {
+        channel @ ("nightly" | "beta") => release.ends_with(&format!("-{channel}")),
+        _ => release.ends_with("-stable"),
+    };
+    let Some(date) = commit.strip_suffix(')').and_then(|commit| commit.split(' ').nth(1)) else {
+        return false;
+    };
+    same_channel && (date == pinned.date || day_after(date).is_some_and(|day| day == pinned.date))
+}
+
+/// The day after `date`, both written like `2024-09-03`.
+fn day_after(date: &str) -> Option<String> {
+    let mut parts = date.splitn(3, '-').map(|part| part.parse::<u32>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    Some(match (day < days, month < 12) {
+        (true, _) => format!("{year}-{month:02}-{:02}", day + 1),
+        (false, true) => format!("{year}-{:02}-01", month + 1),
+        (false, false) => format!("{}-01-01", year + 1),
+    })
+}
@@ -20,6 +20,112 @@ pub enum Color {
     Auto,
 }
 
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Ui {
+    #[default]
+    Plain,
+    Tty,
+}
+
+/// What bootstrap does when a command fails, before exiting, see `utils::failure_report`.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OnFailure {
+    #[default]
+    Nothing,
+    Report,
+    Shell,
+}
+
+/// The options of `x fmt`, which `x test tidy` uses too.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct FormatArgs {
+    /// check formatting instead of applying
+    #[arg(long)]
+    pub check: bool,
+
+    /// apply to all appropriate files, not just those that have been modified
+    #[arg(long)]
+    pub all: bool,
+
+    /// apply only to the files staged in git
+    #[arg(long, conflicts_with = "all")]
+    pub staged: bool,
+
+    /// apply to the files modified since a git revision instead of the upstream merge-base
+    #[arg(long, value_name = "REV", conflicts_with_all = ["all", "staged"])]
+    pub since: Option<String>,
+
+    /// apply to the files modified since the merge-base with a branch instead of the upstream one
+    #[arg(long, value_name = "BRANCH", conflicts_with_all = ["all", "staged", "since"])]
+    pub base: Option<String>,
+
+    /// output format of --check
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = FmtMessageFormat::Human)]
+    pub message_format: FmtMessageFormat,
+
+    /// format the source read from stdin as the file PATH, and print it to stdout
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["check", "all", "staged", "since", "base"]
+    )]
+    pub stdin: Option<PathBuf>,
+
+    /// write the files rewritten by the formatters, and statistics, as JSON to PATH
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["check", "stdin"])]
+    pub summary_json: Option<PathBuf>,
+
+    /// also apply to the files which aren't tracked by git, instead of skipping them
+    #[arg(long)]
+    pub include_untracked: bool,
+
+    /// format the files again whenever they are saved, until interrupted
+    #[arg(
+        long,
+        conflicts_with_all = ["check", "all", "staged", "since", "base", "stdin", "summary_json"]
+    )]
+    pub watch: bool,
+
+    /// don't print the progress line
+    #[arg(long)]
+    pub quiet: bool,
+
+    /// the maximum number of formatter processes running at once, instead of twice the jobs
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub processes: Option<u32>,
+
+    /// the maximum number of files passed to each formatter process
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub batch_size: Option<u32>,
+
+    /// serve the format requests of editors on a Unix socket, until --stop
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "check", "all", "staged", "since", "base", "stdin", "summary_json", "watch"
+        ]
+    )]
+    pub daemon: bool,
+
+    /// stop the daemon started by --daemon
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "check", "all", "staged", "since", "base", "stdin", "summary_json", "watch", "daemon"
+        ]
+    )]
+    pub stop: bool,
+}
+
+/// The output of `x fmt --check`: the messages of the formatters, or one JSON record per
+/// misformatted file with the byte ranges to replace.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FmtMessageFormat {
+    #[default]
+    Human,
+    Json,
+}
+
 /// Whether to deny warnings, emit them as warnings, or use the default behavior
 #[derive(Copy, Clone, Default, Debug, ValueEnum)]
 pub enum Warnings {
@@ -50,6 +156,12 @@ pub struct Flags {
     #[arg(global = true, long, value_hint = clap::ValueHint::FilePath, value_name = "FILE")]
     /// TOML configuration file for build
     pub config: Option<PathBuf>,
+    #[arg(global = true, long, value_hint = clap::ValueHint::Other, value_name = "NAME")]
+    /// use the settings, subcommand and paths of `[profiles.NAME]` in config.toml
+    pub profile: Option<String>,
+    /// Whether the subcommand wasn't passed, so that it's the one of `--profile`.
+    #[arg(skip)]
+    pub command_from_profile: bool,
     #[arg(global = true, long, value_hint = clap::ValueHint::DirPath, value_name = "DIR")]
     /// Build directory, overrides `build.build-dir` in `config.toml`
     pub build_dir: Option<PathBuf>,
@@ -82,12 +194,31 @@ pub struct Flags {
     #[arg(global = true, long, value_hint = clap::ValueHint::CommandString, value_name = "CMD")]
     /// command to run on failure
     pub on_fail: Option<String>,
+    #[arg(global = true, long, value_name = "ACTION")]
+    #[arg(value_enum, default_value_t = OnFailure::Nothing)]
+    /// what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment
+    pub on_failure: OnFailure,
     #[arg(global = true, long)]
     /// dry run; don't build anything
     pub dry_run: bool,
     /// Indicates whether to dump the work done from bootstrap shims
     #[arg(global = true, long)]
     pub dump_bootstrap_shims: bool,
+    /// write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them
+    #[arg(global = true, long)]
+    pub dump_graph: bool,
+    /// print the commands of the steps with their environment and working directory instead of running them
+    #[arg(global = true, long)]
+    pub plan: bool,
+    /// continue an interrupted invocation with the same arguments, skipping the steps it completed
+    #[arg(global = true, long)]
+    pub resume: bool,
+    /// write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)
+    #[arg(global = true, long)]
+    pub timings: bool,
+    /// write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH
+    #[arg(global = true, long, value_hint = clap::ValueHint::FilePath, value_name = "PATH")]
+    pub json_trace: Option<PathBuf>,
     #[arg(global = true, value_hint = clap::ValueHint::Other, long, value_name = "N")]
     /// stage to build (indicates compiler to use/test, e.g., stage 0 uses the
     /// bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)
@@ -136,12 +267,20 @@ pub struct Flags {
     /// whether to use color in cargo and rustc output
     pub color: Color,
 
+    #[arg(global = true, long, value_name = "UI")]
+    #[arg(value_enum, default_value_t = Ui::Plain)]
+    /// how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal
+    pub ui: Ui,
+
     #[arg(global = true, long)]
     /// Bootstrap uses this value to decide whether it should bypass locking the build process.
     /// This is rarely needed (e.g., compiling the std library for different targets in parallel).
     ///
     /// Unless you know exactly what you are doing, you probably don't need this.
     pub bypass_bootstrap_lock: bool,
+    #[arg(global = true, long)]
+    /// warn about the unknown keys of config.toml, like the ones of forks, instead of failing
+    pub allow_unknown_config: bool,
 
     /// generate PGO profile with rustc build
     #[arg(global = true, value_hint = clap::ValueHint::FilePath, long, value_name = "PROFILE")]
@@ -213,7 +352,24 @@ struct HelpVerboseOnly {
     }
 
     pub fn parse(args: &[String]) -> Self {
-        Flags::parse_from(normalize_args(args))
+        let mut args = normalize_args(args);
+        match Flags::try_parse_from(&args) {
+            Ok(flags) => flags,
+            // The subcommand of `--profile` is read from `config.toml` by `Config::parse`, so the
+            // default one stands in for it until then.
+            Err(e)
+                if e.kind() == clap::error::ErrorKind::MissingSubcommand
+                    && args
+                        .iter()
+                        .any(|arg| arg == "--profile" || arg.starts_with("--profile=")) =>
+            {
+                args.insert(1, "build".to_owned());
+                let mut flags = Flags::parse_from(args);
+                flags.command_from_profile = true;
+                flags
+            }
+            Err(e) => e.exit(),
+        }
     }
 }
 
@@ -292,19 +448,46 @@ pub enum Subcommand {
         long_about = "\n
     Arguments:
         This subcommand optionally accepts a `--check` flag which succeeds if
-        formatting is correct and fails if it is not. For example:
+        formatting is correct and fails if it is not, and a `--staged` flag which
+        only formats the files staged in git. `--since <rev>` formats the files
+        modified since a revision, instead of since the merge-base with the
+        upstream branch, and `--base <branch>` since the merge-base with another
+        branch, like the parent of a stacked pull request. When the check fails,
+        the changes of rustfmt are written to `build/fmt.patch` for `git apply`,
+        and with `--message-format=json`, it also prints a JSON record for each
+        misformatted file. `--stdin <path>` formats the source read from stdin
+        like the file `path` would be, for editor integrations, and `--watch`
+        keeps formatting the files whenever they are saved. The files which aren't
+        tracked by git are skipped, unless `--include-untracked` is passed or
+        `build.fmt-untracked` is set. `--daemon` serves the format requests of
+        editors on `build/fmt.sock` until `--stop`, or 30 minutes without
+        requests. After formatting, a summary of the rewritten files is printed,
+        and written as JSON with `--summary-json <path>`. The files matching
+        `--exclude <glob>` are skipped, like the `ignore` list of rustfmt.toml. A
+        progress line is printed to stderr while formatting, unless `--quiet` is
+        passed. `--processes <n>` and `--batch-size <n>` tune the concurrency of
+        the formatters, like `build.fmt-jobs` and `build.fmt-batch-size`, and
+        `--timings` prints the files the formatters spent the most time on, which
+        are estimated from the sizes of the files of each batch unless each one
+        has a single file. For example:
             ./x.py fmt
-            ./x.py fmt --check"
+            ./x.py fmt --check
+            ./x.py fmt --staged
+            ./x.py fmt --since HEAD~3
+            ./x.py fmt --check --base upstream/beta
+            ./x.py fmt --check --message-format=json
+            ./x.py fmt --stdin compiler/rustc/src/main.rs < main.rs
+            ./x.py fmt --summary-json build/fmt-summary.json
+            ./x.py fmt --exclude 'compiler/rustc_foo/src/generated/**'
+            ./x.py fmt --processes 64 --batch-size 16
+            ./x.py fmt --all --timings --batch-size 1
+            ./x.py fmt --watch
+            ./x.py fmt --daemon"
     )]
     /// Run rustfmt
     Format {
-        /// check formatting instead of applying
-        #[arg(long)]
-        check: bool,
-
-        /// apply to all appropriate files, not just those that have been modified
-        #[arg(long)]
-        all: bool,
+        #[command(flatten)]
+        args: FormatArgs,
     },
     #[command(aliases = ["d"], long_about = "\n
     Arguments:
@@ -374,7 +557,7 @@ pub enum Subcommand {
         /// rerun tests even if the inputs are unchanged
         force_rerun: bool,
         #[arg(long)]
-        /// only run tests that result has been changed
+        /// only run tests that result has been changed, and only check modified files with tidy
         only_modified: bool,
         #[arg(long, value_name = "COMPARE MODE")]
         /// mode describing what file the actual ui output will be compared to
@@ -419,6 +602,9 @@ pub enum Subcommand {
         #[arg(long, value_name = "N")]
         /// Clean a specific stage without touching other artifacts. By default, every stage is cleaned if this option is not used.
         stage: Option<u32>,
+        #[arg(long)]
+        /// Remove stale artifacts according to the build.gc policies of config.toml
+        gc: bool,
     },
     /// Build distribution artifacts
     Dist,
@@ -426,9 +612,11 @@ pub enum Subcommand {
     Install,
     #[command(aliases = ["r"], long_about = "\n
     Arguments:
-        This subcommand accepts a number of paths to tools to build and run. For
-        example:
+        This subcommand accepts a number of paths to tools to build and run, or
+        the names of their binaries. Arguments after `--` are passed to the tools.
+        For example:
             ./x.py run src/tools/bump-stage0
+            ./x.py run cargo-fmt --stage 1 -- --check
         At least a tool needs to be called.")]
     /// Run tools contained in this repository
     Run {
@@ -478,9 +666,76 @@ pub enum Subcommand {
     ///
     /// You need to pass arguments after `--`, e.g.`x perf -- cachegrind`.
     Perf {},
+    /// Collect a bundle of information for bug reports
+    #[command(long_about = "\n
+    Collects the configuration (without secrets), information about the host and the
+    toolchain, the recent invocations and the stamps of the build directory into
+    build/bug-report.tar.xz. The failing command can be passed after `--`, its output
+    is included as well. For example:
+        ./x.py bug-report -- build library --stage 1
+    Please review the files in build/bug-report before sharing the archive.")]
+    BugReport {},
+    /// Check which tools build and pass their tests, and which submodules are outdated
+    #[command(long_about = "\n
+    Arguments:
+        This subcommand accepts a number of paths to the tools to check, by default all
+        tools are checked. For example:
+            ./x.py toolstate src/tools/miri --stage 1
+        The states are compared with the ones recorded by `--record`, e.g. on the
+        commit your branch is based on, to find the tools which were broken by a change.
+        The output of each tool is written to build/toolstate/<tool>.log.")]
+    Toolstate {
+        #[arg(long)]
+        /// record the states as the expected ones for later runs
+        record: bool,
+    },
+    /// Print the completion script of a shell, with the paths of the steps of this checkout
+    #[command(long_about = "\n
+    Arguments:
+        This subcommand accepts the shell to print the completion script of, by default the
+        one of $SHELL. Besides the options, the script completes the paths of the steps of
+        each subcommand, like the test suites and the profiles of `x setup`, in bash, zsh
+        and fish. For example, from .bashrc:
+            source <(./x completions bash)")]
+    Completions {
+        #[arg(value_enum)]
+        /// the shell, by default the current one
+        shell: Option<clap_complete::Shell>,
+    },
+    /// Record an invocation into build/repro.json, or replay a recorded one
+    #[command(long_about = "\n
+    Records the invocation passed after `--`, with the effective configuration (merged
+    with the defaults of its profile, without secrets), the commit, the versions of the
+    stage0 toolchain, the environment variables which influence the build and the steps
+    it runs, into build/repro.json. For example:
+        ./x.py repro -- test tests/ui --stage 1
+    `--replay` runs a recorded invocation again with its configuration and environment,
+    e.g. on another machine, after printing how the checkout, the toolchain and the steps
+    differ from the recorded ones.")]
+    Repro {
+        #[arg(long, value_hint = clap::ValueHint::FilePath, value_name = "PATH")]
+        /// replay the invocation recorded in PATH instead of recording one
+        replay: Option<PathBuf>,
+    },
+    /// Explain why the artifacts of `x build` with the same paths would be rebuilt
+    #[command(long_about = "\n
+    Arguments:
+        This subcommand accepts the paths of `x build`, and runs it as a dry run which
+        prints, for each cargo invocation, the keys of config.toml, the arguments and the
+        environment variables which changed since it last ran, and the files and the
+        environment variables each crate was built from which changed since. For LLVM, it
+        prints whether its stamp matches its sources. For example:
+            ./x.py why library/std --stage 1
+            ./x.py why src/llvm-project")]
+    Why,
 }
 
 impl Subcommand {
+    /// Parses the subcommand `name` without options, like the `command` of a profile.
+    pub fn from_name(name: &str) -> Option<Subcommand> {
+        Flags::try_parse_from(["x.py", name]).ok().map(|flags| flags.cmd)
+    }
+
     pub fn kind(&self) -> Kind {
         match self {
             Subcommand::Bench { .. } => Kind::Bench,
@@ -500,6 +755,12 @@ pub fn kind(&self) -> Kind {
             Subcommand::Suggest { .. } => Kind::Suggest,
             Subcommand::Vendor { .. } => Kind::Vendor,
             Subcommand::Perf { .. } => Kind::Perf,
+            Subcommand::BugReport { .. } => Kind::BugReport,
+            Subcommand::Toolstate { .. } => Kind::Toolstate,
+            Subcommand::Completions { .. } => Kind::Completions,
+            Subcommand::Repro { .. } => Kind::Repro,
+            // The steps are the ones of `x build`, which are explained instead of run.
+            Subcommand::Why => Kind::Build,
         }
     }
 
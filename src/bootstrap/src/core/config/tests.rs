@@ -1,13 +1,19 @@
+use std::collections::HashSet;
 use std::env;
 use std::fs::{remove_file, File};
 use std::io::Write;
 use std::path::Path;
 
+use build_helper::stage0_parser::VersionMetadata;
 use clap::CommandFactory;
 use serde::Deserialize;
 
 use super::flags::Flags;
-use super::{ChangeIdWrapper, Config};
+use super::{
+    check_keys, env_override_key, is_pinned_rustfmt, resolve_includes, BinaryPatchingConfig,
+    CacheConfig, ChangeIdWrapper, Config, DistributedConfig, FormattersConfig, GcConfig,
+    MetricsExport, NetConfig, PythonFormatter, RustfmtState, StepHook,
+};
 use crate::core::build_steps::clippy::get_clippy_rules_in_order;
 use crate::core::config::{LldMode, Target, TargetSelection, TomlConfig};
 
@@ -227,6 +233,35 @@ fn get_toml(file: &Path) -> Result<TomlConfig, toml::de::Error> {
     Config::parse_inner(Flags::parse(&["check".to_owned()]), get_toml);
 }
 
+#[test]
+fn named_profile() {
+    let toml = r#"
+        [profiles.quick-check]
+        command = "check"
+        paths = ["compiler"]
+        rust.debug-assertions = true
+    "#;
+    let parse_args = |args: &[&str]| {
+        let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        args.push("--config=/does/not/exist".to_owned());
+        Config::parse_inner(Flags::parse(&args), |&_| toml::from_str(toml))
+    };
+
+    let config = parse_args(&["--profile", "quick-check"]);
+    assert!(matches!(config.cmd, crate::Subcommand::Check { .. }));
+    assert_eq!(config.paths, [Path::new("compiler")]);
+    assert!(config.rust_debug_assertions);
+
+    // The subcommand and the paths passed on the command line, and `--set`, win.
+    let config = parse_args(&["check", "--profile=quick-check", "compiler/rustc_driver"]);
+    assert_eq!(config.paths, [Path::new("compiler/rustc_driver")]);
+    let config = parse_args(&["build", "--profile", "quick-check"]);
+    assert!(matches!(config.cmd, crate::Subcommand::Build));
+    assert!(config.paths.is_empty());
+    let config = parse_args(&["--profile", "quick-check", "--set", "rust.debug-assertions=false"]);
+    assert!(!config.rust_debug_assertions);
+}
+
 #[test]
 fn rust_optimize() {
     assert!(parse("").rust_optimize.is_release());
@@ -326,3 +361,401 @@ fn verbose_tests_default_value() {
     let config = Config::parse(Flags::parse(&["build".into(), "compiler".into(), "-v".into()]));
     assert_eq!(config.verbose_tests, true);
 }
+
+#[test]
+fn dist_mirrors() {
+    assert!(parse("").dist_mirrors.is_empty());
+    assert_eq!(
+        parse("build.dist-mirrors = [\"https://mirror.example.com/rust/\", \"https://a.example\"]")
+            .dist_mirrors,
+        ["https://mirror.example.com/rust", "https://a.example"]
+    );
+}
+
+#[test]
+fn metrics_export() {
+    assert!(parse("").metrics_exports.is_empty());
+    assert_eq!(
+        parse("build.metrics-export = [\"prometheus\", \"otlp\"]").metrics_exports,
+        [MetricsExport::Prometheus, MetricsExport::Otlp]
+    );
+}
+
+#[test]
+fn net_config() {
+    assert_eq!(parse("").net, NetConfig::default());
+    let net = parse(
+        "[build.net]\nproxy = \"http://proxy.example.com:3128\"\nca-bundle = \"/etc/ca.pem\"\n\
+        timeout = 120\nretries = 5",
+    )
+    .net;
+    assert_eq!(
+        net,
+        NetConfig {
+            proxy: Some("http://proxy.example.com:3128".to_owned()),
+            ca_bundle: Some("/etc/ca.pem".into()),
+            timeout: 120,
+            retries: 5,
+        }
+    );
+    assert_eq!(parse("build.net.retries = 0").net, NetConfig { retries: 0, ..Default::default() });
+}
+
+#[test]
+fn gc_config() {
+    assert_eq!(parse("").gc, GcConfig::default());
+    let gc = parse("[build.gc]\nmax-size = 100\nmax-age = 0\nkeep-toolchains = 1").gc;
+    assert_eq!(
+        gc,
+        GcConfig { max_size: Some(100), max_age: None, keep_toolchains: 1, keep_docs: 2 }
+    );
+    assert_eq!(parse("build.gc.keep-docs = 0").gc.max_age, Some(30));
+}
+
+#[test]
+fn cache_config() {
+    assert_eq!(parse("").cache, CacheConfig::default());
+    let cache = parse(
+        "[build.cache]
+url = \"https://cache.example.com/rust/\"
+upload = true",
+    )
+    .cache;
+    assert_eq!(
+        cache,
+        CacheConfig { url: Some("https://cache.example.com/rust".to_owned()), upload: true }
+    );
+}
+
+#[test]
+fn step_hooks() {
+    assert!(parse("").step_hooks.is_empty());
+    let config = parse(
+        r#"
+        [[build.hooks]]
+        step = "compile::Std"
+        stage = 1
+        command = ["cp", "-r", "{sysroot}/lib", "/tmp"]
+
+        [[build.hooks]]
+        step = "dist::Rustc"
+        when = "before"
+        command = ["./prepare-dist.sh", "{target}"]
+        "#,
+    );
+    assert_eq!(
+        config.step_hooks,
+        [
+            StepHook {
+                step: "compile::Std".to_owned(),
+                before: false,
+                stage: Some(1),
+                target: None,
+                command: vec!["cp".into(), "-r".into(), "{sysroot}/lib".into(), "/tmp".into()],
+            },
+            StepHook {
+                step: "dist::Rustc".to_owned(),
+                before: true,
+                stage: None,
+                target: None,
+                command: vec!["./prepare-dist.sh".into(), "{target}".into()],
+            },
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn step_hooks_without_command() {
+    parse("[[build.hooks]]\nstep = \"compile::Std\"");
+}
+
+#[test]
+fn distributed_config() {
+    assert_eq!(parse("").distributed, None);
+    // The distributed compilation is disabled without a scheduler.
+    assert_eq!(parse("build.distributed.jobs = 64").distributed, None);
+    let distributed =
+        parse("[build.distributed]\nscheduler-url = \"https://sccache.example.com\"\njobs = 64")
+            .distributed;
+    assert_eq!(
+        distributed,
+        Some(DistributedConfig {
+            scheduler_url: "https://sccache.example.com".to_owned(),
+            auth_token: None,
+            sccache: "sccache".into(),
+            jobs: Some(64),
+        })
+    );
+}
+
+#[test]
+fn binary_patching_config() {
+    assert_eq!(parse("").binary_patching, BinaryPatchingConfig::default());
+    let binary_patching = parse(
+        "[build.binary-patching]\nenable = true\n\
+        dynamic-linker = \"/opt/glibc/lib/ld-linux-x86-64.so.2\"\nrpath = [\"/opt/gcc/lib\"]",
+    )
+    .binary_patching;
+    assert_eq!(
+        binary_patching,
+        BinaryPatchingConfig {
+            enable: Some(true),
+            dynamic_linker: Some("/opt/glibc/lib/ld-linux-x86-64.so.2".into()),
+            rpath: vec!["/opt/gcc/lib".into()],
+            patchelf: None,
+        }
+    );
+}
+
+#[test]
+fn formatters_config() {
+    assert_eq!(parse("").formatters, FormattersConfig::default());
+    let formatters =
+        parse("[build.formatters]\npython = \"ruff\"\ntoml = true\ntaplo = \"/opt/taplo\"")
+            .formatters;
+    assert_eq!(
+        formatters,
+        FormattersConfig {
+            python: Some(PythonFormatter::Ruff),
+            toml: true,
+            taplo: Some("/opt/taplo".into()),
+            ..FormattersConfig::default()
+        }
+    );
+    assert!(parse("[build.formatters]\nmanifests = true").formatters.manifests);
+    assert!(parse("[build.formatters]\nmarkdown = true").formatters.markdown);
+    assert!(parse("build.fmt-untracked = true").fmt_untracked);
+    let config = parse("build.fmt-jobs = 4\nbuild.fmt-batch-size = 16");
+    assert_eq!((config.fmt_jobs, config.fmt_batch_size), (Some(4), Some(16)));
+}
+
+#[test]
+fn step_cache() {
+    assert!(parse("").step_cache);
+    assert!(!parse("build.step-cache = false").step_cache);
+}
+
+#[test]
+fn rustbook_jobs() {
+    assert_eq!(parse("").rustbook_jobs, 1);
+    assert_eq!(parse("build.rustbook-jobs = 4").rustbook_jobs, 4);
+}
+
+#[test]
+fn max_concurrent_links() {
+    assert_eq!(parse("").max_concurrent_links, None);
+    assert_eq!(parse("build.max-concurrent-links = 0").max_concurrent_links, None);
+    assert_eq!(parse("build.max-concurrent-links = 2").max_concurrent_links, Some(2));
+}
+
+#[test]
+fn why() {
+    let args = ["why", "library/std", "--config=/does/not/exist"].map(str::to_owned);
+    let config = Config::parse_inner(Flags::parse(&args), |&_| toml::from_str(""));
+    // `x why` explains the steps of `x build` during a dry run.
+    assert!(config.why && config.dry_run());
+    assert_eq!(config.stage, 1);
+    assert_eq!(config.paths, [Path::new("library/std")]);
+}
+
+#[test]
+fn auto_keep_stage() {
+    assert!(!parse("").auto_keep_stage);
+    assert!(parse("build.auto-keep-stage = true").auto_keep_stage);
+}
+
+#[test]
+fn graft_ci_rustc() {
+    assert!(!parse("").graft_ci_rustc);
+    assert!(parse("build.graft-ci-rustc = true").graft_ci_rustc);
+}
+
+#[test]
+fn pin_rustfmt() {
+    let config = parse("build.rustfmt = \"/does/not/exist/rustfmt\"");
+    assert!(!config.pin_rustfmt);
+    assert!(matches!(*config.initial_rustfmt.borrow(), RustfmtState::Unavailable));
+
+    // The pinned rustfmt is downloaded instead of the missing one.
+    let config = parse("build.rustfmt = \"/does/not/exist/rustfmt\"\nbuild.pin-rustfmt = true");
+    assert!(config.pin_rustfmt);
+    assert!(matches!(*config.initial_rustfmt.borrow(), RustfmtState::LazyEvaluated));
+
+    let pinned = |version: &str, date: &str| VersionMetadata {
+        version: version.to_owned(),
+        date: date.to_owned(),
+    };
+    let nightly = "rustfmt 1.7.1-nightly (a7399ba6 2024-09-03)";
+    assert!(is_pinned_rustfmt(nightly, &pinned("nightly", "2024-09-04")));
+    assert!(is_pinned_rustfmt(nightly, &pinned("nightly", "2024-09-03")));
+    assert!(!is_pinned_rustfmt(nightly, &pinned("nightly", "2024-09-05")));
+    assert!(!is_pinned_rustfmt(nightly, &pinned("beta", "2024-09-04")));
+    // The day after the 28th of February is the 29th in leap years.
+    let stable = "rustfmt 1.7.0-stable (eeb90cda 2024-02-28)";
+    assert!(is_pinned_rustfmt(stable, &pinned("1.77.0", "2024-02-29")));
+    assert!(!is_pinned_rustfmt(stable, &pinned("1.77.0", "2024-03-01")));
+    assert!(!is_pinned_rustfmt(stable, &pinned("nightly", "2024-02-28")));
+    assert!(!is_pinned_rustfmt("rustfmt: command not found", &pinned("nightly", "2024-09-04")));
+}
+
+#[test]
+fn unknown_keys() {
+    let check = |config: &str, allow_unknown| {
+        let mut value: toml::Value = toml::from_str(config).unwrap();
+        check_keys(&mut value, allow_unknown).map(|()| value).map_err(|e| e.to_string())
+    };
+    let config = "[rust]\ndebug-assertion = true\n[target.x86_64-unknown-linux-gnu]\nrunnr = 1";
+    let err = check(config, false).unwrap_err();
+    let expected = "unknown field `debug-assertion` in `[rust]`, did you mean `debug-assertions`?";
+    assert!(err.contains(expected), "{err}");
+    assert!(err.contains("unknown field `runnr` in `[target.x86_64-unknown-linux-gnu]`"), "{err}");
+    assert!(err.contains("--allow-unknown-config"), "{err}");
+    // The keys of the nested tables and of the profiles are checked too.
+    let err = check("[[profiles.ui.build.hooks]]\nsteps = \"test::Ui\"", false).unwrap_err();
+    assert!(
+        err.contains("unknown field `steps` in `[profiles.ui.build.hooks]`, did you mean `step`?"),
+        "{err}"
+    );
+    let err = check("frobnicate = 1", false).unwrap_err();
+    assert!(err.contains("unknown field `frobnicate` in the top level\n"), "{err}");
+    // The values of the fields which are tables, like the targets, aren't keys.
+    let config = "llvm.build-config = { FOO = \"bar\" }\ntarget.foo.runner = \"qemu\"";
+    assert!(check(config, false).is_ok());
+
+    // The unknown keys of forks are removed.
+    let value = check("[rust]\ndebug-assertion = true\nlto = \"fat\"", true).unwrap();
+    assert_eq!(value["rust"].as_table().unwrap().keys().collect::<Vec<_>>(), ["lto"]);
+}
+
+#[test]
+fn env_overrides() {
+    let key = |name| env_override_key(name).map(|key| key.unwrap_or_default());
+    assert_eq!(key("RUST_BOOTSTRAP_RUST_DEBUG_ASSERTIONS"), Ok("rust.debug-assertions".to_owned()));
+    assert_eq!(key("RUST_BOOTSTRAP_BUILD_BUILD"), Ok("build.build".to_owned()));
+    // The keys of the nested tables are found too.
+    assert_eq!(key("RUST_BOOTSTRAP_BUILD_NET_PROXY"), Ok("build.net.proxy".to_owned()));
+    // The other variables aren't overrides.
+    assert_eq!(env_override_key("RUST_BOOTSTRAP_CONFIG"), Ok(None));
+    assert_eq!(env_override_key("RUSTFLAGS"), Ok(None));
+    let err = env_override_key("RUST_BOOTSTRAP_RUST_DEBUG_ASSERTION").unwrap_err();
+    assert!(err.contains("`[rust]`"), "{err}");
+}
+
+#[test]
+fn includes() {
+    let dir = env::temp_dir().join(format!("bootstrap-includes-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("ci")).unwrap();
+    let write = |path: &str, contents: &str| std::fs::write(dir.join(path), contents).unwrap();
+    write("ci/base.toml", "[build]\ntools = [\"cargo\"]\nverbose = 1\n[rust]\nlto = \"thin\"");
+    write("local.toml", "include = [\"ci/base.toml\"]\nbuild.tools = [\"clippy\"]\nrust.lto = 1");
+    let resolve = |config: &str| {
+        let mut value: toml::Value = toml::from_str(config).unwrap();
+        let file = dir.join("config.toml");
+        resolve_includes(&file, &mut value, &mut Vec::new(), &mut Vec::new()).map(|()| value)
+    };
+
+    // The tables are merged, and the other values of each file, like the arrays, replace the ones
+    // of the files before it.
+    let value = resolve("include = [\"ci/base.toml\", \"local.toml\"]\nrust.lto = 2").unwrap();
+    let expected: toml::Value =
+        toml::from_str("[build]\ntools = [\"clippy\"]\nverbose = 1\n[rust]\nlto = 2").unwrap();
+    assert_eq!(value, expected);
+
+    // The edits of the lists of each file edit the lists of the files before it.
+    write("tools.toml", r#"build."tools+" = ["rustfmt"]"#);
+    let value = resolve(
+        r#"
+        include = ["ci/base.toml", "tools.toml"]
+        build."tools-" = ["cargo"]
+        "#,
+    );
+    let expected: toml::Value =
+        toml::from_str("[build]\ntools = [\"rustfmt\"]\nverbose = 1\n[rust]\nlto = \"thin\"")
+            .unwrap();
+    assert_eq!(value.unwrap(), expected);
+
+    // The included files are recorded, with the ones they include before them.
+    let mut included = Vec::new();
+    let mut value = toml::from_str("include = [\"local.toml\"]").unwrap();
+    resolve_includes(&dir.join("config.toml"), &mut value, &mut Vec::new(), &mut included).unwrap();
+    let canonical = |path: &str| dir.join(path).canonicalize().unwrap();
+    assert_eq!(included, [canonical("ci/base.toml"), canonical("local.toml")]);
+
+    // The includes can't form a cycle, and have to exist.
+    write("cycle.toml", "include = [\"cycle.toml\"]");
+    let err = resolve("include = [\"cycle.toml\"]").unwrap_err().to_string();
+    assert!(err.contains("includes itself"), "{err}");
+    assert!(resolve("include = [\"missing.toml\"]").is_err());
+    assert!(resolve("include = \"local.toml\"").is_err());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn target_inheritance() {
+    let config = parse(
+        r#"
+        [target-templates.arm-none]
+        linker = "arm-none-eabi-gcc"
+        ar = "arm-none-eabi-ar"
+        no-std = true
+
+        [target.thumbv7em-none-eabi]
+        inherits = "arm-none"
+        runner = "qemu-arm"
+
+        [target.thumbv7em-none-eabihf]
+        inherits = "thumbv7em-none-eabi"
+        ar = "llvm-ar"
+        "#,
+    );
+    let target = |triple| &config.target_config[&TargetSelection::from_user(triple)];
+    let soft_float = target("thumbv7em-none-eabi");
+    assert_eq!(soft_float.linker, Some("arm-none-eabi-gcc".into()));
+    assert_eq!(soft_float.runner.as_deref(), Some("qemu-arm"));
+    // The keys a target sets are kept, and the inherited target inherits its template too.
+    let hard_float = target("thumbv7em-none-eabihf");
+    assert_eq!(hard_float.ar, Some("llvm-ar".into()));
+    assert_eq!(hard_float.linker, Some("arm-none-eabi-gcc".into()));
+    assert_eq!(hard_float.runner.as_deref(), Some("qemu-arm"));
+    assert!(hard_float.no_std);
+    // The templates aren't targets.
+    assert!(!config.target_config.contains_key(&TargetSelection::from_user("arm-none")));
+}
+
+#[test]
+fn list_edits() {
+    let parse_set = |options: &[&str]| {
+        let mut args = vec!["check".to_owned(), "--config=/does/not/exist".to_owned()];
+        args.extend(options.iter().map(|option| format!("--set={option}")));
+        Config::parse_inner(Flags::parse(&args), |&_| {
+            toml::from_str(
+                r#"
+                build.tools = ["cargo", "rustfmt"]
+                rust.codegen-backends = ["llvm", "cranelift"]
+                "#,
+            )
+        })
+    };
+
+    // The edits apply in order to the list of the layer below them.
+    let config = parse_set(&[
+        r#"build.tools+=["clippy"]"#,
+        r#"build.tools-=["rustfmt", "clippy"]"#,
+        r#"build.tools+=["clippy"]"#,
+        r#"rust.codegen-backends-=["llvm"]"#,
+    ]);
+    let tools = HashSet::from(["cargo".to_owned(), "clippy".to_owned()]);
+    assert_eq!(config.tools, Some(tools));
+    assert_eq!(config.rust_codegen_backends, ["cranelift"]);
+
+    // A list replaces the edits below it, and the edits of a list which no layer sets edit the
+    // empty list.
+    let config = parse_set(&[
+        r#"rust.codegen-backends+=["gcc"]"#,
+        r#"rust.codegen-backends=["llvm"]"#,
+        r#"dist.compression-formats+=["xz"]"#,
+    ]);
+    assert_eq!(config.rust_codegen_backends, ["llvm"]);
+    assert_eq!(config.dist_compression_formats, Some(vec!["xz".to_owned()]));
+}
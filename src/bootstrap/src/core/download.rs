@@ -1,20 +1,22 @@
 use std::env;
 use std::ffi::OsString;
 use std::fs::{self, File};
-use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
+use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
-use std::sync::OnceLock;
+use std::process::Command;
 
 use build_helper::ci::CiEnv;
 use xz2::bufread::XzDecoder;
 
-use crate::core::config::BUILDER_CONFIG_FILENAME;
+use crate::core::config::{NetConfig, BUILDER_CONFIG_FILENAME};
 use crate::utils::exec::{command, BootstrapCommand};
 use crate::utils::helpers::{check_run, exe, hex_encode, move_file, program_out_of_date};
 use crate::{t, Config};
 
-static SHOULD_FIX_BINS_AND_DYLIBS: OnceLock<bool> = OnceLock::new();
+mod artifact_cache;
+mod patch_binaries;
+#[cfg(test)]
+mod tests;
 
 /// `Config::try_run` wrapper for this module to avoid warnings on `try_run`, since we don't have access to a `builder` yet.
 fn try_run(config: &Config, cmd: &mut Command) -> Result<(), ()> {
@@ -32,6 +34,85 @@ fn extract_curl_version(out: &[u8]) -> semver::Version {
         .unwrap_or(semver::Version::new(1, 0, 0))
 }
 
+/// Returns the arguments of curl to download to `tempfile` with the network settings `net`.
+fn curl_args(net: &NetConfig, tempfile: &Path) -> Vec<OsString> {
+    // options should be kept in sync with
+    // src/bootstrap/bootstrap.py
+    // for consistency
+    let timeout = net.timeout.to_string();
+    let retries = net.retries.to_string();
+    let mut args: Vec<OsString> = [
+        // follow redirect
+        "--location",
+        // timeout if speed is < 10 bytes/sec for > `build.net.timeout` seconds
+        "--speed-time",
+        timeout.as_str(),
+        "--speed-limit",
+        "10",
+        // timeout if cannot connect within `build.net.timeout` seconds
+        "--connect-timeout",
+        timeout.as_str(),
+        // output file
+        "--output",
+        tempfile.to_str().unwrap(),
+        // if there is an error, don't restart the download,
+        // instead continue where it left off.
+        "--continue-at",
+        "-",
+        // retry up to `build.net.retries` times. note that this means a maximum of 4
+        // attempts will be made by default, since the first attempt isn't a *re*try.
+        "--retry",
+        retries.as_str(),
+        // show errors, even if --silent is specified
+        "--show-error",
+        // set timestamp of downloaded file to that of the server
+        "--remote-time",
+        // fail on non-ok http status
+        "--fail",
+    ]
+    .into_iter()
+    .map(OsString::from)
+    .collect();
+    if let Some(proxy) = &net.proxy {
+        args.extend(["--proxy".into(), proxy.into()]);
+    }
+    if let Some(ca_bundle) = &net.ca_bundle {
+        args.extend(["--cacert".into(), ca_bundle.into()]);
+    }
+    args
+}
+
+/// Downloads to `tempfile` with `download` from the first of `urls` that works, and returns it.
+///
+/// An attempt which resumed a partial download is retried from scratch once, as the server may not
+/// support resuming downloads, or the partial download was corrupt. The partial download may be
+/// gone already, when it failed the verification of its checksum.
+fn download_from_urls<'a>(
+    urls: &'a [String],
+    tempfile: &Path,
+    mut download: impl FnMut(&str) -> bool,
+) -> Option<&'a str> {
+    for url in urls {
+        let resuming = tempfile.exists();
+        if url != &urls[0] {
+            eprintln!("trying {url} instead");
+        }
+        if download(url) {
+            return Some(url.as_str());
+        }
+        if resuming {
+            if tempfile.exists() {
+                t!(fs::remove_file(tempfile));
+            }
+            println!("restarting the download of {url}");
+            if download(url) {
+                return Some(url.as_str());
+            }
+        }
+    }
+    None
+}
+
 fn curl_version() -> semver::Version {
     let mut curl = Command::new("curl");
     curl.arg("-V");
@@ -81,186 +162,77 @@ pub(crate) fn check_run(&self, cmd: &mut BootstrapCommand) -> bool {
         check_run(cmd, self.is_verbose())
     }
 
-    /// Whether or not `fix_bin_or_dylib` needs to be run; can only be true
-    /// on NixOS
-    fn should_fix_bins_and_dylibs(&self) -> bool {
-        let val = *SHOULD_FIX_BINS_AND_DYLIBS.get_or_init(|| {
-            match Command::new("uname").arg("-s").stderr(Stdio::inherit()).output() {
-                Err(_) => return false,
-                Ok(output) if !output.status.success() => return false,
-                Ok(output) => {
-                    let mut os_name = output.stdout;
-                    if os_name.last() == Some(&b'\n') {
-                        os_name.pop();
-                    }
-                    if os_name != b"Linux" {
-                        return false;
-                    }
-                }
-            }
-
-            // If the user has asked binaries to be patched for Nix, then
-            // don't check for NixOS or `/lib`.
-            // NOTE: this intentionally comes after the Linux check:
-            // - patchelf only works with ELF files, so no need to run it on Mac or Windows
-            // - On other Unix systems, there is no stable syscall interface, so Nix doesn't manage the global libc.
-            if let Some(explicit_value) = self.patch_binaries_for_nix {
-                return explicit_value;
-            }
-
-            // Use `/etc/os-release` instead of `/etc/NIXOS`.
-            // The latter one does not exist on NixOS when using tmpfs as root.
-            let is_nixos = match File::open("/etc/os-release") {
-                Err(e) if e.kind() == ErrorKind::NotFound => false,
-                Err(e) => panic!("failed to access /etc/os-release: {}", e),
-                Ok(os_release) => BufReader::new(os_release).lines().any(|l| {
-                    let l = l.expect("reading /etc/os-release");
-                    matches!(l.trim(), "ID=nixos" | "ID='nixos'" | "ID=\"nixos\"")
-                }),
-            };
-            if !is_nixos {
-                let in_nix_shell = env::var("IN_NIX_SHELL");
-                if let Ok(in_nix_shell) = in_nix_shell {
-                    eprintln!(
-                        "The IN_NIX_SHELL environment variable is `{in_nix_shell}`; \
-                         you may need to set `patch-binaries-for-nix=true` in config.toml"
-                    );
-                }
-            }
-            is_nixos
-        });
-        if val {
-            eprintln!("INFO: You seem to be using Nix.");
+    /// Downloads a file from the first of `urls` that works, and moves it to `dest_path`.
+    ///
+    /// If `checksum` is given, the download is only accepted if its SHA256 checksum matches, and
+    /// a partial download left behind by an interrupted attempt is resumed. Without a checksum a
+    /// resumed download couldn't be told apart from a corrupt one, so they always start over.
+    fn download_file(
+        &self,
+        urls: &[String],
+        dest_path: &Path,
+        checksum: Option<&str>,
+        help_on_error: &str,
+    ) {
+        // Use a temporary file in case we crash while downloading, to avoid a corrupt download in cache/.
+        let tempfile = self.tempdir().join(dest_path.file_name().unwrap());
+        if checksum.is_none() && tempfile.exists() {
+            self.remove(&tempfile);
         }
-        val
-    }
 
-    /// Modifies the interpreter section of 'fname' to fix the dynamic linker,
-    /// or the RPATH section, to fix the dynamic library search path
-    ///
-    /// This is only required on NixOS and uses the PatchELF utility to
-    /// change the interpreter/RPATH of ELF executables.
-    ///
-    /// Please see <https://nixos.org/patchelf.html> for more information
-    fn fix_bin_or_dylib(&self, fname: &Path) {
-        assert_eq!(SHOULD_FIX_BINS_AND_DYLIBS.get(), Some(&true));
-        println!("attempting to patch {}", fname.display());
-
-        // Only build `.nix-deps` once.
-        static NIX_DEPS_DIR: OnceLock<PathBuf> = OnceLock::new();
-        let mut nix_build_succeeded = true;
-        let nix_deps_dir = NIX_DEPS_DIR.get_or_init(|| {
-            // Run `nix-build` to "build" each dependency (which will likely reuse
-            // the existing `/nix/store` copy, or at most download a pre-built copy).
-            //
-            // Importantly, we create a gc-root called `.nix-deps` in the `build/`
-            // directory, but still reference the actual `/nix/store` path in the rpath
-            // as it makes it significantly more robust against changes to the location of
-            // the `.nix-deps` location.
-            //
-            // bintools: Needed for the path of `ld-linux.so` (via `nix-support/dynamic-linker`).
-            // zlib: Needed as a system dependency of `libLLVM-*.so`.
-            // patchelf: Needed for patching ELF binaries (see doc comment above).
-            let nix_deps_dir = self.out.join(".nix-deps");
-            const NIX_EXPR: &str = "
-            with (import <nixpkgs> {});
-            symlinkJoin {
-                name = \"rust-stage0-dependencies\";
-                paths = [
-                    zlib
-                    patchelf
-                    stdenv.cc.bintools
-                ];
-            }
-            ";
-            nix_build_succeeded = try_run(
-                self,
-                Command::new("nix-build").args([
-                    Path::new("-E"),
-                    Path::new(NIX_EXPR),
-                    Path::new("-o"),
-                    &nix_deps_dir,
-                ]),
-            )
-            .is_ok();
-            nix_deps_dir
+        let downloaded = download_from_urls(urls, &tempfile, |url| {
+            self.try_download_file(url, &tempfile, checksum)
         });
-        if !nix_build_succeeded {
+        if downloaded.is_some() {
+            t!(
+                move_file(&tempfile, dest_path),
+                format!("failed to rename {tempfile:?} to {dest_path:?}")
+            );
             return;
         }
 
-        let mut patchelf = Command::new(nix_deps_dir.join("bin/patchelf"));
-        patchelf.args(&[
-            OsString::from("--add-rpath"),
-            OsString::from(t!(fs::canonicalize(nix_deps_dir)).join("lib")),
-        ]);
-        if !path_is_dylib(fname) {
-            // Finally, set the correct .interp for binaries
-            let dynamic_linker_path = nix_deps_dir.join("nix-support/dynamic-linker");
-            // FIXME: can we support utf8 here? `args` doesn't accept Vec<u8>, only OsString ...
-            let dynamic_linker = t!(String::from_utf8(t!(fs::read(dynamic_linker_path))));
-            patchelf.args(["--set-interpreter", dynamic_linker.trim_end()]);
+        eprintln!("ERROR: failed to download {}", dest_path.file_name().unwrap().to_string_lossy());
+        eprintln!("tried: {}", urls.join(", "));
+        eprintln!("network settings ([build.net] in config.toml): {}", self.net);
+        if !help_on_error.is_empty() {
+            eprintln!("{help_on_error}");
         }
-
-        let _ = try_run(self, patchelf.arg(fname));
+        crate::exit!(1);
     }
 
-    fn download_file(&self, url: &str, dest_path: &Path, help_on_error: &str) {
+    /// Downloads `url` to `tempfile`, and verifies it if a `checksum` is given. A download which
+    /// doesn't match the checksum is removed.
+    fn try_download_file(&self, url: &str, tempfile: &Path, checksum: Option<&str>) -> bool {
         self.verbose(|| println!("download {url}"));
-        // Use a temporary file in case we crash while downloading, to avoid a corrupt download in cache/.
-        let tempfile = self.tempdir().join(dest_path.file_name().unwrap());
         // While bootstrap itself only supports http and https downloads, downstream forks might
         // need to download components from other protocols. The match allows them adding more
         // protocols without worrying about merge conflicts if we change the HTTP implementation.
-        match url.split_once("://").map(|(proto, _)| proto) {
-            Some("http") | Some("https") => {
-                self.download_http_with_retries(&tempfile, url, help_on_error)
-            }
+        let downloaded = match url.split_once("://").map(|(proto, _)| proto) {
+            Some("http") | Some("https") => self.download_http_with_retries(tempfile, url),
             Some(other) => panic!("unsupported protocol {other} in {url}"),
             None => panic!("no protocol in {url}"),
+        };
+        if !downloaded {
+            eprintln!("failed to download {url}");
+            return false;
+        }
+        match checksum {
+            Some(sha256) if !self.verify(tempfile, sha256) => {
+                eprintln!("downloaded file from {url} doesn't match the expected checksum");
+                self.remove(tempfile);
+                false
+            }
+            _ => true,
         }
-        t!(
-            move_file(&tempfile, dest_path),
-            format!("failed to rename {tempfile:?} to {dest_path:?}")
-        );
     }
 
-    fn download_http_with_retries(&self, tempfile: &Path, url: &str, help_on_error: &str) {
+    /// Downloads `url` to `tempfile`, resuming the download if `tempfile` already exists. Returns
+    /// whether the download succeeded.
+    fn download_http_with_retries(&self, tempfile: &Path, url: &str) -> bool {
         println!("downloading {url}");
         // Try curl. If that fails and we are on windows, fallback to PowerShell.
-        // options should be kept in sync with
-        // src/bootstrap/src/core/download.rs
-        // for consistency
         let mut curl = command("curl");
-        curl.args([
-            // follow redirect
-            "--location",
-            // timeout if speed is < 10 bytes/sec for > 30 seconds
-            "--speed-time",
-            "30",
-            "--speed-limit",
-            "10",
-            // timeout if cannot connect within 30 seconds
-            "--connect-timeout",
-            "30",
-            // output file
-            "--output",
-            tempfile.to_str().unwrap(),
-            // if there is an error, don't restart the download,
-            // instead continue where it left off.
-            "--continue-at",
-            "-",
-            // retry up to 3 times.  note that this means a maximum of 4
-            // attempts will be made, since the first attempt isn't a *re*try.
-            "--retry",
-            "3",
-            // show errors, even if --silent is specified
-            "--show-error",
-            // set timestamp of downloaded file to that of the server
-            "--remote-time",
-            // fail on non-ok http status
-            "--fail",
-        ]);
+        curl.args(curl_args(&self.net, tempfile));
         // Don't print progress in CI; the \r wrapping looks bad and downloads don't take long enough for progress to be useful.
         if CiEnv::is_ci() {
             curl.arg("--silent");
@@ -272,29 +244,36 @@ fn download_http_with_retries(&self, tempfile: &Path, url: &str, help_on_error:
             curl.arg("--retry-all-errors");
         }
         curl.arg(url);
-        if !self.check_run(&mut curl) {
-            if self.build.contains("windows-msvc") {
-                eprintln!("Fallback to PowerShell");
-                for _ in 0..3 {
-                    if try_run(self, Command::new("PowerShell.exe").args([
-                        "/nologo",
-                        "-Command",
-                        "[Net.ServicePointManager]::SecurityProtocol = [Net.SecurityProtocolType]::Tls12;",
-                        &format!(
-                            "(New-Object System.Net.WebClient).DownloadFile('{}', '{}')",
-                            url, tempfile.to_str().expect("invalid UTF-8 not supported with powershell downloads"),
-                        ),
-                    ])).is_err() {
-                        return;
-                    }
-                    eprintln!("\nspurious failure, trying again");
+        if self.check_run(&mut curl) {
+            return true;
+        }
+        // PowerShell can't be told to use a different CA bundle.
+        if self.build.contains("windows-msvc") && self.net.ca_bundle.is_none() {
+            eprintln!("Fallback to PowerShell");
+            let proxy = match &self.net.proxy {
+                Some(proxy) => {
+                    format!("$client.Proxy = New-Object System.Net.WebProxy('{proxy}');")
                 }
+                None => String::new(),
+            };
+            for _ in 0..=self.net.retries {
+                if try_run(self, Command::new("PowerShell.exe").args([
+                    "/nologo",
+                    "-Command",
+                    "[Net.ServicePointManager]::SecurityProtocol = [Net.SecurityProtocolType]::Tls12;",
+                    "$client = New-Object System.Net.WebClient;",
+                    &proxy,
+                    &format!(
+                        "$client.DownloadFile('{}', '{}')",
+                        url, tempfile.to_str().expect("invalid UTF-8 not supported with powershell downloads"),
+                    ),
+                ])).is_ok() {
+                    return true;
+                }
+                eprintln!("\nspurious failure, trying again");
             }
-            if !help_on_error.is_empty() {
-                eprintln!("{help_on_error}");
-            }
-            crate::exit!(1);
         }
+        false
     }
 
     fn unpack(&self, tarball: &Path, dst: &Path, pattern: &str) {
@@ -436,10 +415,9 @@ pub(crate) fn download_clippy(&self) -> PathBuf {
 
         let filename = format!("clippy-{version}-{host}.tar.xz");
         self.download_component(DownloadSource::Dist, filename, "clippy-preview", date, "stage0");
-        if self.should_fix_bins_and_dylibs() {
-            self.fix_bin_or_dylib(&cargo_clippy);
-            self.fix_bin_or_dylib(&cargo_clippy.with_file_name(exe("clippy-driver", host)));
-        }
+        let clippy_driver = cargo_clippy.with_file_name(exe("clippy-driver", host));
+        let binaries = [cargo_clippy.clone(), clippy_driver];
+        self.patch_binaries(&binaries, &binaries);
 
         self.create(&clippy_stamp, date);
         cargo_clippy
@@ -482,17 +460,7 @@ pub(crate) fn maybe_download_rustfmt(&self) -> Option<PathBuf> {
             "rustfmt",
         );
 
-        if self.should_fix_bins_and_dylibs() {
-            self.fix_bin_or_dylib(&bin_root.join("bin").join("rustfmt"));
-            self.fix_bin_or_dylib(&bin_root.join("bin").join("cargo-fmt"));
-            let lib_dir = bin_root.join("lib");
-            for lib in t!(fs::read_dir(&lib_dir), lib_dir.display().to_string()) {
-                let lib = t!(lib);
-                if path_is_dylib(&lib.path()) {
-                    self.fix_bin_or_dylib(&lib.path());
-                }
-            }
-        }
+        self.patch_component(&bin_root, &["rustfmt", "cargo-fmt"]);
 
         self.create(&rustfmt_stamp, &channel);
         Some(rustfmt_path)
@@ -588,20 +556,7 @@ fn download_toolchain(
                 download_component(self, filename, component, stamp_key);
             }
 
-            if self.should_fix_bins_and_dylibs() {
-                self.fix_bin_or_dylib(&bin_root.join("bin").join("rustc"));
-                self.fix_bin_or_dylib(&bin_root.join("bin").join("rustdoc"));
-                self.fix_bin_or_dylib(
-                    &bin_root.join("libexec").join("rust-analyzer-proc-macro-srv"),
-                );
-                let lib_dir = bin_root.join("lib");
-                for lib in t!(fs::read_dir(&lib_dir), lib_dir.display().to_string()) {
-                    let lib = t!(lib);
-                    if path_is_dylib(&lib.path()) {
-                        self.fix_bin_or_dylib(&lib.path());
-                    }
-                }
-            }
+            self.patch_component(&bin_root, &["rustc", "rustdoc", "cargo"]);
 
             t!(fs::write(rustc_stamp, stamp_key));
         }
@@ -694,6 +649,13 @@ fn download_component(
             None
         };
 
+        // Only artifacts with a pinned checksum can safely be downloaded from mirrors.
+        let mut urls: Vec<String> = match checksum {
+            Some(_) => self.dist_mirrors.iter().map(|mirror| format!("{mirror}/{url}")).collect(),
+            None => Vec::new(),
+        };
+        urls.push(format!("{base_url}/{url}"));
+
         let mut help_on_error = "";
         if destination == "ci-rustc" {
             help_on_error = "ERROR: failed to download pre-built rustc from CI
@@ -705,12 +667,7 @@ fn download_component(
 download-rustc = false
 ";
         }
-        self.download_file(&format!("{base_url}/{url}"), &tarball, help_on_error);
-        if let Some(sha256) = checksum {
-            if !self.verify(&tarball, sha256) {
-                panic!("failed to verify {}", tarball.display());
-            }
-        }
+        self.download_file(&urls, &tarball, checksum.map(String::as_str), help_on_error);
 
         self.unpack(&tarball, &bin_root, prefix);
     }
@@ -736,11 +693,7 @@ pub(crate) fn maybe_download_ci_llvm(&self) {
         if program_out_of_date(&llvm_stamp, &key) && !self.dry_run() {
             self.download_ci_llvm(&llvm_sha);
 
-            if self.should_fix_bins_and_dylibs() {
-                for entry in t!(fs::read_dir(llvm_root.join("bin"))) {
-                    self.fix_bin_or_dylib(&t!(entry).path());
-                }
-            }
+            self.patch_component(&llvm_root, &["llvm-config"]);
 
             // Update the timestamp of llvm-config to force rustc_llvm to be
             // rebuilt. This is a hacky workaround for a deficiency in Cargo where
@@ -756,16 +709,6 @@ pub(crate) fn maybe_download_ci_llvm(&self) {
             let llvm_config = llvm_root.join("bin").join(exe("llvm-config", self.build));
             t!(crate::utils::helpers::set_file_times(llvm_config, file_times));
 
-            if self.should_fix_bins_and_dylibs() {
-                let llvm_lib = llvm_root.join("lib");
-                for entry in t!(fs::read_dir(llvm_lib)) {
-                    let lib = t!(entry).path();
-                    if path_is_dylib(&lib) {
-                        self.fix_bin_or_dylib(&lib);
-                    }
-                }
-            }
-
             t!(fs::write(llvm_stamp, key));
         }
 
@@ -821,7 +764,8 @@ fn download_ci_llvm(&self, llvm_sha: &str) {
     [llvm]
     download-ci-llvm = false
     ";
-            self.download_file(&format!("{base}/{llvm_sha}/{filename}"), &tarball, help_on_error);
+            let url = format!("{base}/{llvm_sha}/{filename}");
+            self.download_file(&[url], &tarball, None, help_on_error);
         }
         let llvm_root = self.ci_llvm_root();
         self.unpack(&tarball, &llvm_root, "rust-dev");
@@ -0,0 +1,166 @@
+//! The remote cache of `[build.cache]`, which holds the artifacts of LLVM and of the stage 1
+//! standard library and compiler built by CI, so that the other jobs and the developers download
+//! them instead of building them.
+//!
+//! The artifacts are stored at `<url>/<name>/<target>/<key>.tar.xz`, where the key is the hash of
+//! their inputs computed by the step building them, next to their checksum in `.tar.xz.sha256`.
+//! The checksum is downloaded first, which makes a missing artifact a quick miss, and allows
+//! resuming an interrupted download of the archive with a range request, like the other downloads.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use sha2::Digest;
+use xz2::bufread::XzDecoder;
+use xz2::write::XzEncoder;
+
+use crate::core::config::TargetSelection;
+use crate::utils::exec::command;
+use crate::utils::helpers::hex_encode;
+use crate::{t, Config};
+
+#[cfg(test)]
+mod tests;
+
+impl Config {
+    /// Unpacks the artifacts `name` of `target` for `key` from the remote cache into `dst`, and
+    /// returns whether the cache had them.
+    pub(crate) fn fetch_cached_artifacts(
+        &self,
+        name: &str,
+        target: TargetSelection,
+        key: &str,
+        dst: &Path,
+    ) -> bool {
+        let Some(url) = self.cached_artifacts_url(name, target, key) else {
+            return false;
+        };
+        if self.dry_run() {
+            return false;
+        }
+        let Some(checksum) = self.fetch_checksum(&format!("{url}.sha256")) else {
+            self.verbose(|| println!("{name} for {key} isn't cached"));
+            return false;
+        };
+        let archive = self.tempdir().join(format!("{name}-{target}-{key}.tar.xz"));
+        if !self.try_download_file(&url, &archive, Some(&checksum)) {
+            eprintln!("WARNING: failed to download {name} from the cache, building it instead");
+            return false;
+        }
+        println!("extracting {} to {}", archive.display(), dst.display());
+        unpack_archive(&archive, dst);
+        self.remove(&archive);
+        true
+    }
+
+    /// Uploads the artifacts `name` of `target` for `key` to the remote cache, if
+    /// `build.cache.upload` is enabled. The archive holds the files of each `(path, file)` of
+    /// `files` at `path`.
+    pub(crate) fn store_cached_artifacts(
+        &self,
+        name: &str,
+        target: TargetSelection,
+        key: &str,
+        files: &[(PathBuf, PathBuf)],
+    ) {
+        if !self.cache.upload || self.dry_run() {
+            return;
+        }
+        let Some(url) = self.cached_artifacts_url(name, target, key) else {
+            return;
+        };
+        let archive = self.tempdir().join(format!("{name}-{target}-{key}.tar.xz"));
+        let checksum = archive.with_extension("xz.sha256");
+        eprintln!("packing {name} for the cache in {}", archive.display());
+        write_archive(&archive, files);
+        let mut hasher = sha2::Sha256::new();
+        t!(io::copy(&mut t!(File::open(&archive)), &mut hasher));
+        t!(fs::write(&checksum, hex_encode(hasher.finalize())));
+        // The checksum is uploaded last, so that the archive is complete once it can be found.
+        if !self.upload_file(&archive, &url)
+            || !self.upload_file(&checksum, &format!("{url}.sha256"))
+        {
+            eprintln!("WARNING: failed to upload {name} to the cache");
+        }
+        self.remove(&archive);
+        self.remove(&checksum);
+    }
+
+    fn cached_artifacts_url(
+        &self,
+        name: &str,
+        target: TargetSelection,
+        key: &str,
+    ) -> Option<String> {
+        let url = self.cache.url.as_ref()?;
+        Some(format!("{url}/{name}/{target}/{key}.tar.xz"))
+    }
+
+    /// Returns the checksum at `url`, or `None` if the artifacts aren't cached, without the
+    /// retries of the downloads which are known to exist.
+    fn fetch_checksum(&self, url: &str) -> Option<String> {
+        self.verbose(|| println!("download {url}"));
+        let mut curl = Command::new("curl");
+        curl.args(["--silent", "--fail", "--location", "--connect-timeout"]);
+        curl.arg(self.net.timeout.to_string());
+        if let Some(proxy) = &self.net.proxy {
+            curl.args(["--proxy", proxy.as_str()]);
+        }
+        if let Some(ca_bundle) = &self.net.ca_bundle {
+            curl.arg("--cacert").arg(ca_bundle);
+        }
+        let output = curl.arg(url).output().ok().filter(|output| output.status.success())?;
+        let checksum = String::from_utf8(output.stdout).ok()?;
+        // Like `sha256sum`, the checksum can be followed by the name of the file.
+        let checksum = checksum.split_whitespace().next()?;
+        (checksum.len() == 64).then(|| checksum.to_owned())
+    }
+
+    /// Uploads `file` to `url` with a PUT request, and returns whether it succeeded.
+    fn upload_file(&self, file: &Path, url: &str) -> bool {
+        println!("uploading {url}");
+        let timeout = self.net.timeout.to_string();
+        let retries = self.net.retries.to_string();
+        let mut curl = command("curl");
+        curl.args([
+            "--location",
+            "--connect-timeout",
+            timeout.as_str(),
+            "--retry",
+            retries.as_str(),
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--upload-file",
+        ]);
+        curl.arg(file);
+        if let Some(proxy) = &self.net.proxy {
+            curl.args(["--proxy", proxy.as_str()]);
+        }
+        if let Some(ca_bundle) = &self.net.ca_bundle {
+            curl.arg("--cacert").arg(ca_bundle);
+        }
+        curl.arg(url);
+        self.check_run(&mut curl)
+    }
+}
+
+/// Writes an archive of the files of each `(path, file)` of `files` at `path`. Symbolic links are
+/// kept, like the ones of the shared libraries of LLVM.
+fn write_archive(archive: &Path, files: &[(PathBuf, PathBuf)]) {
+    let encoder = XzEncoder::new(BufWriter::new(t!(File::create(archive))), 6);
+    let mut builder = tar::Builder::new(encoder);
+    builder.follow_symlinks(false);
+    for (path, file) in files {
+        t!(builder.append_path_with_name(file, path));
+    }
+    t!(t!(t!(builder.into_inner()).finish()).flush());
+}
+
+fn unpack_archive(archive: &Path, dst: &Path) {
+    let decoder = XzDecoder::new(BufReader::new(t!(File::open(archive))));
+    t!(fs::create_dir_all(dst));
+    t!(tar::Archive::new(decoder).unpack(dst));
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn archives() {
+    let dir = std::env::temp_dir().join(format!("bootstrap-artifact-cache-{}", std::process::id()));
+    let src = dir.join("src");
+    t!(fs::create_dir_all(src.join("lib")));
+    t!(fs::write(src.join("lib/libLLVM.so.19"), "llvm"));
+    t!(fs::write(src.join("stamp"), "t/build/lib/libLLVM.so.19\0"));
+
+    let archive = dir.join("llvm.tar.xz");
+    write_archive(
+        &archive,
+        &[
+            (PathBuf::from("lib/libLLVM.so.19"), src.join("lib/libLLVM.so.19")),
+            // Files can be stored at other paths, like relocated stamps.
+            (PathBuf::from("stage1/stamp"), src.join("stamp")),
+        ],
+    );
+    let dst = dir.join("dst");
+    unpack_archive(&archive, &dst);
+    assert_eq!(t!(fs::read_to_string(dst.join("lib/libLLVM.so.19"))), "llvm");
+    assert_eq!(t!(fs::read_to_string(dst.join("stage1/stamp"))), "t/build/lib/libLLVM.so.19\0");
+    t!(fs::remove_dir_all(&dir));
+}
@@ -0,0 +1,336 @@
+//! Patching of the binaries bootstrap downloads (the stage0 toolchain, CI rustc, CI LLVM, clippy
+//! and rustfmt). They are linked against glibc at the paths of a conventional Linux distribution,
+//! so on hosts without it the interpreter and the RPATH of the ELF files are changed with
+//! `patchelf`:
+//!
+//! - NixOS, or `patch-binaries-for-nix`: the dynamic linker and the libraries from `<nixpkgs>`
+//! - Guix: the dynamic linker of the binaries of the host, and the libraries in `LIBRARY_PATH`
+//! - glibc binaries on a musl host like Alpine: the glibc dynamic linker configured in
+//!   `build.binary-patching.dynamic-linker`, which isn't needed with `gcompat`
+//!
+//! The main executables of a component are run after patching, so that a patch which doesn't work
+//! is reported right away, instead of as a confusing failure of the first step using them.
+//!
+//! Keep the detection in sync with `binary_patching_host` in `src/bootstrap/bootstrap.py`, which
+//! patches the stage0 toolchain used to build bootstrap.
+
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+use std::{env, fmt};
+
+use super::{path_is_dylib, try_run};
+use crate::utils::helpers::exe;
+use crate::{t, Config};
+
+static PATCHER: OnceLock<Option<Patcher>> = OnceLock::new();
+
+/// The kinds of hosts which need patched binaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Host {
+    Nix,
+    Guix,
+    Musl,
+    /// Patching was enabled for a host which isn't detected, only the configuration is applied.
+    Other,
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Host::Nix => "Nix",
+            Host::Guix => "Guix",
+            Host::Musl => "a musl host",
+            Host::Other => "this host",
+        })
+    }
+}
+
+/// How the binaries are patched.
+#[derive(Debug)]
+struct Patcher {
+    host: Host,
+    patchelf: PathBuf,
+    /// The dynamic linker set as the interpreter of the executables, unchanged if `None`.
+    interpreter: Option<OsString>,
+    /// Directories added to the RPATH of the executables and the shared libraries.
+    rpath: Vec<PathBuf>,
+}
+
+impl Config {
+    /// Patches the executables and shared libraries of a downloaded component, which was unpacked
+    /// into `root`, if the host needs it. Checks that the executables `validate` in `bin` run
+    /// afterwards.
+    pub(super) fn patch_component(&self, root: &Path, validate: &[&str]) {
+        if self.dry_run() || self.binary_patcher().is_none() {
+            return;
+        }
+
+        let rustlib_bin = root.join("lib/rustlib").join(self.build.triple).join("bin");
+        let mut files = Vec::new();
+        for (dir, executables) in [
+            (root.join("bin"), true),
+            (root.join("libexec"), true),
+            (rustlib_bin.join("gcc-ld"), true),
+            (rustlib_bin, true),
+            (root.join("lib"), false),
+        ] {
+            let Ok(entries) = fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                // A symlink to a library would add the RPATH to it twice.
+                if !entry.file_type().is_ok_and(|file_type| file_type.is_file()) {
+                    continue;
+                }
+                let path = entry.path();
+                if (executables || path_is_dylib(&path)) && is_elf(&path) {
+                    files.push(path);
+                }
+            }
+        }
+
+        let validate: Vec<_> =
+            validate.iter().map(|name| root.join("bin").join(exe(name, self.build))).collect();
+        self.patch_binaries(&files, &validate);
+    }
+
+    /// Patches `files`, and checks that the executables `validate` run afterwards.
+    pub(super) fn patch_binaries(&self, files: &[PathBuf], validate: &[PathBuf]) {
+        if self.dry_run() {
+            return;
+        }
+        let Some(patcher) = self.binary_patcher() else {
+            return;
+        };
+
+        for file in files {
+            let interpreter = patcher.interpreter.as_ref().filter(|_| !path_is_dylib(file));
+            if patcher.rpath.is_empty() && interpreter.is_none() {
+                continue;
+            }
+            println!("attempting to patch {}", file.display());
+            let mut patchelf = Command::new(&patcher.patchelf);
+            if !patcher.rpath.is_empty() {
+                patchelf.arg("--add-rpath").arg(t!(env::join_paths(&patcher.rpath)));
+            }
+            if let Some(interpreter) = interpreter {
+                patchelf.arg("--set-interpreter").arg(interpreter);
+            }
+            // A failure is reported by the validation.
+            let _ = try_run(self, patchelf.arg(file));
+        }
+
+        for executable in validate.iter().filter(|executable| executable.exists()) {
+            let error = match Command::new(executable).arg("--version").output() {
+                Ok(output) if output.status.success() => continue,
+                Ok(output) => String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+                Err(e) => e.to_string(),
+            };
+            eprintln!(
+                "ERROR: {} doesn't run after patching it for {}: {error}",
+                executable.display(),
+                patcher.host
+            );
+            eprintln!(
+                "HELP: set `dynamic-linker` and `rpath` in the `[build.binary-patching]` section \
+                 of config.toml to the dynamic linker and the library directories of glibc on \
+                 this host, or `enable = false` if the binaries run without patching"
+            );
+            crate::exit!(1);
+        }
+    }
+
+    fn binary_patcher(&self) -> Option<&'static Patcher> {
+        PATCHER
+            .get_or_init(|| {
+                let patcher = self.create_patcher(self.binary_patching_host()?);
+                if let Some(patcher) = &patcher {
+                    eprintln!("INFO: Patching the downloaded binaries for {}.", patcher.host);
+                }
+                patcher
+            })
+            .as_ref()
+    }
+
+    /// Returns the kind of host the binaries are patched for, if any.
+    fn binary_patching_host(&self) -> Option<Host> {
+        // patchelf only works with ELF files, and on other Unix systems, there is no stable
+        // syscall interface, so Nix doesn't manage the global libc.
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        match (self.binary_patching.enable, self.patch_binaries_for_nix) {
+            (Some(false), _) | (None, Some(false)) => None,
+            (None, Some(true)) => Some(Host::Nix),
+            (enable, _) => match detect_host(&self.build.triple) {
+                Some(host) => Some(host),
+                None if enable == Some(true) => Some(Host::Other),
+                None => {
+                    if let Ok(in_nix_shell) = env::var("IN_NIX_SHELL") {
+                        eprintln!(
+                            "The IN_NIX_SHELL environment variable is `{in_nix_shell}`; \
+                             you may need to set `patch-binaries-for-nix=true` in config.toml"
+                        );
+                    }
+                    None
+                }
+            },
+        }
+    }
+
+    fn create_patcher(&self, host: Host) -> Option<Patcher> {
+        let settings = &self.binary_patching;
+        let mut patcher = Patcher {
+            host,
+            patchelf: settings.patchelf.clone().unwrap_or_else(|| "patchelf".into()),
+            interpreter: settings.dynamic_linker.clone().map(OsString::from),
+            rpath: settings.rpath.clone(),
+        };
+
+        match host {
+            Host::Nix => {
+                let nix_deps_dir = self.nix_deps()?;
+                if settings.patchelf.is_none() {
+                    patcher.patchelf = nix_deps_dir.join("bin/patchelf");
+                }
+                if patcher.interpreter.is_none() {
+                    let dynamic_linker_path = nix_deps_dir.join("nix-support/dynamic-linker");
+                    let dynamic_linker = t!(fs::read_to_string(dynamic_linker_path));
+                    patcher.interpreter = Some(dynamic_linker.trim_end().into());
+                }
+                patcher.rpath.push(t!(fs::canonicalize(nix_deps_dir)).join("lib"));
+            }
+            Host::Guix => {
+                // The binaries of the host use the dynamic linker of its glibc, e.g. `/bin/sh`
+                // which is a symlink into `/gnu/store`.
+                if patcher.interpreter.is_none() {
+                    let shell = fs::canonicalize("/bin/sh").unwrap_or_else(|_| "/bin/sh".into());
+                    let output = Command::new(&patcher.patchelf)
+                        .arg("--print-interpreter")
+                        .arg(shell)
+                        .output();
+                    match output {
+                        Ok(output) if output.status.success() => {
+                            let interpreter = String::from_utf8_lossy(&output.stdout);
+                            patcher.interpreter = Some(interpreter.trim().into());
+                        }
+                        _ => {
+                            eprintln!(
+                                "WARNING: failed to find the dynamic linker of Guix with `{}`, \
+                                 `guix shell patchelf` provides it",
+                                patcher.patchelf.display()
+                            );
+                            return None;
+                        }
+                    }
+                }
+                if let Some(library_path) = env::var_os("LIBRARY_PATH") {
+                    patcher.rpath.extend(env::split_paths(&library_path));
+                }
+            }
+            Host::Musl if patcher.interpreter.is_none() => {
+                eprintln!(
+                    "WARNING: the downloaded binaries need glibc, which isn't installed on this \
+                     musl host. Install `gcompat`, or set `build.binary-patching.dynamic-linker` \
+                     in config.toml to a glibc dynamic linker."
+                );
+                return None;
+            }
+            Host::Musl | Host::Other => {}
+        }
+
+        // The libraries of glibc are next to its dynamic linker.
+        if host != Host::Nix {
+            let lib_dir = patcher.interpreter.as_ref().and_then(|i| Path::new(i).parent());
+            if let Some(lib_dir) = lib_dir.filter(|dir| !patcher.rpath.iter().any(|p| p == dir)) {
+                patcher.rpath.insert(0, lib_dir.to_owned());
+            }
+        }
+        Some(patcher)
+    }
+
+    /// Builds the dependencies of the binaries with `nix-build`, and returns the directory which
+    /// contains them.
+    fn nix_deps(&self) -> Option<PathBuf> {
+        // Run `nix-build` to "build" each dependency (which will likely reuse
+        // the existing `/nix/store` copy, or at most download a pre-built copy).
+        //
+        // Importantly, we create a gc-root called `.nix-deps` in the `build/`
+        // directory, but still reference the actual `/nix/store` path in the rpath
+        // as it makes it significantly more robust against changes to the location of
+        // the `.nix-deps` location.
+        //
+        // bintools: Needed for the path of `ld-linux.so` (via `nix-support/dynamic-linker`).
+        // zlib: Needed as a system dependency of `libLLVM-*.so`.
+        // patchelf: Needed for patching ELF binaries (see doc comment above).
+        let nix_deps_dir = self.out.join(".nix-deps");
+        const NIX_EXPR: &str = "
+        with (import <nixpkgs> {});
+        symlinkJoin {
+            name = \"rust-stage0-dependencies\";
+            paths = [
+                zlib
+                patchelf
+                stdenv.cc.bintools
+            ];
+        }
+        ";
+        try_run(
+            self,
+            Command::new("nix-build").args([
+                Path::new("-E"),
+                Path::new(NIX_EXPR),
+                Path::new("-o"),
+                &nix_deps_dir,
+            ]),
+        )
+        .ok()?;
+        Some(nix_deps_dir)
+    }
+}
+
+/// Detects hosts which need patched binaries. The binaries are built for `build`.
+fn detect_host(build: &str) -> Option<Host> {
+    // Use `/etc/os-release` instead of `/etc/NIXOS`.
+    // The latter one does not exist on NixOS when using tmpfs as root.
+    let os_release = fs::read_to_string("/etc/os-release").unwrap_or_default();
+    match os_id(&os_release) {
+        Some("nixos") => return Some(Host::Nix),
+        Some("guix") => return Some(Host::Guix),
+        _ => {}
+    }
+
+    // glibc binaries on a host without glibc, e.g. Alpine. `gcompat` installs a dynamic linker
+    // with the name of the one of glibc.
+    let loaders = |prefix: &str| {
+        ["/lib", "/lib64"]
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .flatten()
+            .any(|entry| entry.file_name().to_string_lossy().starts_with(prefix))
+    };
+    if build.contains("-linux-gnu") && loaders("ld-musl-") && !loaders("ld-linux") {
+        return Some(Host::Musl);
+    }
+    None
+}
+
+/// Returns the `ID` of the distribution in `/etc/os-release`.
+fn os_id(os_release: &str) -> Option<&str> {
+    os_release
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("ID="))
+        .map(|id| id.trim_matches(['"', '\'']))
+}
+
+fn is_elf(path: &Path) -> bool {
+    let mut magic = [0; 4];
+    let read = File::open(path).and_then(|mut file| file.read_exact(&mut magic));
+    read.is_ok() && magic == *b"\x7fELF"
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+#[test]
+fn restart_corrupt_partial_download() {
+    let dir = std::env::temp_dir().join(format!("bootstrap-download-{}", std::process::id()));
+    t!(fs::create_dir_all(&dir));
+    let tempfile = dir.join("rustc.tar.xz");
+    let urls = vec![
+        "https://mirror.example/rustc.tar.xz".to_owned(),
+        "https://static.example/rustc.tar.xz".to_owned(),
+    ];
+
+    // The resumed download fails its checksum, which removes the partial download, so the same
+    // URL is tried once more from scratch.
+    t!(fs::write(&tempfile, "partial"));
+    let mut attempts = Vec::new();
+    let downloaded = download_from_urls(&urls, &tempfile, |url| {
+        attempts.push(url.to_owned());
+        if attempts.len() == 1 {
+            t!(fs::remove_file(&tempfile));
+            return false;
+        }
+        t!(fs::write(&tempfile, "complete"));
+        true
+    });
+    assert_eq!(downloaded, Some(urls[0].as_str()));
+    assert_eq!(attempts, [urls[0].clone(), urls[0].clone()]);
+    assert_eq!(t!(fs::read_to_string(&tempfile)), "complete");
+
+    // A download which didn't resume anything isn't retried, the next URL is tried instead.
+    t!(fs::remove_file(&tempfile));
+    let mut attempts = Vec::new();
+    let downloaded = download_from_urls(&urls, &tempfile, |url| {
+        attempts.push(url.to_owned());
+        false
+    });
+    assert_eq!(downloaded, None);
+    assert_eq!(attempts, urls);
+    t!(fs::remove_dir_all(&dir));
+}
+
+#[test]
+fn curl_net_args() {
+    let curl = |net: &NetConfig| {
+        let args = curl_args(net, Path::new("build/tmp/rustc.tar.xz"));
+        args.into_iter().map(|arg| arg.into_string().unwrap()).collect::<Vec<_>>()
+    };
+    let value_of = |args: &[String], flag: &str| {
+        args.iter().position(|arg| arg == flag).map(|i| args[i + 1].clone())
+    };
+
+    let default = curl(&NetConfig::default());
+    assert_eq!(value_of(&default, "--connect-timeout").as_deref(), Some("30"));
+    assert_eq!(value_of(&default, "--retry").as_deref(), Some("3"));
+    assert_eq!(value_of(&default, "--proxy"), None);
+    assert_eq!(value_of(&default, "--cacert"), None);
+
+    let net = NetConfig {
+        proxy: Some("http://proxy.example.com:3128".to_owned()),
+        ca_bundle: Some("/etc/ca.pem".into()),
+        timeout: 120,
+        retries: 0,
+    };
+    let args = curl(&net);
+    assert_eq!(value_of(&args, "--proxy").as_deref(), Some("http://proxy.example.com:3128"));
+    assert_eq!(value_of(&args, "--cacert").as_deref(), Some("/etc/ca.pem"));
+    assert_eq!(value_of(&args, "--speed-time").as_deref(), Some("120"));
+    assert_eq!(value_of(&args, "--retry").as_deref(), Some("0"));
+}
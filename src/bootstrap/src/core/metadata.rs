@@ -1,10 +1,10 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
 use serde_derive::Deserialize;
 
 use crate::utils::exec::command;
-use crate::{t, Build, Crate};
+use crate::{t, Build, Crate, CrateLib};
 
 /// For more information, see the output of
 /// <https://doc.rust-lang.org/nightly/cargo/commands/cargo-metadata.html>
@@ -20,6 +20,7 @@ struct Package {
     name: String,
     source: Option<String>,
     manifest_path: String,
+    edition: String,
     dependencies: Vec<Dependency>,
     targets: Vec<Target>,
     features: BTreeMap<String, Vec<String>>,
@@ -31,11 +32,17 @@ struct Package {
 struct Dependency {
     name: String,
     source: Option<String>,
+    rename: Option<String>,
+    #[serde(default)]
+    optional: bool,
+    kind: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Target {
+    name: String,
     kind: Vec<String>,
+    src_path: PathBuf,
 }
 
 /// Collects and stores package metadata of each workspace members into `build`,
@@ -43,6 +50,7 @@ struct Target {
 pub fn build(build: &mut Build) {
     for package in workspace_members(build) {
         if package.source.is_none() {
+            let lib = crate_lib(&package);
             let name = package.name;
             let mut path = PathBuf::from(package.manifest_path);
             path.pop();
@@ -59,6 +67,7 @@ pub fn build(build: &mut Build) {
                 path,
                 has_lib,
                 features: package.features.keys().cloned().collect(),
+                lib,
             };
             let relative_path = krate.local_path(build);
             build.crates.insert(name.clone(), krate);
@@ -72,6 +81,45 @@ pub fn build(build: &mut Build) {
     }
 }
 
+/// The library of `package`, with its normal dependencies which aren't optional.
+fn crate_lib(package: &Package) -> Option<CrateLib> {
+    let is_lib = |kind: &String| ["lib", "rlib", "dylib", "proc-macro"].contains(&kind.as_str());
+    let target = package.targets.iter().find(|target| target.kind.iter().any(is_lib))?;
+    let deps = package
+        .dependencies
+        .iter()
+        .filter(|dep| dep.kind.is_none() && !dep.optional)
+        .map(|dep| {
+            let name = dep.rename.as_ref().unwrap_or(&dep.name);
+            (name.replace('-', "_"), dep.name.replace('-', "_"))
+        })
+        .collect();
+    Some(CrateLib {
+        name: target.name.replace('-', "_"),
+        src_path: target.src_path.clone(),
+        edition: package.edition.clone(),
+        proc_macro: target.kind.iter().any(|kind| kind == "proc-macro"),
+        build_script: package.targets.iter().any(|t| t.kind.iter().any(|k| k == "custom-build")),
+        default_features: default_features(&package.features).into_iter().collect(),
+        deps,
+    })
+}
+
+/// The features enabled by the `default` feature of `features`, like cargo enables them, without
+/// the ones of the dependencies.
+fn default_features(features: &BTreeMap<String, Vec<String>>) -> BTreeSet<String> {
+    let mut enabled = BTreeSet::new();
+    let mut pending = vec!["default".to_owned()];
+    while let Some(feature) = pending.pop() {
+        if let Some(implied) = features.get(&feature) {
+            if enabled.insert(feature) {
+                pending.extend(implied.iter().cloned());
+            }
+        }
+    }
+    enabled
+}
+
 /// Invokes `cargo metadata` to get package metadata of each workspace member.
 ///
 /// This is used to resolve specific crate paths in `fn should_run` to compile
@@ -23,7 +23,7 @@
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::OnceLock;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 use std::{env, io, str};
 
 use build_helper::ci::gha;
@@ -180,6 +180,16 @@ pub struct Build {
 
     #[cfg(feature = "build-metrics")]
     metrics: crate::utils::metrics::BuildMetrics,
+    step_graph: crate::utils::step_graph::StepGraph,
+    timings: crate::utils::timings::Timings,
+    json_trace: crate::utils::json_trace::JsonTrace,
+    tui: crate::utils::tui::Tui,
+    scheduler: crate::utils::scheduler::Scheduler,
+    step_journal: crate::utils::step_journal::StepJournal,
+
+    /// The commands which would have been executed in a dry run, checked by the tests.
+    #[cfg(test)]
+    recorded_commands: RefCell<Vec<crate::utils::exec::RecordedCommand>>,
 }
 
 #[derive(Debug, Clone)]
@@ -189,6 +199,22 @@ struct Crate {
     path: PathBuf,
     has_lib: bool,
     features: Vec<String>,
+    /// The library of the crate, for checking it outside of cargo with `build.graft-ci-rustc`.
+    lib: Option<CrateLib>,
+}
+
+#[derive(Debug, Clone)]
+struct CrateLib {
+    /// The name of the crate of the library, like `rustc_borrowck`.
+    name: String,
+    src_path: PathBuf,
+    edition: String,
+    proc_macro: bool,
+    build_script: bool,
+    default_features: Vec<String>,
+    /// The crate names of the dependencies of the library which aren't optional, with the names
+    /// the library uses for them.
+    deps: Vec<(String, String)>,
 }
 
 impl Crate {
@@ -287,6 +313,8 @@ impl Build {
     pub fn new(mut config: Config) -> Build {
         let src = config.src.clone();
         let out = config.out.clone();
+        let json_trace = crate::utils::json_trace::JsonTrace::new(config.json_trace.as_deref());
+        let tui = crate::utils::tui::Tui::new(config.ui);
 
         #[cfg(unix)]
         // keep this consistent with the equivalent check in x.py:
@@ -426,6 +454,15 @@ pub fn new(mut config: Config) -> Build {
 
             #[cfg(feature = "build-metrics")]
             metrics: crate::utils::metrics::BuildMetrics::init(),
+            step_graph: Default::default(),
+            timings: Default::default(),
+            json_trace,
+            tui,
+            scheduler: Default::default(),
+            step_journal: Default::default(),
+
+            #[cfg(test)]
+            recorded_commands: RefCell::new(Vec::new()),
         };
 
         // If local-rust is the same major.minor as the current version, then force a
@@ -583,11 +620,10 @@ pub fn build(&mut self) {
 
         // hardcoded subcommands
         match &self.config.cmd {
-            Subcommand::Format { check, all } => {
+            Subcommand::Format { args } => {
                 return core::build_steps::format::format(
                     &builder::Builder::new(self),
-                    *check,
-                    *all,
+                    args,
                     &self.config.paths,
                 );
             }
@@ -597,6 +633,27 @@ pub fn build(&mut self) {
             Subcommand::Perf { .. } => {
                 return core::build_steps::perf::perf(&builder::Builder::new(self));
             }
+            Subcommand::BugReport { .. } => {
+                return core::build_steps::bug_report::bug_report(&builder::Builder::new(self));
+            }
+            Subcommand::Toolstate { record } => {
+                return core::build_steps::toolstate::toolstate(
+                    &builder::Builder::new(self),
+                    *record,
+                );
+            }
+            Subcommand::Completions { shell } => {
+                return core::build_steps::completions::completions(
+                    &builder::Builder::new(self),
+                    *shell,
+                );
+            }
+            Subcommand::Repro { replay } => {
+                return core::build_steps::repro::repro(
+                    &builder::Builder::new(self),
+                    replay.as_deref(),
+                );
+            }
             _ => (),
         }
 
@@ -609,7 +666,10 @@ pub fn build(&mut self) {
             }
         }
 
+        crate::utils::keep_stage::apply(self);
+
         if !self.config.dry_run() {
+            crate::utils::distributed::write_sccache_config(self);
             {
                 // We first do a dry-run. This is a sanity-check to ensure that
                 // steps don't do anything expensive in the dry-run.
@@ -618,12 +678,14 @@ pub fn build(&mut self) {
                 builder.execute_cli();
             }
             self.config.dry_run = DryRun::Disabled;
+            self.step_journal.open(self);
             let builder = builder::Builder::new(self);
             builder.execute_cli();
         } else {
             let builder = builder::Builder::new(self);
             builder.execute_cli();
         }
+        self.scheduler.wait_all(self);
 
         // Check for postponed failures from `test --no-fail-fast`.
         let failures = self.delayed_failures.borrow();
@@ -634,9 +696,12 @@ pub fn build(&mut self) {
             }
             exit!(1);
         }
+        self.step_journal.finish();
 
         #[cfg(feature = "build-metrics")]
         self.metrics.persist(self);
+        self.step_graph.persist(self);
+        self.timings.persist(self);
     }
 
     /// Clear out `dir` if `input` is newer.
@@ -912,6 +977,16 @@ fn rustc_snapshot_sysroot(&self) -> &Path {
         })
     }
 
+    /// Records a command of a dry run, so that the tests can check which commands bootstrap
+    /// would execute.
+    #[cfg(test)]
+    fn record_command(&self, command: &Command) {
+        if self.config.dry_run() {
+            let command = crate::utils::exec::RecordedCommand::new(command);
+            self.recorded_commands.borrow_mut().push(command);
+        }
+    }
+
     /// Execute a command and return its output.
     /// Note: Ideally, you should use one of the BootstrapCommand::run* functions to
     /// execute commands. They internally call this method.
@@ -923,6 +998,11 @@ fn run(
         stderr: OutputMode,
     ) -> CommandOutput {
         command.mark_as_executed();
+        #[cfg(test)]
+        self.record_command(command.as_command_mut());
+        if self.config.plan {
+            crate::utils::plan::print(command.as_command_mut());
+        }
         if self.config.dry_run() && !command.run_always {
             return CommandOutput::default();
         }
@@ -938,7 +1018,10 @@ fn run(
         cmd.stdout(stdout.stdio());
         cmd.stderr(stderr.stdio());
 
+        let start = Instant::now();
         let output = cmd.output();
+        let exit_code = output.as_ref().ok().and_then(|output| output.status.code());
+        self.json_trace.command(cmd, exit_code, start.elapsed());
 
         use std::fmt::Write;
 
@@ -1010,6 +1093,7 @@ fn run(
                     println!("Command has failed. Rerun with -v to see more details.");
                 }
             }
+            crate::utils::failure_report::handle(self, command.as_command());
             exit!(1);
         };
 
@@ -1975,14 +2059,18 @@ pub fn generate_smart_stamp_hash(
     dir: &Path,
     additional_input: &str,
 ) -> String {
-    let diff = helpers::git(Some(dir))
-        .allow_failure()
-        .arg("diff")
-        .run_capture_stdout(builder)
-        .stdout_if_ok()
-        .unwrap_or_default();
-
-    let status = helpers::git(Some(dir))
+    // `x why` compares the hash with the one of the stamp during its dry run.
+    let git = || {
+        let mut git = helpers::git(Some(dir));
+        if builder.config.why {
+            git.run_always();
+        }
+        git
+    };
+    let diff = git().allow_failure().arg("diff").run_capture_stdout(builder).stdout_if_ok();
+    let diff = diff.unwrap_or_default();
+
+    let status = git()
         .allow_failure()
         .arg("status")
         .arg("--porcelain")
@@ -2001,6 +2089,44 @@ pub fn generate_smart_stamp_hash(
     hex_encode(hasher.finalize().as_slice())
 }
 
+/// Computes a hash of the committed sources of `paths` of the repository and of additional input,
+/// which identifies the artifacts built from them on all machines, unlike
+/// `generate_smart_stamp_hash`, e.g. for the remote cache of `build.cache`.
+///
+/// Returns `None` if the sources have uncommitted changes, or aren't in a git repository.
+pub fn generate_committed_sources_hash(
+    builder: &Builder<'_>,
+    paths: &[&str],
+    additional_input: &str,
+) -> Option<String> {
+    if builder.config.dry_run() {
+        return None;
+    }
+    let status = helpers::git(Some(&builder.src))
+        .allow_failure()
+        .args(["status", "--porcelain", "--untracked-files=no", "--"])
+        .args(paths)
+        .run_capture_stdout(builder)
+        .stdout_if_ok()?;
+    if !status.is_empty() {
+        return None;
+    }
+    // The trees of the directories, and the commits of the submodules.
+    let objects = helpers::git(Some(&builder.src))
+        .allow_failure()
+        .arg("rev-parse")
+        .args(paths.iter().map(|path| format!("HEAD:{path}")))
+        .run_capture_stdout(builder)
+        .stdout_if_ok()?;
+
+    let mut hasher = sha2::Sha256::new();
+
+    hasher.update(objects);
+    hasher.update(additional_input);
+
+    Some(hex_encode(hasher.finalize().as_slice()))
+}
+
 /// Ensures that the behavior dump directory is properly initialized.
 pub fn prepare_behaviour_dump_dir(build: &Build) {
     static INITIALIZED: OnceLock<bool> = OnceLock::new();
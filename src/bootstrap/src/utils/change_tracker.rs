@@ -255,4 +255,249 @@ pub fn human_readable_changes(changes: &[ChangeInfo]) -> String {
         severity: ChangeSeverity::Warning,
         summary: "`download-ci-llvm = true` now checks if CI llvm is available and has become the default for the compiler profile",
     },
+    ChangeInfo {
+        change_id: 130212,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.dist-mirrors` to download the stage0 toolchain from mirrors of the dist server. Interrupted downloads are resumed by the next attempt.",
+    },
+    ChangeInfo {
+        change_id: 130240,
+        severity: ChangeSeverity::Info,
+        summary: "New section `[build.net]` with the options `proxy`, `ca-bundle`, `timeout` and `retries`, which apply to all downloads of bootstrap.",
+    },
+    ChangeInfo {
+        change_id: 130268,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.metrics-export` to export the build metrics, including the timings of the units built by Cargo, as a Prometheus textfile or an OTLP trace.",
+    },
+    ChangeInfo {
+        change_id: 130291,
+        severity: ChangeSeverity::Info,
+        summary: "`x vendor` now writes the Cargo configuration for the vendored sources to `.cargo/config.toml` and checks that all workspaces can be built offline.",
+    },
+    ChangeInfo {
+        change_id: 130305,
+        severity: ChangeSeverity::Info,
+        summary: "`x run` can now run any tool in `src/tools`, selected by its path or the name of its binary, with the arguments after `--`.",
+    },
+    ChangeInfo {
+        change_id: 130316,
+        severity: ChangeSeverity::Info,
+        summary: "New `x bug-report` subcommand, which collects the configuration, host information and the output of a failing command into `build/bug-report.tar.xz`.",
+    },
+    ChangeInfo {
+        change_id: 130330,
+        severity: ChangeSeverity::Info,
+        summary: "New `x toolstate` subcommand, which checks whether the tools build and pass their tests, compares the results with the ones recorded by `--record`, and reports outdated submodules.",
+    },
+    ChangeInfo {
+        change_id: 130345,
+        severity: ChangeSeverity::Info,
+        summary: "New `x clean --gc` flag, which removes stale artifacts of the build directory according to the new `[build.gc]` policies.",
+    },
+    ChangeInfo {
+        change_id: 130360,
+        severity: ChangeSeverity::Info,
+        summary: "The downloaded binaries are also patched on Guix and on musl hosts, which can be configured in the new `[build.binary-patching]` section.",
+    },
+    ChangeInfo {
+        change_id: 130370,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --staged` flag, which only formats the files staged in git.",
+    },
+    ChangeInfo {
+        change_id: 130380,
+        severity: ChangeSeverity::Info,
+        summary: "`x fmt` can also format Python, shell and TOML files, which is enabled in the new `[build.formatters]` section.",
+    },
+    ChangeInfo {
+        change_id: 130390,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --check --message-format=json` option, which prints a JSON record with the byte ranges to replace for each misformatted file.",
+    },
+    ChangeInfo {
+        change_id: 130400,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --since <rev>` flag, which formats the files modified since a revision instead of since the merge-base with upstream.",
+    },
+    ChangeInfo {
+        change_id: 130410,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --stdin <path>` flag, which formats the source read from stdin like the file `path` and prints it, for editor integrations.",
+    },
+    ChangeInfo {
+        change_id: 130420,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --watch` flag, which formats the files whenever they are saved.",
+    },
+    ChangeInfo {
+        change_id: 130430,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --base <branch>` flag, which formats the files modified since the merge-base with `branch` instead of the upstream branch.",
+    },
+    ChangeInfo {
+        change_id: 130440,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.formatters.manifests`, which formats the `Cargo.toml` manifests with taplo and sorts their dependency tables.",
+    },
+    ChangeInfo {
+        change_id: 130450,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --daemon` flag, which serves the format requests of editors on `build/fmt.sock` until `x fmt --stop`.",
+    },
+    ChangeInfo {
+        change_id: 130460,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --include-untracked` flag and `build.fmt-untracked` option, which format the files not tracked by git instead of skipping them.",
+    },
+    ChangeInfo {
+        change_id: 130470,
+        severity: ChangeSeverity::Info,
+        summary: "New `build.pin-rustfmt` option, which downloads the rustfmt pinned in `src/stage0` when the one of `build.rustfmt` is missing or has another version.",
+    },
+    ChangeInfo {
+        change_id: 130480,
+        severity: ChangeSeverity::Info,
+        summary: "New `build.formatters.markdown` option, which formats the ```rust code blocks of the markdown files in `src/doc` with rustfmt.",
+    },
+    ChangeInfo {
+        change_id: 130490,
+        severity: ChangeSeverity::Info,
+        summary: "New `build.fmt-jobs` and `build.fmt-batch-size` options, and `x fmt --processes` and `--batch-size` flags, which tune the concurrency of the formatters.",
+    },
+    ChangeInfo {
+        change_id: 130500,
+        severity: ChangeSeverity::Info,
+        summary: "New `x fmt --timings` flag, which prints the slowest files to format and writes the time of each file to `build/fmt-timings.json`.",
+    },
+    ChangeInfo {
+        change_id: 130510,
+        severity: ChangeSeverity::Info,
+        summary: "New `--dump-graph` flag, which writes the dependency graph of the steps of a dry run to `build/bootstrap-graph.dot` and `build/bootstrap-graph.json`.",
+    },
+    ChangeInfo {
+        change_id: 130520,
+        severity: ChangeSeverity::Info,
+        summary: "New `--json-trace <path>` flag, which writes the steps, commands, cargo artifacts and test suites of a build as newline-delimited JSON events.",
+    },
+    ChangeInfo {
+        change_id: 130530,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.step-cache`, enabled by default, which skips the tool builds whose inputs didn't change since the previous invocation of bootstrap.",
+    },
+    ChangeInfo {
+        change_id: 130540,
+        severity: ChangeSeverity::Info,
+        summary: "New `[build.cache]` section, a remote cache of the artifacts of LLVM and of the stage 1 standard library and compiler, which are downloaded instead of built when CI uploaded them.",
+    },
+    ChangeInfo {
+        change_id: 130550,
+        severity: ChangeSeverity::Info,
+        summary: "New option `[build.distributed]` to compile the crates on the workers of an `sccache-dist` scheduler.",
+    },
+    ChangeInfo {
+        change_id: 130560,
+        severity: ChangeSeverity::Info,
+        summary: "New flag `--plan` to print the commands of a dry run with their environment and working directory.",
+    },
+    ChangeInfo {
+        change_id: 130570,
+        severity: ChangeSeverity::Info,
+        summary: "New flag `--ui=tty` to show the running steps and the progress of cargo at the bottom of the terminal.",
+    },
+    ChangeInfo {
+        change_id: 130580,
+        severity: ChangeSeverity::Info,
+        summary: "New global flag `--timings` to write a report of the time spent in each step and crate to `build/timings`, which replaces the flag of `x fmt` of the same name.",
+    },
+    ChangeInfo {
+        change_id: 130590,
+        severity: ChangeSeverity::Info,
+        summary: "New `[[build.hooks]]` tables to run commands before or after some steps.",
+    },
+    ChangeInfo {
+        change_id: 130600,
+        severity: ChangeSeverity::Info,
+        summary: "Paths which match no step are now reported before running any step, with the closest paths as suggestions.",
+    },
+    ChangeInfo {
+        change_id: 130610,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.rustbook-jobs` to render the books of `x doc` in the background.",
+    },
+    ChangeInfo {
+        change_id: 130620,
+        severity: ChangeSeverity::Info,
+        summary: "The tools using the compiler crates now remove the libraries built by previous compilers from `stageN-tools`.",
+    },
+    ChangeInfo {
+        change_id: 130630,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.auto-keep-stage` to keep stage 0 when only the standard library changed.",
+    },
+    ChangeInfo {
+        change_id: 130640,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.graft-ci-rustc` to check some compiler crates against the ones downloaded by `rust.download-rustc`.",
+    },
+    ChangeInfo {
+        change_id: 130650,
+        severity: ChangeSeverity::Info,
+        summary: "New flag `--on-failure=report|shell` to write `build/failure-report.txt` or to start a shell when a command fails.",
+    },
+    ChangeInfo {
+        change_id: 130660,
+        severity: ChangeSeverity::Info,
+        summary: "New subcommand `x completions` to print the completion script of a shell, which also completes the paths of the steps.",
+    },
+    ChangeInfo {
+        change_id: 130670,
+        severity: ChangeSeverity::Info,
+        summary: "New `x repro` subcommand, which records an invocation with its effective configuration, toolchain, environment and steps into `build/repro.json`, and replays it with `--replay`.",
+    },
+    ChangeInfo {
+        change_id: 130680,
+        severity: ChangeSeverity::Info,
+        summary: "`config.toml` can now define named profiles as `[profiles.<name>]` tables, bundling settings with a default subcommand and paths, which are selected with `x --profile <name>`.",
+    },
+    ChangeInfo {
+        change_id: 130690,
+        severity: ChangeSeverity::Info,
+        summary: "New option `build.max-concurrent-links` limits how many crates are linked at once, and is the default of `llvm.link-jobs`.",
+    },
+    ChangeInfo {
+        change_id: 130700,
+        severity: ChangeSeverity::Info,
+        summary: "New `x why <paths>` explains why the artifacts of `x build <paths>` would be rebuilt, from the changes of `config.toml`, of the cargo invocations and of the inputs of the crates and LLVM.",
+    },
+    ChangeInfo {
+        change_id: 130710,
+        severity: ChangeSeverity::Info,
+        summary: "New global flag `--resume` continues an interrupted or failed invocation with the same arguments, skipping the steps it completed, recorded in `build/step-journal`.",
+    },
+    ChangeInfo {
+        change_id: 130720,
+        severity: ChangeSeverity::Info,
+        summary: "The unknown keys of `config.toml` and `--set` are reported with their table and the closest known key, and only warned about with the new flag `--allow-unknown-config`.",
+    },
+    ChangeInfo {
+        change_id: 130730,
+        severity: ChangeSeverity::Info,
+        summary: "The options of `config.toml` can be overridden by environment variables like `RUST_BOOTSTRAP_RUST_DEBUG_ASSERTIONS`, which `--set` takes precedence over.",
+    },
+    ChangeInfo {
+        change_id: 130740,
+        severity: ChangeSeverity::Info,
+        summary: "New option `include` merges other configuration files into `config.toml`, in order.",
+    },
+    ChangeInfo {
+        change_id: 130750,
+        severity: ChangeSeverity::Info,
+        summary: "New option `target.<triple>.inherits` fills the options of a target from another target, or from a template of the new `[target-templates.<name>]` tables.",
+    },
+    ChangeInfo {
+        change_id: 130760,
+        severity: ChangeSeverity::Info,
+        summary: "The lists of `config.toml`, like `build.tools`, can be edited by the layers of the configuration with the `\"<key>-\"` and `\"<key>+\"` keys, or with `--set <key>+=<list>` and `--set <key>-=<list>`.",
+    },
 ];
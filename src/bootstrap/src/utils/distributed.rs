@@ -0,0 +1,73 @@
+//! The distributed compilation of `[build.distributed]`, which ships the crates built by cargo to
+//! the workers of an `sccache-dist` scheduler, instead of compiling them all on this machine.
+//!
+//! Bootstrap doesn't have a protocol of its own: the rustc shim runs the compiler through sccache,
+//! as `RUSTC_WRAPPER_REAL`, whose client sends the crates and the toolchain compiling them to the
+//! scheduler, which assigns them to its workers. The workers are provisioned with `sccache-dist`,
+//! as described in its documentation. The client is configured by the `sccache-dist.toml` file
+//! written to the build directory, and the dependencies between the crates are still scheduled by
+//! cargo, with the number of jobs of `build.distributed.jobs`, so that more crates are compiled at
+//! once than the local machine could.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_derive::Serialize;
+
+use crate::core::config::DistributedConfig;
+use crate::utils::helpers::t;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Serialize, Debug, PartialEq)]
+struct SccacheConfig<'a> {
+    dist: Dist<'a>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct Dist<'a> {
+    scheduler_url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auth: Option<Auth<'a>>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct Auth<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    token: &'a str,
+}
+
+/// The configuration of the sccache client, set as `SCCACHE_CONF`.
+pub(crate) fn sccache_config_path(out: &Path) -> PathBuf {
+    out.join("sccache-dist.toml")
+}
+
+/// Writes the configuration of the sccache client, if the distributed compilation is enabled.
+pub(crate) fn write_sccache_config(build: &Build) {
+    let Some(distributed) = &build.config.distributed else {
+        return;
+    };
+    if build.config.incremental {
+        eprintln!(
+            "WARNING: sccache doesn't distribute incremental compilations, \
+             set `rust.incremental = false` to compile the crates on the workers"
+        );
+    }
+    let path = sccache_config_path(&build.out);
+    t!(fs::create_dir_all(&build.out));
+    t!(fs::write(&path, sccache_config(distributed)));
+    build.verbose(|| println!("distributing the crates to {}", distributed.scheduler_url));
+}
+
+fn sccache_config(distributed: &DistributedConfig) -> String {
+    let config = SccacheConfig {
+        dist: Dist {
+            scheduler_url: &distributed.scheduler_url,
+            auth: distributed.auth_token.as_deref().map(|token| Auth { kind: "token", token }),
+        },
+    };
+    t!(toml::to_string(&config))
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn sccache_configs() {
+    let mut distributed = DistributedConfig {
+        scheduler_url: "https://sccache.example.com".to_owned(),
+        auth_token: None,
+        sccache: "sccache".into(),
+        jobs: None,
+    };
+    assert_eq!(
+        sccache_config(&distributed),
+        "[dist]\nscheduler_url = \"https://sccache.example.com\"\n"
+    );
+
+    distributed.auth_token = Some("secret".to_owned());
+    assert_eq!(
+        sccache_config(&distributed),
+        "[dist]\n\
+         scheduler_url = \"https://sccache.example.com\"\n\
+         \n\
+         [dist.auth]\n\
+         type = \"token\"\n\
+         token = \"secret\"\n"
+    );
+}
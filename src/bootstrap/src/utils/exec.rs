@@ -1,6 +1,8 @@
 use std::ffi::OsStr;
 use std::fmt::{Debug, Formatter};
 use std::path::Path;
+#[cfg(test)]
+use std::path::PathBuf;
 use std::process::{Command, CommandArgs, CommandEnvs, ExitStatus, Output, Stdio};
 
 use build_helper::ci::CiEnv;
@@ -163,6 +165,18 @@ pub fn as_command_mut(&mut self) -> &mut Command {
         &mut self.command
     }
 
+    /// Provides access to the stdlib Command inside, to inspect it.
+    pub(crate) fn as_command(&self) -> &Command {
+        &self.command
+    }
+
+    /// Returns the command to execute, like `Build::run` does, for the commands running in the
+    /// background.
+    pub(crate) fn into_command(mut self) -> Command {
+        self.mark_as_executed();
+        self.command
+    }
+
     /// Mark the command as being executed, disarming the drop bomb.
     /// If this method is not called before the command is dropped, its drop will panic.
     pub fn mark_as_executed(&mut self) {
@@ -208,6 +222,40 @@ fn from(command: Command) -> Self {
     }
 }
 
+/// A command which bootstrap would have executed in a dry run, recorded for its tests.
+#[cfg(test)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    /// The environment variables set by the command, or removed if the value is `None`.
+    pub env: Vec<(String, Option<String>)>,
+    pub cwd: Option<PathBuf>,
+}
+
+#[cfg(test)]
+impl RecordedCommand {
+    pub fn new(command: &Command) -> Self {
+        let lossy = |s: &OsStr| s.to_string_lossy().into_owned();
+        RecordedCommand {
+            program: lossy(command.get_program()),
+            args: command.get_args().map(lossy).collect(),
+            env: command.get_envs().map(|(key, value)| (lossy(key), value.map(lossy))).collect(),
+            cwd: command.get_current_dir().map(Path::to_path_buf),
+        }
+    }
+
+    /// Returns the value of the environment variable `key`, if the command sets it.
+    pub fn env(&self, key: &str) -> Option<&str> {
+        self.env.iter().rev().find(|(k, _)| k == key).and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Returns the argument after `flag`, e.g. the value of `--manifest-path`.
+    pub fn arg_after(&self, flag: &str) -> Option<&str> {
+        self.args.iter().skip_while(|arg| *arg != flag).nth(1).map(String::as_str)
+    }
+}
+
 /// Represents the current status of `BootstrapCommand`.
 enum CommandStatus {
     /// The command has started and finished with some status.
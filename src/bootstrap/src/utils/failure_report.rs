@@ -0,0 +1,119 @@
+//! `--on-failure`, which helps to investigate a command of bootstrap which failed, before
+//! bootstrap exits. It's different from `--on-fail`, which is run by rustc when it fails.
+//!
+//! `--on-failure=report` runs the command again with `RUST_BACKTRACE=1`, and with `-v` for cargo,
+//! writes its working directory, its environment and its output to `build/failure-report.txt`,
+//! and prints the end of its output. `--on-failure=shell` starts a shell in the working directory
+//! of the command, with its environment, so that it can be run by hand, and continues once the
+//! shell exits.
+
+use std::collections::BTreeMap;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+use crate::core::config::flags::OnFailure;
+use crate::utils::helpers::t;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of lines of the output of the command printed by `--on-failure=report`.
+const TAIL_LINES: usize = 40;
+
+/// Handles the failure of `cmd` like `--on-failure` says.
+pub(crate) fn handle(build: &Build, cmd: &Command) {
+    match build.config.on_failure {
+        OnFailure::Nothing => {}
+        OnFailure::Report => report(build, cmd),
+        OnFailure::Shell => shell(cmd),
+    }
+}
+
+fn report(build: &Build, cmd: &Command) {
+    println!("on-failure: running the command again with RUST_BACKTRACE=1");
+    let (status, output) = match rerun_command(cmd).output() {
+        Ok(output) => {
+            let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            (output.status.to_string(), text)
+        }
+        Err(e) => (format!("not started: {e}"), String::new()),
+    };
+
+    let mut report = String::new();
+    writeln!(report, "command: {cmd:?}").unwrap();
+    writeln!(report, "working directory: {}", working_dir(cmd).display()).unwrap();
+    writeln!(report, "status of the second run: {status}").unwrap();
+    writeln!(report, "\nenvironment:").unwrap();
+    for (key, value) in environment(cmd) {
+        writeln!(report, "{}={}", key.to_string_lossy(), value.to_string_lossy()).unwrap();
+    }
+    writeln!(report, "\noutput of the second run:\n{output}").unwrap();
+    let path = build.out.join("failure-report.txt");
+    t!(fs::create_dir_all(&build.out));
+    t!(fs::write(&path, report));
+
+    println!("{}", tail(&output, TAIL_LINES));
+    println!("on-failure: the environment and the output are in {}", path.display());
+}
+
+fn shell(cmd: &Command) {
+    let shell =
+        env::var_os("SHELL").or_else(|| env::var_os("COMSPEC")).unwrap_or_else(|| "sh".into());
+    let dir = working_dir(cmd);
+    println!(
+        "on-failure: starting {} in {}, with the environment of the command in \
+         BOOTSTRAP_FAILED_COMMAND; bootstrap exits once it exits",
+        shell.to_string_lossy(),
+        dir.display()
+    );
+    let mut sh = Command::new(&shell);
+    sh.env_clear().envs(environment(cmd)).env("BOOTSTRAP_FAILED_COMMAND", format!("{cmd:?}"));
+    if let Err(e) = sh.current_dir(dir).status() {
+        eprintln!("on-failure: failed to start {}: {e}", shell.to_string_lossy());
+    }
+}
+
+/// `cmd`, with `RUST_BACKTRACE=1`, and with `-v` after the subcommand of cargo.
+fn rerun_command(cmd: &Command) -> Command {
+    let mut args: Vec<&OsStr> = cmd.get_args().collect();
+    if Path::new(cmd.get_program()).file_stem() == Some(OsStr::new("cargo")) && !args.is_empty() {
+        args.insert(1, OsStr::new("-v"));
+    }
+    let mut rerun = Command::new(cmd.get_program());
+    rerun.args(args).env_clear().envs(environment(cmd)).env("RUST_BACKTRACE", "1");
+    rerun.current_dir(working_dir(cmd));
+    rerun
+}
+
+/// The environment `cmd` runs with: the one of bootstrap, with the changes of `cmd`.
+fn environment(cmd: &Command) -> BTreeMap<OsString, OsString> {
+    let mut vars: BTreeMap<OsString, OsString> = env::vars_os().collect();
+    for (key, value) in cmd.get_envs() {
+        match value {
+            Some(value) => vars.insert(key.to_owned(), value.to_owned()),
+            None => vars.remove(key),
+        };
+    }
+    vars
+}
+
+fn working_dir(cmd: &Command) -> PathBuf {
+    match cmd.get_current_dir() {
+        Some(dir) => dir.to_owned(),
+        None => t!(env::current_dir()),
+    }
+}
+
+/// The last `lines` lines of `output`.
+fn tail(output: &str, lines: usize) -> &str {
+    let output = output.trim_end();
+    match output.rmatch_indices('\n').nth(lines.saturating_sub(1)) {
+        Some((index, _)) => &output[index + 1..],
+        None => output,
+    }
+}
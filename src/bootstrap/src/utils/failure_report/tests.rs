@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn rerun_cargo_verbosely() {
+    let mut cmd = Command::new("/build/stage0/bin/cargo");
+    cmd.args(["build", "--release", "--", "-v"]).env("CARGO_TARGET_DIR", "/build/out");
+    cmd.env_remove("RUSTFLAGS");
+    let rerun = rerun_command(&cmd);
+    let args: Vec<&OsStr> = rerun.get_args().collect();
+    assert_eq!(args, ["build", "-v", "--release", "--", "-v"]);
+
+    let envs: Vec<_> = rerun.get_envs().collect();
+    assert!(envs.contains(&(OsStr::new("RUST_BACKTRACE"), Some(OsStr::new("1")))));
+    assert!(envs.contains(&(OsStr::new("CARGO_TARGET_DIR"), Some(OsStr::new("/build/out")))));
+    assert!(!environment(&cmd).contains_key(OsStr::new("RUSTFLAGS")));
+    assert!(!envs.iter().any(|(key, _)| *key == "RUSTFLAGS"));
+
+    // The commands besides cargo are run again with the same arguments.
+    let mut cmd = Command::new("rustdoc");
+    cmd.arg("lib.rs");
+    assert_eq!(rerun_command(&cmd).get_args().collect::<Vec<_>>(), ["lib.rs"]);
+}
+
+#[test]
+fn tail_lines() {
+    assert_eq!(tail("a\nb\nc\n", 2), "b\nc");
+    assert_eq!(tail("a\nb\nc", 5), "a\nb\nc");
+    assert_eq!(tail("", 3), "");
+}
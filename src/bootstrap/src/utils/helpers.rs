@@ -476,6 +476,24 @@ pub fn hex_encode<T>(input: T) -> String
     })
 }
 
+/// Returns the Levenshtein distance of `a` and `b`, the number of characters to insert, remove or
+/// replace to change one into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // The distances of the prefix of `a` read so far to each prefix of `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let replaced = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = replaced.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 /// Create a `--check-cfg` argument invocation for a given name
 /// and it's values.
 pub fn check_cfg_arg(name: &str, values: Option<&[&str]>) -> String {
@@ -3,8 +3,8 @@
 use std::path::PathBuf;
 
 use crate::utils::helpers::{
-    check_cfg_arg, extract_beta_rev, hex_encode, make, program_out_of_date, set_file_times,
-    symlink_dir,
+    check_cfg_arg, edit_distance, extract_beta_rev, hex_encode, make, program_out_of_date,
+    set_file_times, symlink_dir,
 };
 use crate::{Config, Flags};
 
@@ -37,6 +37,14 @@ fn test_beta_rev_parsing() {
     assert_eq!(extract_beta_rev("invalid"), None);
 }
 
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance("compiler", "compiler"), 0);
+    assert_eq!(edit_distance("compilerr", "compiler"), 1);
+    assert_eq!(edit_distance("", "std"), 3);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+}
+
 #[test]
 fn test_string_to_hex_encode() {
     let input_string = "Hello, World!";
@@ -0,0 +1,145 @@
+//! A client of the jobserver of an enclosing make or cargo, which limits how many processes of
+//! the whole build run at the same time. See
+//! <https://www.gnu.org/software/make/manual/html_node/Job-Slots.html> for the protocol.
+//!
+//! Only the pipes of Unix are supported, either inherited as file descriptors or named with
+//! `fifo:PATH` since make 4.4. Without a jobserver, callers fall back to their own limit of
+//! processes, which plays the role of a jobserver of bootstrap.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SendError, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+use std::{env, thread};
+
+#[cfg(test)]
+mod tests;
+
+pub struct Client {
+    write: Arc<File>,
+    requests: Sender<()>,
+    tokens: Receiver<io::Result<u8>>,
+    /// Whether a token was requested from the helper thread and wasn't received yet.
+    pending: bool,
+}
+
+/// A token of the jobserver, which allows running one more process. It's given back to the
+/// jobserver when dropped.
+pub struct Token {
+    byte: u8,
+    write: Arc<File>,
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        let _ = (&*self.write).write_all(&[self.byte]);
+    }
+}
+
+impl Client {
+    /// Connects to the jobserver passed by `CARGO_MAKEFLAGS`, `MAKEFLAGS` or `MFLAGS`, if any.
+    pub fn from_env() -> Option<Client> {
+        let flags = ["CARGO_MAKEFLAGS", "MAKEFLAGS", "MFLAGS"]
+            .iter()
+            .find_map(|var| env::var(var).ok().filter(|flags| parse_auth(flags).is_some()))?;
+        let (read, write) = open(parse_auth(&flags)?)?;
+        let write = Arc::new(write);
+
+        // Reading from the pipe blocks until another process gives a token back, so a helper
+        // thread reads the tokens while the caller keeps reaping its own processes.
+        let (requests, requests_rx) = mpsc::channel::<()>();
+        let (tokens_tx, tokens) = mpsc::channel();
+        let helper_write = Arc::clone(&write);
+        thread::spawn(move || {
+            for () in requests_rx {
+                let mut byte = [0];
+                let token = (&read).read_exact(&mut byte).map(|()| byte[0]);
+                if let Err(SendError(Ok(byte))) = tokens_tx.send(token) {
+                    // The client went away, don't leak the token.
+                    let _ = (&*helper_write).write_all(&[byte]);
+                    break;
+                }
+            }
+        });
+        Some(Client { write, requests, tokens, pending: false })
+    }
+
+    /// Waits up to `timeout` for a token of the jobserver.
+    pub fn acquire_timeout(&mut self, timeout: Duration) -> io::Result<Option<Token>> {
+        if !self.pending {
+            self.requests.send(()).map_err(|_| helper_exited())?;
+            self.pending = true;
+        }
+        match self.tokens.recv_timeout(timeout) {
+            Ok(byte) => {
+                self.pending = false;
+                Ok(Some(Token { byte: byte?, write: Arc::clone(&self.write) }))
+            }
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(helper_exited()),
+        }
+    }
+}
+
+fn helper_exited() -> io::Error {
+    io::Error::other("the jobserver helper thread exited")
+}
+
+/// How `--jobserver-auth=` (or `--jobserver-fds=` before make 4.2) names the jobserver.
+#[derive(Debug, PartialEq, Eq)]
+enum Auth<'a> {
+    Fds(i32, i32),
+    Fifo(&'a str),
+}
+
+fn parse_auth(flags: &str) -> Option<Auth<'_>> {
+    // The last one wins, like in make.
+    let auth = flags.split_whitespace().rev().find_map(|flag| {
+        flag.strip_prefix("--jobserver-auth=").or_else(|| flag.strip_prefix("--jobserver-fds="))
+    })?;
+    if let Some(path) = auth.strip_prefix("fifo:") {
+        return Some(Auth::Fifo(path));
+    }
+    let (read, write) = auth.split_once(',')?;
+    Some(Auth::Fds(read.parse().ok()?, write.parse().ok()?))
+}
+
+#[cfg(unix)]
+fn open(auth: Auth<'_>) -> Option<(File, File)> {
+    use std::fs::OpenOptions;
+    use std::os::fd::FromRawFd;
+
+    match auth {
+        Auth::Fifo(path) => {
+            let fifo = OpenOptions::new().read(true).write(true).open(path).ok()?;
+            Some((fifo.try_clone().ok()?, fifo))
+        }
+        Auth::Fds(read, write) => {
+            // The descriptors are only inherited by the commands make knows to be recursive, e.g.
+            // they are prefixed with `+`, and may be closed or reused for something else otherwise.
+            let is_pipe = |fd| {
+                // SAFETY: `stat` is plain data, which `fstat` only writes to.
+                let mut stat = unsafe { std::mem::zeroed::<libc::stat>() };
+                let ok = unsafe { libc::fstat(fd, &mut stat) } == 0;
+                ok && stat.st_mode & libc::S_IFMT == libc::S_IFIFO
+            };
+            if !is_pipe(read) || !is_pipe(write) {
+                return None;
+            }
+            // Duplicate the descriptors so that dropping the files doesn't close the ones of make.
+            // SAFETY: `dup` returns a new descriptor owned by nothing else, or -1.
+            let dup = |fd| match unsafe { libc::dup(fd) } {
+                -1 => None,
+                fd => Some(unsafe { File::from_raw_fd(fd) }),
+            };
+            Some((dup(read)?, dup(write)?))
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn open(_auth: Auth<'_>) -> Option<(File, File)> {
+    // The jobservers of Windows are named semaphores, which aren't supported.
+    None
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+#[test]
+fn auth() {
+    assert_eq!(parse_auth("-j --jobserver-auth=3,4"), Some(Auth::Fds(3, 4)));
+    assert_eq!(parse_auth(" -j4 --jobserver-fds=5,6 -- FOO=bar"), Some(Auth::Fds(5, 6)));
+    assert_eq!(
+        parse_auth("-j --jobserver-auth=3,4 --jobserver-auth=fifo:/tmp/GMfifo1"),
+        Some(Auth::Fifo("/tmp/GMfifo1"))
+    );
+    assert_eq!(parse_auth("-j4"), None);
+    assert_eq!(parse_auth("--jobserver-auth=semaphore"), None);
+}
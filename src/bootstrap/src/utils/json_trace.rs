@@ -0,0 +1,118 @@
+//! `--json-trace <path>`, which writes what bootstrap does to a file as newline-delimited JSON
+//! events, for CI dashboards and profiling tools:
+//!
+//! - `step_start` and `step_finish`, with the `step` type, its `debug` representation and the
+//!   `depth` of the step in the steps being run, and `duration_secs` excluding the steps it ran
+//! - `command`, once a command completed, with its `program`, `args`, `exit_code` (`null` if it
+//!   couldn't be spawned or was killed by a signal) and `duration_secs`
+//! - `cargo_artifact`, for each `target` built by cargo, with its `crate_types`
+//! - `cmake`, once a CMake project of `llvm.rs` like `llvm` or `lld` was built for `target`
+//! - `test_suite`, once a compiletest suite ran, with its `suite`, `mode`, `compare_mode`, `stage`,
+//!   `host`, `target` and whether it `passed`
+//!
+//! All events have a `time` field, the number of seconds since bootstrap started. Dry runs, like
+//! the one checking the steps before building, aren't traced. The names of the events and fields
+//! are stable, as tooling relies on them: add new ones instead of changing them.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::utils::helpers::t;
+
+#[cfg(test)]
+mod tests;
+
+/// The trace file, shared by the clones of `Build`.
+#[derive(Clone)]
+pub(crate) struct JsonTrace {
+    file: Option<Arc<Mutex<File>>>,
+    start: Instant,
+}
+
+impl JsonTrace {
+    /// Creates the trace, truncating the file at `path`, if any.
+    pub(crate) fn new(path: Option<&Path>) -> Self {
+        let file = path.map(|path| Arc::new(Mutex::new(t!(File::create(path)))));
+        JsonTrace { file, start: Instant::now() }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.file.is_some()
+    }
+
+    pub(crate) fn step_start(&self, step: &str, debug: &str, depth: usize) {
+        self.emit("step_start", json!({ "step": step, "debug": debug, "depth": depth }));
+    }
+
+    pub(crate) fn step_finish(&self, step: &str, debug: &str, depth: usize, duration: Duration) {
+        self.emit(
+            "step_finish",
+            json!({
+                "step": step,
+                "debug": debug,
+                "depth": depth,
+                "duration_secs": duration.as_secs_f64(),
+            }),
+        );
+    }
+
+    pub(crate) fn command(&self, command: &Command, exit_code: Option<i32>, duration: Duration) {
+        if !self.is_enabled() {
+            return;
+        }
+        let args: Vec<_> = command.get_args().map(|arg| arg.to_string_lossy()).collect();
+        self.emit(
+            "command",
+            json!({
+                "program": command.get_program().to_string_lossy(),
+                "args": args,
+                "exit_code": exit_code,
+                "duration_secs": duration.as_secs_f64(),
+            }),
+        );
+    }
+
+    pub(crate) fn cargo_artifact(&self, target: &str, crate_types: &[impl AsRef<str>]) {
+        let crate_types: Vec<&str> = crate_types.iter().map(|ty| ty.as_ref()).collect();
+        self.emit("cargo_artifact", json!({ "target": target, "crate_types": crate_types }));
+    }
+
+    pub(crate) fn cmake(&self, project: &str, target: &str, duration: Duration) {
+        self.emit(
+            "cmake",
+            json!({ "project": project, "target": target, "duration_secs": duration.as_secs_f64() }),
+        );
+    }
+
+    /// Records a compiletest suite, whose fields are the ones of `TestSuiteMetadata::Compiletest`
+    /// of the build metrics.
+    pub(crate) fn test_suite(&self, suite: Value, passed: bool, duration: Duration) {
+        let mut fields = suite;
+        fields["passed"] = passed.into();
+        fields["duration_secs"] = duration.as_secs_f64().into();
+        self.emit("test_suite", fields);
+    }
+
+    fn emit(&self, event: &str, fields: Value) {
+        let Some(file) = &self.file else {
+            return;
+        };
+        let line = trace_line(event, self.start.elapsed(), fields);
+        // One write per line, so that the lines of the threads don't interleave.
+        t!(file.lock().unwrap().write_all(line.as_bytes()));
+    }
+}
+
+fn trace_line(event: &str, time: Duration, fields: Value) -> String {
+    let mut record = json!({ "event": event, "time": time.as_secs_f64() });
+    if let Value::Object(fields) = fields {
+        record.as_object_mut().unwrap().extend(fields);
+    }
+    format!("{record}\n")
+}
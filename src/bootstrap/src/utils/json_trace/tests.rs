@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn trace_lines() {
+    let line = trace_line(
+        "step_finish",
+        Duration::from_millis(1500),
+        json!({ "step": "compile::Std", "depth": 1, "duration_secs": 0.25 }),
+    );
+    assert!(line.ends_with('\n'));
+    assert_eq!(line.matches('\n').count(), 1);
+    let record: Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(
+        record,
+        json!({
+            "event": "step_finish",
+            "time": 1.5,
+            "step": "compile::Std",
+            "depth": 1,
+            "duration_secs": 0.25,
+        })
+    );
+}
+
+#[test]
+fn disabled_trace() {
+    let trace = JsonTrace::new(None);
+    assert!(!trace.is_enabled());
+    // Nothing is written without a file.
+    trace.step_start("compile::Std", "Std { .. }", 0);
+}
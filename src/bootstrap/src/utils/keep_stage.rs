@@ -0,0 +1,129 @@
+//! `build.auto-keep-stage`, which keeps the stage 0 artifacts like `--keep-stage 0` when only the
+//! standard library changed since the stage 1 compiler was built, so that working on the library
+//! doesn't rebuild the compiler, which is only linked to the standard library built by stage 0.
+//!
+//! The files which changed are the ones modified since the last build of the stage 1 compiler
+//! started, so that the changes of `git checkout`, `git rebase` and the editor are all seen, even
+//! the ones made while the compiler was being built. The compiler is rebuilt when anything else
+//! it's built from changed: its sources, the lockfile, bootstrap, the stage 0 toolchain, the
+//! version, `config.toml` and the files it includes, or the `RUST_BOOTSTRAP_*` overrides of the
+//! environment, which are recorded when the build of the compiler starts. The decision is printed
+//! with the file it comes from.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use walkdir::WalkDir;
+
+use crate::core::builder::Builder;
+use crate::utils::helpers::t;
+use crate::{Build, Compiler, Config, Mode};
+
+#[cfg(test)]
+mod tests;
+
+/// The inputs of the stage 1 compiler besides the standard library, relative to the sources.
+const COMPILER_INPUTS: &[&str] =
+    &["compiler", "Cargo.toml", "Cargo.lock", "src/bootstrap/src", "src/stage0", "src/version"];
+
+#[derive(Debug, PartialEq, Eq)]
+enum Decision {
+    /// The standard library changed, like this file, but not the compiler.
+    Keep(PathBuf),
+    /// This input of the compiler changed.
+    Rebuild(PathBuf),
+    /// Nothing changed, so there's nothing to keep.
+    Unchanged,
+}
+
+/// Adds stage 0 to `--keep-stage` when it's safe, for `build.auto-keep-stage`.
+pub(crate) fn apply(build: &mut Build) {
+    let config = &build.config;
+    if !config.auto_keep_stage
+        || config.stage == 0
+        || !config.keep_stage.is_empty()
+        || !config.keep_stage_std.is_empty()
+        || config.download_rustc()
+    {
+        return;
+    }
+    let compiler = Compiler { stage: 0, host: config.build };
+    let stamp = build.cargo_out(compiler, Mode::Rustc, config.build).join(".librustc.stamp");
+    let modified = |path: &Path| path.metadata().and_then(|metadata| metadata.modified()).ok();
+    let start_stamp = build_start_stamp(&stamp);
+    let started = match (modified(&start_stamp), modified(&stamp)) {
+        (Some(started), Some(finished)) if started <= finished => started,
+        _ => {
+            println!("auto-keep-stage: building the stage 1 compiler, whose last build is unknown");
+            return;
+        }
+    };
+    if fs::read_to_string(&start_stamp).ok() != Some(env_overrides(config)) {
+        println!(
+            "auto-keep-stage: rebuilding stage 0, as the RUST_BOOTSTRAP_* overrides of the \
+             environment changed since the stage 1 compiler was built"
+        );
+        return;
+    }
+    let mut inputs: Vec<PathBuf> =
+        COMPILER_INPUTS.iter().map(|input| build.src.join(input)).collect();
+    inputs.extend(config.config.iter().chain(&config.config_includes).cloned());
+
+    match decide(&inputs, &[build.src.join("library")], started) {
+        Decision::Keep(path) => {
+            println!(
+                "auto-keep-stage: keeping stage 0, as only the standard library changed since the \
+                 stage 1 compiler was built, like {}",
+                display(&build.src, &path)
+            );
+            build.config.keep_stage.push(0);
+        }
+        Decision::Rebuild(path) => println!(
+            "auto-keep-stage: rebuilding stage 0, as {} changed since the stage 1 compiler was \
+             built",
+            display(&build.src, &path)
+        ),
+        Decision::Unchanged => {}
+    }
+}
+
+/// Records when the build of the stage 1 compiler with the stamp `stamp` starts, with the overrides
+/// of the environment it's built with.
+pub(crate) fn record_build_start(builder: &Builder<'_>, stamp: &Path) {
+    if !builder.config.dry_run() {
+        t!(fs::create_dir_all(stamp.parent().unwrap()));
+        t!(fs::write(build_start_stamp(stamp), env_overrides(&builder.config)));
+    }
+}
+
+/// The `RUST_BOOTSTRAP_*` overrides of `config`, one `NAME=value` per line.
+fn env_overrides(config: &Config) -> String {
+    config.env_overrides.iter().map(|(name, value)| format!("{name}={value}\n")).collect()
+}
+
+fn build_start_stamp(stamp: &Path) -> PathBuf {
+    stamp.with_file_name(".librustc-start.stamp")
+}
+
+fn decide(compiler_inputs: &[PathBuf], library: &[PathBuf], started: SystemTime) -> Decision {
+    if let Some(path) = modified_since(compiler_inputs, started) {
+        return Decision::Rebuild(path);
+    }
+    match modified_since(library, started) {
+        Some(path) => Decision::Keep(path),
+        None => Decision::Unchanged,
+    }
+}
+
+/// Returns a file of `paths`, or of the directories of `paths`, modified after `time`.
+fn modified_since(paths: &[PathBuf], time: SystemTime) -> Option<PathBuf> {
+    paths.iter().flat_map(WalkDir::new).filter_map(Result::ok).find_map(|entry| {
+        let modified = entry.metadata().ok()?.modified().ok()?;
+        (entry.file_type().is_file() && modified > time).then(|| entry.into_path())
+    })
+}
+
+fn display(src: &Path, path: &Path) -> String {
+    path.strip_prefix(src).unwrap_or(path).display().to_string()
+}
@@ -0,0 +1,32 @@
+use std::fs::File;
+use std::time::{Duration, UNIX_EPOCH};
+
+use super::*;
+
+#[test]
+fn decisions() {
+    let dir = std::env::temp_dir().join(format!("bootstrap-keep-stage-{}", std::process::id()));
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    let write = |path: &str, secs| {
+        let path = dir.join(path);
+        t!(fs::create_dir_all(path.parent().unwrap()));
+        t!(t!(File::create(&path)).set_modified(at(secs)));
+    };
+    write("compiler/rustc_middle/src/lib.rs", 10);
+    write("Cargo.lock", 10);
+    write("library/core/src/lib.rs", 10);
+    let compiler = [dir.join("compiler"), dir.join("Cargo.lock")];
+    let library = [dir.join("library")];
+
+    assert_eq!(decide(&compiler, &library, at(20)), Decision::Unchanged);
+
+    write("library/alloc/src/vec/mod.rs", 30);
+    assert_eq!(
+        decide(&compiler, &library, at(20)),
+        Decision::Keep(dir.join("library/alloc/src/vec/mod.rs"))
+    );
+
+    write("Cargo.lock", 30);
+    assert_eq!(decide(&compiler, &library, at(20)), Decision::Rebuild(dir.join("Cargo.lock")));
+    t!(fs::remove_dir_all(&dir));
+}
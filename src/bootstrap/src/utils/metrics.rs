@@ -1,21 +1,38 @@
 //! This module is responsible for collecting metrics profiling information for the current build
 //! and dumping it to disk as JSON, to aid investigations on build and CI performance.
 //!
+//! The timings of the steps and of the units built by Cargo can also be exported in formats which
+//! are understood by monitoring systems, see `build.metrics-export` in `config.example.toml`:
+//!
+//! - `build/metrics.prom`, a textfile for the textfile collector of the Prometheus node exporter,
+//!   with the metrics of the last invocation
+//! - `build/metrics.otlp.json`, the last invocation as an OpenTelemetry trace in the OTLP JSON
+//!   encoding, which can be sent to the `/v1/traces` endpoint of a collector
+//!
+//! The metric, span and attribute names of these formats are stable, tooling aggregating them
+//! across builds relies on that.
+//!
 //! As this module requires additional dependencies not present during local builds, it's cfg'd
 //! away whenever the `build.metrics` config option is not set to `true`.
 
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
 use std::fs::File;
+use std::hash::{BuildHasher, Hash, Hasher, RandomState};
 use std::io::BufWriter;
+use std::path::Path;
 use std::time::{Duration, Instant, SystemTime};
 
 use build_helper::metrics::{
     JsonInvocation, JsonInvocationSystemStats, JsonNode, JsonRoot, JsonStepSystemStats, Test,
     TestOutcome, TestSuite, TestSuiteMetadata,
 };
+use serde_json::{json, Value};
 use sysinfo::{CpuRefreshKind, RefreshKind, System};
 
 use crate::core::builder::{Builder, Step};
+use crate::core::config::MetricsExport;
 use crate::utils::helpers::t;
 use crate::Build;
 
@@ -89,8 +106,13 @@ pub(crate) fn enter_step<S: Step>(&self, step: &S, builder: &Builder<'_>) {
             cpu_usage_time_sec: 0.0,
             duration_excluding_children_sec: Duration::ZERO,
 
+            start_time: SystemTime::now(),
+            timer_start: Instant::now(),
+            duration_including_children: Duration::ZERO,
+
             children: Vec::new(),
             test_suites: Vec::new(),
+            cargo_units: Vec::new(),
         });
     }
 
@@ -104,7 +126,8 @@ pub(crate) fn exit_step(&self, builder: &Builder<'_>) {
 
         self.collect_stats(&mut *state);
 
-        let step = state.running_steps.pop().unwrap();
+        let mut step = state.running_steps.pop().unwrap();
+        step.duration_including_children = step.timer_start.elapsed();
         if state.running_steps.is_empty() {
             state.finished_steps.push(step);
             state.timer_start = None;
@@ -144,6 +167,36 @@ pub(crate) fn record_test(&self, name: &str, outcome: TestOutcome, builder: &Bui
         }
     }
 
+    /// Records a unit built by Cargo, from a `timing-info` message.
+    pub(crate) fn record_cargo_unit(
+        &self,
+        package_id: &str,
+        target: &str,
+        mode: &str,
+        duration_sec: f64,
+        rmeta_duration_sec: Option<f64>,
+        builder: &Builder<'_>,
+    ) {
+        // Do not record dry runs, as they'd be duplicates of the actual steps.
+        if builder.config.dry_run() {
+            return;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let Some(step) = state.running_steps.last_mut() else {
+            return;
+        };
+        step.cargo_units.push(CargoUnitMetrics {
+            package_id: package_id.into(),
+            target: target.into(),
+            mode: mode.into(),
+            // Cargo reports the unit when it's finished.
+            end_time: SystemTime::now(),
+            duration: Duration::from_secs_f64(duration_sec),
+            rmeta_duration: rmeta_duration_sec.map(Duration::from_secs_f64),
+        });
+    }
+
     fn collect_stats(&self, state: &mut MetricsState) {
         let step = state.running_steps.last_mut().unwrap();
 
@@ -174,6 +227,19 @@ pub(crate) fn persist(&self, build: &Build) {
         };
         let steps = std::mem::take(&mut state.finished_steps);
 
+        for export in &build.config.metrics_exports {
+            match export {
+                MetricsExport::Prometheus => {
+                    let contents = prometheus_textfile(&state, &steps);
+                    write_atomically(&build.out.join("metrics.prom"), &contents);
+                }
+                MetricsExport::Otlp => {
+                    let trace = otlp_trace(&state, &steps, build);
+                    write_atomically(&build.out.join("metrics.otlp.json"), &trace.to_string());
+                }
+            }
+        }
+
         // Some of our CI builds consist of multiple independent CI invocations. Ensure all the
         // previous invocations are still present in the resulting file.
         let mut invocations = match std::fs::read(&dest) {
@@ -252,8 +318,304 @@ struct StepMetrics {
     cpu_usage_time_sec: f64,
     duration_excluding_children_sec: Duration,
 
+    start_time: SystemTime,
+    timer_start: Instant,
+    duration_including_children: Duration,
+
     children: Vec<StepMetrics>,
     test_suites: Vec<TestSuite>,
+    cargo_units: Vec<CargoUnitMetrics>,
+}
+
+struct CargoUnitMetrics {
+    package_id: String,
+    target: String,
+    /// The compile mode, e.g. `build`, `check`, `test` or `run-custom-build`.
+    mode: String,
+
+    end_time: SystemTime,
+    duration: Duration,
+    rmeta_duration: Option<Duration>,
+}
+
+/// Returns the name of a step in the exported metrics, e.g. `compile::Std`.
+fn step_name(type_: &str) -> &str {
+    type_.strip_prefix("bootstrap::core::build_steps::").unwrap_or(type_)
+}
+
+/// Returns the name of the package of a Cargo package ID, which is either like
+/// `registry+https://github.com/rust-lang/crates.io-index#serde@1.0.0` or (for older versions of
+/// Cargo) like `serde 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)`.
+fn package_name(package_id: &str) -> &str {
+    match package_id.split_once('#') {
+        // The name is omitted if it's the same as the last component of the path.
+        Some((source, fragment)) => match fragment.split_once('@') {
+            Some((name, _version)) => name,
+            None => source.rsplit('/').next().unwrap_or(source),
+        },
+        None => package_id.split(' ').next().unwrap_or(package_id),
+    }
+}
+
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_nanos().to_string()
+}
+
+/// Writes the file through a temporary file, so that collectors never read a partial file.
+fn write_atomically(dest: &Path, contents: &str) {
+    let tmp = dest.with_extension("tmp");
+    t!(std::fs::create_dir_all(dest.parent().unwrap()));
+    t!(std::fs::write(&tmp, contents));
+    t!(std::fs::rename(&tmp, dest));
+}
+
+/// The totals of a step or a Cargo unit across the whole invocation.
+#[derive(Default)]
+struct Totals {
+    count: u64,
+    duration_sec: f64,
+    cpu_usage_time_sec: f64,
+}
+
+fn collect_totals<'a>(
+    steps: &'a [StepMetrics],
+    step_totals: &mut BTreeMap<&'a str, Totals>,
+    unit_totals: &mut BTreeMap<(&'a str, &'a str, &'a str), Totals>,
+) {
+    for step in steps {
+        let totals = step_totals.entry(step_name(&step.type_)).or_default();
+        totals.count += 1;
+        totals.duration_sec += step.duration_excluding_children_sec.as_secs_f64();
+        totals.cpu_usage_time_sec += step.cpu_usage_time_sec;
+
+        for unit in &step.cargo_units {
+            let key = (package_name(&unit.package_id), unit.target.as_str(), unit.mode.as_str());
+            let totals = unit_totals.entry(key).or_default();
+            totals.count += 1;
+            totals.duration_sec += unit.duration.as_secs_f64();
+        }
+
+        collect_totals(&step.children, step_totals, unit_totals);
+    }
+}
+
+/// Escapes a label value of the Prometheus text format.
+fn escape_label(value: &str) -> String {
+    value.replace('\\', r"\\").replace('"', r#"\""#).replace('\n', r"\n")
+}
+
+/// Renders the metrics of the invocation in the Prometheus text exposition format. The durations
+/// of the steps exclude their children, so they add up to the duration of the invocation.
+fn prometheus_textfile(state: &MetricsState, steps: &[StepMetrics]) -> String {
+    let mut step_totals = BTreeMap::new();
+    let mut unit_totals = BTreeMap::new();
+    collect_totals(steps, &mut step_totals, &mut unit_totals);
+
+    let mut out = String::new();
+    let mut metric = |name: &str, help: &str, samples: Vec<(String, f64)>| {
+        writeln!(out, "# HELP {name} {help}").unwrap();
+        writeln!(out, "# TYPE {name} gauge").unwrap();
+        for (labels, value) in samples {
+            writeln!(out, "{name}{labels} {value}").unwrap();
+        }
+    };
+
+    let start = state.invocation_start.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+    metric(
+        "rust_bootstrap_invocation_start_time_seconds",
+        "Start time of the bootstrap invocation, since the Unix epoch.",
+        vec![(String::new(), start.as_secs_f64())],
+    );
+    metric(
+        "rust_bootstrap_invocation_duration_seconds",
+        "Duration of the bootstrap invocation.",
+        vec![(String::new(), state.invocation_timer_start.elapsed().as_secs_f64())],
+    );
+
+    let step_samples = |value: fn(&Totals) -> f64| -> Vec<(String, f64)> {
+        step_totals
+            .iter()
+            .map(|(step, totals)| (format!("{{step=\"{}\"}}", escape_label(step)), value(totals)))
+            .collect()
+    };
+    metric(
+        "rust_bootstrap_step_runs",
+        "Number of times a step was run.",
+        step_samples(|totals| totals.count as f64),
+    );
+    metric(
+        "rust_bootstrap_step_duration_seconds",
+        "Duration of the runs of a step, excluding the steps they ran.",
+        step_samples(|totals| totals.duration_sec),
+    );
+    metric(
+        "rust_bootstrap_step_cpu_seconds",
+        "CPU time used by the runs of a step, excluding the steps they ran.",
+        step_samples(|totals| totals.cpu_usage_time_sec),
+    );
+
+    let unit_samples = |value: fn(&Totals) -> f64| -> Vec<(String, f64)> {
+        unit_totals
+            .iter()
+            .map(|((package, target, mode), totals)| {
+                let labels = format!(
+                    "{{package=\"{}\",target=\"{}\",mode=\"{}\"}}",
+                    escape_label(package),
+                    escape_label(target),
+                    escape_label(mode)
+                );
+                (labels, value(totals))
+            })
+            .collect()
+    };
+    metric(
+        "rust_bootstrap_cargo_unit_builds",
+        "Number of times a unit was built by Cargo, e.g. once per stage.",
+        unit_samples(|totals| totals.count as f64),
+    );
+    metric(
+        "rust_bootstrap_cargo_unit_duration_seconds",
+        "Duration of the builds of a unit by Cargo.",
+        unit_samples(|totals| totals.duration_sec),
+    );
+
+    out
+}
+
+fn otlp_attribute(key: &str, value: Value) -> Value {
+    let value = match value {
+        Value::String(value) => json!({ "stringValue": value }),
+        // Integers are encoded as strings in the JSON encoding of OTLP.
+        Value::Number(value) if value.is_u64() => json!({ "intValue": value.to_string() }),
+        Value::Number(value) => json!({ "doubleValue": value }),
+        Value::Bool(value) => json!({ "boolValue": value }),
+        value => panic!("unsupported OTLP attribute value {value}"),
+    };
+    json!({ "key": key, "value": value })
+}
+
+/// Generates the IDs of the spans of a trace, which have to be unique but don't need to be random.
+struct SpanIds {
+    trace_id: String,
+    seed: u64,
+    next: u64,
+}
+
+impl SpanIds {
+    fn new(state: &MetricsState) -> Self {
+        let hash = |salt: u64| {
+            let mut hasher = RandomState::new().build_hasher();
+            (salt, std::process::id(), state.invocation_start).hash(&mut hasher);
+            hasher.finish()
+        };
+        SpanIds { trace_id: format!("{:016x}{:016x}", hash(0), hash(1)), seed: hash(2), next: 0 }
+    }
+
+    fn next(&mut self) -> String {
+        self.next += 1;
+        // Zero is not a valid span ID.
+        format!("{:016x}", self.seed.wrapping_add(self.next).max(1))
+    }
+}
+
+fn otlp_span(
+    ids: &mut SpanIds,
+    parent: Option<&str>,
+    name: &str,
+    start: SystemTime,
+    end: SystemTime,
+    attributes: Vec<Value>,
+) -> (String, Value) {
+    let span_id = ids.next();
+    let mut span = json!({
+        "traceId": ids.trace_id,
+        "spanId": span_id,
+        "name": name,
+        // SPAN_KIND_INTERNAL
+        "kind": 1,
+        "startTimeUnixNano": unix_nanos(start),
+        "endTimeUnixNano": unix_nanos(end),
+        "attributes": attributes,
+    });
+    if let Some(parent) = parent {
+        span["parentSpanId"] = parent.into();
+    }
+    (span_id, span)
+}
+
+fn otlp_step_spans(steps: &[StepMetrics], parent: &str, ids: &mut SpanIds, spans: &mut Vec<Value>) {
+    for step in steps {
+        let (span_id, span) = otlp_span(
+            ids,
+            Some(parent),
+            step_name(&step.type_),
+            step.start_time,
+            step.start_time + step.duration_including_children,
+            vec![
+                otlp_attribute("bootstrap.step.debug_repr", step.debug_repr.as_str().into()),
+                otlp_attribute(
+                    "bootstrap.step.duration_excluding_children_sec",
+                    step.duration_excluding_children_sec.as_secs_f64().into(),
+                ),
+                otlp_attribute("bootstrap.step.cpu_usage_time_sec", step.cpu_usage_time_sec.into()),
+            ],
+        );
+        spans.push(span);
+
+        for unit in &step.cargo_units {
+            let mut attributes = vec![
+                otlp_attribute("cargo.package.name", package_name(&unit.package_id).into()),
+                otlp_attribute("cargo.package.id", unit.package_id.as_str().into()),
+                otlp_attribute("cargo.target.name", unit.target.as_str().into()),
+                otlp_attribute("cargo.mode", unit.mode.as_str().into()),
+            ];
+            if let Some(rmeta_duration) = unit.rmeta_duration {
+                let rmeta_duration = rmeta_duration.as_secs_f64().into();
+                attributes.push(otlp_attribute("cargo.rmeta_duration_sec", rmeta_duration));
+            }
+            let start = unit.end_time.checked_sub(unit.duration).unwrap_or(unit.end_time);
+            let (_, span) =
+                otlp_span(ids, Some(&span_id), "cargo.unit", start, unit.end_time, attributes);
+            spans.push(span);
+        }
+
+        otlp_step_spans(&step.children, &span_id, ids, spans);
+    }
+}
+
+/// Renders the invocation as an OTLP `ExportTraceServiceRequest` in the JSON encoding, with a span
+/// named `bootstrap.invocation` containing a span per step (named like `compile::Std`), which in
+/// turn contain the spans of the steps they ran and a `cargo.unit` span per unit built by Cargo.
+fn otlp_trace(state: &MetricsState, steps: &[StepMetrics], build: &Build) -> Value {
+    let mut ids = SpanIds::new(state);
+    let mut spans = Vec::new();
+    let (root_id, root) = otlp_span(
+        &mut ids,
+        None,
+        "bootstrap.invocation",
+        state.invocation_start,
+        state.invocation_start + state.invocation_timer_start.elapsed(),
+        vec![otlp_attribute("bootstrap.subcommand", build.config.cmd.kind().as_str().into())],
+    );
+    spans.push(root);
+    otlp_step_spans(steps, &root_id, &mut ids, &mut spans);
+
+    let resource_attributes = vec![
+        otlp_attribute("service.name", "rust-bootstrap".into()),
+        otlp_attribute("host.name", System::host_name().unwrap_or_default().into()),
+        otlp_attribute("host.arch", std::env::consts::ARCH.into()),
+        otlp_attribute("os.type", std::env::consts::OS.into()),
+        otlp_attribute("bootstrap.build_triple", build.config.build.to_string().into()),
+    ];
+    json!({
+        "resourceSpans": [{
+            "resource": { "attributes": resource_attributes },
+            "scopeSpans": [{
+                "scope": { "name": "bootstrap" },
+                "spans": spans,
+            }],
+        }],
+    })
 }
 
 #[derive(serde_derive::Deserialize)]
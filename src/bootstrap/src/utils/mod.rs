@@ -6,11 +6,27 @@
 pub(crate) mod cc_detect;
 pub(crate) mod change_tracker;
 pub(crate) mod channel;
+pub(crate) mod distributed;
 pub(crate) mod exec;
+pub(crate) mod failure_report;
 pub(crate) mod helpers;
 pub(crate) mod job;
+pub(crate) mod jobserver;
+pub(crate) mod json_trace;
+pub(crate) mod keep_stage;
 #[cfg(feature = "build-metrics")]
 pub(crate) mod metrics;
+pub(crate) mod plan;
 pub(crate) mod render_tests;
+pub(crate) mod scheduler;
 pub(crate) mod shared_helpers;
+pub(crate) mod step_cache;
+pub(crate) mod step_graph;
+pub(crate) mod step_hooks;
+pub(crate) mod step_journal;
 pub(crate) mod tarball;
+#[cfg(test)]
+pub(crate) mod tests;
+pub(crate) mod timings;
+pub(crate) mod tui;
+pub(crate) mod why;
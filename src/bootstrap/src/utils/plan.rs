@@ -0,0 +1,62 @@
+//! `--plan`, a dry run which prints the commands the steps would execute, in the order they would
+//! execute them, for debugging the environment of cross compilations and auditing what bootstrap
+//! runs.
+//!
+//! Each command is printed as a shell command, which runs it in its working directory with the
+//! environment variables bootstrap sets or removes, like the ones of cargo for the flags of rustc.
+//! The rest of the environment is inherited from bootstrap. The commands which run during dry runs
+//! too, like the queries of git, are printed as well, since they're part of the build.
+
+use std::borrow::Cow;
+use std::env;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::Command;
+
+use crate::utils::helpers::t;
+
+#[cfg(test)]
+mod tests;
+
+/// Prints `command`, which runs in the working directory of bootstrap unless it has its own.
+pub(crate) fn print(command: &Command) {
+    println!("{}", format_command(command, &t!(env::current_dir())));
+}
+
+fn format_command(command: &Command, cwd: &Path) -> String {
+    let cwd = command.get_current_dir().unwrap_or(cwd);
+    let mut line = format!("(cd {} && ", quote(cwd.as_os_str()));
+    let envs: Vec<_> = command.get_envs().collect();
+    if !envs.is_empty() {
+        line.push_str("env");
+        // `env` removes the variables before setting the other ones.
+        for (key, _) in envs.iter().filter(|(_, value)| value.is_none()) {
+            line.push_str(&format!(" \\\n    -u {}", quote(key)));
+        }
+        for (key, value) in envs.iter().filter_map(|(key, value)| Some((key, (*value)?))) {
+            let mut assignment = key.to_os_string();
+            assignment.push("=");
+            assignment.push(value);
+            line.push_str(&format!(" \\\n    {}", quote(&assignment)));
+        }
+        line.push_str(" \\\n    ");
+    }
+    line.push_str(&quote(command.get_program()));
+    for arg in command.get_args() {
+        line.push(' ');
+        line.push_str(&quote(arg));
+    }
+    line.push(')');
+    line
+}
+
+/// Quotes `s` for a POSIX shell, unless it only has characters which don't need to be quoted.
+fn quote(s: &OsStr) -> Cow<'_, str> {
+    let s = s.to_string_lossy();
+    let safe = |c: char| c.is_ascii_alphanumeric() || "_-./=:,+@%".contains(c);
+    if !s.is_empty() && s.chars().all(safe) {
+        s
+    } else {
+        format!("'{}'", s.replace('\'', r"'\''")).into()
+    }
+}
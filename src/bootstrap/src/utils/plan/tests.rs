@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn commands() {
+    let mut git = Command::new("git");
+    git.args(["log", "--format=%H", "-n1"]);
+    assert_eq!(format_command(&git, Path::new("/src")), "(cd /src && git log --format=%H -n1)");
+
+    let mut cargo = Command::new("/build/stage0/bin/cargo");
+    cargo.args(["build", "--features", "panic-unwind backtrace"]).current_dir("/src/library");
+    cargo.env("RUSTFLAGS", "-Ctarget-feature=+crt-static -Zon-broken-pipe=kill");
+    cargo.env("CARGO_TARGET_DIR", "/build/stage1-std").env_remove("RUSTC_LOG");
+    assert_eq!(
+        format_command(&cargo, Path::new("/src")),
+        "(cd /src/library && env \\\n    \
+         -u RUSTC_LOG \\\n    \
+         CARGO_TARGET_DIR=/build/stage1-std \\\n    \
+         'RUSTFLAGS=-Ctarget-feature=+crt-static -Zon-broken-pipe=kill' \\\n    \
+         /build/stage0/bin/cargo build --features 'panic-unwind backtrace')"
+    );
+}
+
+#[test]
+fn quoting() {
+    assert_eq!(quote(OsStr::new("--stage=1")), "--stage=1");
+    assert_eq!(quote(OsStr::new("")), "''");
+    assert_eq!(quote(OsStr::new("it's")), r"'it'\''s'");
+}
@@ -34,6 +34,9 @@ pub(crate) fn try_run_tests(
     cmd: &mut BootstrapCommand,
     stream: bool,
 ) -> bool {
+    if builder.config.plan {
+        crate::utils::plan::print(cmd.as_command_mut());
+    }
     if builder.config.dry_run() {
         cmd.mark_as_executed();
         return true;
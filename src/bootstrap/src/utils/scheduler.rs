@@ -0,0 +1,230 @@
+//! The commands which steps run in the background with `build.rustbook-jobs`, so that the steps
+//! which don't depend on each other, like the books of `x doc`, run at the same time instead of one
+//! after another.
+//!
+//! A step hands its last commands, whose outputs only the steps depending on it read, to
+//! `Builder::run_in_background`, and returns. `Builder::ensure` waits for the commands started by
+//! a step and its dependencies before returning it to the step which ensured it, unless the step
+//! is `Step::DETACHED`, so the steps which run concurrently are the ones called from the command
+//! line, which are independent. The commands writing into the same directory still run one after
+//! another, and at most `build.rustbook-jobs` of them run at once. A step reading or removing a
+//! directory first waits for the commands writing into it, see `Builder::wait_for_writes_to`.
+//!
+//! Only the rendering of the books with rustbook runs in the background so far. The steps running
+//! cargo, like the builds of rustdoc and clippy, still run one after another, as they share the
+//! `target` directories and the jobserver of cargo.
+//!
+//! The output of each command is captured, and printed as a group once it finishes, so that the
+//! output of the steps isn't interleaved.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::utils::exec::{BehaviorOnFailure, BootstrapCommand};
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+/// The commands running in the background, shared by the clones of `Build`.
+#[derive(Clone, Default)]
+pub(crate) struct Scheduler {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The number of jobs started, which is the id of the next one.
+    started: usize,
+    /// The jobs which weren't waited for yet, the oldest first.
+    running: Vec<Job>,
+    /// The ids of the jobs started by each step and its dependencies, by the type and the `Debug`
+    /// representation of the step.
+    steps: HashMap<String, Range<usize>>,
+}
+
+struct Job {
+    id: usize,
+    label: String,
+    /// The directory the command writes into.
+    out: PathBuf,
+    /// The `Debug` representation of the command, for its failure.
+    command: String,
+    failure_behavior: BehaviorOnFailure,
+    handle: JoinHandle<JobOutput>,
+}
+
+#[derive(Debug, Default)]
+struct JobOutput {
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+    /// Why the command failed, if it did.
+    error: Option<String>,
+}
+
+impl Scheduler {
+    /// Whether the commands of the steps can run in the background.
+    pub(crate) fn is_enabled(&self, build: &Build) -> bool {
+        build.config.rustbook_jobs > 1 && !build.config.dry_run()
+    }
+
+    /// The id of the next job, so that the jobs started by a step can be found.
+    pub(crate) fn next_job(&self) -> usize {
+        self.state.lock().unwrap().started
+    }
+
+    /// Runs `command`, which writes into `out`, in the background, or right away when the commands
+    /// of the steps don't run in the background.
+    pub(crate) fn run(
+        &self,
+        build: &Build,
+        label: String,
+        out: &Path,
+        mut command: BootstrapCommand,
+    ) {
+        if !self.is_enabled(build) {
+            command.run(build);
+            return;
+        }
+
+        // Waits for the jobs writing into the same directory, and for a free job.
+        self.wait_for_conflicting(out, build);
+        loop {
+            let job = {
+                let mut state = self.state.lock().unwrap();
+                if state.running.len() < build.config.rustbook_jobs as usize {
+                    break;
+                }
+                state.running.remove(0)
+            };
+            finish(job, build);
+        }
+
+        build.verbose(|| println!("running in the background: {command:?}"));
+        let description = format!("{command:?}");
+        let failure_behavior = command.failure_behavior;
+        let cmd = command.into_command();
+        let handle = thread::spawn(move || run_job(cmd));
+        let mut state = self.state.lock().unwrap();
+        let id = state.started;
+        state.started += 1;
+        state.running.push(Job {
+            id,
+            label,
+            out: out.to_owned(),
+            command: description,
+            failure_behavior,
+            handle,
+        });
+    }
+
+    /// Records the jobs started by the step `key` and its dependencies, and waits for them if
+    /// their outputs are read right away.
+    pub(crate) fn step_finished(&self, key: String, jobs: Range<usize>, wait: bool, build: &Build) {
+        if jobs.is_empty() {
+            return;
+        }
+        if wait {
+            self.wait_for(jobs.clone(), build);
+        }
+        self.state.lock().unwrap().steps.insert(key, jobs);
+    }
+
+    /// Waits for the jobs started by the step `key` when it ran, for a step depending on it.
+    pub(crate) fn wait_for_step(&self, key: &str, build: &Build) {
+        let jobs = self.state.lock().unwrap().steps.get(key).cloned();
+        if let Some(jobs) = jobs {
+            self.wait_for(jobs, build);
+        }
+    }
+
+    /// Waits for the jobs writing into `out`, or into a directory which is in it or which it's in.
+    pub(crate) fn wait_for_conflicting(&self, out: &Path, build: &Build) {
+        loop {
+            let job = take_conflicting(&mut self.state.lock().unwrap(), out);
+            let Some(job) = job else {
+                return;
+            };
+            finish(job, build);
+        }
+    }
+
+    pub(crate) fn wait_all(&self, build: &Build) {
+        self.wait_for(0..usize::MAX, build);
+    }
+
+    fn wait_for(&self, jobs: Range<usize>, build: &Build) {
+        loop {
+            let job = {
+                let mut state = self.state.lock().unwrap();
+                match state.running.iter().position(|job| jobs.contains(&job.id)) {
+                    Some(index) => state.running.remove(index),
+                    None => return,
+                }
+            };
+            finish(job, build);
+        }
+    }
+}
+
+/// Whether commands writing into `a` and `b` can't run at the same time, because one of the
+/// directories is in the other, like a book and the books of its older editions.
+fn conflicts(a: &Path, b: &Path) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
+
+/// Removes the oldest job of `state` writing into a directory conflicting with `out`.
+fn take_conflicting(state: &mut State, out: &Path) -> Option<Job> {
+    let index = state.running.iter().position(|job| conflicts(&job.out, out))?;
+    Some(state.running.remove(index))
+}
+
+fn run_job(mut cmd: Command) -> JobOutput {
+    match cmd.stdin(Stdio::null()).output() {
+        Ok(output) => JobOutput {
+            error: (!output.status.success())
+                .then(|| format!("Expected success, got {}", output.status)),
+            stdout: output.stdout,
+            stderr: output.stderr,
+        },
+        Err(e) => JobOutput {
+            error: Some(format!("It was not possible to execute the command: {e:?}")),
+            ..Default::default()
+        },
+    }
+}
+
+/// Prints the output of `job` once it finishes, and handles its failure like `Build::run`.
+fn finish(job: Job, build: &Build) {
+    let output = job.handle.join().expect("a command running in the background panicked");
+    if !output.stdout.is_empty() || !output.stderr.is_empty() {
+        let mut stdout = io::stdout().lock();
+        let _ = writeln!(stdout, "---- {} ----", job.label);
+        let _ = stdout.write_all(&output.stdout);
+        let _ = stdout.flush();
+        drop(stdout);
+        let _ = io::stderr().write_all(&output.stderr);
+    }
+    let Some(error) = output.error else {
+        return;
+    };
+    let message = format!(
+        "\nCommand {} did not execute successfully.\n{error}\nRun in the background for: {}",
+        job.command, job.label
+    );
+    match job.failure_behavior {
+        BehaviorOnFailure::DelayFail if !build.fail_fast => {
+            build.delayed_failures.borrow_mut().push(message);
+        }
+        BehaviorOnFailure::DelayFail | BehaviorOnFailure::Exit => {
+            println!("{message}");
+            crate::exit!(1);
+        }
+        BehaviorOnFailure::Ignore => {}
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn conflicting_dirs() {
+    let book = Path::new("build/host/doc/book");
+    assert!(conflicts(book, book));
+    assert!(conflicts(book, Path::new("build/host/doc/book/first-edition")));
+    assert!(conflicts(Path::new("build/host/doc/book/2018-edition"), book));
+    assert!(!conflicts(book, Path::new("build/host/doc/nomicon")));
+    // The paths are compared by components.
+    assert!(!conflicts(book, Path::new("build/host/doc/books")));
+}
+
+#[test]
+#[cfg(unix)]
+fn job_output() {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "echo out; echo err >&2"]);
+    let output = run_job(cmd);
+    assert_eq!(output.stdout, b"out\n");
+    assert_eq!(output.stderr, b"err\n");
+    assert_eq!(output.error, None);
+
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "exit 3"]);
+    assert!(run_job(cmd).error.unwrap().starts_with("Expected success"));
+    assert!(run_job(Command::new("/nonexistent/command")).error.is_some());
+}
+
+#[test]
+fn conflicting_jobs() {
+    let job = |id, out: &str| Job {
+        id,
+        label: out.to_owned(),
+        out: PathBuf::from(out),
+        command: String::new(),
+        failure_behavior: BehaviorOnFailure::Exit,
+        handle: thread::spawn(JobOutput::default),
+    };
+    let running = vec![job(0, "doc/book"), job(1, "doc/nomicon"), job(2, "doc/book/2018-edition")];
+    let mut state = State { running, ..Default::default() };
+
+    // An older edition of the book waits for the main book, which removes it, and for itself.
+    let out = Path::new("doc/book/first-edition");
+    assert_eq!(take_conflicting(&mut state, out).map(|job| job.id), Some(0));
+    assert!(take_conflicting(&mut state, out).is_none());
+    let out = Path::new("doc/book/2018-edition");
+    assert_eq!(take_conflicting(&mut state, out).map(|job| job.id), Some(2));
+    assert_eq!(state.running.len(), 1);
+}
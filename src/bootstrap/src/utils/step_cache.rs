@@ -0,0 +1,122 @@
+//! The cache of the steps in `build/step-cache`, enabled by `build.step-cache`, which lets a step
+//! skip its work when its inputs didn't change since a previous invocation of bootstrap ran it.
+//!
+//! The entry of a step holds a key and the size and modification time of each input file. The key
+//! is the digest of what configures the step, like its cargo invocation, which includes the flags
+//! derived from `config.toml`. The inputs are the source files and the upstream artifacts, like the
+//! files of the dep-info written by cargo. A step is fresh when its key and its inputs match the
+//! ones of its entry, and its outputs exist.
+//!
+//! Tool builds use it, to skip running cargo for the tools which are up to date.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde_derive::{Deserialize, Serialize};
+use sha2::Digest;
+
+use crate::core::builder::Builder;
+use crate::core::config::TargetSelection;
+use crate::utils::helpers::{hex_encode, t};
+
+#[cfg(test)]
+mod tests;
+
+/// The entry of a step in the cache, unused when the cache is disabled and during dry runs.
+pub(crate) struct StepCache {
+    path: PathBuf,
+    key: String,
+    enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Entry {
+    key: String,
+    inputs: Vec<Input>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Input {
+    path: PathBuf,
+    stat: Option<Stat>,
+}
+
+/// The size and the modification time of a file.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Stat {
+    len: u64,
+    secs: u64,
+    nanos: u32,
+}
+
+impl StepCache {
+    /// Returns the entry of the step `name` for `target`, whose key is the digest of `key`.
+    pub(crate) fn new(
+        builder: &Builder<'_>,
+        name: &str,
+        target: TargetSelection,
+        key: &str,
+    ) -> Self {
+        let path = builder.out.join("step-cache").join(target).join(format!("{name}.json"));
+        let key = hex_encode(sha2::Sha256::digest(key));
+        StepCache { path, key, enabled: builder.config.step_cache && !builder.config.dry_run() }
+    }
+
+    /// Whether the step ran with the same key and inputs, and its `outputs` exist.
+    pub(crate) fn is_fresh(&self, outputs: &[&Path]) -> bool {
+        if !self.enabled || !outputs.iter().all(|output| output.exists()) {
+            return false;
+        }
+        let Ok(entry) = fs::read(&self.path) else {
+            return false;
+        };
+        let Ok(entry) = serde_json::from_slice::<Entry>(&entry) else {
+            return false;
+        };
+        entry.key == self.key && entry.inputs.iter().all(|input| stat(&input.path) == input.stat)
+    }
+
+    /// Records the inputs of the step, once it succeeded.
+    pub(crate) fn store(&self, inputs: impl IntoIterator<Item = PathBuf>) {
+        if !self.enabled {
+            return;
+        }
+        let inputs = inputs.into_iter().map(|path| Input { stat: stat(&path), path }).collect();
+        let entry = Entry { key: self.key.clone(), inputs };
+        t!(fs::create_dir_all(self.path.parent().unwrap()));
+        t!(fs::write(&self.path, t!(serde_json::to_vec(&entry))));
+    }
+}
+
+fn stat(path: &Path) -> Option<Stat> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(Stat { len: metadata.len(), secs: modified.as_secs(), nanos: modified.subsec_nanos() })
+}
+
+/// Returns the files of a dep-info file written by cargo, like `release/cargo.d`, whose lines are
+/// `<output>: <file> <file>...` with the spaces of the paths escaped as `\ `.
+pub(crate) fn dep_info_files(dep_info: &str) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for line in dep_info.lines() {
+        let Some((_, deps)) = line.split_once(": ") else {
+            continue;
+        };
+        let mut file = String::new();
+        let mut chars = deps.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                // The separators of the paths of Windows aren't escaped.
+                '\\' if chars.peek() == Some(&' ') => file.push(chars.next().unwrap()),
+                ' ' if !file.is_empty() => files.push(PathBuf::from(std::mem::take(&mut file))),
+                ' ' => {}
+                c => file.push(c),
+            }
+        }
+        if !file.is_empty() {
+            files.push(PathBuf::from(file));
+        }
+    }
+    files
+}
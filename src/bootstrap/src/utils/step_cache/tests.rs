@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn dep_info() {
+    let dep_info = "/build/release/cargo: /src/main.rs /src/my\\ dir/lib.rs\n\
+                    \n\
+                    /src/main.rs:\n";
+    assert_eq!(
+        dep_info_files(dep_info),
+        [PathBuf::from("/src/main.rs"), PathBuf::from("/src/my dir/lib.rs")]
+    );
+
+    let dep_info = "C:\\build\\release\\cargo.exe: C:\\src\\main.rs  C:\\src\\lib.rs";
+    assert_eq!(
+        dep_info_files(dep_info),
+        [PathBuf::from("C:\\src\\main.rs"), PathBuf::from("C:\\src\\lib.rs")]
+    );
+}
+
+#[test]
+fn stat_of_files() {
+    let path = std::env::temp_dir().join(format!("bootstrap-step-cache-{}", std::process::id()));
+    assert_eq!(stat(&path), None);
+    fs::write(&path, "fn main() {}").unwrap();
+    let written = stat(&path).unwrap();
+    assert_eq!(written.len, 12);
+    assert_eq!(stat(&path), Some(written));
+    fs::remove_file(&path).unwrap();
+}
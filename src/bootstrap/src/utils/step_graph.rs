@@ -0,0 +1,162 @@
+//! `--dump-graph`, which records the steps ensured by a dry run and the steps each of them ensured,
+//! and writes the graph to `build/bootstrap-graph.dot` for Graphviz and to
+//! `build/bootstrap-graph.json` for other tools.
+//!
+//! Steps don't expose their stage and target, so they're parsed from the `Debug` representation of
+//! the steps, which holds them in fields like `target` and `compiler: Compiler { stage, host }`.
+
+use std::any::type_name;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+use crate::core::builder::{Builder, Step};
+use crate::utils::helpers::t;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Default)]
+pub(crate) struct StepGraph {
+    state: RefCell<GraphState>,
+}
+
+#[derive(Clone, Default)]
+struct GraphState {
+    nodes: Vec<Node>,
+    /// The node of each step, by its `Debug` representation.
+    ids: HashMap<String, usize>,
+    /// The steps and the ones they ensured.
+    edges: BTreeSet<(usize, usize)>,
+    /// The nodes of the steps being run, the innermost last.
+    stack: Vec<usize>,
+}
+
+#[derive(serde_derive::Serialize, Clone, Debug, PartialEq, Eq)]
+struct Node {
+    /// The type of the step, like `compile::Std`.
+    step: String,
+    /// The subcommand the module of the step belongs to, like `build` for `compile` and `tool`.
+    kind: &'static str,
+    stage: Option<u32>,
+    target: Option<String>,
+    debug: String,
+}
+
+#[derive(serde_derive::Serialize)]
+struct JsonGraph<'a> {
+    nodes: &'a [Node],
+    /// The indices of the nodes of the steps and of the ones they ensured.
+    edges: Vec<[usize; 2]>,
+}
+
+impl StepGraph {
+    /// Records `step`, and an edge from the step which ensured it. Steps whose output is cached
+    /// are recorded too, and exited right away.
+    pub(crate) fn enter_step<S: Step>(&self, step: &S, builder: &Builder<'_>) {
+        if !builder.config.dump_graph {
+            return;
+        }
+        let mut state = self.state.borrow_mut();
+        let debug = format!("{step:?}");
+        let id = match state.ids.get(&debug) {
+            Some(&id) => id,
+            None => {
+                let id = state.nodes.len();
+                state.nodes.push(node(type_name::<S>(), debug.clone()));
+                state.ids.insert(debug, id);
+                id
+            }
+        };
+        if let Some(&parent) = state.stack.last() {
+            state.edges.insert((parent, id));
+        }
+        state.stack.push(id);
+    }
+
+    pub(crate) fn exit_step(&self, builder: &Builder<'_>) {
+        if builder.config.dump_graph {
+            self.state.borrow_mut().stack.pop();
+        }
+    }
+
+    /// Writes the graph to the build directory.
+    pub(crate) fn persist(&self, build: &Build) {
+        if !build.config.dump_graph {
+            return;
+        }
+        let state = self.state.borrow();
+        let edges = state.edges.iter().map(|&(from, to)| [from, to]).collect();
+        let json = JsonGraph { nodes: &state.nodes, edges };
+        let dot_path = build.out.join("bootstrap-graph.dot");
+        let json_path = build.out.join("bootstrap-graph.json");
+        t!(std::fs::create_dir_all(&build.out));
+        t!(std::fs::write(&dot_path, to_dot(&state.nodes, &state.edges)));
+        t!(std::fs::write(&json_path, t!(serde_json::to_string_pretty(&json))));
+        println!(
+            "Wrote the graph of {} steps to {} and {}",
+            state.nodes.len(),
+            dot_path.display(),
+            json_path.display()
+        );
+    }
+}
+
+fn node(type_name: &str, debug: String) -> Node {
+    let step = type_name.strip_prefix("bootstrap::core::build_steps::").unwrap_or(type_name);
+    let kind = match step.split("::").next().unwrap() {
+        "check" => "check",
+        "clippy" => "clippy",
+        "doc" => "doc",
+        "test" => "test",
+        "dist" => "dist",
+        "install" => "install",
+        "run" => "run",
+        "format" => "fmt",
+        "clean" => "clean",
+        "vendor" => "vendor",
+        _ => "build",
+    };
+    Node {
+        step: step.to_owned(),
+        kind,
+        stage: field(&debug, "stage").and_then(|stage| stage.parse().ok()),
+        target: field(&debug, "target").or_else(|| field(&debug, "host")).map(str::to_owned),
+        debug,
+    }
+}
+
+/// Returns the value of the first field `name` of a `Debug` representation, like `1` for `stage`
+/// in `Compiler { stage: 1, host: x86_64-unknown-linux-gnu }`.
+pub(crate) fn field<'a>(debug: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{name}: ");
+    debug.match_indices(&pattern).find_map(|(i, _)| {
+        // Skip the fields whose names end with `name`, like `build_target`.
+        if i > 0 && !debug[..i].ends_with([' ', '{', '(']) {
+            return None;
+        }
+        let value = &debug[i + pattern.len()..];
+        let end = value.find([',', ' ', '}', ')']).unwrap_or(value.len());
+        Some(&value[..end]).filter(|value| !value.is_empty())
+    })
+}
+
+fn to_dot(nodes: &[Node], edges: &BTreeSet<(usize, usize)>) -> String {
+    let mut dot = String::from("digraph bootstrap {\n    node [shape=box];\n");
+    for (i, node) in nodes.iter().enumerate() {
+        let mut label = format!("{} ({})", node.step, node.kind);
+        if let Some(stage) = node.stage {
+            write!(label, "\\nstage {stage}").unwrap();
+        }
+        if let Some(target) = &node.target {
+            write!(label, "\\n{}", target.replace('\\', "\\\\").replace('"', "\\\"")).unwrap();
+        }
+        writeln!(dot, "    n{i} [label=\"{label}\"];").unwrap();
+    }
+    for (from, to) in edges {
+        writeln!(dot, "    n{from} -> n{to};").unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
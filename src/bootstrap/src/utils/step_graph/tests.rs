@@ -0,0 +1,43 @@
+use super::*;
+
+#[test]
+fn nodes_of_steps() {
+    let debug = "Std { target: x86_64-unknown-linux-gnu, compiler: Compiler { stage: 1, host: \
+                 aarch64-unknown-linux-gnu }, crates: [] }";
+    assert_eq!(
+        node("bootstrap::core::build_steps::compile::Std", debug.to_owned()),
+        Node {
+            step: "compile::Std".to_owned(),
+            kind: "build",
+            stage: Some(1),
+            target: Some("x86_64-unknown-linux-gnu".to_owned()),
+            debug: debug.to_owned(),
+        }
+    );
+
+    let tidy = node("bootstrap::core::build_steps::test::Tidy", "Tidy".to_owned());
+    assert_eq!((tidy.kind, tidy.stage, tidy.target), ("test", None, None));
+
+    // The host of the compiler is the target of the steps without one.
+    let debug = "Rustc { build_target: a, compiler: Compiler { host: b } }";
+    assert_eq!(field(debug, "target"), None);
+    assert_eq!(field(debug, "host"), Some("b"));
+}
+
+#[test]
+fn dot() {
+    let nodes = [
+        node("bootstrap::core::build_steps::test::Tidy", "Tidy".to_owned()),
+        node("bootstrap::core::build_steps::tool::Tidy", "Tool { stage: 0 }".to_owned()),
+    ];
+    assert_eq!(
+        to_dot(&nodes, &BTreeSet::from([(0, 1)])),
+        "digraph bootstrap {
+    node [shape=box];
+    n0 [label=\"test::Tidy (test)\"];
+    n1 [label=\"tool::Tidy (build)\\nstage 0\"];
+    n0 -> n1;
+}
+"
+    );
+}
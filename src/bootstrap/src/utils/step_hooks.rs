@@ -0,0 +1,112 @@
+//! The commands of the `[[build.hooks]]` tables of `config.toml`, which run before or after the
+//! steps they match, so that a fork can post-process the standard library or prepare `x dist`
+//! without patching bootstrap.
+//!
+//! The arguments of the commands are templates, where these variables are replaced:
+//!
+//! - `{stage}`, `{target}` and `{host}`, the ones of the step, which are parsed from its `Debug`
+//!   representation like `--dump-graph` does; the target of a step without one is its host
+//! - `{build-dir}`, the build directory
+//! - `{out}`, the directory of the artifacts of the target, `build/<target>`
+//! - `{sysroot}`, the sysroot of the compiler of the step, `build/<host>/stage<stage>`
+//!
+//! Other braces are kept, and `{{` is a literal `{`. The commands run in the source directory, and
+//! dry runs print them with `--plan` instead of running them.
+
+use std::any::type_name;
+
+use crate::core::builder::{Builder, Step};
+use crate::core::config::StepHook;
+use crate::utils::exec::command;
+use crate::utils::step_graph::field;
+
+#[cfg(test)]
+mod tests;
+
+/// Runs the hooks of `step` which run `before` it, or after it.
+pub(crate) fn run<S: Step>(builder: &Builder<'_>, step: &S, before: bool) {
+    if builder.config.step_hooks.is_empty() {
+        return;
+    }
+    let step_type = type_name::<S>();
+    let step_type = step_type.strip_prefix("bootstrap::core::build_steps::").unwrap_or(step_type);
+    let debug = format!("{step:?}");
+    let vars = variables(builder, &debug);
+    for hook in &builder.config.step_hooks {
+        if hook.before != before || !matches(hook, step_type, &vars) {
+            continue;
+        }
+        let args: Vec<String> = hook
+            .command
+            .iter()
+            .map(|arg| {
+                expand(arg, &vars).unwrap_or_else(|var| {
+                    eprintln!("ERROR: unknown `{{{var}}}` in a hook of {debug}");
+                    crate::exit!(1);
+                })
+            })
+            .collect();
+        builder.verbose(|| println!("running the hook of {debug}"));
+        command(&args[0]).args(&args[1..]).current_dir(&builder.src).run(builder);
+    }
+}
+
+/// The values of the variables of the templates for a step, from its `Debug` representation.
+fn variables(builder: &Builder<'_>, debug: &str) -> Vec<(&'static str, String)> {
+    let mut vars = vec![("build-dir", builder.out.display().to_string())];
+    let stage = field(debug, "stage");
+    let host = field(debug, "host");
+    if let Some(stage) = stage {
+        vars.push(("stage", stage.to_owned()));
+    }
+    if let Some(target) = field(debug, "target").or(host) {
+        vars.push(("target", target.to_owned()));
+        vars.push(("out", builder.out.join(target).display().to_string()));
+    }
+    if let Some(host) = host {
+        vars.push(("host", host.to_owned()));
+        if let Some(stage) = stage {
+            let sysroot = builder.out.join(host).join(format!("stage{stage}"));
+            vars.push(("sysroot", sysroot.display().to_string()));
+        }
+    }
+    vars
+}
+
+fn matches(hook: &StepHook, step_type: &str, vars: &[(&str, String)]) -> bool {
+    let var = |name| vars.iter().find(|(var, _)| *var == name).map(|(_, value)| value.as_str());
+    hook.step == step_type
+        && hook.stage.map_or(true, |stage| var("stage") == Some(stage.to_string().as_str()))
+        && hook.target.as_ref().map_or(true, |target| var("target") == Some(target.as_str()))
+}
+
+/// Replaces the variables of `template`, or returns the name of a variable without a value.
+fn expand(template: &str, vars: &[(&str, String)]) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("{{") {
+            expanded.push('{');
+            rest = after;
+            continue;
+        }
+        let name = rest[1..].find('}').map(|end| &rest[1..1 + end]);
+        let Some(name) = name.filter(|name| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+        }) else {
+            // Not a variable, like the `${HOME}` of a shell script.
+            expanded.push('{');
+            rest = &rest[1..];
+            continue;
+        };
+        match vars.iter().find(|(var, _)| *var == name) {
+            Some((_, value)) => expanded.push_str(value),
+            None => return Err(name.to_owned()),
+        }
+        rest = &rest[name.len() + 2..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
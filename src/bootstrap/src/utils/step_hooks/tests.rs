@@ -0,0 +1,36 @@
+use super::*;
+
+fn vars() -> Vec<(&'static str, String)> {
+    vec![("stage", "1".to_owned()), ("target", "aarch64-unknown-linux-gnu".to_owned())]
+}
+
+#[test]
+fn templates() {
+    assert_eq!(
+        expand("--out=build/{target}/stage{stage}", &vars()),
+        Ok("--out=build/aarch64-unknown-linux-gnu/stage1".to_owned())
+    );
+    // Other braces are kept.
+    assert_eq!(expand("cp ${HOME}/a {{stage} {", &vars()), Ok("cp ${HOME}/a {stage} {".to_owned()));
+    assert_eq!(expand("{sysroot}/lib", &vars()), Err("sysroot".to_owned()));
+}
+
+#[test]
+fn matching_hooks() {
+    let mut hook = StepHook {
+        step: "compile::Std".to_owned(),
+        before: false,
+        stage: None,
+        target: None,
+        command: vec!["true".to_owned()],
+    };
+    assert!(matches(&hook, "compile::Std", &vars()));
+    assert!(!matches(&hook, "compile::StdLink", &vars()));
+    hook.stage = Some(1);
+    hook.target = Some("aarch64-unknown-linux-gnu".to_owned());
+    assert!(matches(&hook, "compile::Std", &vars()));
+    hook.stage = Some(2);
+    assert!(!matches(&hook, "compile::Std", &vars()));
+    hook.stage = Some(1);
+    assert!(!matches(&hook, "compile::Std", &[("stage", "1".to_owned())]));
+}
@@ -0,0 +1,210 @@
+//! The journal of the steps completed by an invocation, in `build/step-journal`, so that
+//! `--resume` continues an invocation which was interrupted, or which failed, from where it
+//! stopped, instead of running all its steps again, like the tests which already passed.
+//!
+//! The first line of the journal is the invocation, with its arguments and the digest of its
+//! configuration: `config.toml`, the files it includes and the `RUST_BOOTSTRAP_*` overrides of the
+//! environment. Each other line is a step which completed, with its output. `--resume` only
+//! uses the journal of the same invocation, and the journal is removed once the invocation
+//! succeeds. The steps are skipped with their recorded output, which is why only the steps with
+//! simple outputs, like `()`, paths and tarballs whose files still exist, are recorded. A step
+//! whose commands failed with `--no-fail-fast`, or still run in the background, isn't completed.
+
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+use sha2::Digest;
+
+use crate::core::builder::Step;
+use crate::utils::helpers::{hex_encode, t};
+use crate::utils::tarball::GeneratedTarball;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Default)]
+pub(crate) struct StepJournal {
+    state: RefCell<Option<State>>,
+}
+
+#[derive(Clone)]
+struct State {
+    path: PathBuf,
+    /// The outputs of the steps completed before the invocation was interrupted, by the type and
+    /// the `Debug` representation of the step.
+    completed: HashMap<String, Output>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Invocation {
+    /// The arguments of the invocation, after `x`, without `--resume`.
+    args: Vec<String>,
+    /// The digest of the configuration, see [`config_digest`].
+    config: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    step: String,
+    output: Output,
+}
+
+/// The outputs of the steps which are recorded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+enum Output {
+    Unit,
+    Path(PathBuf),
+    OptionalPath(Option<PathBuf>),
+    Tarball(GeneratedTarball),
+    OptionalTarball(Option<GeneratedTarball>),
+}
+
+impl StepJournal {
+    /// Starts the journal of this invocation, and with `--resume`, reads the steps which the same
+    /// invocation completed before it was interrupted.
+    pub(crate) fn open(&self, build: &Build) {
+        let path = build.out.join("step-journal");
+        let invocation = invocation(build);
+        let mut completed = HashMap::new();
+        if build.config.resume {
+            match fs::read_to_string(&path) {
+                Ok(journal) => match parse(&journal, &invocation) {
+                    Some(entries) => completed = entries,
+                    None => println!(
+                        "WARNING: the interrupted invocation isn't `x {}`, running all its steps",
+                        invocation.args.join(" ")
+                    ),
+                },
+                Err(_) => println!("WARNING: no interrupted invocation to resume"),
+            }
+            if !completed.is_empty() {
+                println!("Resuming, skipping the {} steps which completed", completed.len());
+            }
+        }
+        if completed.is_empty() {
+            t!(fs::create_dir_all(&build.out));
+            t!(fs::write(&path, format!("{}\n", t!(serde_json::to_string(&invocation)))));
+        }
+        *self.state.borrow_mut() = Some(State { path, completed });
+    }
+
+    /// Returns the recorded output of `step`, if it completed before the invocation was
+    /// interrupted.
+    pub(crate) fn completed<S: Step>(&self, step: &S, build: &Build) -> Option<S::Output> {
+        if build.config.dry_run() {
+            return None;
+        }
+        let state = self.state.borrow();
+        let output = state.as_ref()?.completed.get(&key(step))?;
+        if !output.exists() {
+            return None;
+        }
+        restore(output.clone())
+    }
+
+    /// Records that `step` completed with `output`.
+    pub(crate) fn record<S: Step>(&self, step: &S, output: &S::Output, build: &Build) {
+        if build.config.dry_run() {
+            return;
+        }
+        let state = self.state.borrow();
+        let (Some(state), Some(output)) = (state.as_ref(), save(output)) else {
+            return;
+        };
+        let entry = Entry { step: key(step), output };
+        let mut journal = t!(OpenOptions::new().append(true).open(&state.path));
+        t!(writeln!(journal, "{}", t!(serde_json::to_string(&entry))));
+    }
+
+    /// Removes the journal, as the invocation succeeded.
+    pub(crate) fn finish(&self) {
+        if let Some(state) = self.state.borrow_mut().take() {
+            let _ = fs::remove_file(state.path);
+        }
+    }
+}
+
+impl Output {
+    /// Whether the files of the output still exist, so that the step doesn't need to run again.
+    fn exists(&self) -> bool {
+        match self {
+            Output::Unit | Output::OptionalPath(None) | Output::OptionalTarball(None) => true,
+            Output::Path(path) | Output::OptionalPath(Some(path)) => path.exists(),
+            Output::Tarball(tarball) | Output::OptionalTarball(Some(tarball)) => {
+                tarball.tarball().exists()
+            }
+        }
+    }
+}
+
+fn invocation(build: &Build) -> Invocation {
+    let args = std::env::args().skip(1).filter(|arg| arg != "--resume").collect();
+    let config = &build.config;
+    let files = config.config.iter().chain(&config.config_includes);
+    Invocation { args, config: config_digest(files, &config.env_overrides) }
+}
+
+/// The digest of the configuration files `files`, and of the overrides of the environment
+/// `env_overrides`.
+fn config_digest<'a>(
+    files: impl IntoIterator<Item = &'a PathBuf>,
+    env_overrides: &[(String, String)],
+) -> String {
+    let mut digest = sha2::Sha256::new();
+    for file in files {
+        digest.update(file.as_os_str().as_encoded_bytes());
+        digest.update(fs::read(file).unwrap_or_default());
+    }
+    for (name, value) in env_overrides {
+        digest.update(format!("{name}={value}\n"));
+    }
+    hex_encode(digest.finalize())
+}
+
+/// Returns the completed steps of `journal`, if it's the one of `invocation`.
+fn parse(journal: &str, invocation: &Invocation) -> Option<HashMap<String, Output>> {
+    let mut lines = journal.lines();
+    let recorded: Invocation = serde_json::from_str(lines.next()?).ok()?;
+    if recorded != *invocation {
+        return None;
+    }
+    // The last line is incomplete when the invocation was killed while writing it.
+    let entries = lines.filter_map(|line| serde_json::from_str::<Entry>(line).ok());
+    Some(entries.map(|entry| (entry.step, entry.output)).collect())
+}
+
+fn key<S: Step>(step: &S) -> String {
+    format!("{} {step:?}", type_name::<S>())
+}
+
+fn save<T: Any>(output: &T) -> Option<Output> {
+    let output: &dyn Any = output;
+    if output.is::<()>() {
+        Some(Output::Unit)
+    } else if let Some(path) = output.downcast_ref::<PathBuf>() {
+        Some(Output::Path(path.clone()))
+    } else if let Some(path) = output.downcast_ref::<Option<PathBuf>>() {
+        Some(Output::OptionalPath(path.clone()))
+    } else if let Some(tarball) = output.downcast_ref::<GeneratedTarball>() {
+        Some(Output::Tarball(tarball.clone()))
+    } else {
+        output.downcast_ref::<Option<GeneratedTarball>>().cloned().map(Output::OptionalTarball)
+    }
+}
+
+fn restore<T: Any>(output: Output) -> Option<T> {
+    let output: Box<dyn Any> = match output {
+        Output::Unit => Box::new(()),
+        Output::Path(path) => Box::new(path),
+        Output::OptionalPath(path) => Box::new(path),
+        Output::Tarball(tarball) => Box::new(tarball),
+        Output::OptionalTarball(tarball) => Box::new(tarball),
+    };
+    output.downcast().ok().map(|output| *output)
+}
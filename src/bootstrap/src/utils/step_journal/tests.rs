@@ -0,0 +1,61 @@
+use super::*;
+
+fn invocation(args: &[&str]) -> Invocation {
+    Invocation { args: args.iter().map(|arg| arg.to_string()).collect(), config: "0123".to_owned() }
+}
+
+#[test]
+fn parse_journal() {
+    let entry = |step: &str, output| {
+        serde_json::to_string(&Entry { step: step.to_owned(), output }).unwrap()
+    };
+    let journal = [
+        serde_json::to_string(&invocation(&["test", "tests/ui"])).unwrap(),
+        entry("bootstrap::core::build_steps::compile::Std { .. }", Output::Unit),
+        entry("bootstrap::core::build_steps::dist::Docs { .. }", Output::OptionalPath(None)),
+        // The invocation was killed while writing this line.
+        "{\"step\": \"bootstrap::core::build_steps::test::Ui".to_owned(),
+    ]
+    .join("\n");
+
+    let completed = parse(&journal, &invocation(&["test", "tests/ui"])).unwrap();
+    assert_eq!(completed.len(), 2);
+    assert_eq!(completed["bootstrap::core::build_steps::compile::Std { .. }"], Output::Unit);
+    // The journal of another invocation isn't resumed.
+    assert_eq!(parse(&journal, &invocation(&["test", "tests/codegen"])), None);
+    assert_eq!(parse("", &invocation(&["test", "tests/ui"])), None);
+}
+
+#[test]
+fn save_and_restore_outputs() {
+    let path = PathBuf::from("build/dist/rustc-nightly-src.tar.xz");
+    assert_eq!(save(&()), Some(Output::Unit));
+    assert_eq!(save(&Some(path.clone())), Some(Output::OptionalPath(Some(path.clone()))));
+    // The other outputs aren't recorded, so their steps run again.
+    assert_eq!(save(&1u32), None);
+
+    assert_eq!(restore::<PathBuf>(Output::Path(path.clone())), Some(path.clone()));
+    assert_eq!(restore::<()>(Output::Unit), Some(()));
+    // The output of another type of step with the same key isn't restored.
+    assert_eq!(restore::<Option<PathBuf>>(Output::Path(path)), None);
+    assert!(!Output::Path(PathBuf::from("nonexistent/path")).exists());
+}
+
+#[test]
+fn digest_of_config() {
+    let dir = std::env::temp_dir().join(format!("bootstrap-step-journal-{}", std::process::id()));
+    t!(fs::create_dir_all(&dir));
+    let files = [dir.join("config.toml"), dir.join("included.toml")];
+    t!(fs::write(&files[0], "include = [\"included.toml\"]"));
+    t!(fs::write(&files[1], "rust.lto = \"thin\""));
+    let overrides = [("RUST_BOOTSTRAP_RUST_LTO".to_owned(), "fat".to_owned())];
+    let digest = config_digest(&files, &overrides);
+    assert_eq!(config_digest(&files, &overrides), digest);
+
+    // A change of an included file, or of the overrides, is another configuration.
+    t!(fs::write(&files[1], "rust.lto = \"fat\""));
+    assert_ne!(config_digest(&files, &overrides), digest);
+    let digest = config_digest(&files, &overrides);
+    assert_ne!(config_digest(&files, &[]), digest);
+    t!(fs::remove_dir_all(&dir));
+}
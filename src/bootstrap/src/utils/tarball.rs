@@ -417,7 +417,7 @@ fn run(self, build_cli: impl FnOnce(&Tarball<'a>, &mut BootstrapCommand)) -> Gen
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, Clone, PartialEq)]
 pub struct GeneratedTarball {
     path: PathBuf,
     decompressed_output: PathBuf,
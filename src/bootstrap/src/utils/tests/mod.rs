@@ -0,0 +1,205 @@
+//! Shared utilities for the tests of bootstrap.
+//!
+//! In a dry run, bootstrap records each command it would execute, with its arguments, environment
+//! and working directory. [`ConfigBuilder`] creates the configuration of a dry run, and
+//! [`Recording`] runs the steps selected by its command line and returns the recorded commands.
+//! Tests can check single commands, or compare them with snapshots in `src/utils/tests/snapshots`,
+//! which are updated by `x test src/bootstrap --bless`.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs, thread};
+
+use pretty_assertions::assert_eq;
+
+use crate::core::builder::Builder;
+use crate::core::config::{DryRun, TargetSelection};
+use crate::utils::exec::RecordedCommand;
+use crate::utils::helpers::t;
+use crate::{Build, Config, Flags};
+
+/// Creates the configuration of a dry run, for the targets `A-A`, `B-B`, ... which don't need a
+/// toolchain.
+pub struct ConfigBuilder {
+    args: Vec<String>,
+    hosts: Vec<String>,
+    targets: Vec<String>,
+}
+
+impl ConfigBuilder {
+    /// Starts with the command line `args`, e.g. `&["build", "library"]`, for the host `A-A`.
+    pub fn new(args: &[&str]) -> Self {
+        ConfigBuilder {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            hosts: vec!["A-A".to_owned()],
+            targets: vec!["A-A".to_owned()],
+        }
+    }
+
+    /// Overrides an option of `config.toml`, like `--set section.option=value`.
+    pub fn set(mut self, option: &str) -> Self {
+        self.args.push("--set".to_owned());
+        self.args.push(option.to_owned());
+        self
+    }
+
+    pub fn hosts(mut self, hosts: &[&str]) -> Self {
+        self.hosts = hosts.iter().map(|host| host.to_string()).collect();
+        self
+    }
+
+    pub fn targets(mut self, targets: &[&str]) -> Self {
+        self.targets = targets.iter().map(|target| target.to_string()).collect();
+        self
+    }
+
+    pub fn create_config(self) -> Config {
+        let mut config = Config::parse(Flags::parse(&self.args));
+        // don't save toolstates
+        config.save_toolstates = None;
+        config.dry_run = DryRun::SelfCheck;
+
+        // Ignore most submodules, since we don't need them for a dry run, and the
+        // tests run much faster without them.
+        //
+        // The src/doc/book submodule is needed because TheBook step tries to
+        // access files even during a dry-run (may want to consider just skipping
+        // that in a dry run).
+        let submodule_build = Build::new(Config {
+            // don't include LLVM, so CI doesn't require ninja/cmake to be installed
+            rust_codegen_backends: vec![],
+            ..Config::parse(Flags::parse(&["check".to_owned()]))
+        });
+        submodule_build.require_submodule("src/doc/book", None);
+        config.submodules = Some(false);
+
+        config.ninja_in_file = false;
+        // try to avoid spurious failures in dist where we create/delete each others file
+        // HACK: rather than pull in `tempdir`, use the one that cargo has conveniently created for
+        // us
+        let dir = Path::new(env!("OUT_DIR"))
+            .join("tmp-rustbuild-tests")
+            .join(&thread::current().name().unwrap_or("unknown").replace(":", "-"));
+        t!(fs::create_dir_all(&dir));
+        config.out = dir;
+        config.build = TargetSelection::from_user("A-A");
+        config.hosts = self.hosts.iter().map(|s| TargetSelection::from_user(s)).collect();
+        config.targets = self.targets.iter().map(|s| TargetSelection::from_user(s)).collect();
+        config
+    }
+}
+
+/// The commands recorded in a dry run.
+pub struct Recording {
+    commands: Vec<RecordedCommand>,
+    /// The paths of the build which are replaced in `render`, the longest ones first.
+    placeholders: Vec<(String, &'static str)>,
+}
+
+impl Recording {
+    /// Runs the steps selected by the command line of `config`, like `x.py` does.
+    pub fn run(config: Config) -> Self {
+        let build = Build::new(config);
+        Builder::new(&build).execute_cli();
+        Recording::of(&build)
+    }
+
+    /// Takes the commands recorded by `build` so far.
+    pub fn of(build: &Build) -> Self {
+        let mut placeholders = vec![
+            (normalize(&build.src), "$SRC"),
+            (normalize(&build.out), "$OUT"),
+            (normalize(&build.bootstrap_out), "$BOOTSTRAP"),
+            (normalize(&build.initial_rustc), "$RUSTC"),
+            (normalize(&build.initial_cargo), "$CARGO"),
+        ];
+        placeholders.sort_by_key(|(path, _)| std::cmp::Reverse(path.len()));
+        Recording { commands: build.recorded_commands.take(), placeholders }
+    }
+
+    /// Returns the commands running `program`, matched against the file name of the program
+    /// without its extension.
+    pub fn find(&self, program: &str) -> Vec<&RecordedCommand> {
+        self.commands
+            .iter()
+            .filter(|command| Path::new(&command.program).file_stem().is_some_and(|p| p == program))
+            .collect()
+    }
+
+    /// Returns the cargo invocations for the workspace or package of `manifest`, a path relative to
+    /// the root of the repository like `library/sysroot/Cargo.toml`.
+    pub fn cargo(&self, manifest: &str) -> Vec<&RecordedCommand> {
+        self.find("cargo")
+            .into_iter()
+            .filter(|command| {
+                command
+                    .arg_after("--manifest-path")
+                    .is_some_and(|path| self.replace_paths(path) == format!("$SRC/{manifest}"))
+            })
+            .collect()
+    }
+
+    fn replace_paths(&self, text: &str) -> String {
+        let mut text = text.replace('\\', "/");
+        for (path, placeholder) in &self.placeholders {
+            text = text.replace(path.as_str(), placeholder);
+        }
+        text
+    }
+
+    /// Renders `command` as a shell command line, with the paths of the build replaced by
+    /// placeholders like `$SRC` and `$OUT`. Only the environment variables in `env` are included,
+    /// as most of them differ between hosts.
+    pub fn render(&self, command: &RecordedCommand, env: &[&str]) -> String {
+        let mut line = String::new();
+        if let Some(cwd) = &command.cwd {
+            line.push_str(&format!("cd {} && ", self.replace_paths(&cwd.to_string_lossy())));
+        }
+        for (key, value) in command.env.iter().filter(|(key, _)| env.contains(&key.as_str())) {
+            match value {
+                Some(value) => line.push_str(&format!("{key}={} ", self.replace_paths(value))),
+                None => line.push_str(&format!("-u {key} ")),
+            }
+        }
+        line.push_str(&self.replace_paths(&command.program));
+        for arg in &command.args {
+            line.push(' ');
+            line.push_str(&self.replace_paths(arg));
+        }
+        line
+    }
+
+    /// Compares the rendered `commands` with the snapshot `name`, or updates the snapshot if the
+    /// tests are blessed.
+    pub fn assert_snapshot(&self, name: &str, commands: &[&RecordedCommand], env: &[&str]) {
+        let actual: String =
+            commands.iter().map(|command| self.render(command, env) + "\n").collect();
+        let path = snapshot_dir().join(format!("{name}.txt"));
+        // Set by `x test src/bootstrap --bless`.
+        if env::var("RUSTC_BLESS").is_ok_and(|bless| bless != "0") {
+            t!(fs::create_dir_all(snapshot_dir()));
+            t!(fs::write(&path, actual));
+            return;
+        }
+        let Ok(expected) = fs::read_to_string(&path) else {
+            panic!(
+                "the snapshot {} doesn't exist, run `x test src/bootstrap --bless` to create it",
+                path.display()
+            );
+        };
+        assert_eq!(
+            expected.replace("\r\n", "\n"),
+            actual,
+            "the commands differ from the snapshot {}, run `x test src/bootstrap --bless` to \
+             update it",
+            path.display()
+        );
+    }
+}
+
+fn normalize(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/utils/tests/snapshots")
+}
@@ -0,0 +1,261 @@
+//! `--timings`, which records how long each step took, and each unit built by cargo in its
+//! `timing-info` messages, and writes a report to `build/timings`:
+//!
+//! - `bootstrap-timing-<time>.json`, the steps with their `depth` in the steps being run, their
+//!   `start_secs` since bootstrap started, their `duration_secs` and the `self_secs` spent outside
+//!   of the steps they ran, and the `units` built by cargo for each of them
+//! - `bootstrap-timing-<time>.html`, a timeline of the steps and units, with the slowest ones
+//!
+//! where `<time>` is the number of seconds since the Unix epoch when the invocation started, so
+//! that the reports of several runs can be compared. `bootstrap-timing.{json,html}` are copies of
+//! the latest report. Steps are recorded through `Builder::ensure`, so all subcommands are covered.
+
+use std::any::type_name;
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::fs;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde_derive::Serialize;
+
+use crate::core::builder::{Builder, Step};
+use crate::utils::helpers::t;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of slowest steps and units listed by the HTML report.
+const SLOWEST: usize = 20;
+
+#[derive(Clone)]
+pub(crate) struct Timings {
+    state: RefCell<TimingsState>,
+}
+
+#[derive(Clone)]
+struct TimingsState {
+    start: Instant,
+    start_time: SystemTime,
+    steps: Vec<StepTiming>,
+    /// The indices of the steps being run, the innermost last.
+    stack: Vec<usize>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct StepTiming {
+    /// The type of the step, like `compile::Std`.
+    step: String,
+    debug: String,
+    depth: usize,
+    start_secs: f64,
+    duration_secs: f64,
+    self_secs: f64,
+    units: Vec<UnitTiming>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct UnitTiming {
+    package_id: String,
+    target: String,
+    mode: String,
+    start_secs: f64,
+    duration_secs: f64,
+    rmeta_secs: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    start_time_unix: u64,
+    duration_secs: f64,
+    steps: &'a [StepTiming],
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        let state = TimingsState {
+            start: Instant::now(),
+            start_time: SystemTime::now(),
+            steps: Vec::new(),
+            stack: Vec::new(),
+        };
+        Timings { state: RefCell::new(state) }
+    }
+}
+
+impl Timings {
+    pub(crate) fn enter_step<S: Step>(&self, step: &S, builder: &Builder<'_>) {
+        if !builder.config.timings || builder.config.dry_run() {
+            return;
+        }
+        let mut state = self.state.borrow_mut();
+        let step_type = type_name::<S>();
+        let prefix = "bootstrap::core::build_steps::";
+        let step_type = step_type.strip_prefix(prefix).unwrap_or(step_type);
+        let step = StepTiming {
+            step: step_type.to_owned(),
+            debug: format!("{step:?}"),
+            depth: state.stack.len(),
+            start_secs: state.start.elapsed().as_secs_f64(),
+            duration_secs: 0.0,
+            self_secs: 0.0,
+            units: Vec::new(),
+        };
+        let id = state.steps.len();
+        state.steps.push(step);
+        state.stack.push(id);
+    }
+
+    /// Records the end of the innermost step, which spent `self_secs` outside of its dependencies.
+    pub(crate) fn exit_step(&self, self_secs: f64, builder: &Builder<'_>) {
+        if !builder.config.timings || builder.config.dry_run() {
+            return;
+        }
+        let mut state = self.state.borrow_mut();
+        let now = state.start.elapsed().as_secs_f64();
+        let id = state.stack.pop().expect("no step to exit");
+        let step = &mut state.steps[id];
+        step.duration_secs = now - step.start_secs;
+        step.self_secs = self_secs;
+    }
+
+    /// Records a unit which cargo reported once it was built, for the innermost step.
+    pub(crate) fn record_cargo_unit(
+        &self,
+        package_id: &str,
+        target: &str,
+        mode: &str,
+        duration_secs: f64,
+        rmeta_secs: Option<f64>,
+        builder: &Builder<'_>,
+    ) {
+        if !builder.config.timings || builder.config.dry_run() {
+            return;
+        }
+        let mut state = self.state.borrow_mut();
+        let now = state.start.elapsed().as_secs_f64();
+        let Some(&id) = state.stack.last() else {
+            return;
+        };
+        state.steps[id].units.push(UnitTiming {
+            package_id: package_id.into(),
+            target: target.into(),
+            mode: mode.into(),
+            start_secs: (now - duration_secs).max(0.0),
+            duration_secs,
+            rmeta_secs,
+        });
+    }
+
+    /// Writes the report to `build/timings`.
+    pub(crate) fn persist(&self, build: &Build) {
+        if !build.config.timings || build.config.dry_run() {
+            return;
+        }
+        let state = self.state.borrow();
+        let start_time_unix =
+            state.start_time.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs());
+        let duration_secs = state.start.elapsed().as_secs_f64();
+        let report = Report { start_time_unix, duration_secs, steps: &state.steps };
+
+        let dir = build.out.join("timings");
+        t!(fs::create_dir_all(&dir));
+        let json = t!(serde_json::to_string_pretty(&report));
+        let html = to_html(&report);
+        for name in [format!("bootstrap-timing-{start_time_unix}"), "bootstrap-timing".into()] {
+            t!(fs::write(dir.join(format!("{name}.json")), &json));
+            t!(fs::write(dir.join(format!("{name}.html")), &html));
+        }
+        println!(
+            "Wrote the timings of {} steps to {}",
+            state.steps.len(),
+            dir.join(format!("bootstrap-timing-{start_time_unix}.html")).display()
+        );
+    }
+}
+
+fn to_html(report: &Report<'_>) -> String {
+    // The bars are positioned relatively to the duration of the invocation.
+    let total = report.duration_secs.max(f64::EPSILON);
+    let percent = |secs: f64| secs / total * 100.0;
+
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Bootstrap timings</title>\n<style>\n\
+         body { font-family: sans-serif; }\n\
+         .row { position: relative; height: 18px; margin: 1px 0; }\n\
+         .bar { position: absolute; height: 16px; overflow: hidden; white-space: nowrap; \
+         font-size: 12px; border-radius: 2px; }\n\
+         .step { background: #9cc3e6; }\n\
+         .unit { background: #f2c57c; }\n\
+         td { padding: 0 8px; }\n\
+         </style>\n</head>\n<body>\n",
+    );
+    writeln!(
+        html,
+        "<h1>Bootstrap timings</h1>\n<p>{} steps in {:.1}s</p>",
+        report.steps.len(),
+        report.duration_secs
+    )
+    .unwrap();
+
+    html.push_str("<h2>Timeline</h2>\n");
+    for step in report.steps {
+        writeln!(
+            html,
+            "<div class=\"row\"><div class=\"bar step\" style=\"left: {:.3}%; width: {:.3}%\" \
+             title=\"{}\">{}{} ({:.1}s)</div></div>",
+            percent(step.start_secs),
+            percent(step.duration_secs),
+            escape(&step.debug),
+            "&nbsp;&nbsp;".repeat(step.depth),
+            escape(&step.step),
+            step.duration_secs
+        )
+        .unwrap();
+        for unit in &step.units {
+            writeln!(
+                html,
+                "<div class=\"row\"><div class=\"bar unit\" style=\"left: {:.3}%; width: {:.3}%\" \
+                 title=\"{}\">{} {} ({:.1}s)</div></div>",
+                percent(unit.start_secs),
+                percent(unit.duration_secs),
+                escape(&unit.package_id),
+                escape(&unit.target),
+                escape(&unit.mode),
+                unit.duration_secs
+            )
+            .unwrap();
+        }
+    }
+
+    let mut steps: Vec<_> = report.steps.iter().collect();
+    steps.sort_by(|a, b| b.self_secs.total_cmp(&a.self_secs));
+    html.push_str("<h2>Slowest steps</h2>\n<table>\n<tr><th>Self time</th><th>Step</th></tr>\n");
+    for step in steps.iter().take(SLOWEST) {
+        writeln!(html, "<tr><td>{:.1}s</td><td>{}</td></tr>", step.self_secs, escape(&step.debug))
+            .unwrap();
+    }
+    html.push_str("</table>\n");
+
+    let mut units: Vec<_> = report.steps.iter().flat_map(|step| &step.units).collect();
+    units.sort_by(|a, b| b.duration_secs.total_cmp(&a.duration_secs));
+    html.push_str("<h2>Slowest units</h2>\n<table>\n<tr><th>Time</th><th>Unit</th></tr>\n");
+    for unit in units.iter().take(SLOWEST) {
+        writeln!(
+            html,
+            "<tr><td>{:.1}s</td><td>{} {} ({})</td></tr>",
+            unit.duration_secs,
+            escape(&unit.target),
+            escape(&unit.mode),
+            escape(&unit.package_id)
+        )
+        .unwrap();
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
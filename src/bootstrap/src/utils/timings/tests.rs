@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn html_report() {
+    let unit = UnitTiming {
+        package_id: "core 0.0.0 (path+file:///src/library/core)".to_owned(),
+        target: "core".to_owned(),
+        mode: "build".to_owned(),
+        start_secs: 2.0,
+        duration_secs: 6.0,
+        rmeta_secs: Some(3.0),
+    };
+    let steps = [StepTiming {
+        step: "compile::Std".to_owned(),
+        debug: "Std { crates: [\"core\"] }".to_owned(),
+        depth: 1,
+        start_secs: 1.0,
+        duration_secs: 8.0,
+        self_secs: 7.5,
+        units: vec![unit],
+    }];
+    let report = Report { start_time_unix: 0, duration_secs: 10.0, steps: &steps };
+    let html = to_html(&report);
+    assert!(html.contains("<p>1 steps in 10.0s</p>"));
+    assert!(html.contains(
+        "<div class=\"bar step\" style=\"left: 10.000%; width: 80.000%\" \
+         title=\"Std { crates: [&quot;core&quot;] }\">&nbsp;&nbsp;compile::Std (8.0s)</div>"
+    ));
+    assert!(html.contains("style=\"left: 20.000%; width: 60.000%\""));
+    assert!(html.contains("<tr><td>7.5s</td><td>Std { crates: [&quot;core&quot;] }</td></tr>"));
+    assert!(html.contains(
+        "<tr><td>6.0s</td><td>core build (core 0.0.0 (path+file:///src/library/core))</td></tr>"
+    ));
+}
+
+#[test]
+fn escaped_html() {
+    assert_eq!(escape("Vec<&str>"), "Vec&lt;&amp;str&gt;");
+}
@@ -0,0 +1,222 @@
+//! `--ui=tty`, a view of the progress of the build at the bottom of the terminal: the steps being
+//! run with how long they have been running, and the crates built by the current cargo invocation.
+//!
+//! The output of bootstrap and of the commands it runs, like cargo and ninja, is kept above the
+//! view by a scrolling region of the terminal, so that it scrolls like a log while the view stays
+//! in place. The view is redrawn by a thread, and removed when bootstrap exits, even on failure.
+//! It falls back to the plain output when stdout isn't a terminal or its size is unknown.
+
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::config::flags::Ui;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of lines of the view: a header, the innermost steps and the progress of cargo.
+const HEIGHT: u16 = 6;
+
+/// The first line of the view, or `0` once it's removed.
+static TOP: AtomicU16 = AtomicU16::new(0);
+
+/// The view, shared by the clones of `Build`, which does nothing for the plain output.
+#[derive(Clone, Default)]
+pub(crate) struct Tui {
+    state: Option<Arc<Mutex<State>>>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The steps being run, from the outermost one, with when they started.
+    steps: Vec<(String, Instant)>,
+    cargo: Option<CargoProgress>,
+}
+
+struct CargoProgress {
+    start: Instant,
+    built: usize,
+    last: Option<String>,
+}
+
+impl Tui {
+    /// Shows the view at the bottom of the terminal, for `--ui=tty`.
+    pub(crate) fn new(ui: Ui) -> Self {
+        if ui == Ui::Plain || !io::stdout().is_terminal() {
+            return Tui::default();
+        }
+        let Some((rows, _)) = terminal_size() else {
+            return Tui::default();
+        };
+        if rows <= 2 * HEIGHT {
+            return Tui::default();
+        }
+
+        // Makes room for the view below the output, which then scrolls above it.
+        let top = rows - HEIGHT + 1;
+        let mut stdout = io::stdout().lock();
+        let room = "\n".repeat(HEIGHT.into());
+        let _ = write!(stdout, "{room}\x1b[{HEIGHT}A\x1b7\x1b[1;{}r\x1b8", top - 1);
+        let _ = stdout.flush();
+        TOP.store(top, Ordering::SeqCst);
+        drop(stdout);
+        // SAFETY: `reset` doesn't unwind, and is registered once.
+        unsafe { libc::atexit(reset) };
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let view = Arc::clone(&state);
+        thread::spawn(move || {
+            loop {
+                thread::sleep(Duration::from_millis(250));
+                let Some((rows, cols)) = terminal_size() else {
+                    continue;
+                };
+                let lines = view.lock().unwrap().render(Instant::now());
+                draw(rows, cols, &lines);
+            }
+        });
+        Tui { state: Some(state) }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.state.is_some()
+    }
+
+    pub(crate) fn step_start(&self, step: String) {
+        self.update(|state| state.steps.push((step, Instant::now())));
+    }
+
+    pub(crate) fn step_finish(&self) {
+        self.update(|state| {
+            state.steps.pop();
+        });
+    }
+
+    pub(crate) fn cargo_start(&self) {
+        self.update(|state| {
+            state.cargo = Some(CargoProgress { start: Instant::now(), built: 0, last: None })
+        });
+    }
+
+    /// Records a crate, or a build script, built or found fresh by cargo.
+    pub(crate) fn cargo_artifact(&self, name: &str) {
+        self.update(|state| {
+            if let Some(cargo) = &mut state.cargo {
+                cargo.built += 1;
+                cargo.last = Some(name.to_owned());
+            }
+        });
+    }
+
+    pub(crate) fn cargo_finish(&self) {
+        self.update(|state| state.cargo = None);
+    }
+
+    fn update(&self, f: impl FnOnce(&mut State)) {
+        if let Some(state) = &self.state {
+            f(&mut state.lock().unwrap());
+        }
+    }
+}
+
+impl State {
+    fn render(&self, now: Instant) -> Vec<String> {
+        let mut lines = vec![String::from("---- running steps ----")];
+        // The innermost steps are shown, below the number of the outer ones if they don't fit.
+        let step_lines = usize::from(HEIGHT) - 2;
+        let steps = if self.steps.len() > step_lines {
+            let hidden = self.steps.len() - (step_lines - 1);
+            lines.push(format!("({hidden} outer steps)"));
+            &self.steps[hidden..]
+        } else {
+            &self.steps[..]
+        };
+        for (step, start) in steps {
+            lines.push(format!("{:>6} {step}", format_duration(now - *start)));
+        }
+        lines.resize(usize::from(HEIGHT) - 1, String::new());
+        lines.push(match &self.cargo {
+            Some(cargo) => {
+                let mut line = format!("cargo: {} crates built", cargo.built);
+                if let Some(last) = &cargo.last {
+                    let _ = write!(line, ", last: {last}");
+                }
+                let _ = write!(line, " ({})", format_duration(now - cargo.start));
+                line
+            }
+            None => String::new(),
+        });
+        lines
+    }
+}
+
+/// Formats `duration` like `1m05s`.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 { format!("{secs}s") } else { format!("{}m{:02}s", secs / 60, secs % 60) }
+}
+
+/// Returns the first `cols` characters of `line`, so that it doesn't wrap.
+fn truncate(line: &str, cols: u16) -> &str {
+    match line.char_indices().nth(cols.into()) {
+        Some((end, _)) => &line[..end],
+        None => line,
+    }
+}
+
+fn draw(rows: u16, cols: u16, lines: &[String]) {
+    if rows <= 2 * HEIGHT {
+        return;
+    }
+    let top = rows - HEIGHT + 1;
+    let mut stdout = io::stdout().lock();
+    let previous = TOP.load(Ordering::SeqCst);
+    if previous == 0 {
+        return;
+    }
+    let mut out = String::from("\x1b7");
+    // The terminal was resized.
+    if previous != top {
+        TOP.store(top, Ordering::SeqCst);
+        let _ = write!(out, "\x1b[1;{}r", top - 1);
+    }
+    for (row, line) in (top..).zip(lines) {
+        let _ = write!(out, "\x1b[{row};1H\x1b[2K{}", truncate(line, cols));
+    }
+    out.push_str("\x1b8");
+    let _ = stdout.write_all(out.as_bytes());
+    let _ = stdout.flush();
+}
+
+/// Removes the view, and gives the whole terminal back to the output.
+extern "C" fn reset() {
+    let mut stdout = io::stdout().lock();
+    let top = TOP.swap(0, Ordering::SeqCst);
+    if top == 0 {
+        return;
+    }
+    let mut out = String::from("\x1b7");
+    for row in top..top + HEIGHT {
+        let _ = write!(out, "\x1b[{row};1H\x1b[2K");
+    }
+    out.push_str("\x1b[r\x1b8");
+    let _ = stdout.write_all(out.as_bytes());
+    let _ = stdout.flush();
+}
+
+#[cfg(unix)]
+fn terminal_size() -> Option<(u16, u16)> {
+    // SAFETY: `TIOCGWINSZ` only writes the `winsize`.
+    let mut size = unsafe { std::mem::zeroed::<libc::winsize>() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+    (ok && size.ws_row > 0 && size.ws_col > 0).then_some((size.ws_row, size.ws_col))
+}
+
+#[cfg(not(unix))]
+fn terminal_size() -> Option<(u16, u16)> {
+    None
+}
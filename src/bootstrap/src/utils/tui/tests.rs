@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn render() {
+    let start = Instant::now();
+    let mut state = State::default();
+    state.steps.push(("Assemble { stage: 1 }".to_owned(), start));
+    state.cargo = Some(CargoProgress { start, built: 12, last: Some("core".to_owned()) });
+    let now = start + Duration::from_secs(65);
+    assert_eq!(
+        state.render(now),
+        [
+            "---- running steps ----",
+            " 1m05s Assemble { stage: 1 }",
+            "",
+            "",
+            "",
+            "cargo: 12 crates built, last: core (1m05s)",
+        ]
+    );
+
+    // The outermost steps are hidden.
+    state.cargo = None;
+    for step in ["Rustc", "Std", "StdLink", "Sysroot"] {
+        state.steps.push((step.to_owned(), now));
+    }
+    assert_eq!(
+        state.render(now),
+        [
+            "---- running steps ----",
+            "(2 outer steps)",
+            "    0s Std",
+            "    0s StdLink",
+            "    0s Sysroot",
+            "",
+        ]
+    );
+}
+
+#[test]
+fn truncated_lines() {
+    assert_eq!(truncate("Std { stage: 1 }", 3), "Std");
+    assert_eq!(truncate("Std", 80), "Std");
+    assert_eq!(truncate("é", 1), "é");
+}
@@ -0,0 +1,324 @@
+//! `x why`, a dry run of `x build` which explains why the artifacts of its paths would be rebuilt,
+//! like the standard library or LLVM, instead of building them.
+//!
+//! Each cargo invocation of a build is recorded in `.why` in its target directory, with the
+//! `config.toml` it was derived from. `x why` compares the invocations it would run with the
+//! recorded ones: the keys of `config.toml`, the arguments and the environment variables which
+//! changed. It also reads the dep-info files of the crates, where rustc lists the files and the
+//! environment variables each crate was built from, and reports the ones which changed since, the
+//! way cargo checks its fingerprints. LLVM is rebuilt when the hash of its stamp changes, and the
+//! stamp is reported instead.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+use serde_derive::{Deserialize, Serialize};
+use sha2::Digest;
+
+use crate::utils::helpers::{hex_encode, t, HashStamp};
+use crate::utils::step_cache::dep_info_files;
+use crate::Build;
+
+#[cfg(test)]
+mod tests;
+
+/// The arguments whose value doesn't change what cargo builds.
+const IGNORED_ARGS: &[&str] = &["-j", "--message-format"];
+
+/// The environment variables which don't change what cargo builds.
+const IGNORED_ENV: &[&str] = &["RUSTC_VERBOSE", "CARGO_TERM_VERBOSE"];
+
+/// The changed files printed for each crate.
+const MAX_FILES: usize = 3;
+
+#[derive(Serialize, Deserialize, Default)]
+struct Invocation {
+    /// The contents of `config.toml`.
+    config: String,
+    args: Vec<String>,
+    /// The environment variables set by bootstrap.
+    env: BTreeMap<String, String>,
+}
+
+/// A cargo invocation of a build, with its target directory.
+struct CargoCommand {
+    invocation: Invocation,
+    target_dir: PathBuf,
+    target: Option<String>,
+    /// The path of its record in the target directory.
+    record: PathBuf,
+}
+
+/// Records `command`, if it runs cargo, for `x why`.
+pub(crate) fn record(build: &Build, command: &Command) {
+    let Some(cargo) = CargoCommand::new(build, command) else {
+        return;
+    };
+    t!(fs::create_dir_all(cargo.record.parent().unwrap()));
+    t!(fs::write(&cargo.record, t!(serde_json::to_vec(&cargo.invocation))));
+}
+
+/// Prints why the crates built by `command`, if it runs cargo, would be rebuilt.
+pub(crate) fn explain(build: &Build, command: &Command) {
+    let Some(cargo) = CargoCommand::new(build, command) else {
+        return;
+    };
+    let args = &cargo.invocation.args;
+    let manifest = arg_after(args, "--manifest-path").map(|path| {
+        Path::new(path).strip_prefix(&build.src).unwrap_or(Path::new(path)).display().to_string()
+    });
+    println!(
+        "why: `cargo {}` of {} for {}:",
+        args.first().map_or("", String::as_str),
+        manifest.as_deref().unwrap_or("the workspace"),
+        cargo.target.clone().unwrap_or_else(|| build.config.build.to_string())
+    );
+
+    let Ok(recorded) = fs::read(&cargo.record) else {
+        println!("    it didn't run yet, or before bootstrap recorded its invocations");
+        return;
+    };
+    let recorded: Invocation = t!(serde_json::from_slice(&recorded));
+    let mut reasons = config_changes(&recorded.config, &cargo.invocation.config);
+    reasons.extend(invocation_changes(&recorded, &cargo.invocation));
+
+    let mut deps = vec![cargo.target_dir.join(build.cargo_dir()).join("deps")];
+    if let Some(target) = &cargo.target {
+        deps.insert(0, cargo.target_dir.join(target).join(build.cargo_dir()).join("deps"));
+    }
+    let env = |name: &str| match cargo.invocation.env.get(name) {
+        Some(value) => Some(value.clone()),
+        None => std::env::var(name).ok(),
+    };
+    for (krate, dep_info) in newest_dep_info(&deps) {
+        let changes = crate_changes(&t!(fs::read_to_string(&dep_info)), &env);
+        let shown = changes.files.len().min(MAX_FILES);
+        for file in &changes.files[..shown] {
+            let file = file.strip_prefix(&build.src).unwrap_or(file);
+            reasons.push(format!("{krate}: `{}` changed", file.display()));
+        }
+        if changes.files.len() > shown {
+            let more = changes.files.len() - shown;
+            reasons.push(format!("{krate}: and {more} more files changed"));
+        }
+        for name in changes.env {
+            reasons.push(format!("{krate}: the environment variable `{name}` changed"));
+        }
+    }
+
+    if reasons.is_empty() {
+        println!("    nothing changed, cargo only checks that it's up to date");
+    }
+    for reason in reasons {
+        println!("    {reason}");
+    }
+}
+
+/// Prints why the artifact `what`, like LLVM, with the stamp `stamp`, would be rebuilt.
+pub(crate) fn explain_stamp(what: &str, sources: &str, stamp: &HashStamp) {
+    let path = stamp.path.display();
+    if !stamp.path.exists() {
+        println!("why: {what} is built, as its stamp {path} doesn't exist");
+    } else if stamp.is_done() {
+        println!("why: {what} is up to date, as its stamp {path} is");
+    } else {
+        println!(
+            "why: {what} is rebuilt, as the commit or the uncommitted changes of {sources} changed \
+             since its stamp {path} was written"
+        );
+    }
+}
+
+impl CargoCommand {
+    fn new(build: &Build, command: &Command) -> Option<Self> {
+        if Path::new(command.get_program()) != build.initial_cargo {
+            return None;
+        }
+        let lossy = |s: &OsStr| s.to_string_lossy().into_owned();
+        let env: BTreeMap<String, String> = command
+            .get_envs()
+            .filter_map(|(name, value)| Some((lossy(name), lossy(value?))))
+            .collect();
+        let target_dir = PathBuf::from(env.get("CARGO_TARGET_DIR")?);
+        let args: Vec<String> = command.get_args().map(lossy).collect();
+        let target = arg_after(&args, "--target").map(str::to_owned);
+        let key = [args.first().map(String::as_str), arg_after(&args, "--manifest-path")];
+        let digest = sha2::Sha256::digest(format!("{key:?} {target:?}"));
+        let record = target_dir.join(".why").join(format!("{}.json", hex_encode(digest)));
+        let config = build.config.config.as_ref().and_then(|path| fs::read_to_string(path).ok());
+        let invocation = Invocation { config: config.unwrap_or_default(), args, env };
+        Some(CargoCommand { invocation, target_dir, target, record })
+    }
+}
+
+fn arg_after<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter().skip_while(|arg| *arg != flag).nth(1).map(String::as_str)
+}
+
+/// Returns the keys of `config.toml` which changed from `recorded` to `current`.
+fn config_changes(recorded: &str, current: &str) -> Vec<String> {
+    let (recorded, current) = (flatten_toml(recorded), flatten_toml(current));
+    let keys: BTreeSet<&String> = recorded.keys().chain(current.keys()).collect();
+    let mut changes = Vec::new();
+    for key in keys {
+        match (recorded.get(key), current.get(key)) {
+            (Some(old), Some(new)) if old != new => {
+                changes.push(format!("config.toml: `{key}` changed from {old} to {new}"))
+            }
+            (None, Some(new)) => changes.push(format!("config.toml: `{key}` was set to {new}")),
+            (Some(old), None) => changes.push(format!("config.toml: `{key}` = {old} was removed")),
+            _ => {}
+        }
+    }
+    changes
+}
+
+/// Returns the values of `config.toml` by their dotted keys, like `rust.debug-assertions`.
+fn flatten_toml(config: &str) -> BTreeMap<String, String> {
+    fn flatten(prefix: &str, value: &toml::Value, values: &mut BTreeMap<String, String>) {
+        match value {
+            toml::Value::Table(table) => {
+                for (key, value) in table {
+                    let key =
+                        if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+                    flatten(&key, value, values);
+                }
+            }
+            value => {
+                values.insert(prefix.to_owned(), value.to_string());
+            }
+        }
+    }
+    let mut values = BTreeMap::new();
+    if let Ok(config) = config.parse::<toml::Value>() {
+        flatten("", &config, &mut values);
+    }
+    values
+}
+
+/// Returns the arguments and the environment variables which changed from `recorded` to
+/// `current`.
+fn invocation_changes(recorded: &Invocation, current: &Invocation) -> Vec<String> {
+    // The values of the ignored arguments are skipped with them.
+    let args = |invocation: &Invocation| -> BTreeSet<String> {
+        let mut args = BTreeSet::new();
+        let mut iter = invocation.args.iter();
+        while let Some(arg) = iter.next() {
+            if IGNORED_ARGS.contains(&arg.as_str()) {
+                iter.next();
+            } else {
+                args.insert(arg.clone());
+            }
+        }
+        args
+    };
+    let (recorded_args, current_args) = (args(recorded), args(current));
+    let mut changes = Vec::new();
+    for arg in recorded_args.difference(&current_args) {
+        changes.push(format!("the argument `{arg}` of cargo was removed"));
+    }
+    for arg in current_args.difference(&recorded_args) {
+        changes.push(format!("the argument `{arg}` of cargo was added"));
+    }
+    let names: BTreeSet<&String> = recorded.env.keys().chain(current.env.keys()).collect();
+    for name in names.into_iter().filter(|name| !IGNORED_ENV.contains(&name.as_str())) {
+        match (recorded.env.get(name), current.env.get(name)) {
+            (Some(old), Some(new)) if old != new => changes
+                .push(format!("the environment variable `{name}` changed from `{old}` to `{new}`")),
+            (None, Some(new)) => {
+                changes.push(format!("the environment variable `{name}` was set to `{new}`"))
+            }
+            (Some(old), None) => {
+                changes.push(format!("the environment variable `{name}` = `{old}` was removed"))
+            }
+            _ => {}
+        }
+    }
+    changes
+}
+
+/// Returns the newest dep-info file of each crate in the `deps` directories, by the name of the
+/// crate, as the older ones are left by previous builds with other flags.
+fn newest_dep_info(deps: &[PathBuf]) -> BTreeMap<String, PathBuf> {
+    let mut newest: BTreeMap<String, (SystemTime, PathBuf)> = BTreeMap::new();
+    for entry in deps.iter().filter_map(|dir| fs::read_dir(dir).ok()).flatten() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let path = entry.path();
+        let Some((krate, _hash)) = path
+            .extension()
+            .filter(|extension| *extension == "d")
+            .and_then(|_| path.file_stem()?.to_str()?.rsplit_once('-'))
+        else {
+            continue;
+        };
+        let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) else {
+            continue;
+        };
+        if newest.get(krate).map_or(true, |(newest, _)| modified > *newest) {
+            newest.insert(krate.to_owned(), (modified, path.clone()));
+        }
+    }
+    newest.into_iter().map(|(krate, (_, path))| (krate, path)).collect()
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CrateChanges {
+    /// The files which were removed, or modified since the crate was built.
+    files: Vec<PathBuf>,
+    /// The environment variables read by the crate, like with `env!`, whose value changed.
+    env: Vec<String>,
+}
+
+/// Returns what changed since the crate with the dep-info `dep_info` was built, with the current
+/// environment variables from `env`.
+fn crate_changes(dep_info: &str, env: &dyn Fn(&str) -> Option<String>) -> CrateChanges {
+    let mut changes = CrateChanges::default();
+    let modified = |path: &Path| path.metadata().and_then(|metadata| metadata.modified()).ok();
+    // The first line has the main output of the crate, like its rlib.
+    let output = dep_info.lines().next().and_then(|line| line.split_once(": "));
+    let Some(built) = output.and_then(|(output, _)| modified(Path::new(output))) else {
+        return changes;
+    };
+    let (comments, rules): (Vec<&str>, Vec<&str>) =
+        dep_info.lines().partition(|line| line.starts_with('#'));
+    let files: BTreeSet<PathBuf> = dep_info_files(&rules.join("\n")).into_iter().collect();
+    changes.files = files
+        .into_iter()
+        .filter(|file| modified(file).map_or(true, |modified| modified > built))
+        .collect();
+    for variable in comments.iter().filter_map(|line| line.strip_prefix("# env-dep:")) {
+        let (name, value) = match variable.split_once('=') {
+            Some((name, value)) => (name, Some(unescape_env_dep(value))),
+            None => (variable, None),
+        };
+        if env(name) != value {
+            changes.env.push(name.to_owned());
+        }
+    }
+    changes
+}
+
+/// Reverts the escaping of the values of the environment variables in dep-info files by rustc.
+fn unescape_env_dep(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => unescaped.push('\n'),
+            ('\\', Some('r')) => unescaped.push('\r'),
+            ('\\', Some('\\')) => unescaped.push('\\'),
+            (c, _) => {
+                unescaped.push(c);
+                continue;
+            }
+        }
+        chars.next();
+    }
+    unescaped
+}
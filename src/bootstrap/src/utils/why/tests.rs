@@ -0,0 +1,85 @@
+use std::fs::File;
+use std::time::{Duration, UNIX_EPOCH};
+
+use super::*;
+
+#[test]
+fn changes_of_config() {
+    let recorded = "profile = 'compiler'\n[rust]\ndebug-assertions = false\nlto = 'thin'\n";
+    let current =
+        "profile = 'compiler'\n[rust]\ndebug-assertions = true\n[llvm]\nassertions = true\n";
+    assert_eq!(
+        config_changes(recorded, current),
+        [
+            "config.toml: `llvm.assertions` was set to true",
+            "config.toml: `rust.debug-assertions` changed from false to true",
+            "config.toml: `rust.lto` = \"thin\" was removed",
+        ]
+    );
+    assert!(config_changes(recorded, recorded).is_empty());
+}
+
+#[test]
+fn changes_of_invocation() {
+    let invocation = |args: &[&str], env: &[(&str, &str)]| Invocation {
+        config: String::new(),
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+        env: env.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+    };
+    let recorded = invocation(
+        &["build", "-j", "8", "--features", "panic-unwind"],
+        &[("RUSTFLAGS", "-Cdebuginfo=0"), ("RUSTC_VERBOSE", "0"), ("RUSTC_BOLT_LINK_FLAGS", "1")],
+    );
+    // The number of jobs and the verbosity don't change what cargo builds.
+    let current = invocation(
+        &["build", "-j", "16", "--features", "panic-unwind backtrace"],
+        &[("RUSTFLAGS", "-Cdebuginfo=2"), ("RUSTC_VERBOSE", "2"), ("RUSTC_TIME", "all")],
+    );
+    assert_eq!(
+        invocation_changes(&recorded, &current),
+        [
+            "the argument `panic-unwind` of cargo was removed",
+            "the argument `panic-unwind backtrace` of cargo was added",
+            "the environment variable `RUSTC_BOLT_LINK_FLAGS` = `1` was removed",
+            "the environment variable `RUSTC_TIME` was set to `all`",
+            "the environment variable `RUSTFLAGS` changed from `-Cdebuginfo=0` to `-Cdebuginfo=2`",
+        ]
+    );
+}
+
+#[test]
+fn changes_of_crate() {
+    let dir = std::env::temp_dir().join(format!("bootstrap-why-{}", std::process::id()));
+    let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+    let write = |path: &str, secs| {
+        let path = dir.join(path);
+        t!(fs::create_dir_all(path.parent().unwrap()));
+        t!(t!(File::create(&path)).set_modified(at(secs)));
+        path.display().to_string()
+    };
+    let rlib = write("deps/libstd-0123.rlib", 20);
+    let lib = write("std/src/lib.rs", 10);
+    let env = write("std/src/env.rs", 30);
+    let removed = dir.join("std/src/removed.rs").display().to_string();
+    let dep_info = format!(
+        "{rlib}: {lib} {env} {removed}\n\n{lib}:\n{env}:\n\n\
+         # env-dep:CFG_RELEASE=1.84.0\n# env-dep:RUST_BACKTRACE\n# env-dep:NOTE=a\\nb\n"
+    );
+    let env_vars = |name: &str| match name {
+        "CFG_RELEASE" => Some("1.85.0".to_owned()),
+        "NOTE" => Some("a\nb".to_owned()),
+        _ => None,
+    };
+    assert_eq!(
+        crate_changes(&dep_info, &env_vars),
+        CrateChanges {
+            files: vec![PathBuf::from(env), PathBuf::from(removed)],
+            env: vec!["CFG_RELEASE".to_owned()],
+        }
+    );
+
+    // A crate which wasn't built yet is built anyway.
+    t!(fs::remove_file(&rlib));
+    assert_eq!(crate_changes(&dep_info, &env_vars), CrateChanges::default());
+    t!(fs::remove_dir_all(&dir));
+}
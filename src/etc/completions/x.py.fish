@@ -1,6 +1,6 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_x.py_global_optspecs
-	string join \n v/verbose i/incremental config= build-dir= build= host= target= exclude= skip= include-default-paths rustc-error-format= on-fail= dry-run dump-bootstrap-shims stage= keep-stage= keep-stage-std= src= j/jobs= warnings= error-format= json-output color= bypass-bootstrap-lock rust-profile-generate= rust-profile-use= llvm-profile-use= llvm-profile-generate enable-bolt-settings skip-stage0-validation reproducible-artifact= set= h/help
+	string join \n v/verbose i/incremental config= profile= build-dir= build= host= target= exclude= skip= include-default-paths rustc-error-format= on-fail= on-failure= dry-run dump-bootstrap-shims stage= keep-stage= keep-stage-std= src= j/jobs= warnings= error-format= json-output color= ui= bypass-bootstrap-lock rust-profile-generate= rust-profile-use= llvm-profile-use= llvm-profile-generate enable-bolt-settings skip-stage0-validation reproducible-artifact= set= h/help
 end
 
 function __fish_x.py_needs_command
@@ -25,6 +25,7 @@ function __fish_x.py_using_subcommand
 end
 
 complete -c x.py -n "__fish_x.py_needs_command" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_needs_command" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_needs_command" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_needs_command" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_needs_command" -l host -d 'host targets to build' -r -f
@@ -33,6 +34,8 @@ complete -c x.py -n "__fish_x.py_needs_command" -l exclude -d 'build paths to ex
 complete -c x.py -n "__fish_x.py_needs_command" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_needs_command" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_needs_command" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_needs_command" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_needs_command" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_needs_command" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_needs_command" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_needs_command" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -41,6 +44,7 @@ complete -c x.py -n "__fish_x.py_needs_command" -s j -l jobs -d 'number of jobs
 complete -c x.py -n "__fish_x.py_needs_command" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_needs_command" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_needs_command" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_needs_command" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_needs_command" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_needs_command" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_needs_command" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -51,8 +55,13 @@ complete -c x.py -n "__fish_x.py_needs_command" -s i -l incremental -d 'use incr
 complete -c x.py -n "__fish_x.py_needs_command" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_needs_command" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_needs_command" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_needs_command" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_needs_command" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_needs_command" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_needs_command" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_needs_command" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_needs_command" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_needs_command" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_needs_command" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_needs_command" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_needs_command" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
@@ -74,7 +83,13 @@ complete -c x.py -n "__fish_x.py_needs_command" -a "setup" -d 'Set up the enviro
 complete -c x.py -n "__fish_x.py_needs_command" -a "suggest" -d 'Suggest a subset of tests to run, based on modified files'
 complete -c x.py -n "__fish_x.py_needs_command" -a "vendor" -d 'Vendor dependencies'
 complete -c x.py -n "__fish_x.py_needs_command" -a "perf" -d 'Perform profiling and benchmarking of the compiler using the `rustc-perf-wrapper` tool'
+complete -c x.py -n "__fish_x.py_needs_command" -a "bug-report" -d 'Collect a bundle of information for bug reports'
+complete -c x.py -n "__fish_x.py_needs_command" -a "toolstate" -d 'Check which tools build and pass their tests, and which submodules are outdated'
+complete -c x.py -n "__fish_x.py_needs_command" -a "completions" -d 'Print the completion script of a shell, with the paths of the steps of this checkout'
+complete -c x.py -n "__fish_x.py_needs_command" -a "repro" -d 'Record an invocation into build/repro.json, or replay a recorded one'
+complete -c x.py -n "__fish_x.py_needs_command" -a "why" -d 'Explain why the artifacts of `x build` with the same paths would be rebuilt'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l host -d 'host targets to build' -r -f
@@ -83,6 +98,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand build" -l exclude -d 'build pa
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -91,6 +108,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand build" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -101,13 +119,19 @@ complete -c x.py -n "__fish_x.py_using_subcommand build" -s i -l incremental -d
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand build" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand build" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l host -d 'host targets to build' -r -f
@@ -116,6 +140,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand check" -l exclude -d 'build pa
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -124,6 +150,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand check" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -135,8 +162,13 @@ complete -c x.py -n "__fish_x.py_using_subcommand check" -s i -l incremental -d
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand check" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand check" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
@@ -146,6 +178,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand clippy" -s D -d 'clippy lints
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -s W -d 'clippy lints to warn on' -r
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -s F -d 'clippy lints to forbid' -r
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l host -d 'host targets to build' -r -f
@@ -154,6 +187,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l exclude -d 'build p
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -162,6 +197,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand clippy" -s j -l jobs -d 'numbe
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -175,13 +211,19 @@ complete -c x.py -n "__fish_x.py_using_subcommand clippy" -s i -l incremental -d
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand clippy" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l host -d 'host targets to build' -r -f
@@ -190,6 +232,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand fix" -l exclude -d 'build path
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -198,6 +242,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand fix" -s j -l jobs -d 'number o
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -208,13 +253,26 @@ complete -c x.py -n "__fish_x.py_using_subcommand fix" -s i -l incremental -d 'u
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand fix" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand fix" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l since -d 'apply to the files modified since a git revision instead of the upstream merge-base' -r
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l base -d 'apply to the files modified since the merge-base with a branch instead of the upstream one' -r
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l message-format -d 'output format of --check' -r -f -a "{human\t'',json\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l stdin -d 'format the source read from stdin as the file PATH, and print it to stdout' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l summary-json -d 'write the files rewritten by the formatters, and statistics, as JSON to PATH' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l processes -d 'the maximum number of formatter processes running at once, instead of twice the jobs' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l batch-size -d 'the maximum number of files passed to each formatter process' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l host -d 'host targets to build' -r -f
@@ -223,6 +281,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l exclude -d 'build path
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -231,6 +291,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand fmt" -s j -l jobs -d 'number o
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -238,18 +299,30 @@ complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l reproducible-artifact
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l set -d 'override options in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l check -d 'check formatting instead of applying'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l all -d 'apply to all appropriate files, not just those that have been modified'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l staged -d 'apply only to the files staged in git'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l include-untracked -d 'also apply to the files which aren\'t tracked by git, instead of skipping them'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l watch -d 'format the files again whenever they are saved, until interrupted'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l quiet -d 'don\'t print the progress line'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l daemon -d 'serve the format requests of editors on a Unix socket, until --stop'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l stop -d 'stop the daemon started by --daemon'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -s i -l incremental -d 'use incremental compilation'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand fmt" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l host -d 'host targets to build' -r -f
@@ -258,6 +331,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand doc" -l exclude -d 'build path
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -266,6 +341,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand doc" -s j -l jobs -d 'number o
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -278,8 +354,13 @@ complete -c x.py -n "__fish_x.py_using_subcommand doc" -s i -l incremental -d 'u
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand doc" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand doc" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
@@ -291,6 +372,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand test" -l compare-mode -d 'mode
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l pass -d 'force {check,build,run}-pass tests to this mode' -r
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l run -d 'whether to execute run-* tests' -r
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l host -d 'host targets to build' -r -f
@@ -299,6 +381,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand test" -l exclude -d 'build pat
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -307,6 +391,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand test" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -317,21 +402,27 @@ complete -c x.py -n "__fish_x.py_using_subcommand test" -l no-doc -d 'do not run
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l doc -d 'only run doc tests'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l bless -d 'whether to automatically update stderr/stdout files'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l force-rerun -d 'rerun tests even if the inputs are unchanged'
-complete -c x.py -n "__fish_x.py_using_subcommand test" -l only-modified -d 'only run tests that result has been changed'
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l only-modified -d 'only run tests that result has been changed, and only check modified files with tidy'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l rustfix-coverage -d 'enable this to generate a Rustfix coverage file, which is saved in `/<build_base>/rustfix_missing_coverage.txt`'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -s i -l incremental -d 'use incremental compilation'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand test" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand test" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l test-args -d 'extra arguments to be passed for the test tool being used (e.g. libtest, compiletest or rustdoc)' -r
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l host -d 'host targets to build' -r -f
@@ -340,6 +431,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand miri" -l exclude -d 'build pat
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -348,6 +441,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand miri" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -361,14 +455,20 @@ complete -c x.py -n "__fish_x.py_using_subcommand miri" -s i -l incremental -d '
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand miri" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand miri" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l test-args -r
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l host -d 'host targets to build' -r -f
@@ -377,6 +477,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand bench" -l exclude -d 'build pa
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -385,6 +487,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand bench" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -395,14 +498,20 @@ complete -c x.py -n "__fish_x.py_using_subcommand bench" -s i -l incremental -d
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand bench" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand bench" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l stage -d 'Clean a specific stage without touching other artifacts. By default, every stage is cleaned if this option is not used' -r
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l host -d 'host targets to build' -r -f
@@ -411,6 +520,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand clean" -l exclude -d 'build pa
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l src -d 'path to the root of the rust checkout' -r -f -a "(__fish_complete_directories)"
@@ -418,24 +529,32 @@ complete -c x.py -n "__fish_x.py_using_subcommand clean" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l reproducible-artifact -d 'Additional reproducible artifacts that should be added to the reproducible artifacts archive' -r
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l set -d 'override options in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l all -d 'Clean the entire build directory (not used by default)'
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l gc -d 'Remove stale artifacts according to the build.gc policies of config.toml'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -s i -l incremental -d 'use incremental compilation'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand clean" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand clean" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l host -d 'host targets to build' -r -f
@@ -444,6 +563,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand dist" -l exclude -d 'build pat
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -452,6 +573,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand dist" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -462,13 +584,19 @@ complete -c x.py -n "__fish_x.py_using_subcommand dist" -s i -l incremental -d '
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand dist" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand dist" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l host -d 'host targets to build' -r -f
@@ -477,6 +605,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand install" -l exclude -d 'build
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -485,6 +615,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand install" -s j -l jobs -d 'numb
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -495,14 +626,20 @@ complete -c x.py -n "__fish_x.py_using_subcommand install" -s i -l incremental -
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand install" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand install" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l args -d 'arguments for the tool' -r
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l host -d 'host targets to build' -r -f
@@ -511,6 +648,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand run" -l exclude -d 'build path
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -519,6 +658,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand run" -s j -l jobs -d 'number o
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -529,13 +669,19 @@ complete -c x.py -n "__fish_x.py_using_subcommand run" -s i -l incremental -d 'u
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand run" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand run" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l host -d 'host targets to build' -r -f
@@ -544,6 +690,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand setup" -l exclude -d 'build pa
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -552,6 +700,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand setup" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -562,13 +711,19 @@ complete -c x.py -n "__fish_x.py_using_subcommand setup" -s i -l incremental -d
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand setup" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand setup" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l host -d 'host targets to build' -r -f
@@ -577,6 +732,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l exclude -d 'build
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -585,6 +742,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand suggest" -s j -l jobs -d 'numb
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -596,14 +754,20 @@ complete -c x.py -n "__fish_x.py_using_subcommand suggest" -s i -l incremental -
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand suggest" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l sync -d 'Additional `Cargo.toml` to sync and vendor' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l host -d 'host targets to build' -r -f
@@ -612,6 +776,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l exclude -d 'build p
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -620,6 +786,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand vendor" -s j -l jobs -d 'numbe
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -631,13 +798,19 @@ complete -c x.py -n "__fish_x.py_using_subcommand vendor" -s i -l incremental -d
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand vendor" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l build -d 'build target of the stage0 compiler' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l host -d 'host targets to build' -r -f
@@ -646,6 +819,8 @@ complete -c x.py -n "__fish_x.py_using_subcommand perf" -l exclude -d 'build pat
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l skip -d 'build paths to skip' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l rustc-error-format -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
@@ -654,6 +829,7 @@ complete -c x.py -n "__fish_x.py_using_subcommand perf" -s j -l jobs -d 'number
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l error-format -d 'rustc error format' -r -f
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
@@ -664,9 +840,226 @@ complete -c x.py -n "__fish_x.py_using_subcommand perf" -s i -l incremental -d '
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l include-default-paths -d 'include default paths in addition to the provided ones'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l dry-run -d 'dry run; don\'t build anything'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l json-output -d 'use message-format=json'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand perf" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l enable-bolt-settings -d 'Enable BOLT link flags'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
 complete -c x.py -n "__fish_x.py_using_subcommand perf" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l build -d 'build target of the stage0 compiler' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l host -d 'host targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l target -d 'target targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l exclude -d 'build paths to exclude' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l skip -d 'build paths to skip' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l rustc-error-format -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l src -d 'path to the root of the rust checkout' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -s j -l jobs -d 'number of jobs to run in parallel' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l error-format -d 'rustc error format' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l reproducible-artifact -d 'Additional reproducible artifacts that should be added to the reproducible artifacts archive' -r
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l set -d 'override options in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -s i -l incremental -d 'use incremental compilation'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l include-default-paths -d 'include default paths in addition to the provided ones'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l dry-run -d 'dry run; don\'t build anything'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l json-output -d 'use message-format=json'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l enable-bolt-settings -d 'Enable BOLT link flags'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
+complete -c x.py -n "__fish_x.py_using_subcommand bug-report" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l build -d 'build target of the stage0 compiler' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l host -d 'host targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l target -d 'target targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l exclude -d 'build paths to exclude' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l skip -d 'build paths to skip' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l rustc-error-format -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l src -d 'path to the root of the rust checkout' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -s j -l jobs -d 'number of jobs to run in parallel' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l error-format -d 'rustc error format' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l reproducible-artifact -d 'Additional reproducible artifacts that should be added to the reproducible artifacts archive' -r
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l set -d 'override options in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l record -d 'record the states as the expected ones for later runs'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -s i -l incremental -d 'use incremental compilation'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l include-default-paths -d 'include default paths in addition to the provided ones'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l dry-run -d 'dry run; don\'t build anything'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l json-output -d 'use message-format=json'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l enable-bolt-settings -d 'Enable BOLT link flags'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
+complete -c x.py -n "__fish_x.py_using_subcommand toolstate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l build -d 'build target of the stage0 compiler' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l host -d 'host targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l target -d 'target targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l exclude -d 'build paths to exclude' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l skip -d 'build paths to skip' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l rustc-error-format -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l src -d 'path to the root of the rust checkout' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -s j -l jobs -d 'number of jobs to run in parallel' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l error-format -d 'rustc error format' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l reproducible-artifact -d 'Additional reproducible artifacts that should be added to the reproducible artifacts archive' -r
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l set -d 'override options in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -s i -l incremental -d 'use incremental compilation'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l include-default-paths -d 'include default paths in addition to the provided ones'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l dry-run -d 'dry run; don\'t build anything'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l json-output -d 'use message-format=json'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l enable-bolt-settings -d 'Enable BOLT link flags'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
+complete -c x.py -n "__fish_x.py_using_subcommand completions" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l replay -d 'replay the invocation recorded in PATH instead of recording one' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l build -d 'build target of the stage0 compiler' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l host -d 'host targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l target -d 'target targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l exclude -d 'build paths to exclude' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l skip -d 'build paths to skip' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l rustc-error-format -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l src -d 'path to the root of the rust checkout' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -s j -l jobs -d 'number of jobs to run in parallel' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l error-format -d 'rustc error format' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l reproducible-artifact -d 'Additional reproducible artifacts that should be added to the reproducible artifacts archive' -r
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l set -d 'override options in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -s i -l incremental -d 'use incremental compilation'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l include-default-paths -d 'include default paths in addition to the provided ones'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l dry-run -d 'dry run; don\'t build anything'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l json-output -d 'use message-format=json'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l enable-bolt-settings -d 'Enable BOLT link flags'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
+complete -c x.py -n "__fish_x.py_using_subcommand repro" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l config -d 'TOML configuration file for build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l profile -d 'use the settings, subcommand and paths of `[profiles.NAME]` in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l build-dir -d 'Build directory, overrides `build.build-dir` in `config.toml`' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l build -d 'build target of the stage0 compiler' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l host -d 'host targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l target -d 'target targets to build' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l exclude -d 'build paths to exclude' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l skip -d 'build paths to skip' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l rustc-error-format -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l on-fail -d 'command to run on failure' -r -f -a "(__fish_complete_command)"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l on-failure -d 'what to do when a command fails: nothing, run it again and write build/failure-report.txt, or start a shell with its environment' -r -f -a "{nothing\t'',report\t'',shell\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l json-trace -d 'write the steps, commands and cargo artifacts as newline-delimited JSON events to PATH' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l stage -d 'stage to build (indicates compiler to use/test, e.g., stage 0 uses the bootstrap compiler, stage 1 the stage 0 rustc artifacts, etc.)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l keep-stage -d 'stage(s) to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l keep-stage-std -d 'stage(s) of the standard library to keep without recompiling (pass multiple times to keep e.g., both stages 0 and 1)' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l src -d 'path to the root of the rust checkout' -r -f -a "(__fish_complete_directories)"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -s j -l jobs -d 'number of jobs to run in parallel' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l warnings -d 'if value is deny, will deny warnings if value is warn, will emit warnings otherwise, use the default configured behaviour' -r -f -a "{deny\t'',warn\t'',default\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l error-format -d 'rustc error format' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l color -d 'whether to use color in cargo and rustc output' -r -f -a "{always\t'',never\t'',auto\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l ui -d 'how to show the progress of the build: plain output, or a view of the running steps at the bottom of the terminal' -r -f -a "{plain\t'',tty\t''}"
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l rust-profile-generate -d 'generate PGO profile with rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l rust-profile-use -d 'use PGO profile for rustc build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l llvm-profile-use -d 'use PGO profile for LLVM build' -r -F
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l reproducible-artifact -d 'Additional reproducible artifacts that should be added to the reproducible artifacts archive' -r
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l set -d 'override options in config.toml' -r -f
+complete -c x.py -n "__fish_x.py_using_subcommand why" -s v -l verbose -d 'use verbose output (-vv for very verbose)'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -s i -l incremental -d 'use incremental compilation'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l include-default-paths -d 'include default paths in addition to the provided ones'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l dry-run -d 'dry run; don\'t build anything'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l dump-bootstrap-shims -d 'Indicates whether to dump the work done from bootstrap shims'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l dump-graph -d 'write the graph of the steps to build/bootstrap-graph.{dot,json} instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l plan -d 'print the commands of the steps with their environment and working directory instead of running them'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l resume -d 'continue an interrupted invocation with the same arguments, skipping the steps it completed'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l timings -d 'write a report of the time spent in each step and crate to build/timings (with `fmt`, in each file)'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l json-output -d 'use message-format=json'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l bypass-bootstrap-lock -d 'Bootstrap uses this value to decide whether it should bypass locking the build process. This is rarely needed (e.g., compiling the std library for different targets in parallel)'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l allow-unknown-config -d 'warn about the unknown keys of config.toml, like the ones of forks, instead of failing'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l llvm-profile-generate -d 'generate PGO profile with llvm built for rustc'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l enable-bolt-settings -d 'Enable BOLT link flags'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -l skip-stage0-validation -d 'Skip stage0 compiler validation'
+complete -c x.py -n "__fish_x.py_using_subcommand why" -s h -l help -d 'Print help (see more with \'--help\')'
@@ -154,27 +154,38 @@ pub fn get_git_modified_files(
     extensions: &[&str],
 ) -> Result<Option<Vec<String>>, String> {
     let merge_base = get_closest_merge_commit(git_dir, config, &[])?;
+    get_git_modified_files_since(git_dir, merge_base.trim(), extensions).map(Some)
+}
 
+/// Returns the files that have been modified compared to the revision `base`, like
+/// `get_git_modified_files`.
+pub fn get_git_modified_files_since(
+    git_dir: Option<&Path>,
+    base: &str,
+    extensions: &[&str],
+) -> Result<Vec<String>, String> {
     let mut git = Command::new("git");
     if let Some(git_dir) = git_dir {
         git.current_dir(git_dir);
     }
-    let files = output_result(git.args(["diff-index", "--name-status", merge_base.trim()]))?
+    let files = output_result(git.args(["diff-index", "--name-status", base]))?
         .lines()
         .filter_map(|f| {
             let (status, name) = f.trim().split_once(char::is_whitespace).unwrap();
             if status == "D" {
                 None
-            } else if Path::new(name).extension().map_or(false, |ext| {
-                extensions.is_empty() || extensions.contains(&ext.to_str().unwrap())
-            }) {
+            } else if extensions.is_empty()
+                || Path::new(name)
+                    .extension()
+                    .map_or(false, |ext| extensions.contains(&ext.to_str().unwrap()))
+            {
                 Some(name.to_owned())
             } else {
                 None
             }
         })
         .collect();
-    Ok(Some(files))
+    Ok(files)
 }
 
 /// Returns the files that haven't been added to git yet.
@@ -237,6 +237,9 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Whether `expect` should be allowed in test functions or `#[cfg(test)]`
     #[lints(expect_used)]
     allow_expect_in_tests: bool = false,
+    /// Whether to allow `#[inline(always)]` on large or recursive functions with a `#[cfg(target_*)]` attribute
+    #[lints(large_inline_always)]
+    allow_large_inline_always_in_cfg_target: bool = true,
     /// Whether to allow mixed uninlined format args, e.g. `format!("{} {}", a, foo.bar)`
     #[lints(uninlined_format_args)]
     allow_mixed_uninlined_format_args: bool = true,
@@ -286,6 +289,16 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     #[lints(min_ident_chars)]
     allowed_idents_below_min_chars: Vec<String> =
         DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS.iter().map(ToString::to_string).collect(),
+    /// List of lints which are allowed in test functions or `#[cfg(test)]` modules, while keeping their
+    /// configured level in all other code.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-lints-in-tests = [ "unwrap_used", "expect_used" ]
+    /// ```
+    #[lints(dbg_macro, expect_used, unwrap_used)]
+    allowed_lints_in_tests: Vec<String> = Vec::new(),
     /// List of prefixes to allow when determining whether an item's name ends with the module's name.
     /// If the rest of an item's name is an allowed prefix (e.g. item `ToFoo` or `to_foo` in module `foo`),
     /// then don't emit a warning.
@@ -402,6 +415,12 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Whether to also run the listed lints on private items.
     #[lints(missing_errors_doc, missing_panics_doc, missing_safety_doc, unnecessary_safety_doc)]
     check_private_items: bool = false,
+    /// Whether to require a safety comment or a `# Safety` section in the documentation of `unsafe fn`s
+    #[lints(undocumented_unsafe_blocks)]
+    check_unsafe_fns: bool = false,
+    /// Whether to require a safety comment on `unsafe impl`s
+    #[lints(undocumented_unsafe_blocks)]
+    check_unsafe_impls: bool = true,
     /// The maximum cognitive complexity a function can have
     #[lints(cognitive_complexity)]
     cognitive_complexity_threshold: u64 = 25,
@@ -465,15 +484,42 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// The maximum amount of nesting a block can reside in
     #[lints(excessive_nesting)]
     excessive_nesting_threshold: u64 = 0,
+    /// Additional types whose destructor has to run, e.g. guards releasing a resource, which
+    /// shouldn't be passed to `mem::forget` or `ManuallyDrop::new`
+    #[lints(forget_guard)]
+    forget_guard_types: Vec<String> = Vec::new(),
     /// The maximum byte size a `Future` can have, before it triggers the `clippy::large_futures` lint
     #[lints(large_futures)]
     future_size_threshold: u64 = 16 * 1024,
+    /// Whether to skip emitting lints for code expanded from macros defined in other crates. Lints listed
+    /// in `lints-in-external-macros` are still emitted.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// ignore-external-macros = true
+    /// ```
+    #[lints(expect_used, option_env_unwrap, unwrap_used)]
+    ignore_external_macros: bool = false,
     /// A list of paths to types that should be treated as if they do not contain interior mutability
     #[lints(borrow_interior_mutable_const, declare_interior_mutable_const, ifs_same_cond, mutable_key_type)]
     ignore_interior_mutability: Vec<String> = Vec::from(["bytes::Bytes".into()]),
+    /// The maximum number of expressions in the body of a function marked `#[inline(always)]`
+    #[lints(large_inline_always)]
+    inline_always_size_threshold: u64 = 100,
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
     #[lints(result_large_err)]
     large_error_threshold: u64 = 128,
+    /// List of lints which are still emitted for code expanded from external macros when
+    /// `ignore-external-macros` is enabled.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// lints-in-external-macros = [ "unwrap_used" ]
+    /// ```
+    #[lints(expect_used, option_env_unwrap, unwrap_used)]
+    lints_in_external_macros: Vec<String> = Vec::new(),
     /// The lower bound for linting decimal literals
     #[lints(decimal_literal_representation)]
     literal_representation_threshold: u64 = 16384,
@@ -531,6 +577,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
         manual_bits,
         manual_c_str_literals,
         manual_clamp,
+        manual_div_ceil,
         manual_hash_one,
         manual_is_ascii_check,
         manual_let_else,
@@ -602,6 +649,16 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// if no suggestion can be made.
     #[lints(indexing_slicing)]
     suppress_restriction_lint_in_const: bool = false,
+    /// List of lints which are only emitted in test functions or `#[cfg(test)]` modules, and are allowed
+    /// in all other code.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// test-only-lints = [ "dbg_macro" ]
+    /// ```
+    #[lints(dbg_macro, expect_used, unwrap_used)]
+    test_only_lints: Vec<String> = Vec::new(),
     /// The maximum size of objects (in bytes) that will be linted. Larger objects are ok on the heap
     #[lints(boxed_local, useless_vec)]
     too_large_for_stack: u64 = 200,
@@ -619,6 +676,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// The maximum complexity a type can have
     #[lints(type_complexity)]
     type_complexity_threshold: u64 = 250,
+    /// Additional functions creating unbounded channels, e.g. `tokio::sync::mpsc::unbounded_channel`,
+    /// which return a `(sender, receiver)` tuple
+    #[lints(unbounded_channel_in_loop)]
+    unbounded_channel_constructors: Vec<String> = Vec::new(),
     /// The byte size a `T` in `Box<T>` can have, below which it triggers the `clippy::unnecessary_box` lint
     #[lints(unnecessary_box_returns)]
     unnecessary_box_size: u64 = 128,
@@ -628,6 +689,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Enables verbose mode. Triggers if there is more than one uppercase char next to each other
     #[lints(upper_case_acronyms)]
     upper_case_acronyms_aggressive: bool = false,
+    /// The list of types which may be boxed inside of a `Vec`, e.g. because their address has to
+    /// stay stable when the `Vec` reallocates
+    #[lints(vec_box)]
+    vec_box_allowed_types: Vec<String> = Vec::new(),
     /// The size of the boxed type in bytes, where boxing in a `Vec` is allowed
     #[lints(vec_box)]
     vec_box_size_threshold: u64 = 4096,
@@ -268,7 +268,7 @@ fn emit_invalid_type(cx: &LateContext<'_>, span: Span, path: &'static str, reaso
     );
 }
 
-fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
+pub(crate) fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
     cx.tcx.is_diagnostic_item(sym::MutexGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockReadGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockWriteGuard, def_id)
@@ -0,0 +1,169 @@
+use crate::await_holding_invalid::is_mutex_guard;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::visitors::{for_each_expr_without_closures, Visitable};
+use clippy_utils::{match_def_path, path_to_local_id, paths};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `MutexGuard`s and `RwLock` guards which are held while calling
+    /// `std::thread::sleep`, waiting on a `Condvar` with a different lock, or
+    /// spawning and waiting for a process with `std::process::Command`.
+    ///
+    /// ### Why is this bad?
+    /// Every other thread trying to acquire the lock is blocked for as long as
+    /// the call takes. This is rarely intended, and easily leads to severe
+    /// contention or deadlocks.
+    ///
+    /// The guard can be dropped before the blocking call, either by
+    /// introducing a scope around the code that needs the lock or by an
+    /// explicit call to [`Drop::drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html).
+    ///
+    /// ### Known problems
+    /// Only guards bound to a local in the same block as the blocking call are
+    /// detected.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// # use std::time::Duration;
+    /// fn foo(x: &Mutex<u32>) {
+    ///     let mut guard = x.lock().unwrap();
+    ///     *guard += 1;
+    ///     std::thread::sleep(Duration::from_secs(1));
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// # use std::time::Duration;
+    /// fn foo(x: &Mutex<u32>) {
+    ///     {
+    ///         let mut guard = x.lock().unwrap();
+    ///         *guard += 1;
+    ///     }
+    ///     std::thread::sleep(Duration::from_secs(1));
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BLOCKING_CALL_HOLDING_LOCK,
+    suspicious,
+    "holding a `MutexGuard` while sleeping or waiting for a process or another lock"
+}
+
+declare_lint_pass!(BlockingCallHoldingLock => [BLOCKING_CALL_HOLDING_LOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingCallHoldingLock {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Let(local) = stmt.kind
+                && let PatKind::Binding(_, guard_id, ..) = local.pat.kind
+                && let Some(init) = local.init
+                && let ty::Adt(adt, _) = cx.typeck_results().expr_ty(init).kind()
+                && is_mutex_guard(cx, adt.did())
+            {
+                let blocking_calls = find_blocking_calls(cx, guard_id, &block.stmts[i + 1..], block.expr);
+                if !blocking_calls.is_empty() {
+                    span_lint_hir_and_then(
+                        cx,
+                        BLOCKING_CALL_HOLDING_LOCK,
+                        local.hir_id,
+                        local.pat.span,
+                        "this lock guard is held across a blocking call",
+                        |diag| {
+                            diag.help(
+                                "consider narrowing the scope of the guard or dropping it before the blocking call",
+                            );
+                            diag.span_note(
+                                blocking_calls,
+                                "these are all the blocking calls this lock is held through",
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Collects the blocking calls in `stmts` and `expr` until `guard_id` is explicitly dropped.
+fn find_blocking_calls<'tcx>(
+    cx: &LateContext<'tcx>,
+    guard_id: HirId,
+    stmts: &'tcx [Stmt<'tcx>],
+    expr: Option<&'tcx Expr<'tcx>>,
+) -> Vec<Span> {
+    let mut blocking_calls = Vec::new();
+    for stmt in stmts {
+        if let StmtKind::Semi(e) | StmtKind::Expr(e) = stmt.kind
+            && is_drop_of(cx, e, guard_id)
+        {
+            return blocking_calls;
+        }
+        collect_blocking_calls(cx, guard_id, stmt, &mut blocking_calls);
+    }
+    if let Some(expr) = expr {
+        collect_blocking_calls(cx, guard_id, expr, &mut blocking_calls);
+    }
+    blocking_calls
+}
+
+fn collect_blocking_calls<'tcx>(
+    cx: &LateContext<'tcx>,
+    guard_id: HirId,
+    node: impl Visitable<'tcx>,
+    blocking_calls: &mut Vec<Span>,
+) {
+    for_each_expr_without_closures(node, |e| {
+        if is_blocking_call(cx, e, guard_id) {
+            blocking_calls.push(e.span);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+fn is_drop_of(cx: &LateContext<'_>, expr: &Expr<'_>, guard_id: HirId) -> bool {
+    if let ExprKind::Call(func, [arg]) = expr.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+    {
+        cx.tcx.is_diagnostic_item(sym::mem_drop, def_id) && path_to_local_id(arg, guard_id)
+    } else {
+        false
+    }
+}
+
+fn is_blocking_call(cx: &LateContext<'_>, expr: &Expr<'_>, guard_id: HirId) -> bool {
+    match expr.kind {
+        ExprKind::Call(func, _) => {
+            if let ExprKind::Path(ref qpath) = func.kind
+                && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+            {
+                match_def_path(cx, def_id, &paths::THREAD_SLEEP)
+            } else {
+                false
+            }
+        },
+        ExprKind::MethodCall(path, recv, args, _) => {
+            let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+            if is_type_diagnostic_item(cx, recv_ty, sym::Command) {
+                matches!(path.ident.as_str(), "output" | "spawn" | "status")
+            } else if match_type(cx, recv_ty, &paths::CONDVAR) {
+                // Waiting on a condition variable releases the lock it's given, only waiting with a different
+                // lock keeps this one held.
+                path.ident.as_str().starts_with("wait")
+                    && args.first().is_some_and(|arg| !path_to_local_id(arg, guard_id))
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
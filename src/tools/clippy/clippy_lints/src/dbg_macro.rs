@@ -1,8 +1,8 @@
 use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::is_in_test;
 use clippy_utils::macros::{macro_backtrace, MacroCall};
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::test_scopes::TestScope;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, Node};
@@ -35,7 +35,7 @@
 }
 
 pub struct DbgMacro {
-    allow_dbg_in_tests: bool,
+    test_scope: TestScope,
     /// Tracks the `dbg!` macro callsites that are already checked.
     checked_dbg_call_site: FxHashSet<Span>,
     /// Tracks the previous `SyntaxContext`, to avoid walking the same context chain.
@@ -47,7 +47,11 @@ pub struct DbgMacro {
 impl DbgMacro {
     pub fn new(conf: &'static Conf) -> Self {
         DbgMacro {
-            allow_dbg_in_tests: conf.allow_dbg_in_tests,
+            test_scope: if conf.allow_dbg_in_tests {
+                TestScope::OutsideTests
+            } else {
+                TestScope::new(conf, DBG_MACRO)
+            },
             checked_dbg_call_site: FxHashSet::default(),
             prev_ctxt: SyntaxContext::root(),
         }
@@ -62,8 +66,9 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
             let Some(macro_call) = first_dbg_macro_in_expansion(cx, expr.span) &&
             !in_external_macro(cx.sess(), macro_call.span) &&
             self.checked_dbg_call_site.insert(macro_call.span) &&
-            // allows `dbg!` in test code if allow-dbg-in-test is set to true in clippy.toml
-            !(self.allow_dbg_in_tests && is_in_test(cx.tcx, expr.hir_id))
+            // allows `dbg!` in test code if allow-dbg-in-test is set to true in clippy.toml, or outside
+            // of test code if `dbg_macro` is listed in test-only-lints
+            !self.test_scope.is_skipped(cx.tcx, expr.hir_id)
         {
             self.prev_ctxt = cur_syntax_ctxt;
 
@@ -1,11 +1,15 @@
 use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_then, span_lint_hir_and_then};
-use clippy_utils::ty::{implements_trait, implements_trait_with_env, is_copy};
-use clippy_utils::{has_non_exhaustive_attr, is_lint_allowed, match_def_path, paths};
+use clippy_utils::ty::{implements_trait, implements_trait_with_env, is_copy, is_type_lang_item};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{has_non_exhaustive_attr, is_lint_allowed, match_def_path, path_to_local_id, paths};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{walk_expr, walk_fn, walk_item, FnKind, Visitor};
 use rustc_hir::{
-    self as hir, BlockCheckMode, BodyId, Expr, ExprKind, FnDecl, Impl, Item, ItemKind, Safety, UnsafeSource,
+    self as hir, BlockCheckMode, Body, BodyId, Expr, ExprKind, FnDecl, Impl, ImplItemKind, Item, ItemKind, LangItem,
+    Node, PatKind, Safety, UnOp, UnsafeSource,
 };
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
@@ -15,7 +19,7 @@
 };
 use rustc_session::declare_lint_pass;
 use rustc_span::def_id::LocalDefId;
-use rustc_span::{sym, Span};
+use rustc_span::{sym, Span, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -191,20 +195,71 @@
     "deriving `PartialEq` on a type that can implement `Eq`, without implementing `Eq`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Lints against manual `Hash` implementations for structs with a derived `PartialEq`
+    /// implementation, which don't hash all of the fields.
+    ///
+    /// ### Why is this bad?
+    /// The derived `PartialEq` compares every field, so a `Hash` implementation leaving some of
+    /// them out is usually out of sync with it, e.g. because a field was added later on. Values
+    /// which only differ in these fields always collide in a `HashMap`. If the fields are left out
+    /// on purpose because they shouldn't affect equality either, `PartialEq` has to be implemented
+    /// manually as well, or `k1 == k2 ⇒ hash(k1) == hash(k2)` no longer holds.
+    ///
+    /// ### Known problems
+    /// Only implementations which use `self` exclusively for accessing its fields are checked.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::hash::{Hash, Hasher};
+    /// #[derive(PartialEq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// impl Hash for Point {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.x.hash(state);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::hash::{Hash, Hasher};
+    /// #[derive(PartialEq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// impl Hash for Point {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.x.hash(state);
+    ///         self.y.hash(state);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub DERIVED_EQ_WITH_MANUAL_HASH,
+    pedantic,
+    "deriving `PartialEq` but implementing `Hash` explicitly without hashing all fields"
+}
+
 declare_lint_pass!(Derive => [
     EXPL_IMPL_CLONE_ON_COPY,
     DERIVED_HASH_WITH_MANUAL_EQ,
     DERIVE_ORD_XOR_PARTIAL_ORD,
     UNSAFE_DERIVE_DESERIALIZE,
-    DERIVE_PARTIAL_EQ_WITHOUT_EQ
+    DERIVE_PARTIAL_EQ_WITHOUT_EQ,
+    DERIVED_EQ_WITH_MANUAL_HASH,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Derive {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
-        if let ItemKind::Impl(Impl {
-            of_trait: Some(ref trait_ref),
-            ..
-        }) = item.kind
+        if let ItemKind::Impl(impl_) = item.kind
+            && let Some(trait_ref) = &impl_.of_trait
         {
             let ty = cx.tcx.type_of(item.owner_id).instantiate_identity();
             let is_automatically_derived = cx.tcx.has_attr(item.owner_id, sym::automatically_derived);
@@ -217,6 +272,7 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
                 check_partial_eq_without_eq(cx, item.span, trait_ref, ty);
             } else {
                 check_copy_clone(cx, item, trait_ref, ty);
+                check_manual_hash_derived_peq(cx, item, impl_, trait_ref, ty);
             }
         }
     }
@@ -264,6 +320,95 @@ fn check_hash_peq<'tcx>(
     }
 }
 
+/// Implementation of the `DERIVED_EQ_WITH_MANUAL_HASH` lint.
+fn check_manual_hash_derived_peq<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &Item<'_>,
+    impl_: &Impl<'_>,
+    trait_ref: &hir::TraitRef<'_>,
+    ty: Ty<'tcx>,
+) {
+    if let Some(def_id) = trait_ref.trait_def_id()
+        && cx.tcx.is_diagnostic_item(sym::Hash, def_id)
+        && let ty::Adt(adt, args) = ty.kind()
+        && adt.is_struct()
+        && let Some(peq_trait_def_id) = cx.tcx.lang_items().eq_trait()
+        && has_derived_peq(cx, peq_trait_def_id, ty)
+        && let Some(hash_fn) = impl_.items.iter().find(|impl_item| impl_item.ident.name == sym::hash)
+        && let ImplItemKind::Fn(_, body_id) = cx.tcx.hir().impl_item(hash_fn.id).kind
+        && let Some(hashed) = hashed_fields(cx, cx.tcx.hir().body(body_id))
+    {
+        let missing: Vec<_> = adt
+            .non_enum_variant()
+            .fields
+            .iter()
+            .filter(|field| {
+                !hashed.contains(&field.name) && !is_type_lang_item(cx, field.ty(cx.tcx, args), LangItem::PhantomData)
+            })
+            .map(|field| format!("`{}`", field.name))
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            DERIVED_EQ_WITH_MANUAL_HASH,
+            item.span,
+            "you are deriving `PartialEq` but have implemented `Hash` explicitly without hashing all fields",
+            |diag| {
+                diag.note(format!(
+                    "the derived `PartialEq` compares {}, which {} not hashed",
+                    missing.join(", "),
+                    if missing.len() == 1 { "is" } else { "are" }
+                ));
+                diag.help("hash all of the fields, or implement `PartialEq` manually to compare the same fields");
+            },
+        );
+    }
+}
+
+/// Checks if `ty` has a derived `impl PartialEq<ty> for ty`.
+fn has_derived_peq<'tcx>(cx: &LateContext<'tcx>, peq_trait_def_id: DefId, ty: Ty<'tcx>) -> bool {
+    let mut derived = false;
+    cx.tcx.for_each_relevant_impl(peq_trait_def_id, ty, |impl_id| {
+        derived |= cx.tcx.has_attr(impl_id, sym::automatically_derived)
+            && cx
+                .tcx
+                .impl_trait_ref(impl_id)
+                .is_some_and(|trait_ref| trait_ref.instantiate_identity().args.type_at(1) == ty);
+    });
+    derived
+}
+
+/// Returns the fields of `self` accessed in the body of `Hash::hash`, or `None` if `self` is used
+/// in any other way.
+fn hashed_fields(cx: &LateContext<'_>, body: &Body<'_>) -> Option<FxHashSet<Symbol>> {
+    let PatKind::Binding(_, self_id, ..) = body.params.first()?.pat.kind else {
+        return None;
+    };
+    let mut fields = FxHashSet::default();
+    let other_use = for_each_expr(cx, body.value, |e| {
+        if path_to_local_id(e, self_id) {
+            let mut parent = cx.tcx.parent_hir_node(e.hir_id);
+            if let Node::Expr(deref) = parent
+                && let ExprKind::Unary(UnOp::Deref, _) = deref.kind
+            {
+                parent = cx.tcx.parent_hir_node(deref.hir_id);
+            }
+            if let Node::Expr(field) = parent
+                && let ExprKind::Field(_, name) = field.kind
+            {
+                fields.insert(name.name);
+            } else {
+                return ControlFlow::Break(());
+            }
+        }
+        ControlFlow::Continue(())
+    });
+    other_use.is_none().then_some(fields)
+}
+
 /// Implementation of the `DERIVE_ORD_XOR_PARTIAL_ORD` lint.
 fn check_ord_partial_ord<'tcx>(
     cx: &LateContext<'tcx>,
@@ -0,0 +1,122 @@
+use crate::await_holding_invalid::is_mutex_guard;
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{def_path_def_ids, match_any_def_paths, path_def_id, paths};
+use rustc_hir::def_id::{DefId, DefIdSet};
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `mem::forget` and `ManuallyDrop::new` being used on lock guards, `RefCell`
+    /// borrows and the `Drain` iterators of `Vec`, `VecDeque` and `String`.
+    ///
+    /// ### Why is this bad?
+    /// The destructors of these types restore an invariant of the value they borrow from. A
+    /// forgotten lock guard never unlocks, so the next attempt to lock it deadlocks. A forgotten
+    /// `RefCell` borrow makes every later conflicting borrow panic, and a forgotten `Drain` may
+    /// leak the elements after the drained range.
+    ///
+    /// ### Configuration
+    /// More types can be added with the `forget-guard-types` configuration.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// let m = Mutex::new(0);
+    /// let guard = m.lock().unwrap();
+    /// std::mem::forget(guard);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// let m = Mutex::new(0);
+    /// let guard = m.lock().unwrap();
+    /// drop(guard);
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub FORGET_GUARD,
+    suspicious,
+    "`mem::forget` or `ManuallyDrop::new` on guards whose destructor has to run"
+}
+
+const DRAIN_PATHS: &[&[&str]] = &[&paths::VEC_DRAIN, &paths::VEC_DEQUE_DRAIN, &paths::STRING_DRAIN];
+
+pub struct ForgetGuard {
+    guard_types: DefIdSet,
+}
+
+impl ForgetGuard {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            guard_types: conf
+                .forget_guard_types
+                .iter()
+                .flat_map(|p| def_path_def_ids(tcx, &p.split("::").collect::<Vec<_>>()))
+                .collect(),
+        }
+    }
+
+    fn guard_note(&self, cx: &LateContext<'_>, ty: Ty<'_>) -> Option<&'static str> {
+        let ty::Adt(adt, _) = ty.kind() else {
+            return None;
+        };
+        let def_id = adt.did();
+        let note = if is_mutex_guard(cx, def_id) {
+            "the lock is never released, so any later attempt to acquire it will deadlock"
+        } else if matches!(
+            cx.tcx.get_diagnostic_name(def_id),
+            Some(sym::RefCellRef | sym::RefCellRefMut)
+        ) {
+            "the `RefCell` stays borrowed, so any later conflicting borrow will panic"
+        } else if match_any_def_paths(cx, def_id, DRAIN_PATHS).is_some() {
+            "the elements after the drained range may be leaked and removed from the collection"
+        } else if self.guard_types.contains(&def_id) {
+            "the type is listed in `forget-guard-types`, so its destructor is expected to run"
+        } else {
+            return None;
+        };
+        Some(note)
+    }
+}
+
+impl_lint_pass!(ForgetGuard => [FORGET_GUARD]);
+
+impl<'tcx> LateLintPass<'tcx> for ForgetGuard {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(def_id) = path_def_id(cx, func)
+            && let Some(name) = forgetting_fn_name(cx, expr, def_id)
+            && let Some(note) = self.guard_note(cx, cx.typeck_results().expr_ty(arg))
+        {
+            span_lint_and_then(
+                cx,
+                FORGET_GUARD,
+                expr.span,
+                format!("usage of `{name}` on a value whose destructor has to run"),
+                |diag| {
+                    diag.note(note);
+                    diag.help("use `drop` instead, or keep the value alive for as long as it's needed");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the name of the called function if it's `mem::forget` or `ManuallyDrop::new`.
+fn forgetting_fn_name(cx: &LateContext<'_>, call: &Expr<'_>, fn_id: DefId) -> Option<&'static str> {
+    if cx.tcx.is_diagnostic_item(sym::mem_forget, fn_id) {
+        Some("mem::forget")
+    } else if cx.tcx.opt_item_name(fn_id) == Some(sym::new)
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(call), LangItem::ManuallyDrop)
+    {
+        Some("ManuallyDrop::new")
+    } else {
+        None
+    }
+}
@@ -1,9 +1,10 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::macros::{find_format_arg_expr, is_format_macro, root_macro_call_first_node, FormatArgsStorage};
 use clippy_utils::{get_parent_as_impl, is_diag_trait_item, path_to_local, peel_ref_operators};
 use rustc_ast::{FormatArgsPiece, FormatTrait};
-use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind, QPath};
+use rustc_errors::{Applicability, Diag};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::kw;
@@ -15,6 +16,9 @@
     /// which uses `self` as a parameter.
     /// This is typically done indirectly with the `write!` macro or with `to_string()`.
     ///
+    /// Both `Display` and `Debug` implementations are checked, e.g. `format!("{}", self)` in
+    /// `impl Display` or `write!(f, "{:?}", self)` in `impl Debug`.
+    ///
     /// ### Why is this bad?
     /// This will lead to infinite recursion and a stack overflow.
     ///
@@ -151,28 +155,57 @@ struct FormatImplExpr<'a, 'tcx> {
 impl<'a, 'tcx> FormatImplExpr<'a, 'tcx> {
     fn check_to_string_in_display(&self) {
         if self.format_trait_impl.name == sym::Display
-            && let ExprKind::MethodCall(path, self_arg, ..) = self.expr.kind
-            // Get the hir_id of the object we are calling the method on
-            // Is the method to_string() ?
-            && path.ident.name == sym::to_string
+            && let Some((self_arg, expr_def_id)) = self.to_string_call()
             // Is the method a part of the ToString trait? (i.e. not to_string() implemented
             // separately)
-            && let Some(expr_def_id) = self.cx.typeck_results().type_dependent_def_id(self.expr.hir_id)
             && is_diag_trait_item(self.cx, expr_def_id, sym::ToString)
-            // Is the method is called on self
-            && let ExprKind::Path(QPath::Resolved(_, path)) = self_arg.kind
-            && let [segment] = path.segments
-            && segment.ident.name == kw::SelfLower
+            // Is the method is called on self (directly or via deref)
+            && self.is_self(self_arg)
         {
-            span_lint(
+            span_lint_and_then(
                 self.cx,
                 RECURSIVE_FORMAT_IMPL,
                 self.expr.span,
                 "using `self.to_string` in `fmt::Display` implementation will cause infinite recursion",
+                |diag| self.help(diag),
             );
         }
     }
 
+    /// Returns the receiver and the callee of `x.to_string()` or `ToString::to_string(x)`
+    fn to_string_call(&self) -> Option<(&'tcx Expr<'tcx>, DefId)> {
+        match self.expr.kind {
+            ExprKind::MethodCall(path, self_arg, [], _) if path.ident.name == sym::to_string => Some((
+                self_arg,
+                self.cx.typeck_results().type_dependent_def_id(self.expr.hir_id)?,
+            )),
+            ExprKind::Call(func, [self_arg])
+                if let ExprKind::Path(ref qpath) = func.kind
+                    && let Some(def_id) = self.cx.qpath_res(qpath, func.hir_id).opt_def_id()
+                    && self.cx.tcx.item_name(def_id) == sym::to_string =>
+            {
+                Some((self_arg, def_id))
+            },
+            _ => None,
+        }
+    }
+
+    fn is_self(&self, expr: &Expr<'_>) -> bool {
+        // Handle multiple dereferencing of references e.g. &&self
+        // Handle dereference of &self -> self that is equivalent (i.e. via *self in fmt() impl)
+        // Since the argument to fmt is itself a reference: &self
+        let reference = peel_ref_operators(self.cx, expr);
+        path_to_local(reference).map(|x| self.cx.tcx.hir().name(x)) == Some(kw::SelfLower)
+    }
+
+    fn help(&self, diag: &mut Diag<'_, ()>) {
+        if self.format_trait_impl.name == sym::Debug {
+            diag.help("delegate to the fields of `Self` instead, or use `#[derive(Debug)]`");
+        } else {
+            diag.help("delegate to the fields of `Self` instead");
+        }
+    }
+
     fn check_self_in_format_args(&self) {
         // Check each arg in format calls - do we ever use Display on self (directly or via deref)?
         if let Some(outer_macro) = root_macro_call_first_node(self.cx, self.expr)
@@ -205,19 +238,14 @@ fn check_self_in_format_args(&self) {
     }
 
     fn check_format_arg_self(&self, arg: &Expr<'_>) {
-        // Handle multiple dereferencing of references e.g. &&self
-        // Handle dereference of &self -> self that is equivalent (i.e. via *self in fmt() impl)
-        // Since the argument to fmt is itself a reference: &self
-        let reference = peel_ref_operators(self.cx, arg);
-        let map = self.cx.tcx.hir();
-        // Is the reference self?
-        if path_to_local(reference).map(|x| map.name(x)) == Some(kw::SelfLower) {
+        if self.is_self(arg) {
             let FormatTraitNames { name, .. } = self.format_trait_impl;
-            span_lint(
+            span_lint_and_then(
                 self.cx,
                 RECURSIVE_FORMAT_IMPL,
                 self.expr.span,
                 format!("using `self` as `{name}` in `impl {name}` will cause infinite recursion"),
+                |diag| self.help(diag),
             );
         }
     }
@@ -0,0 +1,169 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_ast::{AttrStyle, Attribute, NestedMetaItem};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::impl_lint_pass;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[inline(always)]` on functions whose body is larger than the configured
+    /// threshold, or which call themselves.
+    ///
+    /// ### Why is this bad?
+    /// Forcing a large function to be inlined copies its whole body into every caller, which
+    /// increases compile times and code size and often makes the caller slower instead of faster.
+    /// Recursive calls can't be inlined at all, so the attribute only costs compile time there.
+    ///
+    /// `#[inline]` still makes the function available for inlining across crates, while leaving
+    /// the decision to the optimizer.
+    ///
+    /// ### Configuration
+    /// Functions with a `#[cfg(target_*)]` attribute, e.g. wrappers around platform intrinsics, are
+    /// ignored unless `allow-large-inline-always-in-cfg-target` is disabled.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[inline(always)]
+    /// fn fact(n: u64) -> u64 {
+    ///     if n == 0 { 1 } else { n * fact(n - 1) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[inline]
+    /// fn fact(n: u64) -> u64 {
+    ///     if n == 0 { 1 } else { n * fact(n - 1) }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub LARGE_INLINE_ALWAYS,
+    suspicious,
+    "`#[inline(always)]` on large or recursive functions"
+}
+
+pub struct LargeInlineAlways {
+    size_threshold: u64,
+    allow_in_cfg_target: bool,
+}
+
+impl LargeInlineAlways {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            size_threshold: conf.inline_always_size_threshold,
+            allow_in_cfg_target: conf.allow_large_inline_always_in_cfg_target,
+        }
+    }
+}
+
+impl_lint_pass!(LargeInlineAlways => [LARGE_INLINE_ALWAYS]);
+
+impl<'tcx> LateLintPass<'tcx> for LargeInlineAlways {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure) || span.from_expansion() || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(def_id));
+        let Some(inline_attr) = attrs.iter().find(|attr| is_inline_always(attr)) else {
+            return;
+        };
+        if self.allow_in_cfg_target && attrs.iter().any(is_cfg_target) {
+            return;
+        }
+
+        let mut size: u64 = 0;
+        let mut recursive_call = None;
+        for_each_expr(cx, body.value, |e| {
+            size += 1;
+            if recursive_call.is_none() && calls_fn(cx, e, def_id) {
+                recursive_call = Some(e.span);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+
+        let msg = if recursive_call.is_some() {
+            "`#[inline(always)]` on a recursive function"
+        } else if size > self.size_threshold {
+            "`#[inline(always)]` on a large function"
+        } else {
+            return;
+        };
+
+        span_lint_and_then(cx, LARGE_INLINE_ALWAYS, inline_attr.span, msg, |diag| {
+            if let Some(call) = recursive_call {
+                diag.span_note(call, "recursive calls can't be inlined");
+            } else {
+                diag.note(format!(
+                    "the body has {size} expressions, which are copied into every caller, \
+                    increasing compile times and code size"
+                ));
+            }
+            if inline_attr.style == AttrStyle::Outer {
+                diag.span_suggestion(
+                    inline_attr.span,
+                    "use `#[inline]` to leave the decision to the optimizer",
+                    "#[inline]",
+                    Applicability::MachineApplicable,
+                );
+            }
+        });
+    }
+}
+
+fn is_inline_always(attr: &Attribute) -> bool {
+    attr.has_name(sym::inline)
+        && attr
+            .meta_item_list()
+            .is_some_and(|items| matches!(&*items, [item] if item.is_word() && item.has_name(sym::always)))
+}
+
+fn is_cfg_target(attr: &Attribute) -> bool {
+    fn has_target_predicate(item: &NestedMetaItem) -> bool {
+        if let Some(items) = item.meta_item_list() {
+            items.iter().any(has_target_predicate)
+        } else {
+            item.ident().is_some_and(|ident| ident.as_str().starts_with("target_"))
+        }
+    }
+
+    attr.has_name(sym::cfg)
+        && attr
+            .meta_item_list()
+            .is_some_and(|items| items.iter().any(has_target_predicate))
+}
+
+/// Checks if `expr` is a direct call to the function `fn_id`.
+fn calls_fn(cx: &LateContext<'_>, expr: &Expr<'_>, fn_id: LocalDefId) -> bool {
+    match expr.kind {
+        ExprKind::Call(func, _) => {
+            if let ExprKind::Path(ref qpath) = func.kind
+                && let Res::Def(DefKind::Fn | DefKind::AssocFn, id) = cx.qpath_res(qpath, func.hir_id)
+            {
+                id == fn_id.to_def_id()
+            } else {
+                false
+            }
+        },
+        ExprKind::MethodCall(..) => cx
+            .typeck_results()
+            .type_dependent_def_id(expr.hir_id)
+            .is_some_and(|id| id == fn_id.to_def_id()),
+        _ => false,
+    }
+}
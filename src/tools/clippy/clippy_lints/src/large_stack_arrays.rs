@@ -3,23 +3,39 @@
 use clippy_utils::is_from_proc_macro;
 use clippy_utils::macros::macro_backtrace;
 use clippy_utils::source::snippet;
-use rustc_hir::{ArrayLen, Expr, ExprKind, Item, ItemKind, Node};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{ArrayLen, Body, Expr, ExprKind, FnDecl, Item, ItemKind, LetStmt, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::layout::LayoutOf;
-use rustc_middle::ty::{self, ConstKind};
+use rustc_middle::ty::{self, ConstKind, Ty};
 use rustc_session::impl_lint_pass;
+use rustc_span::def_id::LocalDefId;
 use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for local arrays that may be too large.
+    /// Checks for local arrays and arrays passed by value that may be too large.
     ///
     /// ### Why is this bad?
-    /// Large local arrays may cause stack overflow.
+    /// Large local arrays may cause stack overflow, especially in recursive functions
+    /// or in `async` functions, where they become part of the future.
     ///
     /// ### Example
     /// ```rust,ignore
     /// let a = [0u32; 1_000_000];
+    ///
+    /// fn sum(values: [u32; 1_000_000]) -> u32 {
+    ///     values.iter().sum()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let a = vec![0u32; 1_000_000].into_boxed_slice();
+    ///
+    /// fn sum(values: &[u32; 1_000_000]) -> u32 {
+    ///     values.iter().sum()
+    /// }
     /// ```
     #[clippy::version = "1.41.0"]
     pub LARGE_STACK_ARRAYS,
@@ -63,10 +79,7 @@ impl<'tcx> LateLintPass<'tcx> for LargeStackArrays {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
         if let ExprKind::Repeat(_, _) | ExprKind::Array(_) = expr.kind
             && !self.is_from_vec_macro(cx, expr.span)
-            && let ty::Array(element_type, cst) = cx.typeck_results().expr_ty(expr).kind()
-            && let ConstKind::Value(_, ty::ValTree::Leaf(element_count)) = cst.kind()
-            && let element_count = element_count.to_target_usize(cx.tcx)
-            && let Ok(element_size) = cx.layout_of(*element_type).map(|l| l.size.bytes())
+            && let Some(size) = array_size(cx, cx.typeck_results().expr_ty(expr))
             && !cx.tcx.hir().parent_iter(expr.hir_id).any(|(_, node)| {
                 matches!(
                     node,
@@ -76,7 +89,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
                     })
                 )
             })
-            && u128::from(self.maximum_allowed_size) < u128::from(element_count) * u128::from(element_size)
+            && u128::from(self.maximum_allowed_size) < size
         {
             span_lint_and_then(
                 cx,
@@ -97,6 +110,93 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
             );
         }
     }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &LetStmt<'tcx>) {
+        // Array expressions are already linted in `check_expr`
+        if !matches!(
+            local.init,
+            Some(Expr {
+                kind: ExprKind::Repeat(..) | ExprKind::Array(_),
+                ..
+            })
+        ) && !in_external_macro(cx.sess(), local.span)
+            && let ty = cx.typeck_results().pat_ty(local.pat)
+            && let Some(size) = array_size(cx, ty)
+            && u128::from(self.maximum_allowed_size) < size
+        {
+            span_lint_and_then(
+                cx,
+                LARGE_STACK_ARRAYS,
+                local.pat.span,
+                format!(
+                    "allocating a local array larger than {} bytes",
+                    self.maximum_allowed_size
+                ),
+                |diag| {
+                    let element_ty = element_ty(ty);
+                    diag.help(format!(
+                        "consider allocating on the heap with `Box<[{element_ty}]>` or `Vec`"
+                    ));
+                },
+            );
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        if in_external_macro(cx.sess(), span) {
+            return;
+        }
+        for param in body.params {
+            let ty = cx.typeck_results().pat_ty(param.pat);
+            if let Some(size) = array_size(cx, ty)
+                && u128::from(self.maximum_allowed_size) < size
+            {
+                span_lint_and_then(
+                    cx,
+                    LARGE_STACK_ARRAYS,
+                    param.span,
+                    format!(
+                        "passing an array larger than {} bytes by value",
+                        self.maximum_allowed_size
+                    ),
+                    |diag| {
+                        let element_ty = element_ty(ty);
+                        diag.help(format!(
+                            "consider passing a reference or a `Box<[{element_ty}]>` instead"
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the size of `ty` in bytes if it's an array of a known length.
+fn array_size<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<u128> {
+    if let ty::Array(element_type, cst) = ty.kind()
+        && let ConstKind::Value(_, ty::ValTree::Leaf(element_count)) = cst.kind()
+        && let element_count = element_count.to_target_usize(cx.tcx)
+        && let Ok(element_size) = cx.layout_of(*element_type).map(|l| l.size.bytes())
+    {
+        Some(u128::from(element_count) * u128::from(element_size))
+    } else {
+        None
+    }
+}
+
+fn element_ty(ty: Ty<'_>) -> Ty<'_> {
+    match ty.kind() {
+        ty::Array(element_type, _) => *element_type,
+        _ => ty,
+    }
 }
 
 /// Only giving help messages if the expr does not contains macro expanded codes.
@@ -82,6 +82,7 @@
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
+mod blocking_call_holding_lock;
 mod blocks_in_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
@@ -140,6 +141,7 @@
 mod field_scoped_visibility_modifiers;
 mod float_literal;
 mod floating_point_arithmetic;
+mod forget_guard;
 mod format;
 mod format_args;
 mod format_impl;
@@ -185,6 +187,7 @@
 mod large_enum_variant;
 mod large_futures;
 mod large_include_file;
+mod large_inline_always;
 mod large_stack_arrays;
 mod large_stack_frames;
 mod legacy_numeric_constants;
@@ -203,6 +206,7 @@
 mod manual_async_fn;
 mod manual_bits;
 mod manual_clamp;
+mod manual_div_ceil;
 mod manual_float_methods;
 mod manual_hash_one;
 mod manual_is_ascii_check;
@@ -254,6 +258,7 @@
 mod needless_else;
 mod needless_for_each;
 mod needless_if;
+mod needless_impl_trait_captures;
 mod needless_late_init;
 mod needless_maybe_sized;
 mod needless_parens_on_range_literals;
@@ -350,6 +355,8 @@
 mod transmute;
 mod tuple_array_conversions;
 mod types;
+mod unbounded_channel_in_loop;
+mod unbounded_const_recursion;
 mod unconditional_recursion;
 mod undocumented_unsafe_blocks;
 mod unicode;
@@ -602,7 +609,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(utils::author::Author));
     store.register_late_pass(move |tcx| Box::new(await_holding_invalid::AwaitHolding::new(tcx, conf)));
     store.register_late_pass(|_| Box::new(serde_api::SerdeApi));
-    store.register_late_pass(move |_| Box::new(types::Types::new(conf)));
+    store.register_late_pass(move |tcx| Box::new(types::Types::new(tcx, conf)));
     store.register_late_pass(|_| Box::new(booleans::NonminimalBool));
     store.register_late_pass(|_| Box::new(enum_clike::UnportableVariant));
     store.register_late_pass(|_| Box::new(float_literal::FloatLiteral));
@@ -763,7 +770,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(let_underscore::LetUnderscore));
     store.register_early_pass(|| Box::<single_component_path_imports::SingleComponentPathImports>::default());
     store.register_late_pass(move |_| Box::new(excessive_bools::ExcessiveBools::new(conf)));
-    store.register_early_pass(|| Box::new(option_env_unwrap::OptionEnvUnwrap));
+    store.register_early_pass(move || Box::new(option_env_unwrap::OptionEnvUnwrap::new(conf)));
     store.register_late_pass(move |_| Box::new(wildcard_imports::WildcardImports::new(conf)));
     store.register_late_pass(|_| Box::<redundant_pub_crate::RedundantPubCrate>::default());
     store.register_late_pass(|_| Box::new(unnamed_address::UnnamedAddress));
@@ -933,5 +940,12 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(set_contains_or_insert::SetContainsOrInsert));
     store.register_early_pass(|| Box::new(byte_char_slices::ByteCharSlice));
     store.register_early_pass(|| Box::new(cfg_not_test::CfgNotTest));
+    store.register_late_pass(|_| Box::new(blocking_call_holding_lock::BlockingCallHoldingLock));
+    store.register_late_pass(|_| Box::new(needless_impl_trait_captures::NeedlessImplTraitCaptures));
+    store.register_late_pass(move |_| Box::new(large_inline_always::LargeInlineAlways::new(conf)));
+    store.register_late_pass(|_| Box::new(unbounded_const_recursion::UnboundedConstRecursion));
+    store.register_late_pass(move |_| Box::new(manual_div_ceil::ManualDivCeil::new(conf)));
+    store.register_late_pass(move |tcx| Box::new(forget_guard::ForgetGuard::new(tcx, conf)));
+    store.register_late_pass(move |tcx| Box::new(unbounded_channel_in_loop::UnboundedChannelInLoop::new(tcx, conf)));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
@@ -0,0 +1,178 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{is_integer_const, SpanlessEq};
+use rustc_ast::{LitIntType, LitKind};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of ceiling division, `(a + b - 1) / b`, and of rounding
+    /// up to a multiple, `(a + b - 1) / b * b`, on unsigned integers.
+    ///
+    /// ### Why is this bad?
+    /// `div_ceil` and `next_multiple_of` are clearer about the intent. They also don't overflow
+    /// when `a + b - 1` doesn't fit into the integer type, which makes the manual version panic
+    /// in debug builds and return a wrong result in release builds.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let (bytes, page): (u32, u32) = (100, 32);
+    /// let pages = (bytes + page - 1) / page;
+    /// let size = (bytes + page - 1) / page * page;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let (bytes, page): (u32, u32) = (100, 32);
+    /// let pages = bytes.div_ceil(page);
+    /// let size = bytes.next_multiple_of(page);
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub MANUAL_DIV_CEIL,
+    complexity,
+    "manual implementations of `div_ceil` or `next_multiple_of`"
+}
+
+pub struct ManualDivCeil {
+    msrv: Msrv,
+}
+
+impl ManualDivCeil {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            msrv: conf.msrv.clone(),
+        }
+    }
+}
+
+impl_lint_pass!(ManualDivCeil => [MANUAL_DIV_CEIL]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualDivCeil {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+            && !expr.span.from_expansion()
+            && let ty::Uint(_) = cx.typeck_results().expr_ty(expr).kind()
+            && self.msrv.meets(msrvs::DIV_CEIL)
+        {
+            match op.node {
+                BinOpKind::Div => {
+                    if let Some(dividend) = manual_div_ceil(cx, lhs, rhs)
+                        && !is_rounded_up(cx, expr, rhs)
+                    {
+                        emit(cx, expr, dividend, rhs, "div_ceil");
+                    }
+                },
+                BinOpKind::Mul => {
+                    for (div, multiple) in [(lhs, rhs), (rhs, lhs)] {
+                        if let ExprKind::Binary(div_op, numerator, divisor) = div.kind
+                            && div_op.node == BinOpKind::Div
+                            && SpanlessEq::new(cx).eq_expr(divisor, multiple)
+                            && let Some(dividend) = manual_div_ceil(cx, numerator, divisor)
+                        {
+                            emit(cx, expr, dividend, multiple, "next_multiple_of");
+                            break;
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Returns `a` if `numerator / divisor` is `(a + divisor - 1) / divisor` or one of its variants.
+fn manual_div_ceil<'tcx>(
+    cx: &LateContext<'tcx>,
+    numerator: &'tcx Expr<'tcx>,
+    divisor: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(op, lhs, rhs) = numerator.kind else {
+        return None;
+    };
+    let mut eq = SpanlessEq::new(cx);
+    match op.node {
+        // `(a + b) - 1` and `(b + a) - 1`
+        BinOpKind::Sub if is_integer_const(cx, rhs, 1) => match lhs.kind {
+            ExprKind::Binary(add_op, a, b) if add_op.node == BinOpKind::Add => {
+                if eq.eq_expr(b, divisor) {
+                    Some(a)
+                } else if eq.eq_expr(a, divisor) {
+                    Some(b)
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        },
+        // `a + (b - 1)` and `(b - 1) + a`
+        BinOpKind::Add => [(lhs, rhs), (rhs, lhs)].into_iter().find_map(|(a, sub)| {
+            if let ExprKind::Binary(sub_op, b, one) = sub.kind
+                && sub_op.node == BinOpKind::Sub
+                && is_integer_const(cx, one, 1)
+                && eq.eq_expr(b, divisor)
+            {
+                Some(a)
+            } else {
+                None
+            }
+        }),
+        _ => None,
+    }
+}
+
+/// Checks if the division is multiplied by the divisor again, which is linted as a manual
+/// `next_multiple_of` instead.
+fn is_rounded_up(cx: &LateContext<'_>, div: &Expr<'_>, divisor: &Expr<'_>) -> bool {
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(div.hir_id)
+        && let ExprKind::Binary(op, lhs, rhs) = parent.kind
+        && op.node == BinOpKind::Mul
+    {
+        let other = if lhs.hir_id == div.hir_id { rhs } else { lhs };
+        SpanlessEq::new(cx).eq_expr(other, divisor)
+    } else {
+        false
+    }
+}
+
+fn emit(cx: &LateContext<'_>, expr: &Expr<'_>, dividend: &Expr<'_>, divisor: &Expr<'_>, method: &str) {
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    let recv = if let ExprKind::Lit(lit) = dividend.kind
+        && let LitKind::Int(_, LitIntType::Unsuffixed) = lit.node
+    {
+        // `7.div_ceil(b)` doesn't compile, the type of the literal has to be given
+        let (snip, _) = snippet_with_context(cx, dividend.span, ctxt, "..", &mut app);
+        format!("{snip}_{}", cx.typeck_results().expr_ty(dividend))
+    } else {
+        Sugg::hir_with_context(cx, dividend, ctxt, "..", &mut app)
+            .maybe_par()
+            .to_string()
+    };
+    let (divisor, _) = snippet_with_context(cx, divisor.span, ctxt, "..", &mut app);
+
+    span_lint_and_then(
+        cx,
+        MANUAL_DIV_CEIL,
+        expr.span,
+        format!("manually reimplementing `{method}`"),
+        |diag| {
+            diag.span_suggestion(
+                expr.span,
+                format!("consider using `{method}`"),
+                format!("{recv}.{method}({divisor})"),
+                app,
+            );
+            diag.note(format!(
+                "unlike the manual implementation, `{method}` doesn't overflow for large dividends"
+            ));
+        },
+    );
+}
@@ -3,21 +3,22 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::macros::matching_root_macro_call;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::{higher, is_in_const_context, path_to_local, peel_ref_operators};
+use clippy_utils::{higher, is_in_const_context, path_to_local, peel_ref_operators, SpanlessEq};
 use rustc_ast::ast::RangeLimits;
 use rustc_ast::LitKind::{Byte, Char};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Node, Param, PatKind, RangeEnd};
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node, Param, PatKind, RangeEnd};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
+use rustc_middle::ty::{self, UintTy};
 use rustc_session::impl_lint_pass;
 use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
     /// Suggests to use dedicated built-in methods,
-    /// `is_ascii_(lowercase|uppercase|digit|hexdigit)` for checking on corresponding
-    /// ascii range
+    /// `is_ascii_(lowercase|uppercase|alphabetic|alphanumeric|digit|hexdigit)` for checking on
+    /// corresponding ascii range, written as a pattern, a range `contains` call or a pair of
+    /// comparisons, or any combination of these joined by `||`
     ///
     /// ### Why is this bad?
     /// Using the built-in functions is more readable and makes it
@@ -35,6 +36,10 @@
     ///     ('0'..='9').contains(&'0');
     ///     ('a'..='z').contains(&'a');
     ///     ('A'..='Z').contains(&'A');
+    ///
+    ///     let c = 'x';
+    ///     c >= '0' && c <= '9';
+    ///     ('0'..='9').contains(&c) || ('a'..='z').contains(&c) || ('A'..='Z').contains(&c);
     /// }
     /// ```
     /// Use instead:
@@ -49,6 +54,10 @@
     ///     '0'.is_ascii_digit();
     ///     'a'.is_ascii_lowercase();
     ///     'A'.is_ascii_uppercase();
+    ///
+    ///     let c = 'x';
+    ///     c.is_ascii_digit();
+    ///     c.is_ascii_alphanumeric();
     /// }
     /// ```
     #[clippy::version = "1.67.0"]
@@ -86,11 +95,82 @@ enum CharRange {
     UpperHexLetter,
     /// '0..=9' | 'a..=f' | 'A..=F'
     HexDigit,
+    /// '0..=9' | 'a..=z' | 'A..=Z'
+    Alphanumeric,
+    /// Any other combination of the ranges above, as a set of the classes below
+    Mixed(u8),
     Otherwise,
 }
 
+const DIGIT: u8 = 0b00001;
+const LOWER_HEX: u8 = 0b00010;
+const LOWER_REST: u8 = 0b00100;
+const UPPER_HEX: u8 = 0b01000;
+const UPPER_REST: u8 = 0b10000;
+
+impl CharRange {
+    /// The ascii characters covered by the range, as a set of the classes above.
+    fn classes(&self) -> Option<u8> {
+        Some(match self {
+            Self::LowerChar => LOWER_HEX | LOWER_REST,
+            Self::UpperChar => UPPER_HEX | UPPER_REST,
+            Self::FullChar => LOWER_HEX | LOWER_REST | UPPER_HEX | UPPER_REST,
+            Self::Digit => DIGIT,
+            Self::LowerHexLetter => LOWER_HEX,
+            Self::UpperHexLetter => UPPER_HEX,
+            Self::HexDigit => DIGIT | LOWER_HEX | UPPER_HEX,
+            Self::Alphanumeric => DIGIT | LOWER_HEX | LOWER_REST | UPPER_HEX | UPPER_REST,
+            Self::Mixed(classes) => *classes,
+            Self::Otherwise => return None,
+        })
+    }
+
+    fn from_classes(classes: u8) -> Self {
+        [
+            Self::LowerChar,
+            Self::UpperChar,
+            Self::FullChar,
+            Self::Digit,
+            Self::LowerHexLetter,
+            Self::UpperHexLetter,
+            Self::HexDigit,
+            Self::Alphanumeric,
+        ]
+        .into_iter()
+        .find(|range| range.classes() == Some(classes))
+        .unwrap_or(Self::Mixed(classes))
+    }
+
+    /// The range matched by any of the given ranges, e.g. `FullChar` for `LowerChar | UpperChar`.
+    fn union(ranges: impl IntoIterator<Item = Self>) -> Self {
+        ranges
+            .into_iter()
+            .try_fold(0, |classes, range| Some(classes | range.classes()?))
+            .map_or(Self::Otherwise, Self::from_classes)
+    }
+
+    fn method(&self) -> Option<&'static str> {
+        match self {
+            Self::UpperChar => Some("is_ascii_uppercase"),
+            Self::LowerChar => Some("is_ascii_lowercase"),
+            Self::FullChar => Some("is_ascii_alphabetic"),
+            Self::Digit => Some("is_ascii_digit"),
+            Self::HexDigit => Some("is_ascii_hexdigit"),
+            Self::Alphanumeric => Some("is_ascii_alphanumeric"),
+            Self::Otherwise | Self::Mixed(_) | Self::LowerHexLetter | Self::UpperHexLetter => None,
+        }
+    }
+}
+
+/// A check of whether `recv` is in `range`, `bound` is one of the literals of the range.
+struct RangeCheck<'tcx> {
+    recv: &'tcx Expr<'tcx>,
+    bound: &'tcx Expr<'tcx>,
+    range: CharRange,
+}
+
 impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
-    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         if !self.msrv.meets(msrvs::IS_ASCII_DIGIT) {
             return;
         }
@@ -104,23 +184,102 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 let range = check_pat(&arm.pat.kind);
                 check_is_ascii(cx, macro_call.span, recv, &range, None);
             }
-        } else if let ExprKind::MethodCall(path, receiver, [arg], ..) = expr.kind
-            && path.ident.name == sym!(contains)
-            && let Some(higher::Range {
+        } else if let Some(check) = range_check(cx, expr)
+            && !is_in_range_chain(cx, expr)
+        {
+            let ty_sugg = get_ty_sugg(cx, check.recv, check.bound);
+            check_is_ascii(cx, expr.span, check.recv, &check.range, ty_sugg);
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Checks for `(start..=end).contains(&recv)`, `recv >= start && recv <= end` and several of
+/// these checking the same `recv` joined by `||`.
+fn range_check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<RangeCheck<'tcx>> {
+    match expr.kind {
+        ExprKind::MethodCall(path, receiver, [arg], ..) if path.ident.name == sym!(contains) => {
+            if let Some(higher::Range {
                 start: Some(start),
                 end: Some(end),
                 limits: RangeLimits::Closed,
             }) = higher::Range::hir(receiver)
-            && !matches!(cx.typeck_results().expr_ty(arg).peel_refs().kind(), ty::Param(_))
-        {
-            let arg = peel_ref_operators(cx, arg);
-            let ty_sugg = get_ty_sugg(cx, arg, start);
-            let range = check_range(start, end);
-            check_is_ascii(cx, expr.span, arg, &range, ty_sugg);
-        }
+                && !matches!(cx.typeck_results().expr_ty(arg).peel_refs().kind(), ty::Param(_))
+            {
+                Some(RangeCheck {
+                    recv: peel_ref_operators(cx, arg),
+                    bound: start,
+                    range: check_range(start, end),
+                })
+            } else {
+                None
+            }
+        },
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::And => {
+            let (recv, lhs_bound, lhs_is_lower) = range_bound(lhs)?;
+            let (rhs_recv, rhs_bound, rhs_is_lower) = range_bound(rhs)?;
+            if lhs_is_lower == rhs_is_lower
+                || !matches!(
+                    cx.typeck_results().expr_ty(recv).kind(),
+                    ty::Char | ty::Uint(UintTy::U8)
+                )
+                || !SpanlessEq::new(cx).deny_side_effects().eq_expr(recv, rhs_recv)
+            {
+                return None;
+            }
+            let (start, end) = if lhs_is_lower {
+                (lhs_bound, rhs_bound)
+            } else {
+                (rhs_bound, lhs_bound)
+            };
+            Some(RangeCheck {
+                recv,
+                bound: start,
+                range: check_range(start, end),
+            })
+        },
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Or => {
+            let lhs = range_check(cx, lhs)?;
+            let rhs = range_check(cx, rhs)?;
+            SpanlessEq::new(cx)
+                .deny_side_effects()
+                .eq_expr(lhs.recv, rhs.recv)
+                .then(|| RangeCheck {
+                    range: CharRange::union([lhs.range, rhs.range]),
+                    ..lhs
+                })
+        },
+        _ => None,
     }
+}
 
-    extract_msrv_attr!(LateContext);
+/// Splits an inclusive comparison against a literal, e.g. `x >= 'a'` or `'z' >= x`, into the
+/// compared expression, the literal and whether it's a lower bound.
+fn range_bound<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, bool)> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    match (op.node, &lhs.kind, &rhs.kind) {
+        (_, ExprKind::Lit(_), ExprKind::Lit(_)) => None,
+        (BinOpKind::Ge, _, ExprKind::Lit(_)) => Some((lhs, rhs, true)),
+        (BinOpKind::Le, _, ExprKind::Lit(_)) => Some((lhs, rhs, false)),
+        (BinOpKind::Le, ExprKind::Lit(_), _) => Some((rhs, lhs, true)),
+        (BinOpKind::Ge, ExprKind::Lit(_), _) => Some((rhs, lhs, false)),
+        _ => None,
+    }
+}
+
+/// Checks if `expr` is part of a larger `||` chain which is linted as a whole.
+fn is_in_range_chain(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let ExprKind::Binary(op, ..) = parent.kind
+        && op.node == BinOpKind::Or
+    {
+        range_check(cx, parent).is_some_and(|check| check.range.method().is_some())
+    } else {
+        false
+    }
 }
 
 fn get_ty_sugg(cx: &LateContext<'_>, arg: &Expr<'_>, bound_expr: &Expr<'_>) -> Option<(Span, &'static str)> {
@@ -147,13 +306,8 @@ fn check_is_ascii(
     range: &CharRange,
     ty_sugg: Option<(Span, &'_ str)>,
 ) {
-    let sugg = match range {
-        CharRange::UpperChar => "is_ascii_uppercase",
-        CharRange::LowerChar => "is_ascii_lowercase",
-        CharRange::FullChar => "is_ascii_alphabetic",
-        CharRange::Digit => "is_ascii_digit",
-        CharRange::HexDigit => "is_ascii_hexdigit",
-        CharRange::Otherwise | CharRange::LowerHexLetter | CharRange::UpperHexLetter => return,
+    let Some(sugg) = range.method() else {
+        return;
     };
     let default_snip = "..";
     let mut app = Applicability::MachineApplicable;
@@ -176,21 +330,7 @@ fn check_is_ascii(
 
 fn check_pat(pat_kind: &PatKind<'_>) -> CharRange {
     match pat_kind {
-        PatKind::Or(pats) => {
-            let ranges = pats.iter().map(|p| check_pat(&p.kind)).collect::<Vec<_>>();
-
-            if ranges.len() == 2 && ranges.contains(&CharRange::UpperChar) && ranges.contains(&CharRange::LowerChar) {
-                CharRange::FullChar
-            } else if ranges.len() == 3
-                && ranges.contains(&CharRange::Digit)
-                && ranges.contains(&CharRange::LowerHexLetter)
-                && ranges.contains(&CharRange::UpperHexLetter)
-            {
-                CharRange::HexDigit
-            } else {
-                CharRange::Otherwise
-            }
-        },
+        PatKind::Or(pats) => CharRange::union(pats.iter().map(|p| check_pat(&p.kind))),
         PatKind::Range(Some(start), Some(end), kind) if *kind == RangeEnd::Included => check_range(start, end),
         _ => CharRange::Otherwise,
     }
@@ -2,13 +2,17 @@
 use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::{get_type_diagnostic_name, is_type_lang_item};
-use clippy_utils::{match_def_path, paths, SpanlessEq};
+use clippy_utils::ty::{get_type_diagnostic_name, is_copy, is_type_lang_item};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{match_def_path, path_to_local_id, paths, SpanlessEq};
+use core::ops::ControlFlow;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::ExprKind::Assign;
+use rustc_hir::HirId;
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::Adjust;
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::sym;
 use rustc_span::Span;
@@ -143,10 +147,19 @@ fn check_iter(
         && let [filter_params] = filter_body.params
     {
         match filter_params.pat.kind {
-            // hir::PatKind::Binding(_, _, _, None) => {
-            //     // Be conservative now. Do nothing here.
-            //     // TODO: Ideally, we can rewrite the lambda by stripping one level of reference
-            // },
+            hir::PatKind::Binding(_, param_id, _, None) => {
+                if let Some(body) = strip_param_ref(cx, param_id, filter_body.value) {
+                    make_span_lint_and_sugg(
+                        cx,
+                        parent_expr_span,
+                        format!(
+                            "{}.retain(|{}| {body})",
+                            snippet(cx, left_expr.span, ".."),
+                            snippet(cx, filter_params.pat.span, ".."),
+                        ),
+                    );
+                }
+            },
             hir::PatKind::Tuple([_, _], _) => {
                 // the `&&` reference for the `filter` method will be auto derefed to `ref`
                 // so, we can directly use the lambda
@@ -201,8 +214,8 @@ fn check_to_owned(
         && let filter_body = cx.tcx.hir().body(closure.body)
         && let [filter_params] = filter_body.params
     {
-        if let hir::PatKind::Ref(pat, _) = filter_params.pat.kind {
-            make_span_lint_and_sugg(
+        match filter_params.pat.kind {
+            hir::PatKind::Ref(pat, _) => make_span_lint_and_sugg(
                 cx,
                 parent_expr_span,
                 format!(
@@ -211,13 +224,73 @@ fn check_to_owned(
                     snippet(cx, pat.span, ".."),
                     snippet(cx, filter_body.value.span, "..")
                 ),
-            );
+            ),
+            hir::PatKind::Binding(_, param_id, _, None) => {
+                if let Some(body) = strip_param_ref(cx, param_id, filter_body.value) {
+                    make_span_lint_and_sugg(
+                        cx,
+                        parent_expr_span,
+                        format!(
+                            "{}.retain(|{}| {body})",
+                            snippet(cx, left_expr.span, ".."),
+                            snippet(cx, filter_params.pat.span, ".."),
+                        ),
+                    );
+                }
+            },
+            _ => {},
         }
-        // Be conservative now. Do nothing for the `Binding` case.
-        // TODO: Ideally, we can rewrite the lambda by stripping one level of reference
     }
 }
 
+/// Rewrites the body of a `filter` closure for `retain`, whose parameter `param_id` has one less
+/// level of references, by removing one dereference from each use of the parameter.
+///
+/// Returns `None` if the parameter is used in a way which would change its meaning, e.g. when
+/// it's passed to a function.
+fn strip_param_ref<'tcx>(cx: &LateContext<'tcx>, param_id: HirId, body: &'tcx hir::Expr<'tcx>) -> Option<String> {
+    let mut replacements = Vec::new();
+    let is_valid = for_each_expr(cx, body, |e| {
+        if !path_to_local_id(e, param_id) {
+            return ControlFlow::Continue(());
+        }
+        match cx.tcx.parent_hir_node(e.hir_id) {
+            hir::Node::Expr(parent) if matches!(parent.kind, hir::ExprKind::Unary(hir::UnOp::Deref, _)) => {
+                replacements.push((parent.span, e.span));
+                ControlFlow::Continue(())
+            },
+            // Auto-deref just stops one level earlier
+            hir::Node::Expr(parent)
+                if let hir::ExprKind::MethodCall(_, receiver, ..) | hir::ExprKind::Field(receiver, _) = parent.kind
+                    && receiver.hir_id == e.hir_id
+                    && cx
+                        .typeck_results()
+                        .expr_adjustments(e)
+                        .iter()
+                        .any(|adjustment| matches!(adjustment.kind, Adjust::Deref(None))) =>
+            {
+                ControlFlow::Continue(())
+            },
+            _ => ControlFlow::Break(()),
+        }
+    })
+    .is_none();
+    if !is_valid || body.span.from_expansion() {
+        return None;
+    }
+
+    replacements.sort_by_key(|(deref_span, _)| deref_span.lo());
+    let mut sugg = String::new();
+    let mut pos = body.span.lo();
+    for (deref_span, param_span) in replacements {
+        sugg.push_str(&snippet(cx, body.span.with_lo(pos).with_hi(deref_span.lo()), ".."));
+        sugg.push_str(&snippet(cx, param_span, ".."));
+        pos = deref_span.hi();
+    }
+    sugg.push_str(&snippet(cx, body.span.with_lo(pos), ".."));
+    Some(sugg)
+}
+
 fn make_sugg(
     cx: &LateContext<'_>,
     key_pat: &rustc_hir::Pat<'_>,
@@ -227,8 +300,14 @@ fn make_sugg(
 ) -> Option<String> {
     match (&key_pat.kind, &value_pat.kind) {
         (hir::PatKind::Binding(_, _, key_param_ident, None), hir::PatKind::Binding(_, _, value_param_ident, None)) => {
+            // The value can only be moved out of the `&mut` if it's `Copy`
+            let value_pat = if is_copy(cx, cx.typeck_results().pat_ty(value_pat).peel_refs()) {
+                format!("&mut {value_param_ident}")
+            } else {
+                value_param_ident.to_string()
+            };
             Some(format!(
-                "{}.retain(|{key_param_ident}, &mut {value_param_ident}| {})",
+                "{}.retain(|{key_param_ident}, {value_pat}| {})",
                 snippet(cx, left_expr.span, ".."),
                 snippet(cx, filter_body.value.span, "..")
             ))
@@ -238,11 +317,18 @@ fn make_sugg(
             snippet(cx, left_expr.span, ".."),
             snippet(cx, filter_body.value.span, "..")
         )),
-        (hir::PatKind::Wild, hir::PatKind::Binding(_, _, value_param_ident, None)) => Some(format!(
-            "{}.retain(|_, &mut {value_param_ident}| {})",
-            snippet(cx, left_expr.span, ".."),
-            snippet(cx, filter_body.value.span, "..")
-        )),
+        (hir::PatKind::Wild, hir::PatKind::Binding(_, _, value_param_ident, None)) => {
+            let value_pat = if is_copy(cx, cx.typeck_results().pat_ty(value_pat).peel_refs()) {
+                format!("&mut {value_param_ident}")
+            } else {
+                value_param_ident.to_string()
+            };
+            Some(format!(
+                "{}.retain(|_, {value_pat}| {})",
+                snippet(cx, left_expr.span, ".."),
+                snippet(cx, filter_body.value.span, "..")
+            ))
+        },
         _ => None,
     }
 }
@@ -0,0 +1,66 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{is_diag_trait_item, is_trait_method};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::CLONE_ON_NEW_REF_PTR;
+
+/// Checks for `Rc::new(x).clone()`
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>) {
+    if is_trait_method(cx, expr, sym::Clone)
+        && let Some(ptr_name) = new_ref_ptr(cx, receiver)
+    {
+        emit(cx, expr, receiver, ptr_name);
+    }
+}
+
+/// Checks for `Rc::clone(&Rc::new(x))`
+pub(super) fn check_function(cx: &LateContext<'_>, expr: &Expr<'_>, func: &Expr<'_>, args: &[Expr<'_>]) {
+    if let [arg] = args
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+        && is_diag_trait_item(cx, def_id, sym::Clone)
+        && let ExprKind::AddrOf(_, _, receiver) = arg.kind
+        && let Some(ptr_name) = new_ref_ptr(cx, receiver)
+    {
+        emit(cx, expr, receiver, ptr_name);
+    }
+}
+
+/// Returns the name of the reference counted pointer if `expr` creates a new one, e.g.
+/// `Rc::new(x)`.
+fn new_ref_ptr(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if let ExprKind::Call(func, _) = expr.kind
+        && let ExprKind::Path(QPath::TypeRelative(ptr_ty, func_name)) = func.kind
+        && matches!(func_name.ident.name, sym::new | sym::from | sym::default)
+        && let Some(adt) = cx.typeck_results().node_type(ptr_ty.hir_id).ty_adt_def()
+    {
+        match cx.tcx.get_diagnostic_name(adt.did()) {
+            Some(sym::Rc) => Some("Rc"),
+            Some(sym::Arc) => Some("Arc"),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+fn emit(cx: &LateContext<'_>, expr: &Expr<'_>, new_expr: &Expr<'_>, ptr_name: &str) {
+    span_lint_and_then(
+        cx,
+        CLONE_ON_NEW_REF_PTR,
+        expr.span,
+        format!("cloning a newly created `{ptr_name}`"),
+        |diag| {
+            let mut app = Applicability::MachineApplicable;
+            let snippet = snippet_with_context(cx, new_expr.span, expr.span.ctxt(), "..", &mut app).0;
+            diag.span_suggestion_verbose(expr.span, "remove the `clone()`", snippet, app);
+            diag.note(format!(
+                "if another handle is needed, bind the `{ptr_name}` to a variable and clone that instead"
+            ));
+        },
+    );
+}
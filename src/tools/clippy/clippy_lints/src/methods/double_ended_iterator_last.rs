@@ -0,0 +1,37 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{get_trait_def_id, is_trait_method, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_span::{sym, Span};
+
+use super::DOUBLE_ENDED_ITERATOR_LAST;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, call_span: Span) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    if is_trait_method(cx, expr, sym::Iterator)
+        && let Some(double_ended) = cx.tcx.get_diagnostic_item(sym::DoubleEndedIterator)
+        && let Some(exact_size) = get_trait_def_id(cx.tcx, &["core", "iter", "ExactSizeIterator"])
+        && implements_trait(cx, recv_ty, double_ended, &[])
+        && implements_trait(cx, recv_ty, exact_size, &[])
+    {
+        // `next_back` needs a mutable reference to the iterator, which a local binding might not allow.
+        // With generic or opaque types the impls used might also differ from what they look like here.
+        let app = if path_to_local(recv).is_some() || recv_ty.has_param() || recv_ty.has_opaque_types() {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        span_lint_and_sugg(
+            cx,
+            DOUBLE_ENDED_ITERATOR_LAST,
+            call_span,
+            "called `Iterator::last` on a `DoubleEndedIterator`; this will needlessly iterate the entire iterator",
+            "try",
+            "next_back()".to_owned(),
+            app,
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{is_in_test, match_def_path, path_def_id, paths};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_session::config::CrateType;
+
+use super::ENV_VAR_UNWRAP;
+
+/// lint use of `env::var(..).unwrap()` and `env::var(..).expect(..)` in libraries
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, name: &str) {
+    if let ExprKind::Call(func, [_]) = recv.kind
+        && let Some(def_id) = path_def_id(cx, func)
+        && let Some(fn_name) = [(&paths::ENV_VAR, "var"), (&paths::ENV_VAR_OS, "var_os")]
+            .into_iter()
+            .find_map(|(path, fn_name)| match_def_path(cx, def_id, path).then_some(fn_name))
+        && !expr.span.from_expansion()
+        && !is_binary_target(cx)
+        && !is_in_test(cx.tcx, expr.hir_id)
+    {
+        span_lint_and_then(
+            cx,
+            ENV_VAR_UNWRAP,
+            expr.span,
+            format!("used `{name}()` on the result of `env::{fn_name}` in library code"),
+            |diag| {
+                diag.note("this panics in every application using the library which doesn't set the variable");
+                diag.help("consider returning an error or falling back to a default value");
+            },
+        );
+    }
+}
+
+/// Binaries, tests and build scripts can expect their environment to be set up, only libraries are
+/// linted.
+fn is_binary_target(cx: &LateContext<'_>) -> bool {
+    cx.tcx.crate_types().contains(&CrateType::Executable)
+        || cx.sess().opts.test
+        || cx
+            .sess()
+            .opts
+            .crate_name
+            .as_ref()
+            .is_some_and(|crate_name| crate_name == "build_script_build")
+}
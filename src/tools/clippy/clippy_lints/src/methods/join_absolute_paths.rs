@@ -17,36 +17,60 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, recv: &'tcx Expr<'tcx>, join_a
         && let ExprKind::Lit(spanned) = expr_or_init(cx, join_arg).kind
         && let LitKind::Str(symbol, _) = spanned.node
         && let sym_str = symbol.as_str()
-        && sym_str.starts_with(['/', '\\'])
     {
-        span_lint_and_then(
-            cx,
-            JOIN_ABSOLUTE_PATHS,
-            join_arg.span,
-            "argument to `Path::join` starts with a path separator",
-            |diag| {
-                let arg_str = snippet(cx, spanned.span, "..");
+        if sym_str.starts_with(['/', '\\']) {
+            span_lint_and_then(
+                cx,
+                JOIN_ABSOLUTE_PATHS,
+                join_arg.span,
+                "argument to `Path::join` starts with a path separator",
+                |diag| {
+                    let arg_str = snippet(cx, spanned.span, "..");
 
-                let no_separator = if sym_str.starts_with('/') {
-                    arg_str.replacen('/', "", 1)
-                } else {
-                    arg_str.replacen('\\', "", 1)
-                };
+                    let no_separator = if sym_str.starts_with('/') {
+                        arg_str.replacen('/', "", 1)
+                    } else {
+                        arg_str.replacen('\\', "", 1)
+                    };
 
-                diag.note("joining a path starting with separator will replace the path instead")
-                    .span_suggestion(
-                        spanned.span,
-                        "if this is unintentional, try removing the starting separator",
-                        no_separator,
-                        Applicability::Unspecified,
-                    )
-                    .span_suggestion(
-                        expr_span,
-                        "if this is intentional, consider using `Path::new`",
-                        format!("PathBuf::from({arg_str})"),
-                        Applicability::Unspecified,
-                    );
-            },
-        );
+                    diag.note("joining a path starting with separator will replace the path instead")
+                        .span_suggestion(
+                            spanned.span,
+                            "if this is unintentional, try removing the starting separator",
+                            no_separator,
+                            Applicability::Unspecified,
+                        )
+                        .span_suggestion(
+                            expr_span,
+                            "if this is intentional, consider using `Path::new`",
+                            format!("PathBuf::from({arg_str})"),
+                            Applicability::Unspecified,
+                        );
+                },
+            );
+        } else if has_drive_letter_prefix(sym_str) {
+            span_lint_and_then(
+                cx,
+                JOIN_ABSOLUTE_PATHS,
+                join_arg.span,
+                "argument to `Path::join` starts with a drive letter",
+                |diag| {
+                    let arg_str = snippet(cx, spanned.span, "..");
+
+                    diag.note("on Windows, joining a path starting with a drive letter will replace the path instead")
+                        .span_suggestion_verbose(
+                            expr_span,
+                            "if this is intentional, consider using `Path::new`",
+                            format!("PathBuf::from({arg_str})"),
+                            Applicability::Unspecified,
+                        );
+                },
+            );
+        }
     }
 }
+
+/// Checks if the path starts with a Windows drive letter, e.g. `C:\` or the drive-relative `C:foo`
+pub(super) fn has_drive_letter_prefix(path: &str) -> bool {
+    matches!(path.as_bytes(), [letter, b':', ..] if letter.is_ascii_alphabetic())
+}
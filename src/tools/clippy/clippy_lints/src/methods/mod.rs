@@ -11,10 +11,13 @@
 mod chars_next_cmp_with_unwrap;
 mod clear_with_drain;
 mod clone_on_copy;
+mod clone_on_new_ref_ptr;
 mod clone_on_ref_ptr;
 mod cloned_instead_of_copied;
 mod collapsible_str_replace;
+mod double_ended_iterator_last;
 mod drain_collect;
+mod env_var_unwrap;
 mod err_expect;
 mod expect_fun_call;
 mod extend_with_drain;
@@ -135,7 +138,8 @@
 use clippy_config::Conf;
 use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
-use clippy_utils::macros::FormatArgsStorage;
+use clippy_utils::macros::{ignores_external_macros, FormatArgsStorage};
+use clippy_utils::test_scopes::TestScope;
 use clippy_utils::ty::{contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item};
 use clippy_utils::{contains_return, is_bool, is_trait_method, iter_input_pats, peel_blocks, return_ty};
 pub use path_ends_with_ext::DEFAULT_ALLOWED_DOTFILES;
@@ -2857,8 +2861,8 @@
     /// calls on `PathBuf` that can cause overwrites.
     ///
     /// ### Why is this bad?
-    /// Calling `push` with a root path at the start can overwrite the
-    /// previous defined path.
+    /// Calling `push` with a root path or a Windows drive letter at the start
+    /// can overwrite the previous defined path.
     ///
     /// ### Example
     /// ```no_run
@@ -3726,14 +3730,16 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for calls to `Path::join` that start with a path separator (`\\` or `/`).
+    /// Checks for calls to `Path::join` that start with a path separator (`\\` or `/`)
+    /// or a Windows drive letter (`C:`).
     ///
     /// ### Why is this bad?
     /// If the argument to `Path::join` starts with a separator, it will overwrite
     /// the original path. If this is intentional, prefer using `Path::new` instead.
     ///
     /// Note the behavior is platform dependent. A leading `\\` will be accepted
-    /// on unix systems as part of the file name
+    /// on unix systems as part of the file name, and a drive letter only replaces the
+    /// original path on Windows.
     ///
     /// See [`Path::join`](https://doc.rust-lang.org/std/path/struct.Path.html#method.join)
     ///
@@ -4126,11 +4132,98 @@
     "use of `map` returning the original item"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for clones of a reference-counted pointer that was just created, e.g.
+    /// `Arc::new(x).clone()` or `Rc::clone(&Rc::new(x))`.
+    ///
+    /// ### Why is this bad?
+    /// The newly created pointer is a temporary, which is dropped right after cloning it.
+    /// The clone only increments and decrements the reference count again, and reads as
+    /// though another handle to the value was kept around.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Arc;
+    /// let shared = Arc::new(vec![1, 2, 3]).clone();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Arc;
+    /// let shared = Arc::new(vec![1, 2, 3]);
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub CLONE_ON_NEW_REF_PTR,
+    complexity,
+    "cloning a newly created `Rc` or `Arc`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.unwrap()` or `.expect(..)` calls on the result of `std::env::var` or
+    /// `std::env::var_os` in library crates.
+    ///
+    /// Binaries, tests, examples and build scripts aren't linted.
+    ///
+    /// ### Why is this bad?
+    /// A library can't know how the environment of the applications using it is set up. A missing
+    /// variable makes the whole application panic, often with no way to recover for the user of
+    /// the library.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn cache_dir() -> String {
+    ///     std::env::var("CACHE_DIR").unwrap()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub fn cache_dir() -> Result<String, std::env::VarError> {
+    ///     std::env::var("CACHE_DIR")
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub ENV_VAR_UNWRAP,
+    pedantic,
+    "unwrapping environment variables in library code"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Iterator::last` being called on an iterator which implements both
+    /// `DoubleEndedIterator` and `ExactSizeIterator`, e.g. the iterators of slices, `Vec` and ranges.
+    ///
+    /// ### Why is this bad?
+    /// `last` walks the whole iterator to find the last element, while `next_back` takes it from
+    /// the back directly.
+    ///
+    /// ### Known problems
+    /// The elements before the last one are not produced by `next_back`, so any side effects of
+    /// closures passed to adapters like `map` won't happen for them.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let v = vec![1, 2, 3];
+    /// let last = v.iter().last();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let v = vec![1, 2, 3];
+    /// let last = v.iter().next_back();
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub DOUBLE_ENDED_ITERATOR_LAST,
+    perf,
+    "using `Iterator::last` on a `DoubleEndedIterator`"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
-    allow_expect_in_tests: bool,
-    allow_unwrap_in_tests: bool,
+    expect_test_scope: TestScope,
+    unwrap_test_scope: TestScope,
+    expect_ignores_external_macros: bool,
+    unwrap_ignores_external_macros: bool,
     allowed_dotfiles: FxHashSet<&'static str>,
     format_args: FormatArgsStorage,
 }
@@ -4143,8 +4236,18 @@ pub fn new(conf: &'static Conf, format_args: FormatArgsStorage) -> Self {
         Self {
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
             msrv: conf.msrv.clone(),
-            allow_expect_in_tests: conf.allow_expect_in_tests,
-            allow_unwrap_in_tests: conf.allow_unwrap_in_tests,
+            expect_test_scope: if conf.allow_expect_in_tests {
+                TestScope::OutsideTests
+            } else {
+                TestScope::new(conf, EXPECT_USED)
+            },
+            unwrap_test_scope: if conf.allow_unwrap_in_tests {
+                TestScope::OutsideTests
+            } else {
+                TestScope::new(conf, UNWRAP_USED)
+            },
+            expect_ignores_external_macros: ignores_external_macros(conf, EXPECT_USED),
+            unwrap_ignores_external_macros: ignores_external_macros(conf, UNWRAP_USED),
             allowed_dotfiles,
             format_args,
         }
@@ -4286,6 +4389,9 @@ pub fn new(conf: &'static Conf, format_args: FormatArgsStorage) -> Self {
     NEEDLESS_CHARACTER_ITERATION,
     MANUAL_INSPECT,
     UNNECESSARY_MIN_OR_MAX,
+    CLONE_ON_NEW_REF_PTR,
+    ENV_VAR_UNWRAP,
+    DOUBLE_ENDED_ITERATOR_LAST,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4314,6 +4420,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 from_iter_instead_of_collect::check(cx, expr, args, func);
                 unnecessary_fallible_conversions::check_function(cx, expr, func);
                 manual_c_str_literals::check(cx, expr, func, args, &self.msrv);
+                clone_on_new_ref_ptr::check_function(cx, expr, func, args);
             },
             ExprKind::MethodCall(method_call, receiver, args, _) => {
                 let method_span = method_call.ident.span;
@@ -4329,6 +4436,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 );
                 clone_on_copy::check(cx, expr, method_call.ident.name, receiver, args);
                 clone_on_ref_ptr::check(cx, expr, method_call.ident.name, receiver, args);
+                if method_call.ident.name == sym::clone && args.is_empty() {
+                    clone_on_new_ref_ptr::check(cx, expr, receiver);
+                }
                 inefficient_to_string::check(cx, expr, method_call.ident.name, receiver, args);
                 single_char_add_str::check(cx, expr, receiver, args);
                 into_iter_on_ref::check(cx, expr, method_span, method_call.ident.name, receiver);
@@ -4615,11 +4725,13 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                             expr,
                             recv,
                             false,
-                            self.allow_expect_in_tests,
+                            self.expect_test_scope,
+                            self.expect_ignores_external_macros,
                             unwrap_expect_used::Variant::Expect,
                         ),
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+                    env_var_unwrap::check(cx, expr, recv, name);
                 },
                 ("expect_err", [_]) => {
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
@@ -4628,7 +4740,8 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         expr,
                         recv,
                         true,
-                        self.allow_expect_in_tests,
+                        self.expect_test_scope,
+                        self.expect_ignores_external_macros,
                         unwrap_expect_used::Variant::Expect,
                     );
                 },
@@ -4743,6 +4856,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     }
                 },
                 ("last", []) => {
+                    double_ended_iterator_last::check(cx, expr, recv, call_span);
                     if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
                         iter_overeager_cloned::check(
                             cx,
@@ -4979,9 +5093,11 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         expr,
                         recv,
                         false,
-                        self.allow_unwrap_in_tests,
+                        self.unwrap_test_scope,
+                        self.unwrap_ignores_external_macros,
                         unwrap_expect_used::Variant::Unwrap,
                     );
+                    env_var_unwrap::check(cx, expr, recv, name);
                 },
                 ("unwrap_err", []) => {
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
@@ -4990,7 +5106,8 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         expr,
                         recv,
                         true,
-                        self.allow_unwrap_in_tests,
+                        self.unwrap_test_scope,
+                        self.unwrap_ignores_external_macros,
                         unwrap_expect_used::Variant::Unwrap,
                     );
                 },
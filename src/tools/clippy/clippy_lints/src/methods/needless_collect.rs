@@ -2,7 +2,7 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::sugg::Sugg;
-use clippy_utils::ty::{get_type_diagnostic_name, make_normalized_projection, make_projection};
+use clippy_utils::ty::{get_type_diagnostic_name, implements_trait, make_normalized_projection, make_projection};
 use clippy_utils::{
     can_move_expr_to_closure, fn_def_id, get_enclosing_block, higher, is_trait_method, path_to_local, path_to_local_id,
     CaptureKind,
@@ -11,7 +11,7 @@
 use rustc_errors::{Applicability, MultiSpan};
 use rustc_hir::intravisit::{walk_block, walk_expr, Visitor};
 use rustc_hir::{
-    BindingMode, Block, Expr, ExprKind, HirId, HirIdSet, LetStmt, Mutability, Node, PatKind, Stmt, StmtKind,
+    BindingMode, Block, BorrowKind, Expr, ExprKind, HirId, HirIdSet, LetStmt, Mutability, Node, PatKind, Stmt, StmtKind,
 };
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
@@ -31,6 +31,7 @@ pub(super) fn check<'tcx>(
     match cx.tcx.parent_hir_node(collect_expr.hir_id) {
         Node::Expr(parent) => {
             check_collect_into_intoiterator(cx, parent, collect_expr, call_span, iter_expr);
+            check_collect_then_iter(cx, parent, collect_expr, iter_expr);
 
             if let ExprKind::MethodCall(name, _, args @ ([] | [_]), _) = parent.kind {
                 let mut app = Applicability::MachineApplicable;
@@ -94,7 +95,7 @@ pub(super) fn check<'tcx>(
                 )
                 && let iter_ty = cx.typeck_results().expr_ty(iter_expr)
                 && let Some(block) = get_enclosing_block(cx, l.hir_id)
-                && let Some(iter_calls) = detect_iter_and_into_iters(block, id, cx, get_captured_ids(cx, iter_ty))
+                && let Some(iter_calls) = detect_iter_and_into_iters(block, id, cx, iter_ty)
                 && let [iter_call] = &*iter_calls
             {
                 let mut used_count_visitor = UsedCountVisitor { cx, id, count: 0 };
@@ -191,6 +192,63 @@ fn check_collect_into_intoiterator<'tcx>(
     }
 }
 
+/// checks for iterating over a freshly collected collection, e.g.
+/// `iter.collect::<Vec<_>>().into_iter()`
+fn check_collect_then_iter<'tcx>(
+    cx: &LateContext<'tcx>,
+    parent: &'tcx Expr<'tcx>,
+    collect_expr: &'tcx Expr<'tcx>,
+    iter_expr: &'tcx Expr<'tcx>,
+) {
+    if let ExprKind::MethodCall(name, recv, [], _) = parent.kind
+        && recv.hir_id == collect_expr.hir_id
+        && let name = name.ident.as_str()
+        && matches!(name, "into_iter" | "iter")
+        && matches!(
+            get_type_diagnostic_name(cx, cx.typeck_results().expr_ty(collect_expr)),
+            Some(sym::Vec | sym::VecDeque | sym::LinkedList)
+        )
+        && !needs_missing_iterator_trait(cx, parent, cx.typeck_results().expr_ty(iter_expr))
+    {
+        // `iter()` produces references to the collected elements instead of the elements themselves
+        let app = if name == "into_iter" {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_COLLECT,
+            parent.span.with_lo(iter_expr.span.hi()),
+            NEEDLESS_COLLECT_MSG,
+            "remove these calls",
+            String::new(),
+            app,
+        );
+    }
+}
+
+/// Checks if the method called on the result of `iter_call` relies on a trait which the collected
+/// iterator implements, but `iter_ty` might not. E.g. `rev()` requires a `DoubleEndedIterator`.
+fn needs_missing_iterator_trait<'tcx>(cx: &LateContext<'tcx>, iter_call: &Expr<'_>, iter_ty: Ty<'tcx>) -> bool {
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(iter_call.hir_id)
+        && let ExprKind::MethodCall(name, recv, ..) = parent.kind
+        && recv.hir_id == iter_call.hir_id
+    {
+        match name.ident.as_str() {
+            "rev" | "next_back" | "nth_back" | "rfold" | "try_rfold" | "rfind" => !cx
+                .tcx
+                .get_diagnostic_item(sym::DoubleEndedIterator)
+                .is_some_and(|double_ended| implements_trait(cx, iter_ty, double_ended, &[])),
+            // `ExactSizeIterator` has no diagnostic item
+            "len" | "rposition" => true,
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
 /// Checks if the given method call matches the expected signature of `([&[mut]] self) -> bool`
 fn is_is_empty_sig(cx: &LateContext<'_>, call_id: HirId) -> bool {
     cx.typeck_results().type_dependent_def_id(call_id).map_or(false, |id| {
@@ -252,7 +310,7 @@ struct IterFunction {
 impl IterFunction {
     fn get_iter_method(&self, cx: &LateContext<'_>) -> String {
         match &self.func {
-            IterFunctionKind::IntoIter => String::new(),
+            IterFunctionKind::IntoIter | IterFunctionKind::Iter => String::new(),
             IterFunctionKind::Len => String::from(".count()"),
             IterFunctionKind::IsEmpty => String::from(".next().is_none()"),
             IterFunctionKind::Contains(span) => {
@@ -270,6 +328,9 @@ fn get_suggestion_text(&self) -> &'static str {
             IterFunctionKind::IntoIter => {
                 "use the original Iterator instead of collecting it and then producing a new one"
             },
+            IterFunctionKind::Iter => {
+                "use the original Iterator instead of collecting it and then iterating over references to its items"
+            },
             IterFunctionKind::Len => {
                 "take the original Iterator's count instead of collecting it and finding the length"
             },
@@ -284,12 +345,14 @@ fn get_suggestion_text(&self) -> &'static str {
 }
 enum IterFunctionKind {
     IntoIter,
+    Iter,
     Len,
     IsEmpty,
     Contains(Span),
 }
 
 struct IterFunctionVisitor<'a, 'tcx> {
+    iter_ty: Ty<'tcx>,
     illegal_mutable_capture_ids: HirIdSet,
     current_mutably_captured_ids: HirIdSet,
     cx: &'a LateContext<'tcx>,
@@ -302,12 +365,21 @@ struct IterFunctionVisitor<'a, 'tcx> {
 impl<'tcx> Visitor<'tcx> for IterFunctionVisitor<'_, 'tcx> {
     fn visit_block(&mut self, block: &'tcx Block<'tcx>) {
         for (expr, hir_id) in block.stmts.iter().filter_map(get_expr_and_hir_id_from_stmt) {
+            if let Some(higher::ForLoop { arg, .. }) = higher::ForLoop::hir(expr) {
+                self.visit_for_loop_arg(arg);
+                continue;
+            }
             if check_loop_kind(expr).is_some() {
                 continue;
             }
             self.visit_block_expr(expr, hir_id);
         }
         if let Some(expr) = block.expr {
+            if let Some(higher::ForLoop { arg, .. }) = higher::ForLoop::hir(expr)
+                && self.visit_for_loop_arg(arg)
+            {
+                return;
+            }
             if let Some(loop_kind) = check_loop_kind(expr) {
                 if let LoopKind::Conditional(block_expr) = loop_kind {
                     self.visit_block_expr(block_expr, None);
@@ -338,10 +410,17 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
                         self.hir_id_uses_map.insert(hir_id, self.uses.len());
                     }
                     match method_name.ident.name.as_str() {
+                        "into_iter" | "iter" if needs_missing_iterator_trait(self.cx, expr, self.iter_ty) => {
+                            self.seen_other = true;
+                        },
                         "into_iter" => self.uses.push(Some(IterFunction {
                             func: IterFunctionKind::IntoIter,
                             span: expr.span,
                         })),
+                        "iter" => self.uses.push(Some(IterFunction {
+                            func: IterFunctionKind::Iter,
+                            span: expr.span,
+                        })),
                         "len" => self.uses.push(Some(IterFunction {
                             func: IterFunctionKind::Len,
                             span: expr.span,
@@ -419,6 +498,27 @@ fn visit_block_expr(&mut self, expr: &'tcx Expr<'tcx>, hir_id: Option<HirId>) {
         self.current_mutably_captured_ids = get_captured_ids(self.cx, self.cx.typeck_results().expr_ty(expr));
         self.visit_expr(expr);
     }
+
+    /// Records `for _ in collection` and `for _ in &collection` as uses of the collection, returns
+    /// `false` if `arg` is neither.
+    ///
+    /// The loop body isn't checked, so loops are only recorded if the original iterator doesn't
+    /// mutably capture anything.
+    fn visit_for_loop_arg(&mut self, arg: &'tcx Expr<'tcx>) -> bool {
+        let func = if !self.illegal_mutable_capture_ids.is_empty() {
+            return false;
+        } else if path_to_local_id(arg, self.target) {
+            IterFunctionKind::IntoIter
+        } else if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) = arg.kind
+            && path_to_local_id(inner, self.target)
+        {
+            IterFunctionKind::Iter
+        } else {
+            return false;
+        };
+        self.uses.push(Some(IterFunction { func, span: arg.span }));
+        true
+    }
 }
 
 fn get_expr_and_hir_id_from_stmt<'v>(stmt: &'v Stmt<'v>) -> Option<(&'v Expr<'v>, Option<HirId>)> {
@@ -463,15 +563,16 @@ fn detect_iter_and_into_iters<'tcx: 'a, 'a>(
     block: &'tcx Block<'tcx>,
     id: HirId,
     cx: &'a LateContext<'tcx>,
-    captured_ids: HirIdSet,
+    iter_ty: Ty<'tcx>,
 ) -> Option<Vec<IterFunction>> {
     let mut visitor = IterFunctionVisitor {
+        iter_ty,
         uses: Vec::new(),
         target: id,
         seen_other: false,
         cx,
         current_mutably_captured_ids: HirIdSet::default(),
-        illegal_mutable_capture_ids: captured_ids,
+        illegal_mutable_capture_ids: get_captured_ids(cx, iter_ty),
         hir_id_uses_map: FxHashMap::default(),
         current_statement_hir_id: None,
     };
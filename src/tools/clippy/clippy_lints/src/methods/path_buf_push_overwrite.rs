@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::ty::is_type_diagnostic_item;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -7,6 +7,7 @@
 use rustc_span::symbol::sym;
 use std::path::{Component, Path};
 
+use super::join_absolute_paths::has_drive_letter_prefix;
 use super::PATH_BUF_PUSH_OVERWRITE;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
@@ -17,18 +18,29 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'t
         && let LitKind::Str(ref path_lit, _) = lit.node
         && let pushed_path = Path::new(path_lit.as_str())
         && let Some(pushed_path_lit) = pushed_path.to_str()
-        && pushed_path.has_root()
-        && let Some(root) = pushed_path.components().next()
-        && root == Component::RootDir
     {
-        span_lint_and_sugg(
-            cx,
-            PATH_BUF_PUSH_OVERWRITE,
-            lit.span,
-            "calling `push` with '/' or '\\' (file system root) will overwrite the previous path definition",
-            "try",
-            format!("\"{}\"", pushed_path_lit.trim_start_matches(['/', '\\'])),
-            Applicability::MachineApplicable,
-        );
+        if pushed_path.has_root()
+            && let Some(root) = pushed_path.components().next()
+            && root == Component::RootDir
+        {
+            span_lint_and_sugg(
+                cx,
+                PATH_BUF_PUSH_OVERWRITE,
+                lit.span,
+                "calling `push` with '/' or '\\' (file system root) will overwrite the previous path definition",
+                "try",
+                format!("\"{}\"", pushed_path_lit.trim_start_matches(['/', '\\'])),
+                Applicability::MachineApplicable,
+            );
+        } else if has_drive_letter_prefix(pushed_path_lit) {
+            span_lint_and_help(
+                cx,
+                PATH_BUF_PUSH_OVERWRITE,
+                lit.span,
+                "calling `push` with a drive letter will overwrite the previous path definition on Windows",
+                None,
+                "if this is intentional, consider assigning a new `PathBuf` instead",
+            );
+        }
     }
 }
@@ -1,6 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_lint_allowed;
+use clippy_utils::macros::in_ignored_external_macro;
+use clippy_utils::test_scopes::TestScope;
 use clippy_utils::ty::{is_never_like, is_type_diagnostic_item};
-use clippy_utils::{is_in_test, is_lint_allowed};
 use rustc_hir::Expr;
 use rustc_lint::{LateContext, Lint};
 use rustc_middle::ty;
@@ -39,7 +41,8 @@ pub(super) fn check(
     expr: &Expr<'_>,
     recv: &Expr<'_>,
     is_err: bool,
-    allow_unwrap_in_tests: bool,
+    test_scope: TestScope,
+    ignore_external_macros: bool,
     variant: Variant,
 ) {
     let ty = cx.typeck_results().expr_ty(recv).peel_refs();
@@ -61,7 +64,7 @@ pub(super) fn check(
 
     let method_suffix = if is_err { "_err" } else { "" };
 
-    if allow_unwrap_in_tests && is_in_test(cx.tcx, expr.hir_id) {
+    if test_scope.is_skipped(cx.tcx, expr.hir_id) || in_ignored_external_macro(ignore_external_macros, expr.span) {
         return;
     }
 
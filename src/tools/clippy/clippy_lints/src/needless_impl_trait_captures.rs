@@ -0,0 +1,159 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{FnRetTy, FnSig, GenericBound, ImplItem, ImplItemKind, Item, ItemKind, Node, OpaqueTyOrigin, TyKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, ClauseKind, GenericParamDefKind, TypeVisitableExt};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `impl Trait` return types which capture lifetimes of the function's
+    /// arguments, while the returned value doesn't borrow from any of them.
+    ///
+    /// Before the 2024 edition a lifetime is captured when it's named in the bounds, e.g. with
+    /// `impl Trait + '_`. Starting with the 2024 edition all lifetimes in scope are captured unless
+    /// a `use<..>` bound lists the captured parameters.
+    ///
+    /// ### Why is this bad?
+    /// Callers can't use the returned value once the borrowed arguments go out of scope, even
+    /// though the value doesn't actually borrow from them. Relaxing the bounds later on is a
+    /// breaking change.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn make_adder(x: &u32) -> impl Fn(u32) -> u32 + '_ {
+    ///     let x = *x;
+    ///     move |y| x + y
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn make_adder(x: &u32) -> impl Fn(u32) -> u32 + 'static {
+    ///     let x = *x;
+    ///     move |y| x + y
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub NEEDLESS_IMPL_TRAIT_CAPTURES,
+    pedantic,
+    "`impl Trait` return types capturing lifetimes they don't use"
+}
+
+declare_lint_pass!(NeedlessImplTraitCaptures => [NEEDLESS_IMPL_TRAIT_CAPTURES]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessImplTraitCaptures {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(sig, ..) = &item.kind {
+            check_sig(cx, item.owner_id.def_id, sig);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // The signature of trait methods is dictated by the trait
+        if let ImplItemKind::Fn(sig, _) = &item.kind
+            && let Node::Item(parent) = cx.tcx.parent_hir_node(item.hir_id())
+            && let ItemKind::Impl(impl_) = parent.kind
+            && impl_.of_trait.is_none()
+        {
+            check_sig(cx, item.owner_id.def_id, sig);
+        }
+    }
+}
+
+fn check_sig(cx: &LateContext<'_>, fn_def_id: LocalDefId, sig: &FnSig<'_>) {
+    if let FnRetTy::Return(ret_ty) = sig.decl.output
+        && let TyKind::OpaqueDef(item_id, ..) = ret_ty.kind
+        && !in_external_macro(cx.sess(), ret_ty.span)
+        && let ItemKind::OpaqueTy(opaque) = cx.tcx.hir().item(item_id).kind
+        && let OpaqueTyOrigin::FnReturn(_) = opaque.origin
+        // Precise capturing was already used
+        && !opaque.bounds.iter().any(|bound| matches!(bound, GenericBound::Use(..)))
+        && let opaque_def_id = item_id.owner_id.def_id
+        && captures_lifetimes(cx, opaque_def_id)
+        && !uses_lifetimes(cx, opaque_def_id)
+        && let Some(params) = non_lifetime_params(cx, fn_def_id)
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let mut bounds: Vec<_> = opaque
+            .bounds
+            .iter()
+            .filter(|bound| !matches!(bound, GenericBound::Outlives(_)))
+            .map(|bound| snippet_with_applicability(cx, bound.span(), "..", &mut app).into_owned())
+            .collect();
+        if ret_ty.span.at_least_rust_2024() {
+            // All lifetimes in scope are captured regardless of the bounds
+            bounds.push(format!("use<{}>", params.join(", ")));
+        } else if params.is_empty() {
+            // Type parameters are always captured, `'static` would require them to be `'static` as well
+            bounds.push("'static".to_owned());
+        }
+
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_IMPL_TRAIT_CAPTURES,
+            ret_ty.span,
+            "this `impl Trait` captures lifetimes which the returned type doesn't use",
+            "consider not capturing them",
+            format!("impl {}", bounds.join(" + ")),
+            app,
+        );
+    }
+}
+
+/// Checks if the opaque type captures any lifetime parameters.
+fn captures_lifetimes(cx: &LateContext<'_>, opaque_def_id: LocalDefId) -> bool {
+    // The captured lifetimes are duplicated as parameters of the opaque type itself
+    cx.tcx
+        .generics_of(opaque_def_id)
+        .own_params
+        .iter()
+        .any(|param| matches!(param.kind, GenericParamDefKind::Lifetime))
+}
+
+/// Checks if the hidden type or the trait bounds of the opaque type use any non-`'static` lifetime.
+fn uses_lifetimes(cx: &LateContext<'_>, opaque_def_id: LocalDefId) -> bool {
+    let hidden_ty = cx.tcx.type_of(opaque_def_id).instantiate_identity();
+    if hidden_ty.references_error() {
+        return true;
+    }
+
+    let mut uses = false;
+    let mut visit_region = |region: ty::Region<'_>| uses |= !region.is_static();
+    cx.tcx.for_each_free_region(&hidden_ty, &mut visit_region);
+    for (clause, _) in cx
+        .tcx
+        .explicit_item_bounds(opaque_def_id)
+        .instantiate_identity_iter_copied()
+    {
+        // `impl Trait + 'a` is what's being linted
+        if !matches!(clause.kind().skip_binder(), ClauseKind::TypeOutlives(_)) {
+            cx.tcx.for_each_free_region(&clause, &mut visit_region);
+        }
+    }
+    uses
+}
+
+/// Returns the names of the type and const parameters in scope of the function, which have to be
+/// listed in a `use<..>` bound. Returns `None` if any of them can't be named.
+fn non_lifetime_params(cx: &LateContext<'_>, fn_def_id: LocalDefId) -> Option<Vec<String>> {
+    let mut params = Vec::new();
+    let mut generics = Some(cx.tcx.generics_of(fn_def_id));
+    while let Some(current) = generics {
+        for param in current.own_params.iter().rev() {
+            match param.kind {
+                GenericParamDefKind::Lifetime => {},
+                // `impl Trait` arguments can't be listed in `use<..>`
+                GenericParamDefKind::Type { synthetic: true, .. } => return None,
+                GenericParamDefKind::Type { .. } | GenericParamDefKind::Const { .. } => {
+                    params.push(param.name.to_string());
+                },
+            }
+        }
+        generics = current.parent.map(|parent| cx.tcx.generics_of(parent));
+    }
+    params.reverse();
+    Some(params)
+}
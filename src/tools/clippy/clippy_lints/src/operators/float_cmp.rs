@@ -1,13 +1,15 @@
 use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::get_item_name;
 use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::for_each_expr_without_closures;
+use clippy_utils::{get_item_name, higher, is_lint_allowed, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 
-use super::{FLOAT_CMP, FLOAT_CMP_CONST};
+use super::{FLOAT_CMP, FLOAT_CMP_CONST, FLOAT_CMP_IN_CONDITION};
 
 pub(crate) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -42,7 +44,16 @@ pub(crate) fn check<'tcx>(
             }
         }
         let is_comparing_arrays = is_array(cx, left) || is_array(cx, right);
-        let (lint, msg) = get_lint_and_message(left_is_local && right_is_local, is_comparing_arrays);
+        let (lint, msg) = match condition_position(cx, expr) {
+            Some(position)
+                if !is_comparing_arrays
+                    && !is_lint_allowed(cx, FLOAT_CMP_IN_CONDITION, expr.hir_id)
+                    && (is_arithmetic(left, position) || is_arithmetic(right, position)) =>
+            {
+                (FLOAT_CMP_IN_CONDITION, position.message())
+            },
+            _ => get_lint_and_message(left_is_local && right_is_local, is_comparing_arrays),
+        };
         span_lint_and_then(cx, lint, expr.span, msg, |diag| {
             let lhs = Sugg::hir(cx, left, "..");
             let rhs = Sugg::hir(cx, right, "..");
@@ -85,6 +96,96 @@ fn get_lint_and_message(is_local: bool, is_comparing_arrays: bool) -> (&'static
     }
 }
 
+#[derive(Clone, Copy)]
+enum ConditionPosition<'tcx> {
+    Guard,
+    /// The condition of a `while` loop with the given body
+    While(&'tcx Expr<'tcx>),
+}
+
+impl ConditionPosition<'_> {
+    fn message(self) -> &'static str {
+        match self {
+            Self::Guard => "strict comparison of `f32` or `f64` in a match guard",
+            Self::While(_) => "strict comparison of `f32` or `f64` in a `while` condition",
+        }
+    }
+}
+
+/// Checks if `expr` is part of a match guard or a `while` condition, possibly combined with other
+/// conditions using `&&`, `||` or `!`.
+fn condition_position<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<ConditionPosition<'tcx>> {
+    let mut child_id = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Arm(arm) => {
+                return arm
+                    .guard
+                    .is_some_and(|guard| guard.hir_id == child_id)
+                    .then_some(ConditionPosition::Guard);
+            },
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Binary(op, ..) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {},
+                ExprKind::Unary(UnOp::Not, _) | ExprKind::DropTemps(_) => {},
+                // `while cond { .. }` is desugared to `loop { if cond { .. } else { break } }`
+                ExprKind::If(cond, ..) if cond.hir_id == child_id => {
+                    let mut parents = cx.tcx.hir().parent_iter(parent_id).map(|(_, node)| node);
+                    return if let Some(Node::Block(_)) = parents.next()
+                        && let Some(Node::Expr(loop_expr)) = parents.next()
+                        && let Some(while_loop) = higher::While::hir(loop_expr)
+                    {
+                        Some(ConditionPosition::While(while_loop.body))
+                    } else {
+                        None
+                    };
+                },
+                _ => return None,
+            },
+            _ => return None,
+        }
+        child_id = parent_id;
+    }
+    None
+}
+
+/// Checks if `expr` is the result of arithmetic. In `while` conditions this includes locals which
+/// are updated with arithmetic in the loop body.
+fn is_arithmetic(expr: &Expr<'_>, position: ConditionPosition<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Binary(op, ..) => is_arithmetic_op(op.node),
+        ExprKind::Unary(UnOp::Neg | UnOp::Deref, inner) | ExprKind::AddrOf(_, _, inner) => {
+            is_arithmetic(inner, position)
+        },
+        _ => {
+            if let ConditionPosition::While(body) = position
+                && let Some(local_id) = path_to_local(expr)
+            {
+                for_each_expr_without_closures(body, |e| match e.kind {
+                    ExprKind::AssignOp(op, lhs, _) if is_arithmetic_op(op.node) && path_to_local_id(lhs, local_id) => {
+                        ControlFlow::Break(())
+                    },
+                    ExprKind::Assign(lhs, rhs, _)
+                        if path_to_local_id(lhs, local_id) && is_arithmetic(rhs, position) =>
+                    {
+                        ControlFlow::Break(())
+                    },
+                    _ => ControlFlow::Continue(()),
+                })
+                .is_some()
+            } else {
+                false
+            }
+        },
+    }
+}
+
+fn is_arithmetic_op(op: BinOpKind) -> bool {
+    matches!(
+        op,
+        BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul | BinOpKind::Div | BinOpKind::Rem
+    )
+}
+
 fn is_allowed(val: &Constant<'_>) -> bool {
     match val {
         // FIXME(f16_f128): add when equality check is available on all platforms
@@ -694,6 +694,37 @@
     "using `==` or `!=` on float constants instead of comparing difference with an allowed error"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for (in-)equality comparisons on floating-point values in match guards and `while`
+    /// conditions, where at least one side is the result of arithmetic. In `while` loops this
+    /// includes locals which are updated with arithmetic in the loop body.
+    ///
+    /// ### Why is this bad?
+    /// The result of floating-point arithmetic is rarely exactly equal to the value it's
+    /// compared to. In a `while` condition this often makes the loop run forever, in a match
+    /// guard the arm silently never matches.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut x = 0.0;
+    /// while x != 1.0 {
+    ///     x += 0.1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut x = 0.0;
+    /// while x < 1.0 {
+    ///     x += 0.1;
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub FLOAT_CMP_IN_CONDITION,
+    suspicious,
+    "using `==` or `!=` on the result of float arithmetic in match guards or `while` conditions"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for getting the remainder of integer division by one or minus
@@ -874,6 +905,7 @@ pub fn new(conf: &'static Conf) -> Self {
     CMP_OWNED,
     FLOAT_CMP,
     FLOAT_CMP_CONST,
+    FLOAT_CMP_IN_CONDITION,
     MODULO_ONE,
     MODULO_ARITHMETIC,
     NEEDLESS_BITWISE_BOOL,
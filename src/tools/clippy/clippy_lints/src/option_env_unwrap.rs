@@ -1,8 +1,10 @@
+use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::is_direct_expn_of;
+use clippy_utils::macros::{ignores_external_macros, in_ignored_external_macro};
 use rustc_ast::ast::{Expr, ExprKind, MethodCall};
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -31,13 +33,26 @@
     "using `option_env!(...).unwrap()` to get environment variable"
 }
 
-declare_lint_pass!(OptionEnvUnwrap => [OPTION_ENV_UNWRAP]);
+pub struct OptionEnvUnwrap {
+    ignore_external_macros: bool,
+}
+
+impl OptionEnvUnwrap {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            ignore_external_macros: ignores_external_macros(conf, OPTION_ENV_UNWRAP),
+        }
+    }
+}
+
+impl_lint_pass!(OptionEnvUnwrap => [OPTION_ENV_UNWRAP]);
 
 impl EarlyLintPass for OptionEnvUnwrap {
     fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
         if let ExprKind::MethodCall(box MethodCall { seg, receiver, .. }) = &expr.kind
             && matches!(seg.ident.name, sym::expect | sym::unwrap)
             && is_direct_expn_of(receiver.span, "option_env").is_some()
+            && !in_ignored_external_macro(self.ignore_external_macros, expr.span)
         {
             span_lint_and_help(
                 cx,
@@ -10,13 +10,16 @@
 mod vec_box;
 
 use clippy_config::Conf;
+use clippy_utils::def_path_def_ids;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefIdSet;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
     Body, FnDecl, FnRetTy, GenericArg, ImplItem, ImplItemKind, Item, ItemKind, LetStmt, MutTy, QPath, TraitFn,
     TraitItem, TraitItemKind, TyKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TyCtxt;
 use rustc_session::impl_lint_pass;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::Span;
@@ -62,7 +65,8 @@
     ///
     /// ### Known problems
     /// Vec<Box<T: Sized>> makes sense if T is a large type (see [#3530](https://github.com/rust-lang/rust-clippy/issues/3530),
-    /// 1st comment).
+    /// 1st comment), or if the address of the elements has to stay stable when the `Vec` reallocates.
+    /// Such types can be listed in the `vec-box-allowed-types` configuration.
     ///
     /// ### Example
     /// ```no_run
@@ -306,6 +310,7 @@
 
 pub struct Types {
     vec_box_size_threshold: u64,
+    vec_box_allowed_types: DefIdSet,
     type_complexity_threshold: u64,
     avoid_breaking_exported_api: bool,
 }
@@ -447,9 +452,14 @@ fn check_local(&mut self, cx: &LateContext<'tcx>, local: &LetStmt<'tcx>) {
 }
 
 impl Types {
-    pub fn new(conf: &'static Conf) -> Self {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
         Self {
             vec_box_size_threshold: conf.vec_box_size_threshold,
+            vec_box_allowed_types: conf
+                .vec_box_allowed_types
+                .iter()
+                .flat_map(|p| def_path_def_ids(tcx, &p.split("::").collect::<Vec<_>>()))
+                .collect(),
             type_complexity_threshold: conf.type_complexity_threshold,
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
         }
@@ -506,7 +516,14 @@ fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>, mut
                         triggered |= box_collection::check(cx, hir_ty, qpath, def_id);
                         triggered |= redundant_allocation::check(cx, hir_ty, qpath, def_id);
                         triggered |= rc_buffer::check(cx, hir_ty, qpath, def_id);
-                        triggered |= vec_box::check(cx, hir_ty, qpath, def_id, self.vec_box_size_threshold);
+                        triggered |= vec_box::check(
+                            cx,
+                            hir_ty,
+                            qpath,
+                            def_id,
+                            self.vec_box_size_threshold,
+                            &self.vec_box_allowed_types,
+                        );
                         triggered |= option_option::check(cx, hir_ty, qpath, def_id);
                         triggered |= linked_list::check(cx, hir_ty, def_id);
                         triggered |= rc_mutex::check(cx, hir_ty, qpath, def_id);
@@ -2,7 +2,7 @@
 use clippy_utils::last_path_segment;
 use clippy_utils::source::snippet;
 use rustc_errors::Applicability;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, DefIdSet};
 use rustc_hir::{self as hir, GenericArg, LangItem, QPath, TyKind};
 use rustc_hir_analysis::lower_ty;
 use rustc_lint::LateContext;
@@ -18,6 +18,7 @@ pub(super) fn check<'tcx>(
     qpath: &QPath<'tcx>,
     def_id: DefId,
     box_size_threshold: u64,
+    allowed_types: &DefIdSet,
 ) -> bool {
     if cx.tcx.is_diagnostic_item(sym::Vec, def_id) {
         if let Some(last) = last_path_segment(qpath).args
@@ -40,6 +41,7 @@ pub(super) fn check<'tcx>(
             && ty_ty.is_sized(cx.tcx, cx.param_env)
             && let Ok(ty_ty_size) = cx.layout_of(ty_ty).map(|l| l.size.bytes())
             && ty_ty_size < box_size_threshold
+            && !ty_ty.ty_adt_def().is_some_and(|adt| allowed_types.contains(&adt.did()))
             // https://github.com/rust-lang/rust-clippy/issues/7114
             && match (vec_alloc_ty, boxed_alloc_ty) {
                 (None, None) => true,
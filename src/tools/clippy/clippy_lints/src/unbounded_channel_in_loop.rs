@@ -0,0 +1,146 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{def_path_def_ids, get_enclosing_block, match_def_path, path_def_id, path_to_local_id, paths};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Block, Expr, ExprKind, HirId, LetStmt, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for unbounded channels, e.g. created by `std::sync::mpsc::channel`, whose sender is
+    /// used in a loop, while the receiver isn't used in the same thread or task.
+    ///
+    /// ### Why is this bad?
+    /// Sending to an unbounded channel never blocks. If the receiver can't keep up with the
+    /// producer, the queued values pile up without limit, which eventually exhausts the memory.
+    /// A bounded channel blocks the sender once it's full, which slows the producer down to the
+    /// speed of the consumer.
+    ///
+    /// ### Known problems
+    /// Only the sender bound by the `let` statement is tracked, sends through clones of it aren't
+    /// checked.
+    ///
+    /// ### Configuration
+    /// Unbounded channels from other crates can be added with the `unbounded-channel-constructors`
+    /// configuration, e.g. `tokio::sync::mpsc::unbounded_channel`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn produce() -> Option<u32> { None }
+    /// let (tx, rx) = std::sync::mpsc::channel();
+    /// std::thread::spawn(move || rx.into_iter().for_each(drop));
+    /// while let Some(value) = produce() {
+    ///     tx.send(value).unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn produce() -> Option<u32> { None }
+    /// let (tx, rx) = std::sync::mpsc::sync_channel(64);
+    /// std::thread::spawn(move || rx.into_iter().for_each(drop));
+    /// while let Some(value) = produce() {
+    ///     tx.send(value).unwrap();
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub UNBOUNDED_CHANNEL_IN_LOOP,
+    pedantic,
+    "sending to an unbounded channel in a loop without receiving in the same thread or task"
+}
+
+pub struct UnboundedChannelInLoop {
+    constructors: DefIdSet,
+}
+
+impl UnboundedChannelInLoop {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            constructors: conf
+                .unbounded_channel_constructors
+                .iter()
+                .flat_map(|path| def_path_def_ids(tcx, &path.split("::").collect::<Vec<_>>()))
+                .collect(),
+        }
+    }
+}
+
+impl_lint_pass!(UnboundedChannelInLoop => [UNBOUNDED_CHANNEL_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for UnboundedChannelInLoop {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let PatKind::Tuple([tx_pat, rx_pat], _) = local.pat.kind
+            && let PatKind::Binding(_, tx, ..) = tx_pat.kind
+            && let PatKind::Binding(_, rx, ..) = rx_pat.kind
+            && let Some(init) = local.init
+            && let ExprKind::Call(func, []) = init.kind
+            && !init.span.from_expansion()
+            && let Some(def_id) = path_def_id(cx, func)
+            && let is_std = match_def_path(cx, def_id, &paths::MPSC_CHANNEL)
+            && (is_std || self.constructors.contains(&def_id))
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+        {
+            let mut sends = Vec::new();
+            let mut receiver_scopes = Vec::new();
+            for_each_expr(cx, block, |e| {
+                if let ExprKind::MethodCall(path, recv, ..) = e.kind
+                    && path_to_local_id(recv, tx)
+                    && path.ident.as_str().contains("send")
+                {
+                    sends.push(e);
+                } else if path_to_local_id(e, rx) {
+                    receiver_scopes.push(task_scope(cx, e, block).0);
+                }
+                ControlFlow::<()>::Continue(())
+            });
+
+            let Some(send) = sends.into_iter().find(|send| {
+                let (scope, in_loop) = task_scope(cx, send, block);
+                in_loop && !receiver_scopes.contains(&scope)
+            }) else {
+                return;
+            };
+            span_lint_and_then(
+                cx,
+                UNBOUNDED_CHANNEL_IN_LOOP,
+                init.span,
+                "unbounded channel whose sender is used in a loop",
+                |diag| {
+                    diag.span_note(
+                        send.span,
+                        "values are sent in a loop here, without receiving them in the same thread or task",
+                    );
+                    if is_std {
+                        diag.help(
+                            "consider using `std::sync::mpsc::sync_channel`, which blocks the sender once it's full",
+                        );
+                    } else {
+                        diag.help("consider using a bounded channel, which blocks the sender once it's full");
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Returns the innermost closure or async block containing `expr`, falling back to the block the
+/// channel was created in, and whether `expr` is inside of a loop within that scope.
+fn task_scope(cx: &LateContext<'_>, expr: &Expr<'_>, block: &Block<'_>) -> (HirId, bool) {
+    let mut in_loop = false;
+    for (id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        if id == block.hir_id {
+            break;
+        }
+        if let Node::Expr(e) = node {
+            match e.kind {
+                ExprKind::Closure(_) => return (id, in_loop),
+                ExprKind::Loop(..) => in_loop = true,
+                _ => {},
+            }
+        }
+    }
+    (block.hir_id, in_loop)
+}
@@ -0,0 +1,109 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr_without_closures;
+use clippy_utils::{fn_def_id, path_to_local};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `const fn`s calling themselves with arguments which are all passed through
+    /// unchanged from the function's own parameters, or are literals.
+    ///
+    /// ### Why is this bad?
+    /// Unless an argument moves towards the base case, the recursion is only bounded by the
+    /// const evaluation step limit. Evaluating such a function in a constant context then fails
+    /// with a hard to understand error, or takes very long to compile.
+    ///
+    /// ### Known problems
+    /// Only arguments which are obviously unchanged are considered, any other expression
+    /// derived from a parameter is assumed to make progress.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// const fn count(n: u32, acc: u32) -> u32 {
+    ///     if n == 0 { acc } else { count(n, acc + 1) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// const fn count(n: u32, acc: u32) -> u32 {
+    ///     if n == 0 { acc } else { count(n - 1, acc + 1) }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub UNBOUNDED_CONST_RECURSION,
+    pedantic,
+    "recursive calls in `const fn` that don't change any argument"
+}
+
+declare_lint_pass!(UnboundedConstRecursion => [UNBOUNDED_CONST_RECURSION]);
+
+impl<'tcx> LateLintPass<'tcx> for UnboundedConstRecursion {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if !kind.header().is_some_and(|header| header.is_const()) || span.from_expansion() {
+            return;
+        }
+
+        let params: FxHashSet<HirId> = body
+            .params
+            .iter()
+            .filter_map(|param| match param.pat.kind {
+                PatKind::Binding(_, id, ..) => Some(id),
+                _ => None,
+            })
+            .collect();
+        for_each_expr_without_closures(body.value, |e| {
+            if let Some(args) = call_args(cx, e, def_id)
+                && args.iter().all(|arg| is_unchanged(arg, &params))
+            {
+                span_lint_and_then(
+                    cx,
+                    UNBOUNDED_CONST_RECURSION,
+                    e.span,
+                    "this recursive call doesn't change any of the arguments",
+                    |diag| {
+                        diag.note("const evaluation will only stop at the step limit if this call is reached again");
+                        diag.help("make sure one of the arguments moves towards the base case");
+                    },
+                );
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
+
+/// Returns the arguments of `expr` if it's a call to the function `fn_id`, including the receiver
+/// of method calls.
+fn call_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, fn_id: LocalDefId) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    if fn_def_id(cx, expr) != Some(fn_id.to_def_id()) {
+        return None;
+    }
+    match expr.kind {
+        ExprKind::Call(_, args) => Some(args.iter().collect()),
+        ExprKind::MethodCall(_, receiver, args, _) => Some([receiver].into_iter().chain(args).collect()),
+        _ => None,
+    }
+}
+
+/// Checks if `arg` is a literal, or one of the parameters passed through as is.
+fn is_unchanged(arg: &Expr<'_>, params: &FxHashSet<HirId>) -> bool {
+    match arg.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) => is_unchanged(inner, params),
+        _ => path_to_local(arg).is_some_and(|id| params.contains(&id)),
+    }
+}
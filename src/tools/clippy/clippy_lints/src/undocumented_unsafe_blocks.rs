@@ -21,6 +21,12 @@
     /// explaining why the unsafe operations performed inside
     /// the block are safe.
     ///
+    /// With the `check-unsafe-fns` configuration, `unsafe fn` declarations
+    /// also need a safety comment or a `# Safety` section in their
+    /// documentation describing the contract callers have to uphold.
+    /// Requiring comments on `unsafe impl`s can be turned off with
+    /// `check-unsafe-impls`.
+    ///
     /// Note the comment must appear on the line(s) preceding the unsafe block
     /// with nothing appearing in between. The following is ok:
     /// ```ignore
@@ -95,6 +101,8 @@
 pub struct UndocumentedUnsafeBlocks {
     accept_comment_above_statement: bool,
     accept_comment_above_attributes: bool,
+    check_unsafe_fns: bool,
+    check_unsafe_impls: bool,
 }
 
 impl UndocumentedUnsafeBlocks {
@@ -102,6 +110,8 @@ pub fn new(conf: &'static Conf) -> Self {
         Self {
             accept_comment_above_statement: conf.accept_comment_above_statement,
             accept_comment_above_attributes: conf.accept_comment_above_attributes,
+            check_unsafe_fns: conf.check_unsafe_fns,
+            check_unsafe_impls: conf.check_unsafe_impls,
         }
     }
 }
@@ -203,7 +213,9 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
         let item_has_safety_comment = item_has_safety_comment(cx, item);
         match (&item.kind, item_has_safety_comment) {
             // lint unsafe impl without safety comment
-            (ItemKind::Impl(impl_), HasSafetyComment::No) if impl_.safety == hir::Safety::Unsafe => {
+            (ItemKind::Impl(impl_), HasSafetyComment::No)
+                if self.check_unsafe_impls && impl_.safety == hir::Safety::Unsafe =>
+            {
                 if !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, item.hir_id())
                     && !is_unsafe_from_proc_macro(cx, item.span)
                 {
@@ -243,6 +255,14 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
                 }
             },
             (ItemKind::Impl(_), _) => {},
+            // lint unsafe fn without safety comment or `# Safety` docs
+            (ItemKind::Fn(sig, ..), has_safety_comment)
+                if self.check_unsafe_fns && sig.header.safety == hir::Safety::Unsafe =>
+            {
+                if matches!(has_safety_comment, HasSafetyComment::No) {
+                    check_unsafe_fn(cx, item.hir_id(), item.span);
+                }
+            },
             // const and static items only need a safety comment if their body is an unsafe block, lint otherwise
             (&ItemKind::Const(.., body) | &ItemKind::Static(.., body), HasSafetyComment::Yes(pos)) => {
                 if !is_lint_allowed(cx, UNNECESSARY_SAFETY_COMMENT, body.hir_id) {
@@ -285,6 +305,75 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
             _ => (),
         }
     }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        // The contract of trait methods is documented on the trait
+        if self.check_unsafe_fns
+            && let hir::ImplItemKind::Fn(sig, _) = item.kind
+            && sig.header.safety == hir::Safety::Unsafe
+            && let Node::Item(parent) = cx.tcx.parent_hir_node(item.hir_id())
+            && let ItemKind::Impl(impl_) = parent.kind
+            && impl_.of_trait.is_none()
+            && !in_external_macro(cx.tcx.sess, item.span)
+        {
+            check_unsafe_fn(cx, item.hir_id(), item.span);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        if self.check_unsafe_fns
+            && let hir::TraitItemKind::Fn(sig, _) = item.kind
+            && sig.header.safety == hir::Safety::Unsafe
+            && !in_external_macro(cx.tcx.sess, item.span)
+        {
+            check_unsafe_fn(cx, item.hir_id(), item.span);
+        }
+    }
+}
+
+/// Lints an `unsafe fn` whose documentation has no `# Safety` section. Safety comments preceding
+/// the function are only detected for free functions, the caller has to check for them.
+fn check_unsafe_fn(cx: &LateContext<'_>, hir_id: HirId, span: Span) {
+    if span.from_expansion()
+        || is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, hir_id)
+        || has_safety_doc_section(cx, hir_id)
+    {
+        return;
+    }
+
+    let source_map = cx.tcx.sess.source_map();
+    let span = if source_map.is_multiline(span) {
+        source_map.span_until_char(span, '\n')
+    } else {
+        span
+    };
+
+    #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
+    span_lint_and_then(
+        cx,
+        UNDOCUMENTED_UNSAFE_BLOCKS,
+        span,
+        "unsafe fn missing a safety comment",
+        |diag| {
+            diag.help("consider adding a safety comment or a `# Safety` section to the documentation");
+        },
+    );
+}
+
+/// Checks if the doc comments of `hir_id` contain a `# Safety` heading.
+fn has_safety_doc_section(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    cx.tcx
+        .hir()
+        .attrs(hir_id)
+        .iter()
+        .filter_map(hir::Attribute::doc_str)
+        .any(|doc| {
+            doc.as_str().lines().any(|line| {
+                line.trim_start()
+                    .strip_prefix('#')
+                    .is_some_and(|heading| heading.trim_start_matches('#').trim().eq_ignore_ascii_case("safety"))
+            })
+        })
 }
 
 fn expr_has_unnecessary_safety_comment<'tcx>(
@@ -176,7 +176,7 @@ fn check_vec_macro<'tcx>(
                 }
             },
             higher::VecArgs::Vec(args) => {
-                let args_span = if let Some(last) = args.iter().last() {
+                let args_span = if let Some(last) = args.last() {
                     if args.len() as u64 * size_of(cx, last) > self.too_large_for_stack {
                         return;
                     }
@@ -71,6 +71,7 @@
 pub mod source;
 pub mod str_utils;
 pub mod sugg;
+pub mod test_scopes;
 pub mod ty;
 pub mod usage;
 pub mod visitors;
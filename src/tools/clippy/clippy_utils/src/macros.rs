@@ -3,11 +3,12 @@
 use crate::visitors::{for_each_expr_without_closures, Descend};
 
 use arrayvec::ArrayVec;
+use clippy_config::Conf;
 use rustc_ast::{FormatArgs, FormatArgument, FormatPlaceholder};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sync::{Lrc, OnceLock};
 use rustc_hir::{self as hir, Expr, ExprKind, HirId, Node, QPath};
-use rustc_lint::LateContext;
+use rustc_lint::{LateContext, Lint};
 use rustc_span::def_id::DefId;
 use rustc_span::hygiene::{self, MacroKind, SyntaxContext};
 use rustc_span::{sym, BytePos, ExpnData, ExpnId, ExpnKind, Span, SpanData, Symbol};
@@ -96,6 +97,27 @@ pub fn expn_is_local(expn: ExpnId) -> bool {
         .map_or(true, DefId::is_local)
 }
 
+/// Checks if `ignore-external-macros` makes `lint` skip code expanded from macros defined in other
+/// crates, i.e. the option is enabled and `lint` isn't listed in `lints-in-external-macros`.
+///
+/// Lints supporting the option should call this once when the lint pass is created, and check
+/// [`in_ignored_external_macro`] with the result before emitting.
+pub fn ignores_external_macros(conf: &Conf, lint: &Lint) -> bool {
+    let name = lint.name_lower();
+    let name = name.strip_prefix("clippy::").unwrap_or(&name);
+    conf.ignore_external_macros
+        && !conf
+            .lints_in_external_macros
+            .iter()
+            .any(|listed| listed.strip_prefix("clippy::").unwrap_or(listed).replace('-', "_") == name)
+}
+
+/// Checks if `span` was expanded from a macro defined in another crate and the lint skips it,
+/// `ignore` being the result of [`ignores_external_macros`] for the lint.
+pub fn in_ignored_external_macro(ignore: bool, span: Span) -> bool {
+    ignore && !span_is_local(span)
+}
+
 /// Returns an iterator of macro expansions that created the given span.
 /// Note that desugaring expansions are skipped.
 pub fn macro_backtrace(span: Span) -> impl Iterator<Item = MacroCall> {
@@ -0,0 +1,52 @@
+//! Test-only lint levels.
+//!
+//! The `allowed-lints-in-tests` and `test-only-lints` configuration options change whether a lint
+//! is emitted depending on if it is inside of a `#[cfg(test)]` module or a `#[test]` function.
+//! Lints supporting them look up their [`TestScope`] when the lint pass is created, and check it
+//! against the HIR before emitting.
+
+use crate::is_in_test;
+use clippy_config::Conf;
+use rustc_hir::HirId;
+use rustc_lint::Lint;
+use rustc_middle::ty::TyCtxt;
+
+/// Where a lint is emitted, according to `allowed-lints-in-tests` and `test-only-lints`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TestScope {
+    /// The lint is emitted in all code, the default.
+    Everywhere,
+    /// The lint is allowed in test code.
+    OutsideTests,
+    /// The lint is only emitted in test code.
+    InTests,
+}
+
+impl TestScope {
+    /// Looks up where the configuration emits `lint`.
+    pub fn new(conf: &Conf, lint: &Lint) -> Self {
+        let name = lint.name_lower();
+        let name = name.strip_prefix("clippy::").unwrap_or(&name);
+        let is_listed = |names: &[String]| {
+            names
+                .iter()
+                .any(|listed| listed.strip_prefix("clippy::").unwrap_or(listed).replace('-', "_") == name)
+        };
+        if is_listed(&conf.allowed_lints_in_tests) {
+            Self::OutsideTests
+        } else if is_listed(&conf.test_only_lints) {
+            Self::InTests
+        } else {
+            Self::Everywhere
+        }
+    }
+
+    /// Checks if the lint is skipped at `hir_id` because it is, or isn't, in test code.
+    pub fn is_skipped(self, tcx: TyCtxt<'_>, hir_id: HirId) -> bool {
+        match self {
+            Self::Everywhere => false,
+            Self::OutsideTests => is_in_test(tcx, hir_id),
+            Self::InTests => !is_in_test(tcx, hir_id),
+        }
+    }
+}
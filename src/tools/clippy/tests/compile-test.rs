@@ -204,7 +204,10 @@ fn run_ui(cx: &TestContext) {
     ui_test::run_tests_generic(
         vec![config],
         ui_test::default_file_filter,
-        ui_test::default_per_file_config,
+        |config, file_contents| {
+            ui_test::default_per_file_config(config, file_contents);
+            enable_unstable_editions(config, file_contents);
+        },
         status_emitter::Text::from(cx.args.format),
     )
     .unwrap();
@@ -244,12 +247,32 @@ fn run_ui_toml(cx: &TestContext) {
                 .program
                 .envs
                 .push(("CLIPPY_CONF_DIR".into(), Some(path.parent().unwrap().into())));
+            enable_unstable_editions(config, file_contents);
         },
         status_emitter::Text::from(cx.args.format),
     )
     .unwrap();
 }
 
+/// Passing `--edition 2024` requires `-Zunstable-options` while the edition is unstable. Enables it
+/// for test files using `//@edition:2024`, so that edition revisions don't have to repeat it, e.g.
+///
+/// ```text
+/// //@revisions: e2018 e2021 e2024
+/// //@[e2018] edition:2018
+/// //@[e2021] edition:2021
+/// //@[e2024] edition:2024
+/// ```
+fn enable_unstable_editions(config: &mut Config, file_contents: &Spanned<Vec<u8>>) {
+    const EDITION_2024: &[u8] = b"edition:2024";
+    if file_contents
+        .windows(EDITION_2024.len())
+        .any(|window| window == EDITION_2024)
+    {
+        config.program.args.push("-Zunstable-options".into());
+    }
+}
+
 // Allow `Default::default` as `OptWithSpan` is not nameable
 #[allow(clippy::default_trait_access)]
 fn run_ui_cargo(cx: &TestContext) {
@@ -0,0 +1,19 @@
+//@compile-flags: --crate-name forget_guard
+#![warn(clippy::forget_guard)]
+
+struct Transaction;
+
+impl Drop for Transaction {
+    fn drop(&mut self) {}
+}
+
+struct Other;
+
+impl Drop for Other {
+    fn drop(&mut self) {}
+}
+
+fn main() {
+    std::mem::forget(Transaction);
+    std::mem::forget(Other);
+}
@@ -0,0 +1,18 @@
+//@aux-build:../../ui/auxiliary/proc_macros.rs
+//@revisions: ignore override
+//@[ignore] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/ignore_external_macros/ignore
+//@[override] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/ignore_external_macros/override
+#![warn(clippy::option_env_unwrap)]
+
+extern crate proc_macros;
+use proc_macros::{external, inline_macros};
+
+#[inline_macros]
+fn main() {
+    let _ = option_env!("PATH").unwrap();
+    //~^ ERROR: this will panic at run-time if the environment variable doesn't exist at compile-time
+    let _ = inline!(option_env!($"PATH").unwrap());
+    //~^ ERROR: this will panic at run-time if the environment variable doesn't exist at compile-time
+    let _ = external!(option_env!($"PATH").unwrap());
+    //~[override]^ ERROR: this will panic at run-time if the environment variable doesn't exist
+}
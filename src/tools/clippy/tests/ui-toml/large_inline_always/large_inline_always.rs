@@ -0,0 +1,23 @@
+#![warn(clippy::large_inline_always)]
+
+#[inline(always)]
+fn large(x: u32) -> u32 {
+    let a = x + 1;
+    let b = a * 2;
+    a + b
+}
+
+#[inline(always)]
+fn small(x: u32) -> u32 {
+    x + 1
+}
+
+#[cfg(target_pointer_width = "64")]
+#[inline(always)]
+fn platform(n: u32) {
+    if n > 0 {
+        platform(n - 1);
+    }
+}
+
+fn main() {}
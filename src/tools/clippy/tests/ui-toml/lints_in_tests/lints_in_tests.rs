@@ -0,0 +1,29 @@
+//@no-rustfix
+//@compile-flags: --test
+#![warn(clippy::unwrap_used, clippy::dbg_macro)]
+#![allow(clippy::unnecessary_literal_unwrap)]
+
+fn main() {
+    let opt = Some(0);
+    let _ = opt.unwrap();
+    //~^ ERROR: used `unwrap()` on an `Option` value
+    dbg!(1);
+}
+
+#[test]
+fn test_fn() {
+    let opt = Some(0);
+    let _ = opt.unwrap();
+    dbg!(1);
+    //~^ ERROR: the `dbg!` macro is intended as a debugging tool
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        let opt = Some(0);
+        let _ = opt.unwrap();
+        dbg!(1);
+        //~^ ERROR: the `dbg!` macro is intended as a debugging tool
+    }
+}
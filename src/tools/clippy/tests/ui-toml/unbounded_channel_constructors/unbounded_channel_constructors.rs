@@ -0,0 +1,27 @@
+//@compile-flags: --crate-name unbounded_channel_constructors
+#![warn(clippy::unbounded_channel_in_loop)]
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    mpsc::channel()
+}
+
+fn other<T>() -> (Sender<T>, Receiver<T>) {
+    mpsc::channel()
+}
+
+fn main() {
+    let (tx, rx) = unbounded();
+    thread::spawn(move || rx.into_iter().for_each(drop));
+    for i in 0..1000 {
+        tx.send(i).unwrap();
+    }
+
+    let (tx, rx) = other();
+    thread::spawn(move || rx.into_iter().for_each(drop));
+    for i in 0..1000 {
+        tx.send(i).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+#![warn(clippy::undocumented_unsafe_blocks, clippy::unnecessary_safety_comment)]
+#![allow(clippy::missing_safety_doc)]
+
+pub unsafe fn undocumented() {}
+//~^ ERROR: unsafe fn missing a safety comment
+
+// SAFETY: callers need to uphold nothing
+pub unsafe fn with_comment() {}
+
+/// Does nothing.
+///
+/// # Safety
+///
+/// Callers need to uphold nothing.
+pub unsafe fn with_docs() {}
+
+/// Does nothing.
+pub unsafe fn with_other_docs(
+    //~^ ERROR: unsafe fn missing a safety comment
+    x: u32,
+) {
+}
+
+pub fn safe() {}
+
+pub struct S;
+
+impl S {
+    pub unsafe fn undocumented_method(&self) {}
+    //~^ ERROR: unsafe fn missing a safety comment
+
+    /// # Safety
+    ///
+    /// Callers need to uphold nothing.
+    pub unsafe fn documented_method(&self) {}
+}
+
+pub unsafe trait T {
+    unsafe fn undocumented_trait_method(&self);
+    //~^ ERROR: unsafe fn missing a safety comment
+
+    /// # Safety
+    ///
+    /// Callers need to uphold nothing.
+    unsafe fn documented_trait_method(&self);
+}
+
+unsafe impl T for S {
+    unsafe fn undocumented_trait_method(&self) {}
+
+    unsafe fn documented_trait_method(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+//@compile-flags: --crate-name vec_box_allowed_types
+#![warn(clippy::vec_box)]
+
+struct Pinned(u32);
+struct Other(u32);
+
+mod inner {
+    pub struct Node(pub u32);
+}
+
+struct A(Vec<Box<Pinned>>);
+struct B(Vec<Box<inner::Node>>);
+struct C(Vec<Box<Other>>);
+
+fn main() {}
@@ -0,0 +1,63 @@
+#![warn(clippy::blocking_call_holding_lock)]
+
+use std::process::Command;
+use std::sync::{Condvar, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+fn sleep_holding_lock(x: &Mutex<u32>) {
+    let mut guard = x.lock().unwrap();
+    //~^ ERROR: this lock guard is held across a blocking call
+    *guard += 1;
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn sleep_holding_rwlock(x: &RwLock<u32>) {
+    let guard = x.read().unwrap();
+    //~^ ERROR: this lock guard is held across a blocking call
+    let _ = *guard;
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+fn spawn_holding_lock(x: &Mutex<u32>) {
+    let guard = x.lock().unwrap();
+    //~^ ERROR: this lock guard is held across a blocking call
+    let _ = *guard;
+    let _ = Command::new("ls").status();
+}
+
+fn wait_on_other_lock(x: &Mutex<u32>, y: &Mutex<bool>, cvar: &Condvar) {
+    let guard = x.lock().unwrap();
+    //~^ ERROR: this lock guard is held across a blocking call
+    let _ = *guard;
+    let ready = y.lock().unwrap();
+    let _ready = cvar.wait_timeout(ready, Duration::from_millis(10)).unwrap();
+}
+
+fn wait_on_same_lock(y: &Mutex<bool>, cvar: &Condvar) {
+    let ready = y.lock().unwrap();
+    let _ready = cvar.wait_timeout(ready, Duration::from_millis(10)).unwrap();
+}
+
+fn scoped_guard(x: &Mutex<u32>) {
+    {
+        let mut guard = x.lock().unwrap();
+        *guard += 1;
+    }
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn dropped_guard(x: &Mutex<u32>) {
+    let mut guard = x.lock().unwrap();
+    *guard += 1;
+    drop(guard);
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn sleep_in_spawned_thread(x: &Mutex<u32>) {
+    let guard = x.lock().unwrap();
+    let _ = *guard;
+    thread::spawn(|| thread::sleep(Duration::from_millis(10)));
+}
+
+fn main() {}
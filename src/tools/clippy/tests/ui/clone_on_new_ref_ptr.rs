@@ -0,0 +1,26 @@
+#![warn(clippy::clone_on_new_ref_ptr)]
+#![allow(clippy::redundant_clone)]
+
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+fn main() {
+    let _ = Arc::new(Mutex::new(0)).clone();
+    //~^ ERROR: cloning a newly created `Arc`
+    let _ = Rc::new(vec![1, 2, 3]).clone();
+    //~^ ERROR: cloning a newly created `Rc`
+    let _ = Arc::clone(&Arc::new(1));
+    //~^ ERROR: cloning a newly created `Arc`
+    let _ = Rc::<str>::from("shared").clone();
+    //~^ ERROR: cloning a newly created `Rc`
+    let _: Arc<String> = Arc::default().clone();
+    //~^ ERROR: cloning a newly created `Arc`
+
+    // Don't lint, the pointer is kept around
+    let shared = Arc::new(1);
+    let _ = shared.clone();
+    let _ = Arc::clone(&shared);
+    // Don't lint, this clones the value
+    let _ = (*Rc::new(1)).clone();
+    let _ = Some(Rc::new(1)).clone();
+}
@@ -1,3 +1,8 @@
+//@revisions: e2018 e2021 e2024
+//@[e2018] edition:2018
+//@[e2021] edition:2021
+//@[e2024] edition:2024
+
 #![warn(clippy::crate_in_macro_def)]
 
 mod hygienic {
@@ -0,0 +1,79 @@
+#![warn(clippy::derived_eq_with_manual_hash)]
+
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+#[derive(PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct Tuple(u32, String, bool);
+
+impl Hash for Tuple {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state);
+    }
+}
+
+// All fields are hashed
+#[derive(PartialEq)]
+struct Complete<T> {
+    a: u32,
+    b: String,
+    marker: PhantomData<T>,
+}
+
+impl<T> Hash for Complete<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        (*self).b.hash(state);
+    }
+}
+
+// `self` is used as a whole
+#[derive(PartialEq)]
+struct Delegated {
+    a: u32,
+    b: u32,
+}
+
+impl Delegated {
+    fn key(&self) -> u32 {
+        self.a ^ self.b
+    }
+}
+
+impl Hash for Delegated {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+// `PartialEq` compares the same fields
+struct Manual {
+    a: u32,
+    b: u32,
+}
+
+impl PartialEq for Manual {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+    }
+}
+
+impl Hash for Manual {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![warn(clippy::double_ended_iterator_last)]
+#![allow(unused_mut, clippy::useless_vec)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.iter().last();
+    let _ = v.iter().map(|x| x * 2).last();
+    let _ = (0..10).last();
+    let _ = [1, 2, 3].into_iter().last();
+
+    // `next_back` needs the binding to be mutable
+    let mut it = v.iter();
+    let _ = it.last();
+
+    // Not `ExactSizeIterator`
+    let _ = "abc".chars().last();
+    let _ = (0..10_u64).last();
+    let _ = v.iter().filter(|x| **x > 1).last();
+    // Not `DoubleEndedIterator`
+    let _ = v.iter().skip_while(|x| **x > 1).last();
+}
+
+fn generic<I: DoubleEndedIterator + ExactSizeIterator>(mut it: I) -> Option<I::Item> {
+    it.last()
+}
@@ -0,0 +1,20 @@
+#![crate_type = "lib"]
+#![warn(clippy::env_var_unwrap)]
+
+use std::env;
+
+pub fn home() -> String {
+    env::var("HOME").unwrap()
+}
+
+pub fn path() -> std::ffi::OsString {
+    std::env::var_os("PATH").expect("PATH is not set")
+}
+
+pub fn fallback() -> String {
+    env::var("HOME").unwrap_or_default()
+}
+
+pub fn propagate() -> Result<String, env::VarError> {
+    env::var("HOME")
+}
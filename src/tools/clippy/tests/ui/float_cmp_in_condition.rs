@@ -0,0 +1,36 @@
+#![warn(clippy::float_cmp_in_condition)]
+//@no-rustfix
+
+fn main() {
+    let mut x = 0.0;
+    while x != 1.0 {
+        x += 0.1;
+    }
+
+    let mut y: f64 = 0.0;
+    while y == 0.5 || y < 0.0 {
+        y = y.abs() * 2.0;
+    }
+
+    let a: f32 = 0.1;
+    let b: f32 = 0.2;
+    match Some(a) {
+        Some(v) if v + b == 0.3 => {},
+        Some(v) if !(v * 2.0 != b) => {},
+        _ => {},
+    }
+
+    // Neither side is the result of arithmetic
+    match Some(a) {
+        Some(v) if v == b => {},
+        None => {},
+        _ => {},
+    }
+    let mut z = 0.0;
+    while z != 1.0 {
+        z = 1.0;
+    }
+
+    // Not a condition
+    let _ = a + b == 0.3;
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::forget_guard)]
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::mem::{self, ManuallyDrop};
+use std::sync::{Mutex, RwLock};
+
+fn main() {
+    let m = Mutex::new(0);
+    mem::forget(m.lock().unwrap());
+    let guard = m.lock().unwrap();
+    let _guard = ManuallyDrop::new(guard);
+
+    let rw = RwLock::new(0);
+    mem::forget(rw.read().unwrap());
+    mem::forget(rw.write().unwrap());
+
+    let cell = RefCell::new(0);
+    mem::forget(cell.borrow());
+    mem::forget(cell.borrow_mut());
+
+    let mut v = vec![1, 2, 3];
+    mem::forget(v.drain(..1));
+    let mut deque = VecDeque::from([1, 2, 3]);
+    mem::forget(deque.drain(..1));
+    let mut s = String::from("abc");
+    mem::forget(s.drain(..1));
+
+    // Not a guard
+    mem::forget(String::new());
+    let _ = ManuallyDrop::new(Vec::<u8>::new());
+    drop(cell.borrow());
+}
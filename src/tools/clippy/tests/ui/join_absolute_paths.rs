@@ -27,4 +27,19 @@ fn main() {
 
     let path = Path::new("/bin");
     path.join("sh");
+
+    let path = Path::new("/bin");
+    path.join("C:\\Windows");
+    //~^ ERROR: argument to `Path::join` starts with a drive letter
+
+    let path = PathBuf::from("/bin");
+    path.join("d:/data");
+    //~^ ERROR: argument to `Path::join` starts with a drive letter
+
+    let path = Path::new("/bin");
+    path.join("C:foo");
+    //~^ ERROR: argument to `Path::join` starts with a drive letter
+
+    let path = Path::new("/bin");
+    path.join("ab:c");
 }
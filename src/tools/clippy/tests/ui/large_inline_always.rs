@@ -0,0 +1,49 @@
+#![warn(clippy::large_inline_always)]
+
+#[inline(always)]
+fn fact(n: u64) -> u64 {
+    if n == 0 {
+        return 1;
+    }
+    n * fact(n - 1)
+}
+
+struct S;
+
+impl S {
+    #[inline(always)]
+    fn count_down(&self, n: u32) {
+        if n > 0 {
+            self.count_down(n - 1);
+        }
+    }
+
+    #[inline(always)]
+    fn count_down_path(n: u32) {
+        if n > 0 {
+            Self::count_down_path(n - 1);
+        }
+    }
+}
+
+#[inline(always)]
+fn small(x: u32) -> u32 {
+    x + 1
+}
+
+#[inline]
+fn recursive_inline(n: u32) {
+    if n > 0 {
+        recursive_inline(n - 1);
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+#[inline(always)]
+fn platform(n: u32) {
+    if n > 0 {
+        platform(n - 1);
+    }
+}
+
+fn main() {}
@@ -103,3 +103,20 @@ macro_rules! create_then_move {
     let y = vec![proc_macros::make_it_big!([x; 10])];
     let y = vec![create_then_move![x; 5]; 5];
 }
+
+fn create_array<const N: usize>() -> [u32; N] {
+    [0; N]
+}
+
+fn large_locals_and_params(by_value: [u32; 200_000], by_ref: &[u32; 200_000]) {
+    //~^ ERROR: passing an array larger than 512000 bytes by value
+    let from_call = create_array::<200_000>();
+    //~^ ERROR: allocating a local array larger than 512000 bytes
+    let uninit: [u32; 200_000];
+    //~^ ERROR: allocating a local array larger than 512000 bytes
+    let small = create_array::<1_000>();
+    let boxed = Box::new(create_array::<200_000>());
+
+    let closure = |_: [u8; 600_000]| {};
+    //~^ ERROR: passing an array larger than 512000 bytes by value
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::manual_div_ceil)]
+
+fn main() {
+    let x: u32 = 7;
+    let y: u32 = 4;
+    let z: i32 = 7;
+
+    let _ = (x + y - 1) / y;
+    let _ = (y + x - 1) / y;
+    let _ = (x + (y - 1)) / y;
+    let _ = ((y - 1) + x) / y;
+    let _ = (7 + y - 1) / y;
+    let _ = (x + 4 - 1) / 4;
+
+    let _ = (x + y - 1) / y * y;
+    let _ = y * ((x + y - 1) / y);
+
+    // Signed integers
+    let _ = (z + 4 - 1) / 4;
+    // Different divisor
+    let _ = (x + y - 1) / x;
+    let _ = (x + y - 2) / y;
+    let _ = (x + y - 1) / y * x;
+}
+
+#[clippy::msrv = "1.72"]
+fn msrv_1_72() {
+    let x: u32 = 7;
+    let y: u32 = 4;
+    let _ = (x + y - 1) / y;
+}
+
+#[clippy::msrv = "1.73"]
+fn msrv_1_73() {
+    let x: u32 = 7;
+    let y: u32 = 4;
+    let _ = (x + y - 1) / y;
+}
@@ -1,4 +1,4 @@
-#![allow(unused, dead_code)]
+#![allow(unused, dead_code, clippy::manual_range_contains)]
 #![warn(clippy::manual_is_ascii_check)]
 
 fn main() {
@@ -82,3 +82,24 @@ fn take_while<Item, F>(cond: F)
     take_while(|c| (b'A'..=b'Z').contains(&c));
     take_while(|c: char| ('A'..='Z').contains(&c));
 }
+
+fn comparisons(c: char, d: char, b: u8) {
+    let _ = c >= 'a' && c <= 'z';
+    let _ = 'A' <= c && c <= 'Z';
+    let _ = c <= '9' && c >= '0';
+    let _ = b >= b'0' && b <= b'9';
+
+    let _ = (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z');
+    let _ = ('0'..='9').contains(&c) || ('a'..='z').contains(&c) || ('A'..='Z').contains(&c);
+    let _ = (b >= b'0' && b <= b'9') || (b'a'..=b'f').contains(&b) || (b'A'..=b'F').contains(&b);
+    assert!(matches!(c, '0'..='9' | 'a'..='z' | 'A'..='Z'));
+
+    // Only a part of the chain checks a known range
+    let _ = c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z' || c == '_';
+    let _ = c >= 'a' && c <= 'z' || c >= '0' && c <= '8';
+
+    // Not a known range, or not the same value
+    let _ = c >= 'a' && c <= 'y';
+    let _ = c > 'a' && c <= 'z';
+    let _ = c >= 'a' && d <= 'z';
+}
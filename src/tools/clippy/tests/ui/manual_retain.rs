@@ -293,13 +293,21 @@ fn issue_10393() {
 }
 
 fn issue_11457() {
-    // Do not lint, as we need to modify the closure
+    // Do lint, removing a dereference of the parameter
     let mut vals = vec![1, 2, 3, 4];
     vals = vals.iter().filter(|v| **v != 1).cloned().collect();
+    vals = vals.iter().filter(|v| v.is_positive()).cloned().collect();
 
-    // Do not lint, as we need to modify the closure
+    // Do not lint, the parameter is used as a reference
+    fn takes_ref(x: &&i32) -> bool {
+        **x != 1
+    }
+    vals = vals.iter().filter(|v| takes_ref(v)).cloned().collect();
+
+    // Do lint, removing a dereference of the parameter
     let mut s = String::from("foobar");
     s = s.chars().filter(|c| *c != 'o').to_owned().collect();
+    s = s.chars().filter(|c| c.is_ascii()).to_owned().collect();
 }
 
 fn issue_12081() {
@@ -315,3 +323,9 @@ fn issue_12081() {
     vec = vec.iter().filter(|&x| *x == 0).cloned().collect();
     vec = vec.into_iter().filter(|x| *x == 0).collect();
 }
+
+fn non_copy_map_values() {
+    let mut map: HashMap<i8, String> = HashMap::new();
+    map = map.into_iter().filter(|(_, v)| v.is_empty()).collect();
+    map = map.into_iter().filter(|(k, v)| *k == 0 && !v.is_empty()).collect();
+}
@@ -78,3 +78,17 @@ fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
 fn foo(_: impl IntoIterator<Item = usize>) {}
 fn bar<I: IntoIterator<Item = usize>>(_: Vec<usize>, _: I) {}
 fn baz<I: IntoIterator<Item = usize>>(_: I, _: (), _: impl IntoIterator<Item = char>) {}
+
+#[warn(clippy::needless_collect)]
+fn collect_then_iter() {
+    let sample = [1; 5];
+    let _: i32 = sample.iter().map(|x| x + 1).collect::<Vec<_>>().into_iter().sum();
+    let _ = sample.iter().collect::<Vec<_>>().iter().count();
+    let _: Vec<_> = (0..5).map(|x| x * 2).collect::<Vec<_>>().into_iter().rev().collect();
+
+    // `Take<Successors<_, _>>` isn't a `DoubleEndedIterator`
+    let powers = std::iter::successors(Some(1), |x| Some(x * 2)).take(5);
+    let _: Vec<_> = powers.collect::<Vec<_>>().into_iter().rev().collect();
+    // Collecting into a set removes duplicates
+    let _ = sample.iter().collect::<HashSet<_>>().into_iter().count();
+}
@@ -322,3 +322,42 @@ fn test_while_let_cond_false_case() {
         w.len();
     }
 }
+
+mod collect_then_iterate {
+    fn lint_for_loop(sample: &[i32]) {
+        let v: Vec<_> = sample.iter().map(|x| x * 2).collect();
+        //~^ ERROR: avoid using `collect()` when not needed
+        for x in v {
+            println!("{x}");
+        }
+    }
+
+    fn lint_for_loop_ref(sample: &[i32]) {
+        let v: Vec<_> = sample.iter().map(|x| x * 2).collect();
+        //~^ ERROR: avoid using `collect()` when not needed
+        for x in &v {
+            println!("{x}");
+        }
+        println!("done");
+    }
+
+    fn lint_iter(sample: &[i32]) -> i32 {
+        let v: Vec<_> = sample.iter().map(|x| x * 2).collect();
+        //~^ ERROR: avoid using `collect()` when not needed
+        v.iter().sum()
+    }
+
+    fn dont_lint_rev() -> Vec<i32> {
+        // `Take<Successors<_, _>>` isn't a `DoubleEndedIterator`
+        let v: Vec<_> = std::iter::successors(Some(1), |x| Some(x * 2)).take(5).collect();
+        v.into_iter().rev().collect()
+    }
+
+    fn dont_lint_mutable_capture(sample: &[i32]) {
+        let mut count = 0;
+        let v: Vec<_> = sample.iter().inspect(|_| count += 1).collect();
+        for x in v {
+            count += x;
+        }
+    }
+}
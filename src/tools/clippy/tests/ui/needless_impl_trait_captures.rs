@@ -0,0 +1,74 @@
+//@revisions: edition2021 edition2024
+//@[edition2021] edition:2021
+//@[edition2024] edition:2024
+
+#![warn(clippy::needless_impl_trait_captures)]
+
+fn make_adder(x: &u32) -> impl Fn(u32) -> u32 + '_ {
+    let x = *x;
+    move |y| x + y
+}
+
+fn owned_iter(v: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    v.to_vec().into_iter()
+}
+
+fn generic<T: Clone>(x: &T) -> impl Fn() -> T + '_ {
+    let x = x.clone();
+    move || x.clone()
+}
+
+fn implicit(x: &u32) -> impl Fn() -> u32 {
+    let x = *x;
+    move || x
+}
+
+struct S<T>(T);
+
+impl<T: Copy> S<T> {
+    fn get(&self) -> impl Fn() -> T {
+        let x = self.0;
+        move || x
+    }
+}
+
+// Borrows from the argument
+fn borrows(x: &u32) -> impl Fn() -> u32 + '_ {
+    move || *x
+}
+
+// Uses the lifetime in the bounds
+fn item_ref(v: &[u8]) -> impl Iterator<Item = &u8> {
+    v.iter()
+}
+
+// Already precise
+fn precise(x: &u32) -> impl Fn() -> u32 + use<> {
+    let x = *x;
+    move || x
+}
+
+// Nothing captured
+fn static_str() -> impl AsRef<str> {
+    "a"
+}
+
+// `impl Trait` arguments can't be named in `use<..>`
+fn apit(x: &u32, _: impl Copy) -> impl Fn() -> u32 {
+    let x = *x;
+    move || x
+}
+
+trait Tr {
+    fn f(&self) -> impl Fn() -> u32;
+}
+
+impl Tr for u32 {
+    // Signature is dictated by the trait
+    fn f(&self) -> impl Fn() -> u32 {
+        let x = *self;
+        move || x
+    }
+}
+
+fn main() {}
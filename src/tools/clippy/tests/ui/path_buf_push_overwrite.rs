@@ -5,4 +5,5 @@
 fn main() {
     let mut x = PathBuf::from("/foo");
     x.push("/bar");
+    x.push("C:\\bar");
 }
@@ -332,3 +332,22 @@ fn main() {
     let c = C;
     c.to_string();
 }
+
+struct M;
+
+#[allow(clippy::explicit_auto_deref)]
+impl fmt::Display for M {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", (*self).to_string())
+        //~^ ERROR: using `self.to_string` in `fmt::Display` implementation will cause inf
+    }
+}
+
+struct M2;
+
+impl fmt::Display for M2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&ToString::to_string(self))
+        //~^ ERROR: using `self.to_string` in `fmt::Display` implementation will cause inf
+    }
+}
@@ -0,0 +1,69 @@
+#![warn(clippy::unbounded_channel_in_loop)]
+
+use std::sync::mpsc;
+use std::thread;
+
+fn producer_in_loop() {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || rx.into_iter().for_each(drop));
+    for i in 0..1000 {
+        tx.send(i).unwrap();
+    }
+}
+
+fn producer_in_thread() {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut i = 0;
+        loop {
+            tx.send(i).unwrap();
+            i += 1;
+        }
+    });
+    for value in rx {
+        println!("{value}");
+    }
+}
+
+fn received_in_loop() {
+    let (tx, rx) = mpsc::channel();
+    for i in 0..1000 {
+        tx.send(i).unwrap();
+        println!("{}", rx.recv().unwrap());
+    }
+}
+
+fn received_in_thread() {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for i in 0..1000 {
+            tx.send(i).unwrap();
+        }
+        rx.try_iter().for_each(drop);
+    });
+}
+
+fn not_in_loop() {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || rx.into_iter().for_each(drop));
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+}
+
+fn bounded() {
+    let (tx, rx) = mpsc::sync_channel(16);
+    thread::spawn(move || rx.into_iter().for_each(drop));
+    for i in 0..1000 {
+        tx.send(i).unwrap();
+    }
+}
+
+fn recreated_in_loop() {
+    for i in 0..1000 {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || rx.into_iter().for_each(drop));
+        tx.send(i).unwrap();
+    }
+}
+
+fn main() {}
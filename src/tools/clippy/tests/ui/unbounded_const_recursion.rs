@@ -0,0 +1,48 @@
+#![warn(clippy::unbounded_const_recursion)]
+
+const fn count(n: u32, acc: u32) -> u32 {
+    if n == 0 { acc } else { count(n, acc) }
+}
+
+const fn restart(n: u32) -> u32 {
+    if n > 10 { n } else { restart(0) }
+}
+
+const fn swapped(a: u32, b: u32) -> u32 {
+    if a == b { a } else { swapped(b, a) }
+}
+
+const fn by_ref(x: &u32) -> u32 {
+    if *x == 0 { 0 } else { by_ref(x) + by_ref(&*x) }
+}
+
+struct S(u32);
+
+impl S {
+    const fn method(&self) -> u32 {
+        if self.0 == 0 { 0 } else { self.method() }
+    }
+}
+
+// Arguments move towards the base case
+const fn fact(n: u64) -> u64 {
+    if n == 0 { 1 } else { n * fact(n - 1) }
+}
+
+const fn count_up(i: usize, n: usize) -> usize {
+    if i < n { count_up(i + 1, n) } else { i }
+}
+
+const fn sum(s: &[u32]) -> u32 {
+    match s {
+        [] => 0,
+        [first, rest @ ..] => *first + sum(rest),
+    }
+}
+
+// Not a `const fn`
+fn not_const(n: u32) -> u32 {
+    if n == 0 { 0 } else { not_const(n) }
+}
+
+fn main() {}
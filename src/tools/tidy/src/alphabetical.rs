@@ -9,17 +9,21 @@
 //! // tidy-alphabetical-end
 //! ```
 //!
-//! The following lines are ignored:
-//! - Empty lines
-//! - Lines that are indented with more or less spaces than the first line
-//! - Lines starting with `//`, `#` (except those starting with `#!`), `)`, `]`, `}` if the comment
-//!   has the same indentation as the first line
-//! - Lines starting with a closing delimiter (`)`, `[`, `}`) are ignored.
+//! The lines in between are grouped into entries, which are compared by their lines joined
+//! together:
+//! - An entry starts at a line with the same indentation as the first line of the section.
+//! - Lines that are indented with more or less spaces than the first line, or that start with a
+//!   closing delimiter (`)`, `]`, `}`), continue the previous entry. E.g. `foo(\n    a,\n    b\n)`
+//!   is treated like `foo(a, b)`.
+//! - Lines starting with `//` or `#` (except those starting with `#!`), i.e. comments and
+//!   attributes, belong to the entry following them and aren't compared.
+//! - Empty lines are ignored.
 //!
-//! If a line ends with an opening delimiter, we effectively join the following line to it before
-//! checking it. E.g. `foo(\nbar)` is treated like `foo(bar)`.
+//! Running tidy with `--bless` sorts the sections. Comments and attributes are moved together with
+//! their entry, while empty lines stay where they are.
 
 use std::fmt::Display;
+use std::fs;
 use std::path::Path;
 
 use crate::walk::{filter_dirs, walk};
@@ -38,21 +42,98 @@ fn is_close_bracket(c: char) -> bool {
 const START_MARKER: &str = "tidy-alphabetical-start";
 const END_MARKER: &str = "tidy-alphabetical-end";
 
+fn is_comment_or_attribute(trimmed_line: &str) -> bool {
+    trimmed_line.starts_with("//")
+        || (trimmed_line.starts_with('#') && !trimmed_line.starts_with("#!"))
+}
+
+/// An item of a section, possibly spanning multiple lines.
+struct Entry<'a> {
+    /// Index of the line the entry starts at, after its comments and attributes.
+    idx: usize,
+    /// All lines of the entry, including its comments and attributes.
+    lines: Vec<&'a str>,
+    /// The compared lines of the entry, joined together.
+    key: String,
+}
+
+/// The entries of a section, and the empty lines in between them.
+#[derive(Default)]
+struct Section<'a> {
+    entries: Vec<Entry<'a>>,
+    /// The empty lines preceding each entry.
+    gaps: Vec<Vec<&'a str>>,
+    /// The lines following the last entry.
+    trailing: Vec<&'a str>,
+}
+
+fn parse_section<'a>(lines: &[(usize, &'a str)]) -> Section<'a> {
+    let indent = lines
+        .iter()
+        .find(|(_, line)| !line.trim().is_empty())
+        .map_or(0, |(_, line)| indentation(line));
+    let mut section = Section::default();
+    // Empty lines, comments and attributes which aren't part of an entry yet.
+    let mut gap = Vec::new();
+    let mut prefix = Vec::new();
+
+    for &(idx, line) in lines {
+        let trimmed_line = line.trim_start_matches(' ');
+        if trimmed_line.trim().is_empty() {
+            if prefix.is_empty() {
+                gap.push(line);
+            } else {
+                prefix.push(line);
+            }
+        } else if indentation(line) != indent || trimmed_line.starts_with(is_close_bracket) {
+            match section.entries.last_mut() {
+                // A split line, e.g. the arguments of a function call
+                Some(entry) if prefix.is_empty() => {
+                    entry.lines.append(&mut gap);
+                    entry.lines.push(line);
+                    // Join `foo(\n    a,\n    b,\n)` like `foo(a, b,)`
+                    if entry.key.ends_with(',') && !trimmed_line.starts_with(is_close_bracket) {
+                        entry.key.push(' ');
+                    }
+                    entry.key.push_str(trimmed_line);
+                }
+                // A split comment or attribute
+                _ => {
+                    prefix.append(&mut gap);
+                    prefix.push(line);
+                }
+            }
+        } else if is_comment_or_attribute(trimmed_line) {
+            prefix.push(line);
+        } else {
+            prefix.push(line);
+            section.gaps.push(std::mem::take(&mut gap));
+            section.entries.push(Entry {
+                idx,
+                lines: std::mem::take(&mut prefix),
+                key: trimmed_line.to_string(),
+            });
+        }
+    }
+
+    section.trailing = gap;
+    section.trailing.append(&mut prefix);
+    section
+}
+
+/// Checks the section following a start marker. If `sorted` is given, the lines are appended to it
+/// in sorted order instead of reporting the entries which aren't sorted.
 fn check_section<'a>(
     file: impl Display,
     lines: impl Iterator<Item = (usize, &'a str)>,
     err: &mut dyn FnMut(&str) -> std::io::Result<()>,
     bad: &mut bool,
+    sorted: Option<&mut Vec<&'a str>>,
 ) {
-    let mut prev_line = String::new();
-    let mut first_indent = None;
-    let mut in_split_line = None;
+    let mut section_lines = Vec::new();
+    let mut end_line = None;
 
     for (idx, line) in lines {
-        if line.is_empty() {
-            continue;
-        }
-
         if line.contains(START_MARKER) {
             tidy_error_ext!(
                 err,
@@ -60,55 +141,54 @@ fn check_section<'a>(
                 "{file}:{} found `{START_MARKER}` expecting `{END_MARKER}`",
                 idx + 1
             );
+            if let Some(sorted) = sorted {
+                sorted.extend(section_lines.iter().map(|&(_, line)| line));
+                sorted.push(line);
+            }
             return;
         }
 
         if line.contains(END_MARKER) {
-            return;
+            end_line = Some(line);
+            break;
         }
 
-        let indent = first_indent.unwrap_or_else(|| {
-            let indent = indentation(line);
-            first_indent = Some(indent);
-            indent
-        });
-
-        let line = if let Some(prev_split_line) = in_split_line {
-            // Join the split lines.
-            in_split_line = None;
-            format!("{prev_split_line}{}", line.trim_start())
-        } else {
-            line.to_string()
-        };
-
-        if indentation(&line) != indent {
-            continue;
-        }
-
-        let trimmed_line = line.trim_start_matches(' ');
+        section_lines.push((idx, line));
+    }
 
-        if trimmed_line.starts_with("//")
-            || (trimmed_line.starts_with('#') && !trimmed_line.starts_with("#!"))
-            || trimmed_line.starts_with(is_close_bracket)
-        {
-            continue;
+    let Some(end_line) = end_line else {
+        tidy_error_ext!(err, bad, "{file}: reached end of file expecting `{END_MARKER}`");
+        if let Some(sorted) = sorted {
+            sorted.extend(section_lines.iter().map(|&(_, line)| line));
         }
-
-        if line.trim_end().ends_with('(') {
-            in_split_line = Some(line);
-            continue;
+        return;
+    };
+
+    let section = parse_section(&section_lines);
+    if let Some(sorted) = sorted {
+        let mut entries: Vec<_> = section.entries.iter().collect();
+        entries.sort_by_cached_key(|entry| entry.key.to_lowercase());
+        for (gap, entry) in section.gaps.iter().zip(entries) {
+            sorted.extend(gap);
+            sorted.extend(&entry.lines);
         }
-
-        let prev_line_trimmed_lowercase = prev_line.trim_start_matches(' ').to_lowercase();
-
-        if trimmed_line.to_lowercase() < prev_line_trimmed_lowercase {
-            tidy_error_ext!(err, bad, "{file}:{}: line not in alphabetical order", idx + 1);
+        sorted.extend(section.trailing);
+        sorted.push(end_line);
+    } else {
+        let mut prev_key = String::new();
+        for entry in &section.entries {
+            let key = entry.key.to_lowercase();
+            if key < prev_key {
+                tidy_error_ext!(
+                    err,
+                    bad,
+                    "{file}:{}: line not in alphabetical order",
+                    entry.idx + 1
+                );
+            }
+            prev_key = key;
         }
-
-        prev_line = line;
     }
-
-    tidy_error_ext!(err, bad, "{file}: reached end of file expecting `{END_MARKER}`")
 }
 
 fn check_lines<'a>(
@@ -116,8 +196,13 @@ fn check_lines<'a>(
     mut lines: impl Iterator<Item = (usize, &'a str)>,
     err: &mut dyn FnMut(&str) -> std::io::Result<()>,
     bad: &mut bool,
+    mut sorted: Option<&mut Vec<&'a str>>,
 ) {
     while let Some((idx, line)) = lines.next() {
+        if let Some(sorted) = sorted.as_deref_mut() {
+            sorted.push(line);
+        }
+
         if line.contains(END_MARKER) {
             tidy_error_ext!(
                 err,
@@ -128,18 +213,30 @@ fn check_lines<'a>(
         }
 
         if line.contains(START_MARKER) {
-            check_section(file, &mut lines, err, bad);
+            check_section(file, &mut lines, err, bad, sorted.as_deref_mut());
         }
     }
 }
 
-pub fn check(path: &Path, bad: &mut bool) {
+pub fn check(path: &Path, bless: bool, bad: &mut bool) {
     let skip =
         |path: &_, _is_dir| filter_dirs(path) || path.ends_with("tidy/src/alphabetical/tests.rs");
 
     walk(path, skip, &mut |entry, contents| {
         let file = &entry.path().display();
         let lines = contents.lines().enumerate();
-        check_lines(file, lines, &mut crate::tidy_error, bad)
+        if bless {
+            let mut sorted = Vec::new();
+            check_lines(file, lines, &mut crate::tidy_error, bad, Some(&mut sorted));
+            let mut sorted_contents = sorted.join("\n");
+            if contents.ends_with('\n') {
+                sorted_contents.push('\n');
+            }
+            if sorted_contents != contents {
+                fs::write(entry.path(), sorted_contents).unwrap();
+            }
+        } else {
+            check_lines(file, lines, &mut crate::tidy_error, bad, None)
+        }
     });
 }
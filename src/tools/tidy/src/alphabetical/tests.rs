@@ -10,7 +10,7 @@ fn test(lines: &str, name: &str, expected_msg: &str, expected_bad: bool) {
         write!(&mut actual_msg, "{args}")?;
         Ok(())
     };
-    check_lines(&name, lines.lines().enumerate(), &mut err, &mut actual_bad);
+    check_lines(&name, lines.lines().enumerate(), &mut err, &mut actual_bad, None);
     assert_eq!(expected_msg, from_utf8(&actual_msg).unwrap());
     assert_eq!(expected_bad, actual_bad);
 }
@@ -23,6 +23,21 @@ fn bad(lines: &str, expected_msg: &str) {
     test(lines, "bad", expected_msg, true);
 }
 
+fn bless(lines: &str, expected_lines: &str) {
+    let mut actual_msg = Vec::new();
+    let mut actual_bad = false;
+    let mut err = |args: &_| {
+        write!(&mut actual_msg, "{args}")?;
+        Ok(())
+    };
+    let mut sorted = Vec::new();
+    check_lines(&"bless", lines.lines().enumerate(), &mut err, &mut actual_bad, Some(&mut sorted));
+    assert_eq!("", from_utf8(&actual_msg).unwrap());
+    assert!(!actual_bad);
+    assert_eq!(expected_lines.lines().collect::<Vec<_>>(), sorted);
+    good(expected_lines);
+}
+
 #[test]
 fn test_no_markers() {
     let lines = "\
@@ -144,7 +159,7 @@ fn test_split_bad() {
         )
         && tidy-alphabetical-end
     ";
-    bad(lines, "bad:7: line not in alphabetical order");
+    bad(lines, "bad:6: line not in alphabetical order");
 }
 
 #[test]
@@ -187,3 +202,84 @@ fn test_double_end() {
     ";
     bad(lines, "bad:5 found `tidy-alphabetical-end` expecting `tidy-alphabetical-start`");
 }
+
+#[test]
+fn test_multi_line_good() {
+    let lines = "\
+        // tidy-alphabetical-start
+        #[cfg(test)]
+        fn abc() {}
+        /// Doc comments are ok
+        #[cfg(
+            not(test)
+        )]
+        fn def(
+            x: u32,
+        ) {
+        }
+        fn xyz() {}
+        // tidy-alphabetical-end
+    ";
+    good(lines);
+}
+
+#[test]
+fn test_multi_line_bad() {
+    let lines = "\
+        // tidy-alphabetical-start
+        foo(
+            xyz,
+        );
+        #[attribute]
+        foo(
+            abc,
+        );
+        // tidy-alphabetical-end
+    ";
+    bad(lines, "bad:6: line not in alphabetical order");
+}
+
+#[test]
+fn test_bless() {
+    let lines = "\
+        // tidy-alphabetical-start
+        xyz
+        // Comments are moved with the line after them
+        def(
+            split,
+        )
+
+        #[attribute]
+        abc
+        // tidy-alphabetical-end
+    ";
+    let expected_lines = "\
+        // tidy-alphabetical-start
+        #[attribute]
+        abc
+        // Comments are moved with the line after them
+        def(
+            split,
+        )
+
+        xyz
+        // tidy-alphabetical-end
+    ";
+    bless(lines, expected_lines);
+}
+
+#[test]
+fn test_bless_unchanged() {
+    let lines = "\
+        aaa
+        // tidy-alphabetical-start
+        abc
+        def
+        // tidy-alphabetical-end
+        zzz
+        // tidy-alphabetical-start
+        foo
+        // tidy-alphabetical-end
+    ";
+    bless(lines, lines);
+}
@@ -0,0 +1,178 @@
+//! Per-directory configuration of tidy, read from `.tidy.toml` files.
+//!
+//! A `.tidy.toml` file applies to all files in its directory and in its subdirectories. The
+//! configurations of the parent directories are merged, with the keys of the innermost file taking
+//! precedence:
+//!
+//! ```toml
+//! # Maximum number of columns of a line, instead of 100.
+//! line-length = 120
+//! # Maximum number of lines of a file, instead of 3000.
+//! file-length = 5000
+//! # The files are generated, the style checks are skipped.
+//! generated = true
+//! # Checks which are ignored, like with an `ignore-tidy-*` directive in each file.
+//! ignore = ["copyright", "linelength"]
+//! # Checks which are ignored by a parent directory, but not by this one.
+//! check = ["linelength"]
+//! ```
+//!
+//! Only this subset of TOML is supported, as tidy doesn't depend on a TOML parser. Tidy checks that
+//! all `.tidy.toml` files are valid.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use crate::walk::{filter_dirs, walk_dir};
+
+#[cfg(test)]
+mod tests;
+
+pub const FILE_NAME: &str = ".tidy.toml";
+
+/// The checks of `style` which can be ignored, i.e. the `CHECK` of `ignore-tidy-CHECK`.
+pub const CHECKS: &[&str] = &[
+    // tidy-alphabetical-start
+    "copyright",
+    "cr",
+    "dbg",
+    "end-whitespace",
+    "filelength",
+    "leading-newlines",
+    "linelength",
+    "odd-backticks",
+    "tab",
+    "trailing-newlines",
+    "undocumented-unsafe",
+    // tidy-alphabetical-end
+];
+
+/// The merged configuration of a directory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    pub line_length: Option<usize>,
+    pub file_length: Option<usize>,
+    pub generated: bool,
+    ignore: BTreeSet<String>,
+}
+
+impl Config {
+    /// Returns whether the `check` (as in `ignore-tidy-CHECK`) is ignored.
+    pub fn ignores(&self, check: &str) -> bool {
+        self.ignore.contains(check)
+    }
+
+    /// Applies the contents of a `.tidy.toml` file on top of the configuration of its parent
+    /// directory. Returns the line number and a description of the first error.
+    fn merge(&mut self, contents: &str) -> Result<(), (usize, String)> {
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |msg: String| (i + 1, msg);
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(error(format!("expected `key = value`, found `{line}`")));
+            };
+            let value = strip_comment(value.trim());
+            match key.trim() {
+                "line-length" => self.line_length = Some(parse_integer(value).map_err(error)?),
+                "file-length" => self.file_length = Some(parse_integer(value).map_err(error)?),
+                "generated" => self.generated = parse_bool(value).map_err(error)?,
+                "ignore" => {
+                    let checks = parse_checks(value).map_err(error)?;
+                    self.ignore.extend(checks.into_iter().map(str::to_owned));
+                }
+                "check" => {
+                    for check in parse_checks(value).map_err(error)? {
+                        self.ignore.remove(check);
+                    }
+                }
+                key => return Err(error(format!("unknown key `{key}`"))),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes a trailing comment after a value. Strings with a `#` aren't valid values anyway.
+fn strip_comment(value: &str) -> &str {
+    value.split_once('#').map_or(value, |(value, _)| value).trim_end()
+}
+
+fn parse_integer(value: &str) -> Result<usize, String> {
+    value.parse().map_err(|_| format!("expected an integer, found `{value}`"))
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(format!("expected `true` or `false`, found `{value}`")),
+    }
+}
+
+/// Parses an array of check names, e.g. `["copyright", "linelength"]`.
+fn parse_checks(value: &str) -> Result<Vec<&str>, String> {
+    let Some(items) = value.strip_prefix('[').and_then(|value| value.strip_suffix(']')) else {
+        return Err(format!("expected an array of strings, found `{value}`"));
+    };
+    let mut checks = Vec::new();
+    for item in items.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        let Some(check) = item.strip_prefix('"').and_then(|item| item.strip_suffix('"')) else {
+            return Err(format!("expected a string, found `{item}`"));
+        };
+        if !CHECKS.contains(&check) {
+            return Err(format!("unknown check `{check}`, expected one of {}", CHECKS.join(", ")));
+        }
+        checks.push(check);
+    }
+    Ok(checks)
+}
+
+static CACHE: LazyLock<Mutex<HashMap<PathBuf, Arc<Config>>>> = LazyLock::new(Default::default);
+
+/// Returns the configuration of the directory `dir`.
+///
+/// Configuration files which are invalid are skipped here, they are reported by `check`.
+pub fn for_dir(dir: &Path) -> Arc<Config> {
+    if let Some(config) = CACHE.lock().unwrap().get(dir) {
+        return Arc::clone(config);
+    }
+
+    // The checkout of the repository is the outermost directory which is configured.
+    let mut config = match dir.parent() {
+        Some(parent) if !dir.join("x.py").exists() => Config::clone(&for_dir(parent)),
+        _ => Config::default(),
+    };
+    if let Ok(contents) = fs::read_to_string(dir.join(FILE_NAME)) {
+        let parent_config = config.clone();
+        if config.merge(&contents).is_err() {
+            config = parent_config;
+        }
+    }
+
+    let config = Arc::new(config);
+    CACHE.lock().unwrap().insert(dir.to_owned(), Arc::clone(&config));
+    config
+}
+
+/// Returns the configuration which applies to the file at `path`.
+pub fn for_file(path: &Path) -> Arc<Config> {
+    for_dir(path.parent().unwrap_or(path))
+}
+
+/// Checks that all `.tidy.toml` files are valid.
+pub fn check(root: &Path, bad: &mut bool) {
+    walk_dir(root, filter_dirs, &mut |entry| {
+        let path = entry.path().join(FILE_NAME);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return;
+        };
+        if let Err((line, msg)) = Config::default().merge(&contents) {
+            tidy_error!(bad, "{}:{line}: {msg}", path.display());
+        }
+    });
+}
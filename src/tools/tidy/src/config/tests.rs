@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn test_merge() {
+    let mut config = Config::default();
+    let contents = "# comment\n\nline-length = 120 # columns\nignore = [\"tab\", \"cr\"]\n";
+    config.merge(contents).unwrap();
+    assert_eq!(config.line_length, Some(120));
+    assert_eq!(config.file_length, None);
+    assert!(config.ignores("tab") && config.ignores("cr") && !config.ignores("copyright"));
+
+    config.merge("line-length = 80\nfile-length = 5000\ncheck = [\"tab\"]\nignore = []\n").unwrap();
+    assert_eq!(config.line_length, Some(80));
+    assert_eq!(config.file_length, Some(5000));
+    assert!(!config.ignores("tab") && config.ignores("cr"));
+}
+
+#[test]
+fn test_merge_errors() {
+    let error = |contents| Config::default().merge(contents).unwrap_err();
+    assert_eq!(error("\nlength"), (2, "expected `key = value`, found `length`".to_owned()));
+    assert_eq!(error("line-length = long"), (1, "expected an integer, found `long`".to_owned()));
+    assert_eq!(error("generated = 1"), (1, "expected `true` or `false`, found `1`".to_owned()));
+    assert_eq!(error("columns = 1"), (1, "unknown key `columns`".to_owned()));
+    assert_eq!(error("ignore = 1"), (1, "expected an array of strings, found `1`".to_owned()));
+    assert_eq!(error("ignore = [tab]"), (1, "expected a string, found `tab`".to_owned()));
+    assert!(error("ignore = [\"tabs\"]").1.starts_with("unknown check `tabs`, expected one of "));
+}
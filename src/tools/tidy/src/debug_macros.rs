@@ -0,0 +1,114 @@
+//! Checks for debugging code which was left in the (non-test) sources of the compiler and the
+//! standard library:
+//!
+//! - `todo!()` without a message explaining what's missing
+//! - `unimplemented!` with a message mentioning debugging
+//! - `eprintln!` and `eprint!` printing only `Debug` formatted values, e.g. `eprintln!("{x:?}")`,
+//!   or starting with a marker like `DEBUG` or `here`
+//!
+//! Intentional uses can be allowed with a `// tidy-allow: debug` comment on the same line or on
+//! the line before. `dbg!` is checked by `style` in the whole repository, it accepts the comment on
+//! the same line.
+
+use std::path::Path;
+
+use crate::walk::{filter_dirs, walk};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const ALLOW_DIRECTIVE: &str = "tidy-allow: debug";
+
+fn is_test(path: &Path) -> bool {
+    path.components().any(|c| c.as_os_str() == "tests" || c.as_os_str() == "benches")
+        || path.file_stem().map_or(false, |stem| stem == "tests" || stem == "benches")
+}
+
+/// Checks if an `eprintln!` format string looks like debugging output.
+fn is_debug_format_string(format: &str) -> bool {
+    if static_regex!(r"(?i)^\s*(?:debug|xxx|here\b|>>>)").is_match(format) {
+        return true;
+    }
+    if !format.contains("?}") {
+        return false;
+    }
+    // Only a label for the values, like `x = {x:?}` or `#{i} {node:?}`.
+    let text = static_regex!(r"\{[^{}]*\}").replace_all(format, "");
+    static_regex!(r"^[\s\w#.\[\]:=,()-]*$").is_match(&text)
+        && static_regex!(r"[A-Za-z]{2,}").find_iter(&text).count() <= 1
+}
+
+/// Returns the problem with the debugging macro in `line`, if any.
+fn debug_macro(line: &str) -> Option<&'static str> {
+    let macro_call = static_regex!(
+        r#"(?x)
+        \b(todo|unimplemented|eprintln|eprint)!\s*\(\s*
+        # the end of the call without arguments, or the first argument if it's a string literal
+        (\)|"(?:[^"\\]|\\.)*")?
+        "#
+    );
+    for captures in macro_call.captures_iter(line) {
+        // Macro calls in string literals, e.g. in suggestions.
+        if line[..captures.get(0).unwrap().start()].matches('"').count() % 2 == 1 {
+            continue;
+        }
+        let argument = captures.get(2).map_or("", |argument| argument.as_str());
+        let format = argument.strip_prefix('"').and_then(|argument| argument.strip_suffix('"'));
+        match &captures[1] {
+            "todo" if argument == ")" => {
+                return Some("`todo!()` should explain what's missing");
+            }
+            "unimplemented" if format.is_some_and(|f| f.to_lowercase().contains("debug")) => {
+                return Some("`unimplemented!` used for debugging");
+            }
+            "eprintln" | "eprint" if format.is_some_and(is_debug_format_string) => {
+                return Some("debugging output");
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub fn check(path: &Path, bad: &mut bool) {
+    walk(
+        path,
+        |path, is_dir| {
+            filter_dirs(path)
+                || is_test(path)
+                || (!is_dir && path.extension().map_or(true, |ext| ext != "rs"))
+        },
+        &mut |entry, contents| {
+            let file = entry.path();
+            let lines: Vec<&str> = contents.lines().collect();
+            for (i, line) in lines.iter().enumerate() {
+                let trimmed = line.trim_start();
+                // The unit tests of a module are usually at its end.
+                if trimmed.starts_with("#[cfg(test)]")
+                    && lines.get(i + 1).is_some_and(|next| {
+                        static_regex!(r"^\s*(?:pub(?:\([^)]*\))? )?mod \w+ \{").is_match(next)
+                    })
+                {
+                    break;
+                }
+                if trimmed.starts_with("//") {
+                    continue;
+                }
+                let Some(problem) = debug_macro(line) else {
+                    continue;
+                };
+                let allowed = line.contains(ALLOW_DIRECTIVE)
+                    || i.checked_sub(1).is_some_and(|prev| lines[prev].contains(ALLOW_DIRECTIVE));
+                if !allowed {
+                    tidy_error!(
+                        bad,
+                        "{}:{}: {problem}, remove it or add a `// {ALLOW_DIRECTIVE}` comment if \
+                        it's intentional",
+                        file.display(),
+                        i + 1
+                    );
+                }
+            }
+        },
+    );
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn test_todo() {
+    assert!(debug_macro("    ty::InferConst::Fresh(_) => todo!(),").is_some());
+    assert!(debug_macro("    todo!( )").is_some());
+    assert!(debug_macro(r#"    todo!("lower async closures")"#).is_none());
+    assert!(debug_macro(r#"    #[suggestion(code = " => todo!(),")]"#).is_none());
+}
+
+#[test]
+fn test_unimplemented() {
+    assert!(debug_macro(r#"unimplemented!("debugging {x:?}")"#).is_some());
+    assert!(debug_macro(r#"unimplemented!("unsupported target")"#).is_none());
+    assert!(debug_macro("unimplemented!()").is_none());
+}
+
+#[test]
+fn test_eprintln() {
+    assert!(debug_macro(r#"eprintln!("{:?}", ty);"#).is_some());
+    assert!(debug_macro(r#"eprintln!("ty = {ty:#?}");"#).is_some());
+    assert!(debug_macro(r#"eprint!("DEBUG: {}", x);"#).is_some());
+    assert!(debug_macro(r#"eprintln!("here");"#).is_some());
+    assert!(debug_macro(r#"eprintln!("error: io error when listing tests: {e:?}");"#).is_none());
+    assert!(debug_macro(r#"eprintln!("{}", msg);"#).is_none());
+    assert!(debug_macro("eprintln!();").is_none());
+}
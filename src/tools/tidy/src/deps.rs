@@ -1,12 +1,17 @@
-//! Checks the licenses of third-party dependencies.
+//! Checks the licenses of third-party dependencies, and how the crates of the workspaces
+//! declare their dependencies.
 
 use std::collections::HashSet;
-use std::fs::read_dir;
+use std::fs::{self, read_dir};
 use std::path::Path;
 
 use build_helper::ci::CiEnv;
+use cargo_metadata::semver::Version;
 use cargo_metadata::{Metadata, Package, PackageId};
 
+#[cfg(test)]
+mod tests;
+
 /// These are licenses that are allowed for all crates, including the runtime,
 /// rustc, tools, etc.
 #[rustfmt::skip]
@@ -562,11 +567,34 @@
     // tidy-alphabetical-end
 ];
 
+/// Crates of which each workspace may only depend on a single semver compatible version. Multiple
+/// versions of them increase build times, and make mixing their types fail with confusing errors.
+const NO_DUPLICATE_VERSIONS: &[&str] = &[
+    // tidy-alphabetical-start
+    "cc",
+    "indexmap",
+    "itertools",
+    "libc",
+    "memchr",
+    "proc-macro2",
+    "quote",
+    "regex",
+    "serde",
+    "serde_json",
+    // tidy-alphabetical-end
+];
+
+/// Keys of a dependency which are inherited from `[workspace.dependencies]` and may not be
+/// repeated by crates using `workspace = true`.
+const INHERITED_DEPENDENCY_KEYS: &[&str] =
+    &["branch", "git", "package", "path", "registry", "rev", "tag", "version"];
+
 /// Dependency checks.
 ///
 /// `root` is path to the directory with the root `Cargo.toml` (for the workspace). `cargo` is path
-/// to the cargo executable.
-pub fn check(root: &Path, cargo: &Path, bad: &mut bool) {
+/// to the cargo executable. With `bless`, dependencies which should be inherited from the
+/// workspace are rewritten to use `workspace = true`.
+pub fn check(root: &Path, cargo: &Path, bless: bool, bad: &mut bool) {
     let mut checked_runtime_licenses = false;
 
     for &(workspace, exceptions, permitted_deps, submodules) in WORKSPACES {
@@ -595,6 +623,13 @@ pub fn check(root: &Path, cargo: &Path, bad: &mut bool) {
             check_runtime_license_exceptions(&metadata, bad);
             checked_runtime_licenses = true;
         }
+
+        // The manifests of subtrees and submodules are maintained upstream.
+        if !crate::walk::filter_dirs(&root.join(workspace)) {
+            check_workspace_inheritance(&metadata, bless, bad);
+            check_duplicate_versions(&metadata, workspace, bad);
+            check_path_dependencies(root, &metadata, bad);
+        }
     }
 
     // Sanity check to ensure we don't accidentally remove the workspace containing the runtime
@@ -613,6 +648,173 @@ pub fn has_missing_submodule(root: &Path, submodules: &[&str]) -> bool {
         })
 }
 
+/// Returns the packages which are members of the workspace, except those in subtrees.
+fn workspace_members(metadata: &Metadata) -> impl Iterator<Item = &Package> {
+    metadata.packages.iter().filter(|pkg| {
+        metadata.workspace_members.contains(&pkg.id)
+            && !pkg.manifest_path.ancestors().any(|dir| crate::walk::filter_dirs(dir.as_std_path()))
+    })
+}
+
+/// Returns the names of the dependencies declared in the `[workspace.dependencies]` table of a
+/// manifest.
+fn workspace_dependencies(manifest: &str) -> Vec<&str> {
+    let mut in_table = false;
+    let mut dependencies = Vec::new();
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_table = line == "[workspace.dependencies]";
+        } else if in_table && !line.starts_with('#') {
+            if let Some((key, _)) = line.split_once('=') {
+                // `name.version = "1.0"` declares `name`
+                dependencies.push(key.split('.').next().unwrap().trim());
+            }
+        }
+    }
+    dependencies
+}
+
+/// Returns the replacement of a dependency `line`, if it declares a dependency which is part of
+/// `workspace_deps` without inheriting it.
+fn inherit_workspace_dependency(line: &str, workspace_deps: &[&str]) -> Option<String> {
+    let captures = static_regex!(r"^(\s*)([\w-]+)\s*=\s*(.*?)\s*$").captures(line)?;
+    let (indent, name, value) = (&captures[1], &captures[2], &captures[3]);
+    if !workspace_deps.contains(&name) {
+        return None;
+    }
+    if value.starts_with('"') {
+        // `name = "1.0"`
+        return Some(format!("{indent}{name}.workspace = true"));
+    }
+
+    // `name = { version = "1.0", features = ["foo"] }`, only inline tables on a single line are
+    // rewritten.
+    let table = value.strip_prefix('{')?.strip_suffix('}')?;
+    let entry = static_regex!(r#"([\w-]+)\s*=\s*("(?:[^"\\]|\\.)*"|\[[^\]]*\]|\w+)"#);
+    let mut entries = vec!["workspace = true".to_owned()];
+    for captures in entry.captures_iter(table) {
+        let key = &captures[1];
+        if key == "workspace" {
+            return None;
+        }
+        if !INHERITED_DEPENDENCY_KEYS.contains(&key) {
+            entries.push(format!("{key} = {}", &captures[2]));
+        }
+    }
+    Some(if entries.len() == 1 {
+        format!("{indent}{name}.workspace = true")
+    } else {
+        format!("{indent}{name} = {{ {} }}", entries.join(", "))
+    })
+}
+
+/// Checks that the crates of a workspace inherit the dependencies which are declared in its
+/// `[workspace.dependencies]` table, instead of declaring their own versions.
+fn check_workspace_inheritance(metadata: &Metadata, bless: bool, bad: &mut bool) {
+    let root_manifest = t!(fs::read_to_string(metadata.workspace_root.join("Cargo.toml")));
+    let workspace_deps = workspace_dependencies(&root_manifest);
+    if workspace_deps.is_empty() {
+        return;
+    }
+
+    for package in workspace_members(metadata) {
+        let path = &package.manifest_path;
+        let manifest = t!(fs::read_to_string(path));
+        let mut lines: Vec<String> = manifest.lines().map(str::to_owned).collect();
+        let mut in_dependencies = false;
+        let mut changed = false;
+        for (i, line) in lines.iter_mut().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                // `[dependencies]`, `[dev-dependencies]`, `[target.'cfg(unix)'.dependencies]`, ...
+                in_dependencies = trimmed.trim_end_matches(']').ends_with("dependencies");
+                continue;
+            }
+            if !in_dependencies {
+                continue;
+            }
+            let Some(replacement) = inherit_workspace_dependency(line, &workspace_deps) else {
+                continue;
+            };
+            if bless {
+                *line = replacement;
+                changed = true;
+            } else {
+                tidy_error!(
+                    bad,
+                    "{path}:{}: dependency is declared in `[workspace.dependencies]`, replace it \
+                    with `{}`\n\
+                    Run `./x.py test tidy --bless` to fix this automatically.",
+                    i + 1,
+                    replacement.trim()
+                );
+            }
+        }
+        if changed {
+            let mut fixed = lines.join("\n");
+            if manifest.ends_with('\n') {
+                fixed.push('\n');
+            }
+            t!(fs::write(path, fixed));
+        }
+    }
+}
+
+fn is_semver_compatible(a: &Version, b: &Version) -> bool {
+    a.major == b.major
+        && (a.major != 0 || (a.minor == b.minor && (a.minor != 0 || a.patch == b.patch)))
+}
+
+/// Checks that a workspace doesn't depend on semver incompatible versions of the crates in
+/// `NO_DUPLICATE_VERSIONS`.
+fn check_duplicate_versions(metadata: &Metadata, workspace: &str, bad: &mut bool) {
+    for &name in NO_DUPLICATE_VERSIONS {
+        let mut versions: Vec<&Version> = metadata
+            .packages
+            .iter()
+            .filter(|pkg| pkg.name == name)
+            .map(|pkg| &pkg.version)
+            .collect();
+        versions.sort();
+        versions.dedup_by(|a, b| is_semver_compatible(a, b));
+        if versions.len() > 1 {
+            let versions: Vec<String> = versions.iter().map(ToString::to_string).collect();
+            tidy_error!(
+                bad,
+                "the `{workspace}` workspace depends on semver incompatible versions of `{name}`: \
+                {}\n\
+                Update the dependencies to use a single version, or remove `{name}` from \
+                NO_DUPLICATE_VERSIONS in {}",
+                versions.join(", "),
+                file!()
+            );
+        }
+    }
+}
+
+/// Checks that path dependencies don't point outside of the repository, which would break the
+/// source tarballs and vendoring.
+fn check_path_dependencies(root: &Path, metadata: &Metadata, bad: &mut bool) {
+    let root = t!(root.canonicalize());
+    for package in workspace_members(metadata) {
+        for dependency in &package.dependencies {
+            let Some(path) = &dependency.path else {
+                continue;
+            };
+            // Optional dependencies which aren't checked out may not exist.
+            let resolved = path.canonicalize().unwrap_or_else(|_| path.clone().into_std_path_buf());
+            if !resolved.starts_with(&root) {
+                tidy_error!(
+                    bad,
+                    "{}: path dependency `{}` points outside of the repository: {path}",
+                    package.manifest_path,
+                    dependency.name
+                );
+            }
+        }
+    }
+}
+
 /// Check that all licenses of runtime dependencies are in the valid list in `LICENSES`.
 ///
 /// Unlike for tools we don't allow exceptions to the `LICENSES` list for the runtime with the sole
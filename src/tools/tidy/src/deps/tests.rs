@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_workspace_dependencies() {
+    let manifest = "[workspace]\nmembers = [\"a\"]\n\n[workspace.dependencies]\n\
+        # comment\nregex = \"1.0\"\nserde = { version = \"1.0\" }\nlibc.version = \"0.2\"\n\n\
+        [profile.release]\ndebug = true\n";
+    assert_eq!(workspace_dependencies(manifest), ["regex", "serde", "libc"]);
+}
+
+#[test]
+fn test_inherit_workspace_dependency() {
+    let deps = ["regex", "serde"];
+    let inherit = |line| inherit_workspace_dependency(line, &deps);
+    assert_eq!(inherit("regex = \"1.0\"").as_deref(), Some("regex.workspace = true"));
+    assert_eq!(inherit("regex = { version = \"1.0\" }").as_deref(), Some("regex.workspace = true"));
+    assert_eq!(
+        inherit("serde = { version = \"1.0\", features = [\"derive\", \"rc\"], optional = true }")
+            .as_deref(),
+        Some("serde = { workspace = true, features = [\"derive\", \"rc\"], optional = true }")
+    );
+    assert_eq!(inherit("regex.workspace = true"), None);
+    assert_eq!(inherit("regex = { workspace = true, optional = true }"), None);
+    assert_eq!(inherit("memchr = \"2.5\""), None);
+}
+
+#[test]
+fn test_is_semver_compatible() {
+    let compatible =
+        |a, b| is_semver_compatible(&Version::parse(a).unwrap(), &Version::parse(b).unwrap());
+    assert!(compatible("1.0.0", "1.2.3"));
+    assert!(!compatible("1.0.0", "2.0.0"));
+    assert!(compatible("0.2.1", "0.2.150"));
+    assert!(!compatible("0.1.0", "0.2.0"));
+    assert!(!compatible("0.0.1", "0.0.2"));
+}
@@ -0,0 +1,168 @@
+//! Checks the style of diagnostic messages, following the diagnostic output style guide of the
+//! rustc-dev-guide. Fluent messages, and the string literals passed to the diagnostic methods,
+//! shouldn't:
+//!
+//! - start with a capital letter, unless the first word is a name or an acronym
+//! - end in a period (checked by `fluent_period` for Fluent messages)
+//! - contain filler like "an error occurred"
+//! - have unclosed backticks
+
+use std::path::Path;
+
+use fluent_syntax::ast::{Entry, Expression, InlineExpression, Pattern, PatternElement};
+
+use crate::fluent_period::find_line;
+use crate::walk::{filter_dirs, walk};
+
+/// Words which may start a message although they are capitalized.
+const NAMES: &[&str] = &["Cargo", "Ferris", "Fluent", "Rust", "Unicode"];
+
+/// Fluent messages and attributes (as `message.attribute`) which don't follow the style guide.
+///
+/// These should probably be reworked eventually.
+const FLUENT_ALLOWLIST: &[&str] = &[
+    // tidy-alphabetical-start
+    "codegen_ssa_atomic_compare_exchange",
+    "codegen_ssa_dlltool_fail_import_library",
+    "codegen_ssa_error_calling_dlltool",
+    "codegen_ssa_error_creating_import_library",
+    "codegen_ssa_error_writing_def_file",
+    "codegen_ssa_missing_memory_ordering",
+    "codegen_ssa_static_library_native_artifacts",
+    "codegen_ssa_static_library_native_artifacts_to_file",
+    "codegen_ssa_unable_to_write_debugger_visualizer",
+    "const_eval_validation_failure_note",
+    "driver_impl_rlink_empty_version_number",
+    "driver_impl_rlink_wrong_file_type",
+    "monomorphize_couldnt_dump_mono_stats",
+    "monomorphize_large_assignments.note",
+    "query_system_increment_compilation.help",
+    "query_system_increment_compilation_note1",
+    "query_system_increment_compilation_note2",
+    // tidy-alphabetical-end
+];
+
+/// Beginnings of diagnostic string literals which don't follow the style guide.
+///
+/// These should probably be reworked eventually.
+const LITERAL_ALLOWLIST: &[&str] = &[
+    // tidy-alphabetical-start
+    "First Pass analysis includes:",
+    "Min Capture analysis includes:",
+    "See <https://github.com/rust-lang/rust/pull/94901>",
+    "`static` and `const` variables can refer to other `const` variables.",
+    "the compiler expectedly panicked. this is a feature.",
+    "to fix this, the value can be extracted to a `const` and then used.",
+    // tidy-alphabetical-end
+];
+
+/// Returns the ways in which `message` violates the style guide.
+fn violations(message: &str, check_period: bool) -> Vec<&'static str> {
+    let mut violations = Vec::new();
+    let first_word = message.split_whitespace().next().unwrap_or_default();
+    let first_word = first_word.trim_end_matches([':', ',']);
+    if static_regex!("^[A-Z][a-z]*$").is_match(first_word) && !NAMES.contains(&first_word) {
+        violations.push("starts with a capital letter");
+    }
+    if check_period && message.ends_with('.') && !message.ends_with("...") {
+        violations.push("ends in a period");
+    }
+    if static_regex!(r"(?i)\berror (has )?occurr?ed\b|\bsomething went wrong\b").is_match(message) {
+        violations.push("contains filler like \"an error occurred\"");
+    }
+    if message.matches('`').count() % 2 != 0 {
+        violations.push("has an unclosed backtick");
+    }
+    violations
+}
+
+/// Returns the text of `pattern`, with `{}` in place of placeables other than string literals.
+fn pattern_text(pattern: &Pattern<&str>) -> String {
+    let mut text = String::new();
+    for element in &pattern.elements {
+        match element {
+            PatternElement::TextElement { value } => text.push_str(value),
+            PatternElement::Placeable {
+                expression: Expression::Inline(InlineExpression::StringLiteral { value }),
+            } => text.push_str(value),
+            PatternElement::Placeable { .. } => text.push_str("{}"),
+        }
+    }
+    text.trim().to_owned()
+}
+
+fn check_fluent(filename: &str, contents: &str, bad: &mut bool) {
+    let (Ok(parse) | Err((parse, _))) = fluent_syntax::parser::parse(contents);
+    for entry in &parse.body {
+        let Entry::Message(m) = entry else {
+            continue;
+        };
+        let attributes = m
+            .attributes
+            .iter()
+            // Teach notes and help usually are longer explanations.
+            .filter(|attr| !attr.id.name.starts_with("teach_"))
+            .map(|attr| (format!("{}.{}", m.id.name, attr.id.name), &attr.value));
+        let value = m.value.iter().map(|value| (m.id.name.to_owned(), value));
+        for (name, pattern) in value.chain(attributes) {
+            if FLUENT_ALLOWLIST.contains(&name.as_str()) {
+                continue;
+            }
+            for violation in violations(&pattern_text(pattern), false) {
+                let ll = match pattern.elements.first() {
+                    Some(PatternElement::TextElement { value }) => find_line(contents, value),
+                    _ => find_line(contents, m.id.name),
+                };
+                tidy_error!(bad, "{filename}:{ll}: message `{name}` {violation}");
+            }
+        }
+    }
+}
+
+fn check_literals(filename: &str, contents: &str, bad: &mut bool) {
+    // Diagnostic methods with the message as their first or second argument, e.g.
+    // `diag.span_label(span, "message")`.
+    let diagnostic_call = static_regex!(
+        r#"(?x)
+        \.(?:
+            span_err|struct_span_err|span_warn|struct_span_warn|span_fatal
+            |err|struct_err|warn|struct_warn|fatal
+            |span_note|note|span_help|help|span_label
+            |span_suggestion\w*|multipart_suggestion\w*|tool_only_span_suggestion
+        )\(
+        (?:[^,;()"]*(?:\([^()]*\))?[^,;()"]*,\s*)?
+        "((?:[^"\\]|\\.)*)"
+        "#
+    );
+    for captures in diagnostic_call.captures_iter(contents) {
+        let message = captures.get(1).unwrap();
+        if LITERAL_ALLOWLIST.iter().any(|allowed| message.as_str().starts_with(allowed)) {
+            continue;
+        }
+        for violation in violations(message.as_str(), true) {
+            let ll = contents[..message.start()].lines().count();
+            let message = message.as_str();
+            tidy_error!(bad, "{filename}:{ll}: diagnostic message {violation}: \"{message}\"");
+        }
+    }
+}
+
+pub fn check(path: &Path, bad: &mut bool) {
+    walk(
+        path,
+        |path, is_dir| {
+            filter_dirs(path)
+                || (!is_dir
+                    && !matches!(path.extension().and_then(|ext| ext.to_str()), Some("ftl" | "rs")))
+                || path.file_name().is_some_and(|name| name == "tests.rs" || name == "tests")
+        },
+        &mut |entry, contents| {
+            let filename = entry.path().to_str().unwrap();
+            if filename.ends_with(".ftl") {
+                check_fluent(filename, contents, bad);
+            } else {
+                check_literals(filename, contents, bad);
+            }
+        },
+    );
+}
@@ -0,0 +1,107 @@
+//! Machine readable output of tidy errors.
+//!
+//! With `--format json`, every error is printed to stdout as a JSON object on its own line:
+//!
+//! ```json
+//! {"check":"style","file":"src/lib.rs","line":3,"message":"trailing whitespace","fixable":false}
+//! ```
+//!
+//! `check` is the name of the check reporting the error. `file` and `line` are `null` if the error
+//! isn't about a specific file or line. `fixable` is `true` for errors of checks which are able to
+//! fix (some of) their errors when running tidy with `--bless`.
+
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+#[cfg(test)]
+mod tests;
+
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Checks which fix (some of) their errors when running tidy with `--bless`.
+const BLESSABLE_CHECKS: &[&str] = &[
+    // tidy-alphabetical-start
+    "alphabetical",
+    "deps",
+    "ext_tool_checks",
+    "fluent_alphabetical",
+    "mir_opt_tests",
+    "run_make_tests",
+    "style",
+    "ui_tests",
+    // tidy-alphabetical-end
+];
+
+/// Report errors as JSON records instead of as text.
+pub fn enable_json_output() {
+    JSON_OUTPUT.store(true, Ordering::Relaxed);
+}
+
+pub(crate) fn is_json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// A tidy error, split into the location it refers to and its message.
+#[derive(Debug, PartialEq)]
+struct Diagnostic<'a> {
+    file: Option<&'a str>,
+    line: Option<usize>,
+    message: &'a str,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Most errors start with their location, e.g. `path/to/file.rs:12: message`.
+    fn parse(error: &'a str) -> Self {
+        if let Some(captures) = static_regex!(r"(?s)^([^\s:]+):(\d+):?\s(.*)$").captures(error) {
+            Diagnostic {
+                file: Some(captures.get(1).unwrap().as_str()),
+                line: captures[2].parse().ok(),
+                message: captures.get(3).unwrap().as_str(),
+            }
+        } else if let Some(captures) = static_regex!(r"(?s)^([^\s:]+\.\w+): (.*)$").captures(error)
+        {
+            Diagnostic {
+                file: Some(captures.get(1).unwrap().as_str()),
+                line: None,
+                message: captures.get(2).unwrap().as_str(),
+            }
+        } else {
+            Diagnostic { file: None, line: None, message: error }
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_record(check: Option<&str>, diagnostic: &Diagnostic<'_>) -> String {
+    let fixable = check.is_some_and(|check| BLESSABLE_CHECKS.contains(&check));
+    format!(
+        r#"{{"check":{},"file":{},"line":{},"message":{},"fixable":{fixable}}}"#,
+        check.map_or("null".to_owned(), json_string),
+        diagnostic.file.map_or("null".to_owned(), json_string),
+        diagnostic.line.map_or("null".to_owned(), |line| line.to_string()),
+        json_string(diagnostic.message),
+    )
+}
+
+pub(crate) fn emit_json(error: &str) -> io::Result<()> {
+    // Checks run on threads named after them, e.g. `style (compiler)`.
+    let thread = thread::current();
+    let check = thread.name().map(|name| name.split_once(" (").map_or(name, |(check, _)| check));
+    writeln!(io::stdout().lock(), "{}", json_record(check, &Diagnostic::parse(error)))
+}
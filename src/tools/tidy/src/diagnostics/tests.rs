@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Diagnostic::parse("src/lib.rs:3: line not in alphabetical order"),
+        Diagnostic {
+            file: Some("src/lib.rs"),
+            line: Some(3),
+            message: "line not in alphabetical order",
+        }
+    );
+    assert_eq!(
+        Diagnostic::parse("src/lib.rs:3 found `tidy-alphabetical-end`"),
+        Diagnostic {
+            file: Some("src/lib.rs"),
+            line: Some(3),
+            message: "found `tidy-alphabetical-end`",
+        }
+    );
+    assert_eq!(
+        Diagnostic::parse("src/lib.rs: reached end of file\nexpecting `tidy-alphabetical-end`"),
+        Diagnostic {
+            file: Some("src/lib.rs"),
+            line: None,
+            message: "reached end of file\nexpecting `tidy-alphabetical-end`",
+        }
+    );
+    assert_eq!(
+        Diagnostic::parse("Found 2 features without a gate test."),
+        Diagnostic { file: None, line: None, message: "Found 2 features without a gate test." }
+    );
+}
+
+#[test]
+fn test_json_record() {
+    let diagnostic =
+        Diagnostic { file: Some("src/lib.rs"), line: Some(3), message: "use \"x\"\n\t" };
+    assert_eq!(
+        json_record(Some("alphabetical"), &diagnostic),
+        concat!(
+            r#"{"check":"alphabetical","file":"src/lib.rs","line":3,"#,
+            r#""message":"use \"x\"\n\u0009","fixable":true}"#,
+        )
+    );
+    let diagnostic = Diagnostic { file: None, line: None, message: "error" };
+    assert_eq!(
+        json_record(None, &diagnostic),
+        r#"{"check":null,"file":null,"line":null,"message":"error","fixable":false}"#,
+    );
+}
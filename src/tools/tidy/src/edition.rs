@@ -2,10 +2,11 @@
 
 use std::path::Path;
 
-use crate::walk::{filter_dirs, walk};
+use crate::walk::{filter_dirs, filter_unmodified, walk};
 
 pub fn check(path: &Path, bad: &mut bool) {
-    walk(path, |path, _is_dir| filter_dirs(path), &mut |entry, contents| {
+    let skip = |path: &Path, is_dir| filter_dirs(path) || filter_unmodified(path, is_dir);
+    walk(path, skip, &mut |entry, contents| {
         let file = entry.path();
         let filename = file.file_name().unwrap();
         if filename != "Cargo.toml" {
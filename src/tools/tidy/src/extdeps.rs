@@ -1,25 +1,122 @@
 //! Check for external package sources. Allow only vendorable packages.
+//!
+//! Packages have to come from crates.io, unless they are listed in `EXCEPTIONS_SOURCES`. Versions
+//! which have been yanked from crates.io are rejected as well, unless they are listed in
+//! `EXCEPTIONS_YANKED`. The licenses of the packages are checked by `deps`.
 
-use std::fs;
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
 
-/// List of allowed sources for packages.
-const ALLOWED_SOURCES: &[&str] = &[
-    r#""registry+https://github.com/rust-lang/crates.io-index""#,
-    // This is `rust_team_data` used by `site` in src/tools/rustc-perf,
-    r#""git+https://github.com/rust-lang/team#a5260e76d3aa894c64c56e6ddc8545b9a98043ec""#,
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+/// Packages which are allowed to come from somewhere else than crates.io, with their source.
+const EXCEPTIONS_SOURCES: &[(&str, &str)] = &[
+    // Used by `site` in src/tools/rustc-perf
+    (
+        "rust_team_data",
+        "git+https://github.com/rust-lang/team#a5260e76d3aa894c64c56e6ddc8545b9a98043ec",
+    ),
 ];
 
+/// Yanked versions which are used anyway, with their package name. Each of them should have a
+/// comment explaining why it can't be updated.
+const EXCEPTIONS_YANKED: &[(&str, &str)] = &[];
+
+/// A `[[package]]` entry of a `Cargo.lock` file.
+struct LockedPackage<'a> {
+    name: &'a str,
+    version: &'a str,
+    source: Option<&'a str>,
+}
+
+fn parse_lockfile(cargo_lock: &str) -> Vec<LockedPackage<'_>> {
+    let mut packages = Vec::new();
+    for entry in cargo_lock.split("[[package]]").skip(1) {
+        let mut package = LockedPackage { name: "", version: "", source: None };
+        for line in entry.lines() {
+            let Some((key, value)) = line.split_once(" = ") else {
+                continue;
+            };
+            let value = value.trim_matches('"');
+            match key {
+                "name" => package.name = value,
+                "version" => package.version = value,
+                "source" => package.source = Some(value),
+                _ => {}
+            }
+        }
+        packages.push(package);
+    }
+    packages
+}
+
+/// Returns the cache directories of the local copies of the crates.io index, which cargo keeps in
+/// `$CARGO_HOME/registry/index`.
+fn crates_io_index_caches() -> Vec<PathBuf> {
+    let cargo_home = env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")));
+    let Some(Ok(entries)) = cargo_home.map(|home| fs::read_dir(home.join("registry/index"))) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let dir_name = entry.file_name();
+            let dir_name = dir_name.to_string_lossy();
+            // Sparse and git index, respectively
+            dir_name.starts_with("index.crates.io-") || dir_name.starts_with("github.com-")
+        })
+        .map(|entry| entry.path().join(".cache"))
+        .collect()
+}
+
+/// Checks if `version` of `name` has been yanked, according to the local copies of the index.
+///
+/// Tidy doesn't access the network, so packages which cargo didn't download the index entry of
+/// are assumed not to be yanked.
+fn is_yanked(index_caches: &[PathBuf], name: &str, version: &str) -> bool {
+    const YANKED: &[u8] = br#""yanked":true"#;
+
+    let name = name.to_lowercase();
+    let dir = match name.len() {
+        1 => PathBuf::from("1"),
+        2 => PathBuf::from("2"),
+        3 => Path::new("3").join(&name[..1]),
+        _ => Path::new(&name[..2]).join(&name[2..4]),
+    };
+    index_caches.iter().any(|cache| {
+        let Ok(contents) = fs::read(cache.join(&dir).join(&name)) else {
+            return false;
+        };
+        // The cache starts with a one byte cache version and a four byte index format version,
+        // followed by null terminated fields: the index file version, then pairs of a version and
+        // its JSON entry.
+        let mut fields = contents.get(5..).unwrap_or_default().split(|&byte| byte == 0).skip(1);
+        while let (Some(entry_version), Some(json)) = (fields.next(), fields.next()) {
+            if entry_version == version.as_bytes() {
+                return json.windows(YANKED.len()).any(|window| window == YANKED);
+            }
+        }
+        false
+    })
+}
+
 /// Checks for external package sources. `root` is the path to the directory that contains the
 /// workspace `Cargo.toml`.
 pub fn check(root: &Path, bad: &mut bool) {
+    let index_caches = crates_io_index_caches();
+    let mut used_source_exceptions = HashSet::new();
+    let mut checked_all_workspaces = true;
+
     for &(workspace, _, _, submodules) in crate::deps::WORKSPACES {
         if crate::deps::has_missing_submodule(root, submodules) {
+            checked_all_workspaces = false;
             continue;
         }
 
-        // FIXME check other workspaces too
-        // `Cargo.lock` of rust.
+        // `Cargo.lock` of the workspace.
         let path = root.join(workspace).join("Cargo.lock");
 
         if !path.exists() {
@@ -30,19 +127,48 @@ pub fn check(root: &Path, bad: &mut bool) {
         // Open and read the whole file.
         let cargo_lock = t!(fs::read_to_string(&path));
 
-        // Process each line.
-        for line in cargo_lock.lines() {
-            // Consider only source entries.
-            if !line.starts_with("source = ") {
+        for package in parse_lockfile(&cargo_lock) {
+            let LockedPackage { name, version, source } = package;
+            let Some(source) = source else {
+                // Local packages are part of the repository.
                 continue;
-            }
+            };
 
-            // Extract source value.
-            let source = line.split_once('=').unwrap().1.trim();
+            if source == CRATES_IO {
+                if is_yanked(&index_caches, name, version)
+                    && !EXCEPTIONS_YANKED.contains(&(name, version))
+                {
+                    tidy_error!(
+                        bad,
+                        "`{name} {version}` in the `{workspace}` workspace has been yanked \
+                        from crates.io\n\
+                        Update it with `cargo update`, or add it to EXCEPTIONS_YANKED if that \
+                        isn't possible."
+                    );
+                }
+            } else if EXCEPTIONS_SOURCES.contains(&(name, source)) {
+                used_source_exceptions.insert(name.to_owned());
+            } else {
+                tidy_error!(
+                    bad,
+                    "invalid source `{source}` of `{name} {version}` in the `{workspace}` \
+                    workspace\n\
+                    Only packages from crates.io are allowed, unless they are added to \
+                    EXCEPTIONS_SOURCES."
+                );
+            }
+        }
+    }
 
-            // Ensure source is allowed.
-            if !ALLOWED_SOURCES.contains(&&*source) {
-                tidy_error!(bad, "invalid source: {}", source);
+    // Exceptions of workspaces in missing submodules can't be checked.
+    if checked_all_workspaces {
+        for (name, _) in EXCEPTIONS_SOURCES {
+            if !used_source_exceptions.contains(*name) {
+                tidy_error!(
+                    bad,
+                    "could not find source exception package `{name}`\n\
+                    Remove from EXCEPTIONS_SOURCES list if it is no longer used."
+                );
             }
         }
     }
@@ -8,8 +8,11 @@
 //! * Library features have at most one `since` value.
 //! * All unstable lang features have tests to ensure they are actually unstable.
 //! * Language features in a group are sorted by feature name.
+//! * All unstable lang features are checked somewhere in the compiler or rustdoc.
+//! * All features enabled by the library with `#![feature]` are declared.
 
 use std::collections::hash_map::{Entry, HashMap};
+use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
@@ -95,6 +98,9 @@ pub fn check(
     let lib_features = get_and_check_lib_features(lib_path, bad, &features);
     assert!(!lib_features.is_empty());
 
+    check_lang_features_used(&features, compiler_path, src_path, bad);
+    check_library_feature_uses(lib_path, &features, &lib_features, bad);
+
     walk_many(
         &[
             &tests_path.join("ui"),
@@ -225,6 +231,114 @@ pub fn check(
     CollectedFeatures { lib: lib_features, lang: features }
 }
 
+/// Checks that all unstable lang features are referenced outside of their declaration, i.e. that
+/// the compiler or rustdoc actually checks if they are enabled.
+fn check_lang_features_used(
+    features: &Features,
+    compiler_path: &Path,
+    src_path: &Path,
+    bad: &mut bool,
+) {
+    let mut unused: HashSet<&str> = features
+        .iter()
+        .filter(|(_, feature)| feature.level == Status::Unstable)
+        .map(|(name, _)| name.as_str())
+        .collect();
+
+    walk_many(
+        &[compiler_path, &src_path.join("librustdoc")],
+        |path, _is_dir| {
+            filter_dirs(path)
+                || filter_not_rust(path)
+                || [
+                    "rustc_feature/src/accepted.rs",
+                    "rustc_feature/src/removed.rs",
+                    "rustc_feature/src/unstable.rs",
+                    // All features are declared as symbols as well
+                    "rustc_span/src/symbol.rs",
+                ]
+                .iter()
+                .any(|file| path.ends_with(file))
+        },
+        &mut |_, contents| {
+            if !unused.is_empty() {
+                for word in contents.split(|c: char| !c.is_alphanumeric() && c != '_') {
+                    unused.remove(word);
+                }
+            }
+        },
+    );
+
+    for name in unused {
+        let feature = &features[name];
+        tidy_error!(
+            bad,
+            "{}:{}: unstable feature `{name}` is declared, but never checked by the compiler",
+            feature.file.display(),
+            feature.line,
+        );
+    }
+}
+
+/// Checks that all features enabled in the library with `#![feature(..)]` are declared, either
+/// as lang features or by a stability attribute in the library.
+fn check_library_feature_uses(
+    lib_path: &Path,
+    lang_features: &Features,
+    lib_features: &Features,
+    bad: &mut bool,
+) {
+    const SUBMODULES: &[&str] = &["backtrace", "portable-simd", "stdarch"];
+
+    // The submodules are skipped when collecting the library features, but their features are
+    // enabled by the library as well.
+    if crate::deps::has_missing_submodule(lib_path, SUBMODULES) {
+        return;
+    }
+    let submodule_paths: Vec<_> =
+        SUBMODULES.iter().map(|submodule| lib_path.join(submodule)).collect();
+    let mut submodule_features = HashSet::new();
+    walk_many(
+        &submodule_paths.iter().map(PathBuf::as_path).collect::<Vec<_>>(),
+        |path, _is_dir| filter_not_rust(path),
+        &mut |_, contents| {
+            for line in contents.lines() {
+                if let Some(name) = find_attr_val(line, "feature") {
+                    submodule_features.insert(name.to_owned());
+                }
+            }
+        },
+    );
+
+    walk(
+        lib_path,
+        |path, _is_dir| filter_dirs(path) || filter_not_rust(path),
+        &mut |entry, contents| {
+            for (i, line) in contents.lines().enumerate() {
+                let Some(names) =
+                    line.strip_prefix("#![feature(").and_then(|names| names.strip_suffix(")]"))
+                else {
+                    continue;
+                };
+                for name in names.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                    if !lang_features.contains_key(name)
+                        && !lib_features.contains_key(name)
+                        && !submodule_features.contains(name)
+                    {
+                        tidy_error!(
+                            bad,
+                            "{}:{}: feature `{name}` is enabled, but not declared as a lang or \
+                            library feature",
+                            entry.path().display(),
+                            i + 1,
+                        );
+                    }
+                }
+            }
+        },
+    );
+}
+
 fn get_version_and_channel(src_path: &Path) -> (Version, String) {
     let version_str = t!(std::fs::read_to_string(src_path.join("version")));
     let version_str = version_str.trim();
@@ -64,7 +64,7 @@ fn check_period(filename: &str, contents: &str, bad: &mut bool) {
 }
 
 /// Evil cursed bad hack. Requires that `value` be a substr (in memory) of `contents`.
-fn find_line(haystack: &str, needle: &str) -> usize {
+pub(crate) fn find_line(haystack: &str, needle: &str) -> usize {
     for (ll, line) in haystack.lines().enumerate() {
         if line.as_ptr() > needle.as_ptr() {
             return ll;
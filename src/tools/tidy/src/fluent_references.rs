@@ -0,0 +1,190 @@
+//! Checks references to Fluent messages and their arguments, which are only resolved at runtime
+//! when the diagnostic is emitted:
+//!
+//! - messages referenced from other messages (`{ message }` or `{ message.attribute }`) have to
+//!   be defined
+//! - the variables (`{ $arg }`) used by a message have to be fields of the diagnostic structs
+//!   using the message
+//!
+//! Duplicated slugs are checked by `fluent_alphabetical`, unused messages by `fluent_used`.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use fluent_syntax::ast::{Entry, Expression, InlineExpression, Pattern, PatternElement};
+
+use crate::walk::{filter_dirs, walk};
+
+#[derive(Default)]
+struct Message {
+    filename: String,
+    attributes: Vec<String>,
+    variables: BTreeSet<String>,
+    /// Referenced messages, with the referenced attribute.
+    references: Vec<(String, Option<String>)>,
+}
+
+fn collect_pattern(pattern: &Pattern<&str>, message: &mut Message) {
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            collect_expression(expression, message);
+        }
+    }
+}
+
+fn collect_expression(expression: &Expression<&str>, message: &mut Message) {
+    match expression {
+        Expression::Select { selector, variants } => {
+            collect_inline_expression(selector, message);
+            for variant in variants {
+                collect_pattern(&variant.value, message);
+            }
+        }
+        Expression::Inline(expression) => collect_inline_expression(expression, message),
+    }
+}
+
+fn collect_inline_expression(expression: &InlineExpression<&str>, message: &mut Message) {
+    match expression {
+        InlineExpression::VariableReference { id } => {
+            message.variables.insert(id.name.to_owned());
+        }
+        InlineExpression::MessageReference { id, attribute } => {
+            let attribute = attribute.as_ref().map(|attribute| attribute.name.to_owned());
+            message.references.push((id.name.to_owned(), attribute));
+        }
+        InlineExpression::FunctionReference { arguments, .. } => {
+            for argument in &arguments.positional {
+                collect_inline_expression(argument, message);
+            }
+            for argument in &arguments.named {
+                collect_inline_expression(&argument.value, message);
+            }
+        }
+        InlineExpression::Placeable { expression } => collect_expression(expression, message),
+        _ => {}
+    }
+}
+
+fn collect_messages(filename: &str, contents: &str, messages: &mut HashMap<String, Message>) {
+    let (Ok(parse) | Err((parse, _))) = fluent_syntax::parser::parse(contents);
+    for entry in &parse.body {
+        let Entry::Message(m) = entry else {
+            continue;
+        };
+        let mut message = Message { filename: filename.to_owned(), ..Message::default() };
+        if let Some(value) = &m.value {
+            collect_pattern(value, &mut message);
+        }
+        for attribute in &m.attributes {
+            message.attributes.push(attribute.id.name.to_owned());
+            collect_pattern(&attribute.value, &mut message);
+        }
+        messages.insert(m.id.name.to_owned(), message);
+    }
+}
+
+/// Checks that the diagnostic structs deriving `Diagnostic` or `LintDiagnostic` have a field for
+/// every variable used by their message.
+fn check_diagnostic_structs(
+    filename: &str,
+    contents: &str,
+    messages: &HashMap<String, Message>,
+    bad: &mut bool,
+) {
+    let diagnostic_struct = static_regex!(
+        r"(?x)
+        \#\[derive\([^)]*\b(?:Diagnostic|LintDiagnostic)\b[^)]*\)\]\s*
+        # the attributes of the struct, including the slug
+        ((?:\#\[[^\]]*\]\s*)*)
+        pub(?:\([^)]*\))?\ struct\ (\w+)[^{;]*\{
+        "
+    );
+    for captures in diagnostic_struct.captures_iter(contents) {
+        // Slugs which aren't defined in the Fluent files are compile errors.
+        let Some(slug) = static_regex!(r"#\[diag\(\s*(\w+)").captures(&captures[1]) else {
+            continue;
+        };
+        let Some(message) = messages.get(&slug[1]) else {
+            continue;
+        };
+
+        let body_start = captures.get(0).unwrap().end();
+        let mut depth = 1;
+        let body_len = contents[body_start..]
+            .find(|c: char| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .unwrap_or(contents.len() - body_start);
+        let body = &contents[body_start..body_start + body_len];
+        // The arguments of subdiagnostics are available to the message as well.
+        if body.contains("#[subdiagnostic]") {
+            continue;
+        }
+        let fields: BTreeSet<&str> = static_regex!(r"(?m)^\s*(?:pub(?:\([^)]*\))? )?(\w+)\s*:")
+            .captures_iter(body)
+            .map(|field| field.get(1).unwrap().as_str())
+            .collect();
+
+        for variable in &message.variables {
+            if !fields.contains(variable.as_str()) {
+                let line = contents[..body_start].lines().count();
+                tidy_error!(
+                    bad,
+                    "{filename}:{line}: `{}` doesn't have a field for the argument `{variable}` \
+                    used by `{}` in {}",
+                    &captures[2],
+                    &slug[1],
+                    message.filename
+                );
+            }
+        }
+    }
+}
+
+pub fn check(path: &Path, bad: &mut bool) {
+    let mut messages = HashMap::new();
+    walk(
+        path,
+        |path, is_dir| {
+            filter_dirs(path) || (!is_dir && path.extension().map_or(true, |ext| ext != "ftl"))
+        },
+        &mut |entry, contents| {
+            collect_messages(entry.path().to_str().unwrap(), contents, &mut messages);
+        },
+    );
+
+    for (name, message) in &messages {
+        for (reference, attribute) in &message.references {
+            let defined = messages.get(reference).is_some_and(|referenced| {
+                attribute.as_ref().map_or(true, |attr| referenced.attributes.contains(attr))
+            });
+            if !defined {
+                let reference = match attribute {
+                    Some(attribute) => format!("{reference}.{attribute}"),
+                    None => reference.clone(),
+                };
+                tidy_error!(
+                    bad,
+                    "{}: message `{name}` refers to `{reference}`, which isn't defined",
+                    message.filename
+                );
+            }
+        }
+    }
+
+    walk(
+        path,
+        |path, is_dir| {
+            filter_dirs(path) || (!is_dir && path.extension().map_or(true, |ext| ext != "rs"))
+        },
+        &mut |entry, contents| {
+            check_diagnostic_structs(entry.path().to_str().unwrap(), contents, &messages, bad);
+        },
+    );
+}
@@ -0,0 +1,102 @@
+//! Checks for large files, and files with binary content, which get committed by accident, e.g.
+//! build artifacts, core dumps or generated files.
+//!
+//! Only files tracked by git are checked, untracked files in the source tree are left alone.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::walk::{filter_dirs, walk_no_read};
+
+/// Files larger than this (in bytes) are rejected, unless they are listed in `EXCEPTIONS`.
+const SIZE_LIMIT: u64 = 512 * 1024;
+
+/// Like git, files with a null byte in the first 8000 bytes are considered to be binary.
+const BINARY_DETECTION_LEN: usize = 8000;
+
+/// Directories which may contain binary files.
+const BINARY_DIRS: &[&str] = &[
+    // tidy-alphabetical-start
+    "src/doc/rustc/src/images",
+    "src/doc/rustdoc/src/images",
+    "src/etc/installer/gfx",
+    "src/librustdoc/html/static/fonts",
+    "src/librustdoc/html/static/images",
+    // tidy-alphabetical-end
+];
+
+/// Files which may be larger than `SIZE_LIMIT` or contain binary content.
+const EXCEPTIONS: &[&str] = &[
+    // tidy-alphabetical-start
+    "RELEASES.md",
+    "src/etc/installer/gfx/dialogbg.bmp",
+    "tests/ui/macros/not-utf8.bin", // testing including non UTF-8 files
+    "tests/ui/parser/bad-char-literals.rs", // testing null bytes and control characters in literals
+    "tests/ui/parser/issues/issue-66473.rs", // testing recovery from null bytes
+    "tests/ui/parser/issues/issue-68629.rs", // testing recovery from null bytes
+    "tests/ui/parser/issues/issue-68730.rs", // testing recovery from null bytes
+    "tests/ui/parser/survive-peano-lesson-queue.rs", // testing deeply nested input
+    "tests/ui/parser/utf16-be-without-bom.rs", // testing UTF-16 source files
+    "tests/ui/parser/utf16-le-without-bom.rs", // testing UTF-16 source files
+    "tests/ui/raw-str.rs",          // testing null bytes in raw strings
+    "tests/ui/rfcs/rfc-3348-c-string-literals/no-nuls.rs", // testing null bytes in C strings
+                                    // tidy-alphabetical-end
+];
+
+fn is_binary(path: &Path) -> bool {
+    let mut start = Vec::with_capacity(BINARY_DETECTION_LEN);
+    let file = t!(File::open(path), path);
+    t!(file.take(BINARY_DETECTION_LEN as u64).read_to_end(&mut start), path);
+    start.contains(&0)
+}
+
+/// Checks if `path` (relative to `root`) is tracked by git. Returns `false` if `root` isn't a git
+/// repository.
+fn is_tracked(root: &Path, path: &str) -> bool {
+    let output = Command::new("git")
+        .args(["ls-files", "--", path])
+        .current_dir(root)
+        .stderr(Stdio::null())
+        .output()
+        .unwrap_or_else(|e| panic!("could not run git ls-files: {e}"));
+    output.status.success() && !output.stdout.is_empty()
+}
+
+pub fn check(root: &Path, bad: &mut bool) {
+    for exception in EXCEPTIONS {
+        if !root.join(exception).exists() {
+            tidy_error!(
+                bad,
+                "`{exception}` doesn't exist anymore, remove it from EXCEPTIONS in \
+                src/tools/tidy/src/large_files.rs"
+            );
+        }
+    }
+
+    walk_no_read(&[root], |path, _is_dir| filter_dirs(path), &mut |entry| {
+        let file = entry.path();
+        let rel_path = file.strip_prefix(root).unwrap();
+        let git_friendly_path = rel_path.to_str().unwrap().replace('\\', "/");
+        if EXCEPTIONS.contains(&git_friendly_path.as_str()) {
+            return;
+        }
+
+        let size = t!(entry.metadata(), file).len();
+        let problem = if size > SIZE_LIMIT {
+            format!("is larger than {} KiB ({} KiB)", SIZE_LIMIT / 1024, size / 1024)
+        } else if !BINARY_DIRS.iter().any(|dir| rel_path.starts_with(dir)) && is_binary(file) {
+            "has binary content".to_owned()
+        } else {
+            return;
+        };
+        if is_tracked(root, &git_friendly_path) {
+            tidy_error!(
+                bad,
+                "{git_friendly_path}: file {problem}, it was probably committed by accident\n\
+                If the file is needed, add it to EXCEPTIONS in src/tools/tidy/src/large_files.rs"
+            );
+        }
+    });
+}
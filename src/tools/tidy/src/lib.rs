@@ -53,7 +53,13 @@ fn tidy_error(args: &str) -> std::io::Result<()> {
 
     use termcolor::{Color, ColorChoice, ColorSpec, StandardStream};
 
-    let mut stderr = StandardStream::stdout(ColorChoice::Auto);
+    if diagnostics::is_json_output() {
+        return diagnostics::emit_json(args);
+    }
+
+    let stdout = StandardStream::stdout(ColorChoice::Auto);
+    // Checks run in parallel, hold the lock so that their errors aren't interleaved.
+    let mut stderr = stdout.lock();
     stderr.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
 
     write!(&mut stderr, "tidy error")?;
@@ -65,8 +71,12 @@ fn tidy_error(args: &str) -> std::io::Result<()> {
 
 pub mod alphabetical;
 pub mod bins;
+pub mod config;
 pub mod debug_artifacts;
+pub mod debug_macros;
 pub mod deps;
+pub mod diagnostic_style;
+pub mod diagnostics;
 pub mod edition;
 pub mod error_codes;
 pub mod ext_tool_checks;
@@ -74,9 +84,11 @@ fn tidy_error(args: &str) -> std::io::Result<()> {
 pub mod features;
 pub mod fluent_alphabetical;
 pub mod fluent_period;
+pub mod fluent_references;
 mod fluent_used;
 pub(crate) mod iter_header;
 pub mod known_bug;
+pub mod large_files;
 pub mod mir_opt_tests;
 pub mod pal;
 pub mod run_make_tests;
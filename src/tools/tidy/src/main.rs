@@ -9,9 +9,12 @@
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 use std::thread::{self, scope, ScopedJoinHandle};
+use std::time::{Duration, Instant};
 use std::{env, process};
 
+use build_helper::git::{get_git_modified_files, get_git_untracked_files, GitConfig};
 use tidy::*;
 
 fn main() {
@@ -45,8 +48,48 @@ fn main() {
     let bless = cfg_args.iter().any(|s| *s == "--bless");
     let extra_checks =
         cfg_args.iter().find(|s| s.starts_with("--extra-checks=")).map(String::as_str);
+    if cfg_args.iter().any(|s| *s == "--only-modified") {
+        fn value<'a>(args: &'a [String], name: &str) -> &'a str {
+            args.iter()
+                .find_map(|arg| arg.strip_prefix(name)?.strip_prefix('='))
+                .unwrap_or_else(|| panic!("`--only-modified` needs `{name}`"))
+        }
+        let git_config = GitConfig {
+            git_repository: value(cfg_args, "--git-repository"),
+            nightly_branch: value(cfg_args, "--nightly-branch"),
+            git_merge_commit_email: value(cfg_args, "--git-merge-commit-email"),
+        };
+        let files =
+            get_git_modified_files(&git_config, Some(&root_path), &[]).and_then(|modified| {
+                // The new files aren't known to git yet, but they're checked too.
+                let untracked = get_git_untracked_files(&git_config, Some(&root_path))?;
+                Ok(modified
+                    .zip(untracked)
+                    .map(|(modified, untracked)| [modified, untracked].concat()))
+            });
+        match files {
+            Ok(Some(files)) => {
+                walk::set_modified_files(files.iter().map(|file| root_path.join(file)).collect())
+            }
+            Ok(None) => eprintln!("tidy: couldn't find the modified files, checking all files"),
+            Err(e) => eprintln!("tidy: couldn't find the modified files, checking all files: {e}"),
+        }
+    }
+    let format = cfg_args
+        .iter()
+        .position(|s| *s == "--format")
+        .map(|pos| cfg_args.get(pos + 1).map_or("", String::as_str));
+    match format {
+        None | Some("human") => {}
+        Some("json") => diagnostics::enable_json_output(),
+        Some(format) => {
+            eprintln!("unknown format `{format}`, expected `human` or `json`");
+            process::exit(1);
+        }
+    }
 
     let bad = std::sync::Arc::new(AtomicBool::new(false));
+    let timings: Mutex<Vec<(String, Duration)>> = Mutex::new(Vec::new());
 
     let drain_handles = |handles: &mut VecDeque<ScopedJoinHandle<'_, ()>>| {
         // poll all threads for completion before awaiting the oldest one
@@ -81,12 +124,15 @@ macro_rules! check {
             (@ $p:ident, name=$name:expr $(, $args:expr)* ) => {
                 drain_handles(&mut handles);
 
-                let handle = thread::Builder::new().name($name).spawn_scoped(s, || {
+                let name = $name;
+                let handle = thread::Builder::new().name(name.clone()).spawn_scoped(s, || {
+                    let start = Instant::now();
                     let mut flag = false;
                     $p::check($($args, )* &mut flag);
                     if (flag) {
                         bad.store(true, Ordering::Relaxed);
                     }
+                    timings.lock().unwrap().push((name, start.elapsed()));
                 }).unwrap();
                 handles.push_back(handle);
             }
@@ -95,7 +141,7 @@ macro_rules! check {
         check!(target_specific_tests, &tests_path);
 
         // Checks that are done on the cargo workspace.
-        check!(deps, &root_path, &cargo);
+        check!(deps, &root_path, &cargo, bless);
         check!(extdeps, &root_path);
 
         // Checks over tests.
@@ -115,6 +161,8 @@ macro_rules! check {
         check!(error_codes, &root_path, &[&compiler_path, &librustdoc_path], verbose);
         check!(fluent_alphabetical, &compiler_path, bless);
         check!(fluent_period, &compiler_path);
+        check!(fluent_references, &compiler_path);
+        check!(diagnostic_style, &compiler_path);
         check!(target_policy, &root_path);
 
         // Checks that only make sense for the std libs.
@@ -128,45 +176,65 @@ macro_rules! check {
         if bins::check_filesystem_support(&[&root_path], &output_directory) {
             check!(bins, &root_path);
         }
+        check!(large_files, &root_path);
+        check!(config, &root_path);
+
+        check!(debug_macros, &compiler_path);
+        check!(debug_macros, &library_path);
 
-        check!(style, &src_path);
-        check!(style, &tests_path);
-        check!(style, &compiler_path);
-        check!(style, &library_path);
+        check!(style, &src_path, bless);
+        check!(style, &tests_path, bless);
+        check!(style, &compiler_path, bless);
+        check!(style, &library_path, bless);
 
         check!(edition, &src_path);
         check!(edition, &compiler_path);
         check!(edition, &library_path);
 
-        check!(alphabetical, &src_path);
-        check!(alphabetical, &tests_path);
-        check!(alphabetical, &compiler_path);
-        check!(alphabetical, &library_path);
+        check!(alphabetical, &src_path, bless);
+        check!(alphabetical, &tests_path, bless);
+        check!(alphabetical, &compiler_path, bless);
+        check!(alphabetical, &library_path, bless);
 
         check!(x_version, &root_path, &cargo);
 
         let collected = {
             drain_handles(&mut handles);
 
-            let mut flag = false;
-            let r = features::check(
-                &src_path,
-                &tests_path,
-                &compiler_path,
-                &library_path,
-                &mut flag,
-                verbose,
-            );
-            if flag {
-                bad.store(true, Ordering::Relaxed);
-            }
-            r
+            // Run on a thread named after the check as well, which is used for the JSON output.
+            let handle = thread::Builder::new().name("features".to_owned()).spawn_scoped(s, || {
+                let start = Instant::now();
+                let mut flag = false;
+                let r = features::check(
+                    &src_path,
+                    &tests_path,
+                    &compiler_path,
+                    &library_path,
+                    &mut flag,
+                    verbose,
+                );
+                if flag {
+                    bad.store(true, Ordering::Relaxed);
+                }
+                timings.lock().unwrap().push(("features".to_owned(), start.elapsed()));
+                r
+            });
+            handle.unwrap().join().unwrap()
         };
         check!(unstable_book, &src_path, collected);
 
         check!(ext_tool_checks, &root_path, &output_directory, bless, extra_checks, pos_args);
     });
 
+    if verbose {
+        let mut timings = timings.into_inner().unwrap();
+        timings.sort_by(|(_, a), (_, b)| b.cmp(a));
+        println!("tidy check durations:");
+        for (name, duration) in timings {
+            println!("{:>10} {name}", format!("{duration:.2?}"));
+        }
+    }
+
     if bad.load(Ordering::Relaxed) {
         eprintln!("some tidy checks failed");
         process::exit(1);
@@ -14,7 +14,8 @@
 //! preferable to be formatted rather than tidy-clean.
 //!
 //! A number of these checks can be opted-out of with various directives of the form:
-//! `// ignore-tidy-CHECK-NAME`.
+//! `// ignore-tidy-CHECK-NAME`. Whole directories can opt out of them, or change the line and file
+//! length limits, with a `.tidy.toml` file, see the `config` module.
 // ignore-tidy-dbg
 
 use std::ffi::OsStr;
@@ -24,7 +25,7 @@
 use regex::RegexSetBuilder;
 use rustc_hash::FxHashMap;
 
-use crate::walk::{filter_dirs, walk};
+use crate::walk::{filter_dirs, filter_unmodified, walk};
 
 #[cfg(test)]
 mod tests;
@@ -221,6 +222,7 @@ fn long_line_is_ok(extension: &str, is_error_code: bool, max_columns: usize, lin
     }
 }
 
+#[derive(Clone, Copy)]
 enum Directive {
     /// By default, tidy always warns against style issues.
     Deny,
@@ -232,6 +234,64 @@ enum Directive {
     Ignore(bool),
 }
 
+/// Fixes trailing whitespace, tabs used for indentation, CRLF line endings and missing or extra
+/// trailing newlines, for the checks which aren't ignored in the file.
+fn fix_whitespace(
+    contents: &str,
+    end_whitespace: bool,
+    tabs: bool,
+    cr: bool,
+    trailing_newlines: bool,
+) -> String {
+    let mut fixed = String::with_capacity(contents.len());
+    for line in contents.split_inclusive('\n') {
+        let (mut line, newline) = match line.strip_suffix('\n') {
+            Some(line) => (line, "\n"),
+            None => (line, ""),
+        };
+        if cr {
+            line = line.strip_suffix('\r').unwrap_or(line);
+        }
+        if end_whitespace {
+            line = line.trim_end_matches(&[' ', '\t'][..]);
+        }
+        if tabs {
+            // Tabs elsewhere may be part of string literals.
+            let indent = line.len() - line.trim_start_matches(&[' ', '\t'][..]).len();
+            fixed.push_str(&line[..indent].replace('\t', "    "));
+            line = &line[indent..];
+        }
+        fixed.push_str(line);
+        fixed.push_str(newline);
+    }
+    if trailing_newlines && !fixed.is_empty() {
+        fixed.truncate(fixed.trim_end_matches('\n').len());
+        fixed.push('\n');
+    }
+    fixed
+}
+
+/// Removes the lines which only consist of an `ignore-tidy-{check}` directive. Returns `None` if
+/// there is no such line, e.g. because the directive is part of a longer comment.
+fn remove_ignore_directive(contents: &str, check: &str) -> Option<String> {
+    let directives = [
+        format!("// ignore-tidy-{check}"),
+        format!("# ignore-tidy-{check}"),
+        format!("/* ignore-tidy-{check} */"),
+        format!("<!-- ignore-tidy-{check} -->"),
+    ];
+    let mut removed = false;
+    let fixed: String = contents
+        .split_inclusive('\n')
+        .filter(|line| {
+            let is_directive = directives.iter().any(|directive| line.trim() == *directive);
+            removed |= is_directive;
+            !is_directive
+        })
+        .collect();
+    removed.then_some(fixed)
+}
+
 fn contains_ignore_directive(can_contain: bool, contents: &str, check: &str) -> Directive {
     if !can_contain {
         return Directive::Deny;
@@ -304,14 +364,16 @@ fn is_unexplained_ignore(extension: &str, line: &str) -> bool {
     true
 }
 
-pub fn check(path: &Path, bad: &mut bool) {
+/// Checks the style of the files in `path`. With `bless`, whitespace problems and unnecessary
+/// `ignore-tidy-*` directives are fixed instead of being reported.
+pub fn check(path: &Path, bless: bool, bad: &mut bool) {
     fn skip(path: &Path, is_dir: bool) -> bool {
         if path.file_name().map_or(false, |name| name.to_string_lossy().starts_with(".#")) {
             // vim or emacs temporary file
             return true;
         }
 
-        if filter_dirs(path) || skip_markdown_path(path) {
+        if filter_dirs(path) || skip_markdown_path(path) || filter_unmodified(path, is_dir) {
             return true;
         }
 
@@ -359,13 +421,15 @@ fn skip(path: &Path, is_dir: bool) -> bool {
         let is_error_code = extension == "md" && is_in(file, "src", "error_codes");
         let is_goml_code = extension == "goml";
 
-        let max_columns = if is_error_code {
+        let config = crate::config::for_file(file);
+        let max_columns = config.line_length.unwrap_or(if is_error_code {
             ERROR_CODE_COLS
         } else if is_goml_code {
             GOML_COLS
         } else {
             COLS
-        };
+        });
+        let max_lines = config.file_length.unwrap_or(LINES);
 
         let can_contain = contents.contains("// ignore-tidy-")
             || contents.contains("# ignore-tidy-")
@@ -376,28 +440,41 @@ fn skip(path: &Path, is_dir: bool) -> bool {
         if filename.contains("ignore-tidy") {
             return;
         }
-        // Shell completions are automatically generated
-        if let Some(p) = file.parent() {
-            if p.ends_with(Path::new("src/etc/completions")) {
-                return;
+        if config.generated {
+            return;
+        }
+        // Checks ignored by a `.tidy.toml` apply to many files, so they're never unnecessary.
+        let directive = |check| {
+            if config.ignores(check) {
+                Directive::Ignore(true)
+            } else {
+                contains_ignore_directive(can_contain, &contents, check)
             }
+        };
+        let mut skip_cr = directive("cr");
+        let mut skip_undocumented_unsafe = directive("undocumented-unsafe");
+        let mut skip_tab = directive("tab");
+        let mut skip_line_length = directive("linelength");
+        let mut skip_file_length = directive("filelength");
+        let mut skip_end_whitespace = directive("end-whitespace");
+        let mut skip_trailing_newlines = directive("trailing-newlines");
+        let mut skip_leading_newlines = directive("leading-newlines");
+        let mut skip_copyright = directive("copyright");
+        let mut skip_dbg = directive("dbg");
+        let mut skip_odd_backticks = directive("odd-backticks");
+        let original = contents;
+        let mut fixed = None;
+        if bless {
+            let deny = |skip: Directive| matches!(skip, Directive::Deny);
+            fixed = Some(fix_whitespace(
+                contents,
+                deny(skip_end_whitespace),
+                deny(skip_tab) && !is_style_file,
+                deny(skip_cr),
+                deny(skip_trailing_newlines),
+            ));
         }
-        let mut skip_cr = contains_ignore_directive(can_contain, &contents, "cr");
-        let mut skip_undocumented_unsafe =
-            contains_ignore_directive(can_contain, &contents, "undocumented-unsafe");
-        let mut skip_tab = contains_ignore_directive(can_contain, &contents, "tab");
-        let mut skip_line_length = contains_ignore_directive(can_contain, &contents, "linelength");
-        let mut skip_file_length = contains_ignore_directive(can_contain, &contents, "filelength");
-        let mut skip_end_whitespace =
-            contains_ignore_directive(can_contain, &contents, "end-whitespace");
-        let mut skip_trailing_newlines =
-            contains_ignore_directive(can_contain, &contents, "trailing-newlines");
-        let mut skip_leading_newlines =
-            contains_ignore_directive(can_contain, &contents, "leading-newlines");
-        let mut skip_copyright = contains_ignore_directive(can_contain, &contents, "copyright");
-        let mut skip_dbg = contains_ignore_directive(can_contain, &contents, "dbg");
-        let mut skip_odd_backticks =
-            contains_ignore_directive(can_contain, &contents, "odd-backticks");
+        let contents = fixed.as_deref().unwrap_or(original);
         let mut leading_new_lines = false;
         let mut trailing_new_lines = 0;
         let mut lines = 0;
@@ -435,11 +512,11 @@ fn skip(path: &Path, is_dir: bool) -> bool {
 
             if trimmed.contains("dbg!")
                 && !trimmed.starts_with("//")
-                && !file.ancestors().any(|a| {
-                    (a.ends_with("tests") && a.join("COMPILER_TESTS.md").exists())
-                        || a.ends_with("library/alloc/tests")
-                })
+                && !file
+                    .ancestors()
+                    .any(|a| a.ends_with("tests") && a.join("COMPILER_TESTS.md").exists())
                 && filename != "tests.rs"
+                && !trimmed.contains(crate::debug_macros::ALLOW_DIRECTIVE)
             {
                 suppressible_tidy_err!(
                     err,
@@ -592,7 +669,7 @@ fn skip(path: &Path, is_dir: bool) -> bool {
                 "too many trailing newlines ({n})"
             ),
         };
-        if lines > LINES {
+        if lines > max_lines {
             let mut err = |_| {
                 tidy_error!(
                     bad,
@@ -605,23 +682,29 @@ fn skip(path: &Path, is_dir: bool) -> bool {
             suppressible_tidy_err!(err, skip_file_length, "");
         }
 
-        if let Directive::Ignore(false) = skip_cr {
-            tidy_error!(bad, "{}: ignoring CR characters unnecessarily", file.display());
-        }
-        if let Directive::Ignore(false) = skip_tab {
-            tidy_error!(bad, "{}: ignoring tab characters unnecessarily", file.display());
-        }
-        if let Directive::Ignore(false) = skip_end_whitespace {
-            tidy_error!(bad, "{}: ignoring trailing whitespace unnecessarily", file.display());
-        }
-        if let Directive::Ignore(false) = skip_trailing_newlines {
-            tidy_error!(bad, "{}: ignoring trailing newlines unnecessarily", file.display());
-        }
-        if let Directive::Ignore(false) = skip_leading_newlines {
-            tidy_error!(bad, "{}: ignoring leading newlines unnecessarily", file.display());
+        let unnecessary_directives = [
+            (skip_cr, "cr", "CR characters"),
+            (skip_tab, "tab", "tab characters"),
+            (skip_end_whitespace, "end-whitespace", "trailing whitespace"),
+            (skip_trailing_newlines, "trailing-newlines", "trailing newlines"),
+            (skip_leading_newlines, "leading-newlines", "leading newlines"),
+            (skip_copyright, "copyright", "copyright"),
+        ];
+        for (skip, check, ignored) in unnecessary_directives {
+            let Directive::Ignore(false) = skip else {
+                continue;
+            };
+            let removed = fixed.as_deref().and_then(|fixed| remove_ignore_directive(fixed, check));
+            if removed.is_some() {
+                fixed = removed;
+            } else {
+                tidy_error!(bad, "{}: ignoring {ignored} unnecessarily", file.display());
+            }
         }
-        if let Directive::Ignore(false) = skip_copyright {
-            tidy_error!(bad, "{}: ignoring copyright unnecessarily", file.display());
+        if let Some(fixed) = fixed {
+            if fixed != original {
+                t!(std::fs::write(file, fixed), file);
+            }
         }
         // We deliberately do not warn about these being unnecessary,
         // that would just lead to annoying churn.
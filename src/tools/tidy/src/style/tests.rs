@@ -8,3 +8,30 @@ fn test_contains_problematic_const() {
     assert!(contains_problematic_const(&format!("{:x}B5", 2816))); // check for case-alternating hex display
     assert!(!contains_problematic_const("1193046")); // check for non-matching value
 }
+
+#[test]
+fn test_fix_whitespace() {
+    let contents = "fn main() {\r\n\tlet x = \"\t\"; \n\t \t// comment\t\n}\n\n\n";
+    assert_eq!(
+        fix_whitespace(contents, true, true, true, true),
+        "fn main() {\n    let x = \"\t\";\n         // comment\n}\n"
+    );
+    assert_eq!(fix_whitespace(contents, false, false, false, false), contents);
+    assert_eq!(fix_whitespace("fn main() {}", true, true, true, true), "fn main() {}\n");
+    assert_eq!(fix_whitespace("a \r\nb", false, false, true, false), "a \nb");
+}
+
+#[test]
+fn test_remove_ignore_directive() {
+    // Split up, so that the directives don't apply to this file.
+    let tidy = "tidy";
+    let contents = format!("// ignore-{tidy}-tab\n// ignore-{tidy}-linelength\nfn main() {{}}\n");
+    assert_eq!(
+        remove_ignore_directive(&contents, "tab"),
+        Some(format!("// ignore-{tidy}-linelength\nfn main() {{}}\n"))
+    );
+    let contents = format!("<!-- ignore-{tidy}-cr -->\n# Title\n");
+    assert_eq!(remove_ignore_directive(&contents, "cr").as_deref(), Some("# Title\n"));
+    let contents = format!("// ignore-{tidy}-tab because of the table\n");
+    assert_eq!(remove_ignore_directive(&contents, "tab"), None);
+}
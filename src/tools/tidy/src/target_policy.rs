@@ -1,8 +1,12 @@
 //! Tests for target tier policy compliance.
 //!
-//! As of writing, only checks that sanity-check assembly test for targets doesn't miss any targets.
+//! Checks that the sanity-check assembly test for targets doesn't miss any targets, and that the
+//! platform support docs are in sync with the target specs: every target is listed with the tier
+//! from its `TargetMetadata`, the linked pages exist and name the target maintainers, and there
+//! are no docs left for removed targets.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::Path;
 
 use crate::walk::{filter_not_rust, walk};
@@ -10,6 +14,10 @@
 const TARGET_DEFINITIONS_PATH: &str = "compiler/rustc_target/src/spec/targets/";
 const ASSEMBLY_TEST_PATH: &str = "tests/assembly/targets/";
 const REVISION_LINE_START: &str = "//@ revisions: ";
+const TARGET_LIST_PATH: &str = "compiler/rustc_target/src/spec/mod.rs";
+const TARGET_BASES_PATH: &str = "compiler/rustc_target/src/spec/base/";
+const PLATFORM_SUPPORT_PATH: &str = "src/doc/rustc/src/platform-support.md";
+const PLATFORM_SUPPORT_PAGES_PATH: &str = "src/doc/rustc/src/platform-support/";
 const EXCEPTIONS: &[&str] = &[
     // FIXME: disabled since it fails on CI saying the csky component is missing
     "csky_unknown_linux_gnuabiv2",
@@ -23,7 +31,18 @@
     "xtensa_esp32s3_espidf",
 ];
 
+/// Pages in `PLATFORM_SUPPORT_PAGES_PATH` which don't need to name the target maintainers.
+const EXCEPTIONS_MAINTAINERS: &[&str] = &[
+    // Overview of the Arm targets, their maintainers are named on the pages of the targets.
+    "arm-none-eabi.md",
+];
+
 pub fn check(root_path: &Path, bad: &mut bool) {
+    check_assembly_tests(root_path, bad);
+    check_platform_support(root_path, bad);
+}
+
+fn check_assembly_tests(root_path: &Path, bad: &mut bool) {
     let mut targets_to_find = HashSet::new();
 
     let definitions_path = root_path.join(TARGET_DEFINITIONS_PATH);
@@ -59,3 +78,166 @@ pub fn check(root_path: &Path, bad: &mut bool) {
         }
     }
 }
+
+/// A target listed in `PLATFORM_SUPPORT_PATH`.
+struct DocumentedTarget {
+    line: usize,
+    tier: u64,
+    page: Option<String>,
+}
+
+fn check_platform_support(root_path: &Path, bad: &mut bool) {
+    let target_list = t!(fs::read_to_string(root_path.join(TARGET_LIST_PATH)));
+    let Some((_, target_list)) = target_list.split_once("supported_targets! {") else {
+        tidy_error!(bad, "{TARGET_LIST_PATH}: couldn't find `supported_targets!`");
+        return;
+    };
+    let targets: Vec<(&str, &str)> = target_list
+        .lines()
+        .take_while(|line| *line != "}")
+        .filter_map(|line| static_regex!(r#"^\s*\("([^"]+)", (\w+)\),"#).captures(line))
+        .map(|captures| (captures.get(1).unwrap().as_str(), captures.get(2).unwrap().as_str()))
+        .collect();
+
+    let platform_support = t!(fs::read_to_string(root_path.join(PLATFORM_SUPPORT_PATH)));
+    let mut documented = HashMap::new();
+    let mut tier = None;
+    for (i, line) in platform_support.lines().enumerate() {
+        let line_number = i + 1;
+        if let Some(captures) = static_regex!(r"^## Tier (\d)").captures(line) {
+            tier = Some(captures[1].parse().unwrap());
+            continue;
+        }
+        // Rows of the target tables, e.g. "[`target`](platform-support/target.md) | notes"
+        let (Some(tier), Some(captures)) =
+            (tier, static_regex!(r"^\[?`([^`]+)`\]?(?:\(([^)]+)\))?\s*\|").captures(line))
+        else {
+            continue;
+        };
+        let name = captures.get(1).unwrap().as_str();
+        let page = captures.get(2).map(|page| page.as_str().split('#').next().unwrap().to_owned());
+        let target = DocumentedTarget { line: line_number, tier, page };
+        if documented.insert(name, target).is_some() {
+            tidy_error!(bad, "{PLATFORM_SUPPORT_PATH}:{line_number}: `{name}` is listed twice");
+        }
+    }
+
+    for &(name, module) in &targets {
+        let spec_path = root_path.join(TARGET_DEFINITIONS_PATH).join(format!("{module}.rs"));
+        let spec_tier = spec_tier(root_path, &spec_path);
+        let spec_path = spec_path.strip_prefix(root_path).unwrap().display();
+        let Some(target) = documented.get(name) else {
+            tidy_error!(bad, "{PLATFORM_SUPPORT_PATH}: missing target `{name}`");
+            continue;
+        };
+        match spec_tier {
+            None => tidy_error!(
+                bad,
+                "{spec_path}: missing tier of `{name}`, set the `tier` of its `TargetMetadata`"
+            ),
+            Some(spec_tier) if spec_tier != target.tier => tidy_error!(
+                bad,
+                "{spec_path}: `{name}` is a tier {spec_tier} target, but \
+                {PLATFORM_SUPPORT_PATH}:{} lists it as tier {}",
+                target.line,
+                target.tier
+            ),
+            Some(_) => {}
+        }
+    }
+
+    let target_names: HashSet<&str> = targets.iter().map(|&(name, _)| name).collect();
+    let mut linked_pages = HashSet::new();
+    for (name, target) in &documented {
+        if !target_names.contains(name) {
+            tidy_error!(
+                bad,
+                "{PLATFORM_SUPPORT_PATH}:{}: `{name}` isn't a target (anymore), remove it",
+                target.line
+            );
+        }
+        if let Some(page) = &target.page {
+            if !root_path.join(PLATFORM_SUPPORT_PATH).with_file_name(page).is_file() {
+                tidy_error!(
+                    bad,
+                    "{PLATFORM_SUPPORT_PATH}:{}: the page `{page}` of `{name}` doesn't exist",
+                    target.line
+                );
+            }
+            linked_pages.insert(page.trim_start_matches("platform-support/"));
+        }
+    }
+
+    let mut used_exceptions = HashSet::new();
+    for entry in t!(fs::read_dir(root_path.join(PLATFORM_SUPPORT_PAGES_PATH))) {
+        let path = t!(entry).path();
+        let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+        if file_name == "TEMPLATE.md" {
+            continue;
+        }
+        if !linked_pages.contains(file_name.as_str()) {
+            tidy_error!(
+                bad,
+                "{PLATFORM_SUPPORT_PAGES_PATH}{file_name}: page isn't linked from any target in \
+                {PLATFORM_SUPPORT_PATH}, remove it if its targets don't exist anymore"
+            );
+        }
+        if !names_maintainers(&t!(fs::read_to_string(&path), &path)) {
+            if EXCEPTIONS_MAINTAINERS.contains(&file_name.as_str()) {
+                used_exceptions.insert(file_name);
+            } else {
+                tidy_error!(
+                    bad,
+                    "{PLATFORM_SUPPORT_PAGES_PATH}{file_name}: missing a \"Target maintainers\" \
+                    section (see TEMPLATE.md)"
+                );
+            }
+        }
+    }
+    for exception in EXCEPTIONS_MAINTAINERS {
+        if !used_exceptions.contains(*exception) {
+            tidy_error!(
+                bad,
+                "{PLATFORM_SUPPORT_PAGES_PATH}{exception} names its maintainers now, remove it \
+                from EXCEPTIONS_MAINTAINERS"
+            );
+        }
+    }
+}
+
+/// Returns the tier set in the `TargetMetadata` of the target spec (or target base) at `path`. If
+/// the spec doesn't set the tier itself, it's taken from the spec it is derived from.
+fn spec_tier(root_path: &Path, path: &Path) -> Option<u64> {
+    let contents = t!(fs::read_to_string(path), path);
+    if let Some(tier) =
+        static_regex!(r"tier(?:: | = )(?:None|Some\((\d)\))").captures_iter(&contents).last()
+    {
+        return tier.get(1).map(|tier| tier.as_str().parse().unwrap());
+    }
+    if let Some(captures) =
+        static_regex!(r"(?:super|crate::spec::targets)::(\w+)::target").captures(&contents)
+    {
+        let base = root_path.join(TARGET_DEFINITIONS_PATH).join(format!("{}.rs", &captures[1]));
+        spec_tier(root_path, &base)
+    } else if let Some(captures) = static_regex!(r"base::(\w+)::target\(").captures(&contents) {
+        let base = root_path.join(TARGET_BASES_PATH).join(format!("{}.rs", &captures[1]));
+        spec_tier(root_path, &base)
+    } else {
+        None
+    }
+}
+
+/// Checks if the page has a non-empty section about the target maintainers. Some older pages call
+/// them "Designated Developers".
+fn names_maintainers(page: &str) -> bool {
+    let mut in_section = false;
+    for line in page.lines() {
+        if line.starts_with('#') {
+            let heading = line.to_lowercase();
+            in_section = heading.contains("maintainer") || heading.contains("designated developer");
+        } else if in_section && !line.trim().is_empty() {
+            return true;
+        }
+    }
+    false
+}
@@ -1,5 +1,7 @@
 //! Tidy check to ensure below in UI test directories:
-//! - the number of entries in each directory must be less than `ENTRY_LIMIT`
+//! - the number of entries in each directory must be less than `ENTRY_LIMIT`, or the limit of the
+//!   directory in `ENTRY_LIMIT_EXCEPTIONS`. Subdirectories for the tests are suggested when a
+//!   directory has too many entries.
 //! - there are no stray `.stderr` files
 
 use std::collections::{BTreeSet, HashMap};
@@ -10,14 +12,19 @@
 
 use ignore::Walk;
 
+#[cfg(test)]
+mod tests;
+
 // FIXME: GitHub's UI truncates file lists that exceed 1000 entries, so these
 // should all be 1000 or lower. Limits significantly smaller than 1000 are also
 // desirable, because large numbers of files are unwieldy in general. See issue
 // #73494.
 const ENTRY_LIMIT: u32 = 901;
-// FIXME: The following limits should be reduced eventually.
 
-const ISSUES_ENTRY_LIMIT: u32 = 1673;
+/// Directories in `tests/ui` which may contain more than `ENTRY_LIMIT` entries, with their own
+/// limit. The limits have to be lowered whenever tests are moved out of the directories.
+// FIXME: The following limits should be reduced eventually.
+const ENTRY_LIMIT_EXCEPTIONS: &[(&str, u32)] = &[("issues", 1673)];
 
 const EXPECTED_TEST_FILE_EXTENSIONS: &[&str] = &[
     "rs",     // test source files
@@ -54,42 +61,98 @@
     "tests/ui/std/windows-bat-args3.bat", // tests escaping arguments through batch files
 ];
 
+#[derive(Default)]
+struct DirectoryEntries {
+    count: u32,
+    file_names: Vec<String>,
+}
+
 fn check_entries(tests_path: &Path, bad: &mut bool) {
-    let mut directories: HashMap<PathBuf, u32> = HashMap::new();
+    let mut directories: HashMap<PathBuf, DirectoryEntries> = HashMap::new();
 
     for dir in Walk::new(&tests_path.join("ui")) {
         if let Ok(entry) = dir {
             let parent = entry.path().parent().unwrap().to_path_buf();
-            *directories.entry(parent).or_default() += 1;
+            let entries = directories.entry(parent).or_default();
+            entries.count += 1;
+            if entry.file_type().is_some_and(|file_type| file_type.is_file()) {
+                entries.file_names.push(entry.file_name().to_string_lossy().into_owned());
+            }
         }
     }
 
-    let (mut max, mut max_issues) = (0, 0);
-    for (dir_path, count) in directories {
-        let is_issues_dir = tests_path.join("ui/issues") == dir_path;
-        let (limit, maxcnt) = if is_issues_dir {
-            (ISSUES_ENTRY_LIMIT, &mut max_issues)
-        } else {
-            (ENTRY_LIMIT, &mut max)
+    let ui_path = tests_path.join("ui");
+    let mut max_counts = vec![0; ENTRY_LIMIT_EXCEPTIONS.len()];
+    for (dir_path, entries) in directories {
+        let count = entries.count;
+        let exception =
+            ENTRY_LIMIT_EXCEPTIONS.iter().position(|(dir, _)| ui_path.join(dir) == dir_path);
+        let limit = match exception {
+            Some(i) => {
+                max_counts[i] = max_counts[i].max(count);
+                ENTRY_LIMIT_EXCEPTIONS[i].1
+            }
+            None => ENTRY_LIMIT,
         };
-        *maxcnt = (*maxcnt).max(count);
         if count > limit {
+            let dir_name = dir_path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+            let mut suggestions = String::new();
+            for (prefix, tests) in subdirectory_suggestions(dir_name, &entries.file_names) {
+                if suggestions.is_empty() {
+                    suggestions
+                        .push_str("\nsubdirectories for tests with a common prefix could be:");
+                }
+                suggestions.push_str(&format!("\n  - `{prefix}` ({tests} tests"));
+                if dir_path.join(prefix).is_dir() {
+                    suggestions.push_str(", the directory exists already");
+                }
+                suggestions.push(')');
+            }
             tidy_error!(
                 bad,
                 "following path contains more than {} entries, \
-                    you should move the test to some relevant subdirectory (current: {}): {}",
+                    you should move the test to some relevant subdirectory (current: {}): {}{}",
                 limit,
                 count,
-                dir_path.display()
+                dir_path.display(),
+                suggestions
             );
         }
     }
-    if ISSUES_ENTRY_LIMIT > max_issues {
-        tidy_error!(
-            bad,
-            "`ISSUES_ENTRY_LIMIT` is too high (is {ISSUES_ENTRY_LIMIT}, should be {max_issues})"
-        );
+    for (&(dir, limit), max) in ENTRY_LIMIT_EXCEPTIONS.iter().zip(max_counts) {
+        if limit > max {
+            tidy_error!(
+                bad,
+                "the limit of `tests/ui/{dir}` in `ENTRY_LIMIT_EXCEPTIONS` is too high \
+                (is {limit}, should be {max})"
+            );
+        }
+    }
+}
+
+/// Returns the most common first words of the names of the tests in a directory, with the number
+/// of tests starting with them. Words which don't tell what a test is about, like `issue`, issue
+/// numbers or the name of the directory itself, are skipped.
+fn subdirectory_suggestions<'a>(dir_name: &str, file_names: &'a [String]) -> Vec<(&'a str, usize)> {
+    // Count every test once, not each of its `.rs`, `.stderr`, ... files.
+    let tests: BTreeSet<&str> =
+        file_names.iter().map(|name| name.split('.').next().unwrap()).collect();
+    let mut prefixes: HashMap<&str, usize> = HashMap::new();
+    for test in tests {
+        let prefix = test.split(['-', '_']).find(|word| {
+            word.len() > 2
+                && *word != "issue"
+                && *word != dir_name
+                && !word.bytes().all(|byte| byte.is_ascii_digit())
+        });
+        if let Some(prefix) = prefix {
+            *prefixes.entry(prefix).or_default() += 1;
+        }
     }
+    let mut prefixes: Vec<_> = prefixes.into_iter().filter(|&(_, tests)| tests > 1).collect();
+    prefixes.sort_by(|(a, a_tests), (b, b_tests)| b_tests.cmp(a_tests).then(a.cmp(b)));
+    prefixes.truncate(3);
+    prefixes
 }
 
 pub fn check(root_path: &Path, bless: bool, bad: &mut bool) {
@@ -0,0 +1,44 @@
+use super::*;
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_subdirectory_suggestions() {
+    let file_names = names(&[
+        "keyword-fn.rs",
+        "keyword-fn.stderr",
+        "keyword-let.rs",
+        "keyword_in.rs",
+        "pat-or.rs",
+        "pat-range.rs",
+        "pat-range.fixed",
+        "unique.rs",
+    ]);
+    assert_eq!(subdirectory_suggestions("parser", &file_names), [("keyword", 3), ("pat", 2)]);
+}
+
+#[test]
+fn test_subdirectory_suggestions_skip_words() {
+    let file_names = names(&[
+        "issue-12345-closure-move.rs",
+        "issue-23456-closure.rs",
+        "borrowck-move-out.rs",
+        "borrowck-move.rs",
+        "42-in.rs",
+        "42-in-loop.rs",
+    ]);
+    assert_eq!(subdirectory_suggestions("borrowck", &file_names), [("closure", 2), ("move", 2)]);
+}
+
+#[test]
+fn test_subdirectory_suggestions_limit() {
+    let file_names = names(&["a-x.rs", "aaa-x.rs", "aaa-y.rs", "bbb-x.rs", "bbb-y.rs", "bbb-z.rs"]);
+    assert_eq!(subdirectory_suggestions("ui", &file_names), [("bbb", 3), ("aaa", 2)]);
+    let file_names = names(&[
+        "aaa-1.rs", "aaa-2.rs", "bbb-1.rs", "bbb-2.rs", "ccc-1.rs", "ccc-2.rs", "ddd-1.rs",
+        "ddd-2.rs",
+    ]);
+    assert_eq!(subdirectory_suggestions("ui", &file_names), [("aaa", 2), ("bbb", 2), ("ccc", 2)]);
+}
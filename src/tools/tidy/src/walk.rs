@@ -1,7 +1,9 @@
+use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fs::File;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use ignore::DirEntry;
 
@@ -41,6 +43,21 @@ pub fn filter_dirs(path: &Path) -> bool {
     skip.iter().any(|p| path.ends_with(p))
 }
 
+static MODIFIED_FILES: OnceLock<HashSet<PathBuf>> = OnceLock::new();
+
+/// Restricts the checks using `filter_unmodified` to `files`, i.e. the files modified in the
+/// current branch.
+pub fn set_modified_files(files: HashSet<PathBuf>) {
+    MODIFIED_FILES.set(files).expect("modified files are already set");
+}
+
+/// Filter for files which haven't been modified, if tidy only checks the modified files. This is
+/// only used by the checks looking at each file on its own, checks which need to see the whole
+/// tree don't use it.
+pub fn filter_unmodified(path: &Path, is_dir: bool) -> bool {
+    !is_dir && MODIFIED_FILES.get().is_some_and(|files| !files.contains(path))
+}
+
 /// Filter for only files that end in `.rs`.
 pub fn filter_not_rust(path: &Path) -> bool {
     path.extension() != Some(OsStr::new("rs")) && !path.is_dir()